@@ -1,9 +1,10 @@
 use sp_core::{Pair, Public, sr25519, crypto::UncheckedInto};
 use subsocial_runtime::{
-	AccountId, AuraConfig, BalancesConfig,
+	AccountId, AuraConfig, BalancesConfig, CallFilterConfig,
 	GenesisConfig, GrandpaConfig, UtilsConfig,
 	SudoConfig, SpacesConfig, SystemConfig,
 	WASM_BINARY, Signature, constants::currency::DOLLARS,
+	initially_paused_pallets, initially_paused_calls,
 };
 use subsocial_primitives::Block;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -207,6 +208,10 @@ fn testnet_genesis(
         },
 		spaces: SpacesConfig {
             endowed_account: root_key,
+        },
+		call_filter: CallFilterConfig {
+            paused_pallets: initially_paused_pallets(),
+            paused_calls: initially_paused_calls(),
         },
 	}
 }
@@ -33,26 +33,40 @@ pub fn create_full<C, P>(deps: FullDeps<C, P>) -> jsonrpc_core::IoHandler<sc_rpc
         C: Send + Sync + 'static,
         C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
         C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+        C::Api: call_filter_rpc::CallFilterRuntimeApi<Block>,
+        C::Api: energy_rpc::EnergyRuntimeApi<Block, AccountId, Balance>,
+        C::Api: post_history_rpc::PostHistoryRuntimeApi<Block, AccountId, BlockNumber>,
         C::Api: posts_rpc::PostsRuntimeApi<Block, AccountId, BlockNumber>,
         C::Api: profile_follows_rpc::ProfileFollowsRuntimeApi<Block, AccountId>,
+        C::Api: profile_history_rpc::ProfileHistoryRuntimeApi<Block, AccountId, BlockNumber>,
         C::Api: profiles_rpc::ProfilesRuntimeApi<Block, AccountId, BlockNumber>,
         C::Api: reactions_rpc::ReactionsRuntimeApi<Block, AccountId, BlockNumber>,
         C::Api: roles_rpc::RolesRuntimeApi<Block, AccountId>,
         C::Api: space_follows_rpc::SpaceFollowsRuntimeApi<Block, AccountId>,
+        C::Api: space_history_rpc::SpaceHistoryRuntimeApi<Block, AccountId, BlockNumber>,
+        C::Api: space_ownership_rpc::SpaceOwnershipRuntimeApi<Block, AccountId, BlockNumber>,
         C::Api: spaces_rpc::SpacesRuntimeApi<Block, AccountId, BlockNumber>,
+        C::Api: utils_rpc::UtilsRuntimeApi<Block, AccountId, Balance>,
         C::Api: BlockBuilder<Block>,
         P: TransactionPool + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
     use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
+    use call_filter_rpc::{CallFilter, CallFilterApi};
+    use energy_rpc::{Energy, EnergyApi};
+    use post_history_rpc::{PostHistory, PostHistoryApi};
     use posts_rpc::{Posts, PostsApi};
     use profile_follows_rpc::{ProfileFollows, ProfileFollowsApi};
+    use profile_history_rpc::{ProfileHistory, ProfileHistoryApi};
     use profiles_rpc::{Profiles, ProfilesApi};
     use reactions_rpc::{Reactions, ReactionsApi};
     use roles_rpc::{Roles, RolesApi};
     use space_follows_rpc::{SpaceFollows, SpaceFollowsApi};
+    use space_history_rpc::{SpaceHistory, SpaceHistoryApi};
+    use space_ownership_rpc::{SpaceOwnership, SpaceOwnershipApi};
     use spaces_rpc::{Spaces, SpacesApi};
+    use utils_rpc::{Utils, UtilsApi};
 
     let mut io = jsonrpc_core::IoHandler::default();
     let FullDeps { client, pool, deny_unsafe } = deps;
@@ -61,19 +75,33 @@ pub fn create_full<C, P>(deps: FullDeps<C, P>) -> jsonrpc_core::IoHandler<sc_rpc
 
     io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone())));
 
+    io.extend_with(CallFilterApi::to_delegate(CallFilter::new(client.clone())));
+
+    io.extend_with(EnergyApi::to_delegate(Energy::new(client.clone())));
+
     io.extend_with(SpacesApi::to_delegate(Spaces::new(client.clone())));
 
+    io.extend_with(SpaceHistoryApi::to_delegate(SpaceHistory::new(client.clone())));
+
     io.extend_with(SpaceFollowsApi::to_delegate(SpaceFollows::new(client.clone())));
 
+    io.extend_with(SpaceOwnershipApi::to_delegate(SpaceOwnership::new(client.clone())));
+
     io.extend_with(PostsApi::to_delegate(Posts::new(client.clone())));
 
+    io.extend_with(PostHistoryApi::to_delegate(PostHistory::new(client.clone())));
+
     io.extend_with(ProfileFollowsApi::to_delegate(ProfileFollows::new(client.clone())));
 
     io.extend_with(ProfilesApi::to_delegate(Profiles::new(client.clone())));
 
+    io.extend_with(ProfileHistoryApi::to_delegate(ProfileHistory::new(client.clone())));
+
     io.extend_with(ReactionsApi::to_delegate(Reactions::new(client.clone())));
 
-    io.extend_with(RolesApi::to_delegate(Roles::new(client)));
+    io.extend_with(RolesApi::to_delegate(Roles::new(client.clone())));
+
+    io.extend_with(UtilsApi::to_delegate(Utils::new(client)));
 
     io
 }
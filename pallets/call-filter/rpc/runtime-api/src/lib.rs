@@ -0,0 +1,15 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait CallFilterApi {
+        fn get_paused_pallets() -> Vec<Vec<u8>>;
+
+        fn get_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)>;
+
+        fn get_paused_calls_of_pallet(pallet_name: Vec<u8>) -> Vec<Vec<u8>>;
+
+        fn is_call_paused(pallet_name: Vec<u8>, call_name: Vec<u8>) -> bool;
+    }
+}
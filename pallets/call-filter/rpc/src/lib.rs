@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+
+use pallet_utils::rpc::map_rpc_error;
+pub use call_filter_runtime_api::CallFilterApi as CallFilterRuntimeApi;
+
+#[rpc]
+pub trait CallFilterApi<BlockHash> {
+    #[rpc(name = "callFilter_getPausedPallets")]
+    fn get_paused_pallets(&self, at: Option<BlockHash>) -> Result<Vec<String>>;
+
+    #[rpc(name = "callFilter_getPausedCalls")]
+    fn get_paused_calls(&self, at: Option<BlockHash>) -> Result<Vec<(String, String)>>;
+
+    #[rpc(name = "callFilter_getPausedCallsOfPallet")]
+    fn get_paused_calls_of_pallet(
+        &self,
+        at: Option<BlockHash>,
+        pallet_name: String,
+    ) -> Result<Vec<String>>;
+
+    #[rpc(name = "callFilter_isCallPaused")]
+    fn is_call_paused(
+        &self,
+        at: Option<BlockHash>,
+        pallet_name: String,
+        call_name: String,
+    ) -> Result<bool>;
+}
+
+pub struct CallFilter<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> CallFilter<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Pallet and call names are valid UTF-8 on the runtime side,
+/// but the invalid bytes are replaced just in case.
+fn bytes_to_string(bytes: Vec<u8>) -> String {
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+impl<C, Block> CallFilterApi<<Block as BlockT>::Hash> for CallFilter<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: CallFilterRuntimeApi<Block>,
+{
+    fn get_paused_pallets(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<String>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_paused_pallets(&at);
+        runtime_api_result
+            .map(|pallets| pallets.into_iter().map(bytes_to_string).collect())
+            .map_err(map_rpc_error)
+    }
+
+    fn get_paused_calls(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<(String, String)>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_paused_calls(&at);
+        runtime_api_result
+            .map(|calls| calls.into_iter()
+                .map(|(pallet_name, call_name)| (bytes_to_string(pallet_name), bytes_to_string(call_name)))
+                .collect())
+            .map_err(map_rpc_error)
+    }
+
+    fn get_paused_calls_of_pallet(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        pallet_name: String,
+    ) -> Result<Vec<String>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_paused_calls_of_pallet(&at, pallet_name.into_bytes());
+        runtime_api_result
+            .map(|calls| calls.into_iter().map(bytes_to_string).collect())
+            .map_err(map_rpc_error)
+    }
+
+    fn is_call_paused(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        pallet_name: String,
+        call_name: String,
+    ) -> Result<bool> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.is_call_paused(&at, pallet_name.into_bytes(), call_name.into_bytes());
+        runtime_api_result.map_err(map_rpc_error)
+    }
+}
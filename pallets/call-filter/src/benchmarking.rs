@@ -0,0 +1,49 @@
+//! Call filter pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+
+const PALLET_NAME: &[u8] = b"BenchmarkedPallet";
+const CALL_NAME: &[u8] = b"benchmarked_call";
+
+benchmarks! {
+    pause_pallet {
+        let origin = T::ManagerOrigin::successful_origin();
+    }: { Pallet::<T>::pause_pallet(origin, PALLET_NAME.to_vec())?; }
+    verify {
+        assert!(Pallet::<T>::is_pallet_paused(PALLET_NAME));
+    }
+
+    unpause_pallet {
+        Pallet::<T>::pause_pallet(T::ManagerOrigin::successful_origin(), PALLET_NAME.to_vec())?;
+        let origin = T::ManagerOrigin::successful_origin();
+    }: { Pallet::<T>::unpause_pallet(origin, PALLET_NAME.to_vec())?; }
+    verify {
+        assert!(!Pallet::<T>::is_pallet_paused(PALLET_NAME));
+    }
+
+    pause_call {
+        let origin = T::ManagerOrigin::successful_origin();
+    }: { Pallet::<T>::pause_call(origin, PALLET_NAME.to_vec(), CALL_NAME.to_vec())?; }
+    verify {
+        assert!(Pallet::<T>::is_call_paused(PALLET_NAME, CALL_NAME));
+    }
+
+    unpause_call {
+        Pallet::<T>::pause_call(T::ManagerOrigin::successful_origin(), PALLET_NAME.to_vec(), CALL_NAME.to_vec())?;
+        let origin = T::ManagerOrigin::successful_origin();
+    }: { Pallet::<T>::unpause_call(origin, PALLET_NAME.to_vec(), CALL_NAME.to_vec())?; }
+    verify {
+        assert!(!Pallet::<T>::is_call_paused(PALLET_NAME, CALL_NAME));
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::ExtBuilder::build(),
+        crate::mock::Test,
+    );
+}
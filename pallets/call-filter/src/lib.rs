@@ -0,0 +1,217 @@
+//! # Call Filter Module
+//!
+//! Pallet that allows root or governance to pause and unpause a whole pallet or a single call
+//! of a pallet without a runtime upgrade. Paused calls are rejected by `ExcludePausedCalls`
+//! that should be used as the `BaseCallFilter` of a runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod rpc;
+pub mod weights;
+
+use frame_support::traits::{CallMetadata, Contains, GetCallMetadata};
+use sp_std::{marker::PhantomData, prelude::*};
+pub use weights::WeightInfo;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        ensure, pallet_prelude::*,
+        dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    };
+    use frame_system::pallet_prelude::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// The origin that is allowed to pause and unpause pallets and calls.
+        type ManagerOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Names of pallets whose calls cannot be paused, e.g. `System` or `Sudo`.
+        /// This pallet itself can never be paused.
+        type UnpausablePallets: Get<Vec<Vec<u8>>>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    /// Names of pallets all calls of which are paused.
+    #[pallet::storage]
+    #[pallet::getter(fn is_pallet_paused)]
+    pub type PausedPallets<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, bool, ValueQuery>;
+
+    /// Names of single paused calls grouped by the name of their pallet.
+    #[pallet::storage]
+    pub type PausedCalls<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, Vec<u8>, Blake2_128Concat, Vec<u8>, bool, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[cfg_attr(feature = "std", derive(Default))]
+    pub struct GenesisConfig {
+        pub paused_pallets: Vec<Vec<u8>>,
+        pub paused_calls: Vec<(Vec<u8>, Vec<u8>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        fn build(&self) {
+            Pallet::<T>::pause_initial_calls(&self.paused_pallets, &self.paused_calls);
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// All calls of a pallet were paused. \[pallet_name\]
+        PalletPaused(Vec<u8>),
+        /// All calls of a pallet were unpaused. \[pallet_name\]
+        PalletUnpaused(Vec<u8>),
+        /// A single call was paused. \[pallet_name, call_name\]
+        CallPaused(Vec<u8>, Vec<u8>),
+        /// A single call was unpaused. \[pallet_name, call_name\]
+        CallUnpaused(Vec<u8>, Vec<u8>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Calls of this pallet cannot be paused.
+        PalletIsUnpausable,
+        /// Pallet or call name is empty.
+        EmptyName,
+        /// Pallet is already paused.
+        PalletAlreadyPaused,
+        /// Pallet is not paused.
+        PalletNotPaused,
+        /// Call is already paused.
+        CallAlreadyPaused,
+        /// Call is not paused.
+        CallNotPaused,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(<T as Config>::WeightInfo::pause_pallet())]
+        pub fn pause_pallet(origin: OriginFor<T>, pallet_name: Vec<u8>) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Self::ensure_pallet_is_pausable(&pallet_name)?;
+            ensure!(!Self::is_pallet_paused(&pallet_name), Error::<T>::PalletAlreadyPaused);
+
+            PausedPallets::<T>::insert(&pallet_name, true);
+
+            Self::deposit_event(Event::PalletPaused(pallet_name));
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::unpause_pallet())]
+        pub fn unpause_pallet(origin: OriginFor<T>, pallet_name: Vec<u8>) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(Self::is_pallet_paused(&pallet_name), Error::<T>::PalletNotPaused);
+
+            PausedPallets::<T>::remove(&pallet_name);
+
+            Self::deposit_event(Event::PalletUnpaused(pallet_name));
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::pause_call())]
+        pub fn pause_call(
+            origin: OriginFor<T>,
+            pallet_name: Vec<u8>,
+            call_name: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Self::ensure_pallet_is_pausable(&pallet_name)?;
+            ensure!(!call_name.is_empty(), Error::<T>::EmptyName);
+            ensure!(!PausedCalls::<T>::get(&pallet_name, &call_name), Error::<T>::CallAlreadyPaused);
+
+            PausedCalls::<T>::insert(&pallet_name, &call_name, true);
+
+            Self::deposit_event(Event::CallPaused(pallet_name, call_name));
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::unpause_call())]
+        pub fn unpause_call(
+            origin: OriginFor<T>,
+            pallet_name: Vec<u8>,
+            call_name: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(PausedCalls::<T>::get(&pallet_name, &call_name), Error::<T>::CallNotPaused);
+
+            PausedCalls::<T>::remove(&pallet_name, &call_name);
+
+            Self::deposit_event(Event::CallUnpaused(pallet_name, call_name));
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Check whether a call is paused, either by itself or together with its whole pallet.
+        pub fn is_call_paused(pallet_name: &[u8], call_name: &[u8]) -> bool {
+            Self::is_pallet_paused(pallet_name) || PausedCalls::<T>::get(pallet_name, call_name)
+        }
+
+        /// Pause the given pallets and calls without any checks and events.
+        /// Used to set the initial filter state at genesis or in a runtime migration.
+        pub fn pause_initial_calls(paused_pallets: &[Vec<u8>], paused_calls: &[(Vec<u8>, Vec<u8>)]) -> Weight {
+            for pallet_name in paused_pallets {
+                PausedPallets::<T>::insert(pallet_name, true);
+            }
+
+            for (pallet_name, call_name) in paused_calls {
+                PausedCalls::<T>::insert(pallet_name, call_name, true);
+            }
+
+            let writes = paused_pallets.len().saturating_add(paused_calls.len());
+            T::DbWeight::get().writes(writes as Weight)
+        }
+
+        fn ensure_pallet_is_pausable(pallet_name: &[u8]) -> DispatchResult {
+            ensure!(!pallet_name.is_empty(), Error::<T>::EmptyName);
+
+            let is_this_pallet = pallet_name == <Self as PalletInfoAccess>::name().as_bytes();
+            let is_unpausable = T::UnpausablePallets::get().iter().any(|name| name.as_slice() == pallet_name);
+            ensure!(!is_this_pallet && !is_unpausable, Error::<T>::PalletIsUnpausable);
+
+            Ok(())
+        }
+    }
+}
+
+/// Allows all calls except those that are paused in this pallet.
+/// Intended to be used as `frame_system::Config::BaseCallFilter`.
+pub struct ExcludePausedCalls<T>(PhantomData<T>);
+
+impl<T: Config, Call: GetCallMetadata> Contains<Call> for ExcludePausedCalls<T> {
+    fn contains(call: &Call) -> bool {
+        let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+        !Pallet::<T>::is_call_paused(pallet_name.as_bytes(), function_name.as_bytes())
+    }
+}
@@ -0,0 +1,150 @@
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup}, testing::Header,
+};
+
+use crate as call_filter;
+
+use frame_support::{
+    parameter_types,
+    dispatch::DispatchResultWithPostInfo,
+};
+use frame_system::EnsureRoot;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        CallFilter: call_filter::{Pallet, Call, Storage, Event<T>, Config},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = call_filter::ExcludePausedCalls<Test>;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+}
+
+parameter_types! {
+    pub UnpausablePallets: Vec<Vec<u8>> = vec![UNPAUSABLE_PALLET.to_vec()];
+}
+
+impl call_filter::Config for Test {
+    type Event = Event;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type UnpausablePallets = UnpausablePallets;
+    type WeightInfo = ();
+}
+
+pub(crate) type AccountId = u64;
+
+pub(crate) const ACCOUNT1: AccountId = 1;
+
+pub(crate) const SYSTEM_PALLET: &[u8] = b"System";
+pub(crate) const REMARK_CALL: &[u8] = b"remark";
+pub(crate) const UNPAUSABLE_PALLET: &[u8] = b"Sudo";
+
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+
+        ext
+    }
+
+    pub(crate) fn build_with_paused_system_pallet() -> TestExternalities {
+        let mut ext = Self::build();
+        ext.execute_with(|| CallFilter::pause_initial_calls(&[SYSTEM_PALLET.to_vec()], &[]));
+        ext
+    }
+
+    pub(crate) fn build_with_paused_remark_call() -> TestExternalities {
+        let mut ext = Self::build();
+        ext.execute_with(|| CallFilter::pause_initial_calls(&[], &[(SYSTEM_PALLET.to_vec(), REMARK_CALL.to_vec())]));
+        ext
+    }
+}
+
+pub(crate) fn remark_call() -> Call {
+    Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+pub(crate) fn remark_call_is_allowed() -> bool {
+    use frame_support::traits::Contains;
+    <Test as frame_system::Config>::BaseCallFilter::contains(&remark_call())
+}
+
+pub(crate) fn _pause_pallet(origin: Option<Origin>, pallet_name: Option<Vec<u8>>) -> DispatchResultWithPostInfo {
+    CallFilter::pause_pallet(
+        origin.unwrap_or_else(Origin::root),
+        pallet_name.unwrap_or_else(|| SYSTEM_PALLET.to_vec()),
+    )
+}
+
+pub(crate) fn _unpause_pallet(origin: Option<Origin>, pallet_name: Option<Vec<u8>>) -> DispatchResultWithPostInfo {
+    CallFilter::unpause_pallet(
+        origin.unwrap_or_else(Origin::root),
+        pallet_name.unwrap_or_else(|| SYSTEM_PALLET.to_vec()),
+    )
+}
+
+pub(crate) fn _pause_call(
+    origin: Option<Origin>,
+    pallet_name: Option<Vec<u8>>,
+    call_name: Option<Vec<u8>>,
+) -> DispatchResultWithPostInfo {
+    CallFilter::pause_call(
+        origin.unwrap_or_else(Origin::root),
+        pallet_name.unwrap_or_else(|| SYSTEM_PALLET.to_vec()),
+        call_name.unwrap_or_else(|| REMARK_CALL.to_vec()),
+    )
+}
+
+pub(crate) fn _unpause_call(
+    origin: Option<Origin>,
+    pallet_name: Option<Vec<u8>>,
+    call_name: Option<Vec<u8>>,
+) -> DispatchResultWithPostInfo {
+    CallFilter::unpause_call(
+        origin.unwrap_or_else(Origin::root),
+        pallet_name.unwrap_or_else(|| SYSTEM_PALLET.to_vec()),
+        call_name.unwrap_or_else(|| REMARK_CALL.to_vec()),
+    )
+}
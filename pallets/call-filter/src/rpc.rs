@@ -0,0 +1,17 @@
+use sp_std::prelude::*;
+
+use crate::{Pallet, Config, PausedCalls, PausedPallets};
+
+impl<T: Config> Pallet<T> {
+    pub fn get_paused_pallets() -> Vec<Vec<u8>> {
+        PausedPallets::<T>::iter_keys().collect()
+    }
+
+    pub fn get_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)> {
+        PausedCalls::<T>::iter_keys().collect()
+    }
+
+    pub fn get_paused_calls_of_pallet(pallet_name: Vec<u8>) -> Vec<Vec<u8>> {
+        PausedCalls::<T>::iter_key_prefix(pallet_name).collect()
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{Error, Event, mock::*};
+use frame_support::{assert_ok, assert_noop};
+use sp_runtime::DispatchError::BadOrigin;
+
+// Test `fn pause_pallet(..)`
+
+#[test]
+fn pause_pallet_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert!(remark_call_is_allowed());
+
+        assert_ok!(_pause_pallet(None, None));
+
+        assert!(CallFilter::is_pallet_paused(SYSTEM_PALLET));
+        assert!(!remark_call_is_allowed());
+        System::assert_last_event(Event::<Test>::PalletPaused(SYSTEM_PALLET.to_vec()).into());
+    });
+}
+
+#[test]
+fn pause_pallet_should_fail_when_origin_is_not_manager() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_pause_pallet(Some(Origin::signed(ACCOUNT1)), None), BadOrigin);
+    });
+}
+
+#[test]
+fn pause_pallet_should_fail_when_pallet_is_unpausable() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            _pause_pallet(None, Some(UNPAUSABLE_PALLET.to_vec())),
+            Error::<Test>::PalletIsUnpausable
+        );
+        assert_noop!(
+            _pause_pallet(None, Some(b"CallFilter".to_vec())),
+            Error::<Test>::PalletIsUnpausable
+        );
+    });
+}
+
+#[test]
+fn pause_pallet_should_fail_when_pallet_name_is_empty() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_pause_pallet(None, Some(vec![])), Error::<Test>::EmptyName);
+    });
+}
+
+#[test]
+fn pause_pallet_should_fail_when_pallet_already_paused() {
+    ExtBuilder::build_with_paused_system_pallet().execute_with(|| {
+        assert_noop!(_pause_pallet(None, None), Error::<Test>::PalletAlreadyPaused);
+    });
+}
+
+// Test `fn unpause_pallet(..)`
+
+#[test]
+fn unpause_pallet_should_work() {
+    ExtBuilder::build_with_paused_system_pallet().execute_with(|| {
+        assert!(!remark_call_is_allowed());
+
+        assert_ok!(_unpause_pallet(None, None));
+
+        assert!(!CallFilter::is_pallet_paused(SYSTEM_PALLET));
+        assert!(remark_call_is_allowed());
+        System::assert_last_event(Event::<Test>::PalletUnpaused(SYSTEM_PALLET.to_vec()).into());
+    });
+}
+
+#[test]
+fn unpause_pallet_should_fail_when_origin_is_not_manager() {
+    ExtBuilder::build_with_paused_system_pallet().execute_with(|| {
+        assert_noop!(_unpause_pallet(Some(Origin::signed(ACCOUNT1)), None), BadOrigin);
+    });
+}
+
+#[test]
+fn unpause_pallet_should_fail_when_pallet_not_paused() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_unpause_pallet(None, None), Error::<Test>::PalletNotPaused);
+    });
+}
+
+// Test `fn pause_call(..)`
+
+#[test]
+fn pause_call_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_pause_call(None, None, None));
+
+        assert!(CallFilter::is_call_paused(SYSTEM_PALLET, REMARK_CALL));
+        assert!(!CallFilter::is_call_paused(SYSTEM_PALLET, b"remark_with_event"));
+        assert!(!remark_call_is_allowed());
+        System::assert_last_event(Event::<Test>::CallPaused(SYSTEM_PALLET.to_vec(), REMARK_CALL.to_vec()).into());
+    });
+}
+
+#[test]
+fn pause_call_should_fail_when_origin_is_not_manager() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_pause_call(Some(Origin::signed(ACCOUNT1)), None, None), BadOrigin);
+    });
+}
+
+#[test]
+fn pause_call_should_fail_when_pallet_is_unpausable() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            _pause_call(None, Some(UNPAUSABLE_PALLET.to_vec()), None),
+            Error::<Test>::PalletIsUnpausable
+        );
+    });
+}
+
+#[test]
+fn pause_call_should_fail_when_call_name_is_empty() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_pause_call(None, None, Some(vec![])), Error::<Test>::EmptyName);
+    });
+}
+
+#[test]
+fn pause_call_should_fail_when_call_already_paused() {
+    ExtBuilder::build_with_paused_remark_call().execute_with(|| {
+        assert_noop!(_pause_call(None, None, None), Error::<Test>::CallAlreadyPaused);
+    });
+}
+
+// Test `fn unpause_call(..)`
+
+#[test]
+fn unpause_call_should_work() {
+    ExtBuilder::build_with_paused_remark_call().execute_with(|| {
+        assert_ok!(_unpause_call(None, None, None));
+
+        assert!(!CallFilter::is_call_paused(SYSTEM_PALLET, REMARK_CALL));
+        assert!(remark_call_is_allowed());
+        System::assert_last_event(Event::<Test>::CallUnpaused(SYSTEM_PALLET.to_vec(), REMARK_CALL.to_vec()).into());
+    });
+}
+
+#[test]
+fn unpause_call_should_fail_when_origin_is_not_manager() {
+    ExtBuilder::build_with_paused_remark_call().execute_with(|| {
+        assert_noop!(_unpause_call(Some(Origin::signed(ACCOUNT1)), None, None), BadOrigin);
+    });
+}
+
+#[test]
+fn unpause_call_should_fail_when_call_not_paused() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_unpause_call(None, None, None), Error::<Test>::CallNotPaused);
+    });
+}
+
+#[test]
+fn unpause_call_should_not_allow_call_of_paused_pallet() {
+    ExtBuilder::build_with_paused_system_pallet().execute_with(|| {
+        assert_ok!(_pause_call(None, None, None));
+        assert_ok!(_unpause_call(None, None, None));
+
+        assert!(!remark_call_is_allowed());
+    });
+}
+
+// Test runtime API functions
+
+#[test]
+fn get_paused_pallets_and_calls_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_pause_pallet(None, Some(b"Posts".to_vec())));
+        assert_ok!(_pause_call(None, None, None));
+
+        assert_eq!(CallFilter::get_paused_pallets(), vec![b"Posts".to_vec()]);
+        assert_eq!(CallFilter::get_paused_calls(), vec![(SYSTEM_PALLET.to_vec(), REMARK_CALL.to_vec())]);
+        assert_eq!(CallFilter::get_paused_calls_of_pallet(SYSTEM_PALLET.to_vec()), vec![REMARK_CALL.to_vec()]);
+    });
+}
@@ -12,10 +12,10 @@ mod tests {
     use frame_support::{
         assert_ok, assert_noop,
         parameter_types,
-        dispatch::{DispatchResult, DispatchError},
-        storage::StorageMap,
-        traits::Everything,
+        dispatch::{DispatchResultWithPostInfo, DispatchError},
+        traits::{Currency, Everything, OnInitialize},
     };
+    use codec::Encode;
     use frame_system as system;
 
     use pallet_permissions::{
@@ -23,19 +23,21 @@ mod tests {
         SpacePermission as SP,
         SpacePermissions,
     };
-    use pallet_posts::{Post, PostUpdate, PostExtension, Comment, Error as PostsError};
-    use pallet_profiles::{ProfileUpdate, Error as ProfilesError};
+    use pallet_posts::{Post, PostUpdate, PostExtension, Comment, Error as PostsError, rpc::FlatPostKind, FeedPostIdsByAccount};
+    use pallet_profiles::{ProfileUpdate, SocialAccountById, Error as ProfilesError};
     use pallet_profile_follows::Error as ProfileFollowsError;
-    use pallet_reactions::{ReactionId, ReactionKind, Error as ReactionsError};
-    use pallet_spaces::{SpaceById, SpaceUpdate, Error as SpacesError, SpacesSettings};
+    use pallet_reactions::{ReactionId, ReactionKind, ReactionTarget, Error as ReactionsError};
+    use pallet_scores::{ScoreDiff, ScoringAction};
+    use pallet_session_keys::{SessionKeyScope, Error as SessionKeysError};
+    use pallet_spaces::{SpaceById, SpaceUpdate, Error as SpacesError, SpacesSettings, SpaceOwnersAction, SpaceOwnersUpdate};
     use pallet_space_follows::Error as SpaceFollowsError;
     use pallet_space_ownership::Error as SpaceOwnershipError;
-    use pallet_moderation::{EntityId, EntityStatus, ReportId};
+    use pallet_moderation::{EntityId, EntityStatus, ReportId, SpaceModerationSettingsUpdate};
     use pallet_utils::{
         mock_functions::*,
         DEFAULT_MIN_HANDLE_LEN, DEFAULT_MAX_HANDLE_LEN,
         Error as UtilsError,
-        SpaceId, PostId, User, Content,
+        SpaceId, PostId, User, Content, StorageItem,
     };
 
     type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
@@ -59,11 +61,13 @@ mod tests {
             ProfileHistory: pallet_profile_history::{Pallet, Storage},
             Reactions: pallet_reactions::{Pallet, Call, Storage, Event<T>},
             Roles: pallet_roles::{Pallet, Call, Storage, Event<T>},
+            Scores: pallet_scores::{Pallet, Storage, Event<T>},
+            SessionKeys: pallet_session_keys::{Pallet, Call, Storage, Event<T>},
             SpaceFollows: pallet_space_follows::{Pallet, Call, Storage, Event<T>},
             SpaceHistory: pallet_space_history::{Pallet, Storage},
             SpaceOwnership: pallet_space_ownership::{Pallet, Call, Storage, Event<T>},
             Spaces: pallet_spaces::{Pallet, Call, Storage, Event<T>, Config<T>},
-            Utils: pallet_utils::{Pallet, Storage, Event<T>, Config<T>},
+            Utils: pallet_utils::{Pallet, Call, Storage, Event<T>, Config<T>},
         }
     );
 
@@ -127,13 +131,32 @@ mod tests {
     parameter_types! {
       pub const MinHandleLen: u32 = DEFAULT_MIN_HANDLE_LEN;
       pub const MaxHandleLen: u32 = DEFAULT_MAX_HANDLE_LEN;
+      pub const PremiumHandleLen: u32 = 8;
+      pub const HandleRegistrationPeriod: Option<u64> = Some(HANDLE_REGISTRATION_PERIOD);
+      pub const HandleGracePeriod: u64 = HANDLE_GRACE_PERIOD;
+      pub const MaxHandlesToReleasePerBlock: u32 = 2;
+      pub const MaxRawContentLen: u32 = 20;
+      pub storage StorageDepositPerItem: u64 = 0;
+      pub storage StorageDepositPerByte: u64 = 0;
     }
 
+    const HANDLE_REGISTRATION_PERIOD: u64 = 100;
+    const HANDLE_GRACE_PERIOD: u64 = 10;
+
     impl pallet_utils::Config for TestRuntime {
         type Event = Event;
         type Currency = Balances;
         type MinHandleLen = MinHandleLen;
         type MaxHandleLen = MaxHandleLen;
+        type PremiumHandleLen = PremiumHandleLen;
+        type HandleRegistrationPeriod = HandleRegistrationPeriod;
+        type HandleGracePeriod = HandleGracePeriod;
+        type MaxHandlesToReleasePerBlock = MaxHandlesToReleasePerBlock;
+        type MaxRawContentLen = MaxRawContentLen;
+        type StorageDepositPerItem = StorageDepositPerItem;
+        type StorageDepositPerByte = StorageDepositPerByte;
+        type MaxItemsToMigratePerBlock = ();
+        type MultiBlockMigrations = ();
     }
 
     use pallet_permissions::default_permissions::DefaultSpacePermissions;
@@ -148,72 +171,154 @@ mod tests {
 
     impl pallet_posts::Config for TestRuntime {
         type Event = Event;
+        type WeightInfo = ();
         type MaxCommentDepth = MaxCommentDepth;
         type AfterPostUpdated = PostHistory;
+        type AfterPostDeleted = (Reactions, PostHistory);
+        type PostScores = Scores;
         type IsPostBlocked = Moderation;
     }
 
-    impl pallet_post_history::Config for TestRuntime {}
+    parameter_types! {
+        pub const MaxEditHistoryRecords: u32 = 3;
+    }
+
+    impl pallet_post_history::Config for TestRuntime {
+        type MaxHistoryRecords = MaxEditHistoryRecords;
+    }
 
     impl pallet_profile_follows::Config for TestRuntime {
         type Event = Event;
-        type BeforeAccountFollowed = ();
-        type BeforeAccountUnfollowed = ();
+        type WeightInfo = ();
+        type BeforeAccountFollowed = Scores;
+        type BeforeAccountUnfollowed = Scores;
     }
 
     impl pallet_profiles::Config for TestRuntime {
         type Event = Event;
+        type WeightInfo = ();
         type AfterProfileUpdated = ProfileHistory;
+        type Currency = Balances;
+        type SpaceHandles = Spaces;
+        type HandleDeposit = HandleDeposit;
+    }
+
+    impl pallet_profile_history::Config for TestRuntime {
+        type MaxHistoryRecords = MaxEditHistoryRecords;
     }
 
-    impl pallet_profile_history::Config for TestRuntime {}
+    parameter_types! {
+        pub const MaxCustomReactionKinds: u32 = 3;
+        pub const MaxCustomReactionKindLen: u32 = 8;
+    }
 
     impl pallet_reactions::Config for TestRuntime {
         type Event = Event;
+        type WeightInfo = ();
+        type MaxCustomReactionKinds = MaxCustomReactionKinds;
+        type MaxCustomReactionKindLen = MaxCustomReactionKindLen;
+        type ReactionScores = Scores;
     }
 
     parameter_types! {
         pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+        pub const MaxSpaceRoleHoldersPerSpace: u32 = 10;
+        pub const MaxExpiredGrantsToProcessPerBlock: u32 = 10;
     }
 
     impl pallet_roles::Config for TestRuntime {
         type Event = Event;
+        type WeightInfo = ();
         type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+        type MaxSpaceRoleHoldersPerSpace = MaxSpaceRoleHoldersPerSpace;
+        type MaxExpiredGrantsToProcessPerBlock = MaxExpiredGrantsToProcessPerBlock;
         type Spaces = Spaces;
         type SpaceFollows = SpaceFollows;
         type IsAccountBlocked = Moderation;
         type IsContentBlocked = Moderation;
     }
 
+    parameter_types! {
+        pub const FollowSpaceActionWeight: i16 = 7;
+        pub const FollowAccountActionWeight: i16 = 3;
+
+        pub const SharePostActionWeight: i16 = 7;
+        pub const UpvotePostActionWeight: i16 = 5;
+        pub const DownvotePostActionWeight: i16 = -3;
+
+        pub const CreateCommentActionWeight: i16 = 5;
+        pub const ShareCommentActionWeight: i16 = 5;
+        pub const UpvoteCommentActionWeight: i16 = 4;
+        pub const DownvoteCommentActionWeight: i16 = -2;
+    }
+
+    impl pallet_scores::Config for TestRuntime {
+        type Event = Event;
+
+        type FollowSpaceActionWeight = FollowSpaceActionWeight;
+        type FollowAccountActionWeight = FollowAccountActionWeight;
+
+        type SharePostActionWeight = SharePostActionWeight;
+        type UpvotePostActionWeight = UpvotePostActionWeight;
+        type DownvotePostActionWeight = DownvotePostActionWeight;
+
+        type CreateCommentActionWeight = CreateCommentActionWeight;
+        type ShareCommentActionWeight = ShareCommentActionWeight;
+        type UpvoteCommentActionWeight = UpvoteCommentActionWeight;
+        type DownvoteCommentActionWeight = DownvoteCommentActionWeight;
+    }
+
     impl pallet_space_follows::Config for TestRuntime {
         type Event = Event;
-        type BeforeSpaceFollowed = ();
-        type BeforeSpaceUnfollowed = ();
+        type WeightInfo = ();
+        type BeforeSpaceFollowed = Scores;
+        type BeforeSpaceUnfollowed = Scores;
+    }
+
+    parameter_types! {
+        pub const TransferExpirationPeriod: u64 = 10;
+        pub const MaxExpiredTransfersToProcessPerBlock: u32 = 3;
     }
 
     impl pallet_space_ownership::Config for TestRuntime {
         type Event = Event;
+        type TransferExpirationPeriod = TransferExpirationPeriod;
+        type MaxExpiredTransfersToProcessPerBlock = MaxExpiredTransfersToProcessPerBlock;
+        type WeightInfo = ();
     }
 
     const HANDLE_DEPOSIT: u64 = 15;
 
     parameter_types! {
         pub const HandleDeposit: u64 = HANDLE_DEPOSIT;
+        pub const MaxSubspaceDepth: u32 = 3;
+        pub const MaxSubspacesToMove: u32 = 2;
+        pub const MaxSpaceOwners: u16 = 3;
+        pub const MaxPendingSpaceOwnersActions: u32 = 2;
     }
 
     impl pallet_spaces::Config for TestRuntime {
         type Event = Event;
+        type WeightInfo = ();
         type Currency = Balances;
         type Roles = Roles;
         type SpaceFollows = SpaceFollows;
         type BeforeSpaceCreated = SpaceFollows;
         type AfterSpaceUpdated = SpaceHistory;
+        type BeforeSpaceDeleted = (Posts, Reactions, Roles, SpaceFollows, SpaceOwnership);
         type IsAccountBlocked = Moderation;
         type IsContentBlocked = Moderation;
+        type ProfileHandles = Profiles;
         type HandleDeposit = HandleDeposit;
+        type MaxSubspaceDepth = MaxSubspaceDepth;
+        type MaxSubspacesToMove = MaxSubspacesToMove;
+        type MaxSpaceOwners = MaxSpaceOwners;
+        type MaxPendingSpaceOwnersActions = MaxPendingSpaceOwnersActions;
     }
 
-    impl pallet_space_history::Config for TestRuntime {}
+    impl pallet_space_history::Config for TestRuntime {
+        type MaxHistoryRecords = MaxEditHistoryRecords;
+    }
 
     parameter_types! {
         pub const DefaultAutoblockThreshold: u16 = 20;
@@ -224,6 +329,21 @@ mod tests {
         type DefaultAutoblockThreshold = DefaultAutoblockThreshold;
     }
 
+    parameter_types! {
+        pub const MaxSessionKeysPerAccount: u16 = 10;
+        pub const MaxScopesPerSessionKey: u16 = 10;
+        pub const MaxSessionKeyTimeToLive: BlockNumber = 100;
+    }
+
+    impl pallet_session_keys::Config for TestRuntime {
+        type Event = Event;
+        type Call = Call;
+        type MaxSessionKeysPerAccount = MaxSessionKeysPerAccount;
+        type MaxScopesPerSessionKey = MaxScopesPerSessionKey;
+        type MaxSessionKeyTimeToLive = MaxSessionKeyTimeToLive;
+        type WeightInfo = ();
+    }
+
     type AccountId = u64;
     type BlockNumber = u64;
 
@@ -384,11 +504,17 @@ mod tests {
 
     const SPACE1: SpaceId = 1001;
     const SPACE2: SpaceId = 1002;
+    const SPACE3: SpaceId = 1003;
+    const SPACE4: SpaceId = 1004;
+    const SPACE5: SpaceId = 1005;
 
     const POST1: PostId = 1;
     const POST2: PostId = 2;
     const POST3: PostId = 3;
 
+    const MAX_REPLIES_TO_DELETE: u32 = 10;
+    const MAX_ITEMS_TO_DELETE_WITH_SPACE: u32 = 20;
+
     const REACTION1: ReactionId = 1;
     const REACTION2: ReactionId = 2;
 
@@ -425,6 +551,17 @@ mod tests {
         default_permissions
     }
 
+    fn permissions_where_everyone_can_create_subspaces() -> SpacePermissions {
+        let mut default_permissions = DefaultSpacePermissions::get();
+        default_permissions.everyone = default_permissions.everyone
+          .map(|mut permissions| {
+              permissions.insert(SP::CreateSubspaces);
+              permissions
+          });
+
+        default_permissions
+    }
+
     fn permissions_where_follower_can_create_post() -> SpacePermissions {
         let mut default_permissions = DefaultSpacePermissions::get();
         default_permissions.follower = Some(vec![SP::CreatePosts].into_iter().collect());
@@ -432,6 +569,24 @@ mod tests {
         default_permissions
     }
 
+    fn post_permissions_where_comments_are_locked() -> SpacePermissions {
+        SpacePermissions {
+            none: Some(vec![SP::CreateComments].into_iter().collect()),
+            ..Default::default()
+        }
+    }
+
+    fn post_permissions_where_only_followers_can_comment() -> SpacePermissions {
+        let mut everyone_permissions = DefaultSpacePermissions::get().everyone.unwrap_or_default();
+        everyone_permissions.remove(&SP::CreateComments);
+
+        SpacePermissions {
+            everyone: Some(everyone_permissions),
+            follower: Some(vec![SP::CreateComments].into_iter().collect()),
+            ..Default::default()
+        }
+    }
+
     fn update_for_space_handle(
         new_handle: Option<Vec<u8>>,
     ) -> SpaceUpdate {
@@ -444,6 +599,15 @@ mod tests {
         space_update(None, Some(new_content), None)
     }
 
+    fn update_for_space_parent_id(
+        new_parent_id: Option<SpaceId>,
+    ) -> SpaceUpdate {
+        SpaceUpdate {
+            parent_id: Some(new_parent_id),
+            ..space_update(None, None, None)
+        }
+    }
+
     fn space_update(
         handle: Option<Option<Vec<u8>>>,
         content: Option<Content>,
@@ -506,6 +670,14 @@ mod tests {
         ReactionKind::Downvote
     }
 
+    fn custom_reaction_kind() -> Vec<u8> {
+        "🔥".as_bytes().to_vec()
+    }
+
+    fn reaction_custom() -> ReactionKind {
+        ReactionKind::Custom(custom_reaction_kind())
+    }
+
     fn extension_regular_post() -> PostExtension {
         PostExtension::RegularPost
     }
@@ -518,7 +690,17 @@ mod tests {
         PostExtension::SharedPost(post_id)
     }
 
-    fn _create_default_space() -> DispatchResult {
+    /// Set the storage deposit constants and give the accounts enough balance to pay them.
+    fn set_storage_deposits(per_item: u64, per_byte: u64) {
+        StorageDepositPerItem::set(&per_item);
+        StorageDepositPerByte::set(&per_byte);
+
+        for account in ACCOUNT1..=ACCOUNT3 {
+            let _ = Balances::deposit_creating(&account, 1_000);
+        }
+    }
+
+    fn _create_default_space() -> DispatchResultWithPostInfo {
         _create_space(None, None, None, None)
     }
 
@@ -527,7 +709,7 @@ mod tests {
         handle: Option<Option<Vec<u8>>>,
         content: Option<Content>,
         permissions: Option<Option<SpacePermissions>>
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         _create_space_with_parent_id(
             origin,
             None,
@@ -543,7 +725,7 @@ mod tests {
         handle: Option<Option<Vec<u8>>>,
         content: Option<Content>,
         permissions: Option<Option<SpacePermissions>>
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         _create_space_with_parent_id(
             origin,
             parent_id_opt,
@@ -559,7 +741,7 @@ mod tests {
         handle: Option<Option<Vec<u8>>>,
         content: Option<Content>,
         permissions: Option<Option<SpacePermissions>>
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Spaces::create_space(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             parent_id_opt.unwrap_or_default(),
@@ -573,7 +755,7 @@ mod tests {
         origin: Option<Origin>,
         space_id: Option<SpaceId>,
         update: Option<SpaceUpdate>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Spaces::update_space(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             space_id.unwrap_or(SPACE1),
@@ -581,16 +763,36 @@ mod tests {
         )
     }
 
-    fn _update_space_settings_with_handles_enabled() -> DispatchResult {
+    fn _delete_default_space() -> DispatchResultWithPostInfo {
+        _delete_space(None, None)
+    }
+
+    fn _delete_space(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResultWithPostInfo {
+        _delete_space_with_max_items(origin, space_id, MAX_ITEMS_TO_DELETE_WITH_SPACE)
+    }
+
+    fn _delete_space_with_max_items(
+        origin: Option<Origin>,
+        space_id: Option<SpaceId>,
+        max_items: u32,
+    ) -> DispatchResultWithPostInfo {
+        Spaces::delete_space(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            space_id.unwrap_or(SPACE1),
+            max_items,
+        )
+    }
+
+    fn _update_space_settings_with_handles_enabled() -> DispatchResultWithPostInfo {
         _update_space_settings(None, Some(space_settings_with_handles_enabled()))
     }
 
-    fn _update_space_settings_with_handles_disabled() -> DispatchResult {
+    fn _update_space_settings_with_handles_disabled() -> DispatchResultWithPostInfo {
         _update_space_settings(None, Some(space_settings_with_handles_disabled()))
     }
 
     /// Default origin is a root.
-    fn _update_space_settings(origin: Option<Origin>, new_settings: Option<SpacesSettings>) -> DispatchResult {
+    fn _update_space_settings(origin: Option<Origin>, new_settings: Option<SpacesSettings>) -> DispatchResultWithPostInfo {
         Spaces::update_settings(
             origin.unwrap_or_else(Origin::root),
             new_settings.unwrap_or_else(space_settings_with_handles_disabled)
@@ -598,29 +800,29 @@ mod tests {
     }
 
     /// Account 2 follows Space 1
-    fn _default_follow_space() -> DispatchResult {
+    fn _default_follow_space() -> DispatchResultWithPostInfo {
         _follow_space(None, None)
     }
 
-    fn _follow_space(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResult {
+    fn _follow_space(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResultWithPostInfo {
         SpaceFollows::follow_space(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
             space_id.unwrap_or(SPACE1),
         )
     }
 
-    fn _default_unfollow_space() -> DispatchResult {
+    fn _default_unfollow_space() -> DispatchResultWithPostInfo {
         _unfollow_space(None, None)
     }
 
-    fn _unfollow_space(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResult {
+    fn _unfollow_space(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResultWithPostInfo {
         SpaceFollows::unfollow_space(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
             space_id.unwrap_or(SPACE1),
         )
     }
 
-    fn _create_default_post() -> DispatchResult {
+    fn _create_default_post() -> DispatchResultWithPostInfo {
         _create_post(None, None, None, None)
     }
 
@@ -629,7 +831,7 @@ mod tests {
         space_id_opt: Option<Option<SpaceId>>,
         extension: Option<PostExtension>,
         content: Option<Content>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Posts::create_post(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             space_id_opt.unwrap_or(Some(SPACE1)),
@@ -642,7 +844,7 @@ mod tests {
         origin: Option<Origin>,
         post_id: Option<PostId>,
         update: Option<PostUpdate>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Posts::update_post(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             post_id.unwrap_or(POST1),
@@ -650,12 +852,12 @@ mod tests {
         )
     }
 
-    fn _move_post_1_to_space_2() -> DispatchResult {
+    fn _move_post_1_to_space_2() -> DispatchResultWithPostInfo {
         _move_post(None, None, None)
     }
 
     /// Move the post out of this space to nowhere (space = None).
-    fn _move_post_to_nowhere(post_id: PostId) -> DispatchResult {
+    fn _move_post_to_nowhere(post_id: PostId) -> DispatchResultWithPostInfo {
         _move_post(None, Some(post_id), Some(None))
     }
 
@@ -663,7 +865,7 @@ mod tests {
         origin: Option<Origin>,
         post_id: Option<PostId>,
         new_space_id: Option<Option<SpaceId>>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Posts::move_post(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             post_id.unwrap_or(POST1),
@@ -671,7 +873,39 @@ mod tests {
         )
     }
 
-    fn _create_default_comment() -> DispatchResult {
+    fn _delete_default_post() -> DispatchResultWithPostInfo {
+        _delete_post(None, None)
+    }
+
+    fn _delete_post(origin: Option<Origin>, post_id: Option<PostId>) -> DispatchResultWithPostInfo {
+        _delete_post_with_max_replies(origin, post_id, MAX_REPLIES_TO_DELETE)
+    }
+
+    fn _delete_post_with_max_replies(
+        origin: Option<Origin>,
+        post_id: Option<PostId>,
+        max_replies: u32,
+    ) -> DispatchResultWithPostInfo {
+        Posts::delete_post(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            post_id.unwrap_or(POST1),
+            max_replies,
+        )
+    }
+
+    fn _update_post_permissions(
+        origin: Option<Origin>,
+        post_id: Option<PostId>,
+        permissions: Option<Option<SpacePermissions>>,
+    ) -> DispatchResultWithPostInfo {
+        Posts::update_post_permissions(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            post_id.unwrap_or(POST1),
+            permissions.unwrap_or_else(|| Some(post_permissions_where_comments_are_locked())),
+        )
+    }
+
+    fn _create_default_comment() -> DispatchResultWithPostInfo {
         _create_comment(None, None, None, None)
     }
 
@@ -680,7 +914,7 @@ mod tests {
         post_id: Option<PostId>,
         parent_id: Option<Option<PostId>>,
         content: Option<Content>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         _create_post(
             origin,
             Some(None),
@@ -696,7 +930,7 @@ mod tests {
         origin: Option<Origin>,
         post_id: Option<PostId>,
         update: Option<PostUpdate>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         _update_post(
             origin,
             Some(post_id.unwrap_or(POST2)),
@@ -706,11 +940,11 @@ mod tests {
         )
     }
 
-    fn _create_default_post_reaction() -> DispatchResult {
+    fn _create_default_post_reaction() -> DispatchResultWithPostInfo {
         _create_post_reaction(None, None, None)
     }
 
-    fn _create_default_comment_reaction() -> DispatchResult {
+    fn _create_default_comment_reaction() -> DispatchResultWithPostInfo {
         _create_comment_reaction(None, None, None)
     }
 
@@ -718,7 +952,7 @@ mod tests {
         origin: Option<Origin>,
         post_id: Option<PostId>,
         kind: Option<ReactionKind>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Reactions::create_post_reaction(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             post_id.unwrap_or(POST1),
@@ -730,7 +964,7 @@ mod tests {
         origin: Option<Origin>,
         post_id: Option<PostId>,
         kind: Option<ReactionKind>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         _create_post_reaction(origin, Some(post_id.unwrap_or(2)), kind)
     }
 
@@ -739,7 +973,7 @@ mod tests {
         post_id: Option<PostId>,
         reaction_id: ReactionId,
         kind: Option<ReactionKind>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Reactions::update_post_reaction(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             post_id.unwrap_or(POST1),
@@ -753,7 +987,7 @@ mod tests {
         post_id: Option<PostId>,
         reaction_id: ReactionId,
         kind: Option<ReactionKind>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         _update_post_reaction(origin, Some(post_id.unwrap_or(2)), reaction_id, kind)
     }
 
@@ -761,7 +995,7 @@ mod tests {
         origin: Option<Origin>,
         post_id: Option<PostId>,
         reaction_id: ReactionId,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Reactions::delete_post_reaction(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             post_id.unwrap_or(POST1),
@@ -773,18 +1007,42 @@ mod tests {
         origin: Option<Origin>,
         post_id: Option<PostId>,
         reaction_id: ReactionId,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         _delete_post_reaction(origin, Some(post_id.unwrap_or(2)), reaction_id)
     }
 
-    fn _create_default_profile() -> DispatchResult {
+    fn _create_reaction(
+        origin: Option<Origin>,
+        target: Option<ReactionTarget<AccountId>>,
+        kind: Option<ReactionKind>,
+    ) -> DispatchResultWithPostInfo {
+        Reactions::create_reaction(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
+            target.unwrap_or(ReactionTarget::Space(SPACE1)),
+            kind.unwrap_or_else(reaction_upvote),
+        )
+    }
+
+    fn _update_space_reaction_kinds(
+        origin: Option<Origin>,
+        space_id: Option<SpaceId>,
+        kinds: Option<Vec<Vec<u8>>>,
+    ) -> DispatchResultWithPostInfo {
+        Reactions::update_space_reaction_kinds(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            space_id.unwrap_or(SPACE1),
+            kinds.unwrap_or_else(|| vec![custom_reaction_kind()]),
+        )
+    }
+
+    fn _create_default_profile() -> DispatchResultWithPostInfo {
         _create_profile(None, None)
     }
 
     fn _create_profile(
         origin: Option<Origin>,
         content: Option<Content>
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Profiles::create_profile(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             content.unwrap_or_else(profile_content_ipfs),
@@ -794,79 +1052,135 @@ mod tests {
     fn _update_profile(
         origin: Option<Origin>,
         content: Option<Content>
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Profiles::update_profile(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             ProfileUpdate {
+                handle: None,
                 content,
             },
         )
     }
 
-    fn _default_follow_account() -> DispatchResult {
+    fn profile_handle() -> Vec<u8> {
+        b"profile_handle".to_vec()
+    }
+
+    fn _update_profile_handle(
+        origin: Option<Origin>,
+        handle: Option<Vec<u8>>
+    ) -> DispatchResultWithPostInfo {
+        Profiles::update_profile(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            ProfileUpdate {
+                handle: Some(handle),
+                content: None,
+            },
+        )
+    }
+
+    fn _default_follow_account() -> DispatchResultWithPostInfo {
         _follow_account(None, None)
     }
 
-    fn _follow_account(origin: Option<Origin>, account: Option<AccountId>) -> DispatchResult {
+    fn _follow_account(origin: Option<Origin>, account: Option<AccountId>) -> DispatchResultWithPostInfo {
         ProfileFollows::follow_account(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
             account.unwrap_or(ACCOUNT1),
         )
     }
 
-    fn _default_unfollow_account() -> DispatchResult {
+    fn _default_unfollow_account() -> DispatchResultWithPostInfo {
         _unfollow_account(None, None)
     }
 
-    fn _unfollow_account(origin: Option<Origin>, account: Option<AccountId>) -> DispatchResult {
+    fn _unfollow_account(origin: Option<Origin>, account: Option<AccountId>) -> DispatchResultWithPostInfo {
         ProfileFollows::unfollow_account(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
             account.unwrap_or(ACCOUNT1),
         )
     }
 
-    fn _transfer_default_space_ownership() -> DispatchResult {
-        _transfer_space_ownership(None, None, None)
+    fn _transfer_default_space_ownership() -> DispatchResultWithPostInfo {
+        _transfer_space_ownership(None, None, None, None)
     }
 
     fn _transfer_space_ownership(
         origin: Option<Origin>,
         space_id: Option<SpaceId>,
         transfer_to: Option<AccountId>,
-    ) -> DispatchResult {
+        transfer_roles_and_follow: Option<bool>,
+    ) -> DispatchResultWithPostInfo {
         SpaceOwnership::transfer_space_ownership(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             space_id.unwrap_or(SPACE1),
             transfer_to.unwrap_or(ACCOUNT2),
+            transfer_roles_and_follow.unwrap_or(false),
         )
     }
 
-    fn _accept_default_pending_ownership() -> DispatchResult {
+    fn _accept_default_pending_ownership() -> DispatchResultWithPostInfo {
         _accept_pending_ownership(None, None)
     }
 
-    fn _accept_pending_ownership(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResult {
+    fn _accept_pending_ownership(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResultWithPostInfo {
         SpaceOwnership::accept_pending_ownership(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
             space_id.unwrap_or(SPACE1),
         )
     }
 
-    fn _reject_default_pending_ownership() -> DispatchResult {
+    fn _reject_default_pending_ownership() -> DispatchResultWithPostInfo {
         _reject_pending_ownership(None, None)
     }
 
-    fn _reject_default_pending_ownership_by_current_owner() -> DispatchResult {
+    fn _reject_default_pending_ownership_by_current_owner() -> DispatchResultWithPostInfo {
         _reject_pending_ownership(Some(Origin::signed(ACCOUNT1)), None)
     }
 
-    fn _reject_pending_ownership(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResult {
+    fn _reject_pending_ownership(origin: Option<Origin>, space_id: Option<SpaceId>) -> DispatchResultWithPostInfo {
         SpaceOwnership::reject_pending_ownership(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
             space_id.unwrap_or(SPACE1),
         )
     }
 
+    fn space_owners_update(
+        add_owners: Vec<AccountId>,
+        remove_owners: Vec<AccountId>,
+        threshold: Option<u16>,
+    ) -> SpaceOwnersUpdate<AccountId> {
+        SpaceOwnersUpdate { add_owners, remove_owners, threshold }
+    }
+
+    fn _add_default_space_co_owner() -> DispatchResultWithPostInfo {
+        _update_space_owners(None, None, Some(space_owners_update(vec![ACCOUNT2], vec![], Some(2))))
+    }
+
+    fn _update_space_owners(
+        origin: Option<Origin>,
+        space_id: Option<SpaceId>,
+        update: Option<SpaceOwnersUpdate<AccountId>>,
+    ) -> DispatchResultWithPostInfo {
+        Spaces::update_space_owners(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            space_id.unwrap_or(SPACE1),
+            update.unwrap_or_else(|| space_owners_update(vec![ACCOUNT2], vec![], None)),
+        )
+    }
+
+    fn _approve_space_owners_action(
+        origin: Option<Origin>,
+        space_id: Option<SpaceId>,
+        action: SpaceOwnersAction<AccountId>,
+    ) -> DispatchResultWithPostInfo {
+        Spaces::approve_space_owners_action(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT2)),
+            space_id.unwrap_or(SPACE1),
+            action,
+        )
+    }
+
     /* ---------------------------------------------------------------------------------------------- */
 
     // TODO: fix copy-paste from pallet_roles
@@ -887,7 +1201,7 @@ mod tests {
     }
 
 
-    pub fn _create_default_role() -> DispatchResult {
+    pub fn _create_default_role() -> DispatchResultWithPostInfo {
         _create_role(None, None, None, None, None)
     }
 
@@ -897,7 +1211,7 @@ mod tests {
         time_to_live: Option<Option<BlockNumber>>,
         content: Option<Content>,
         permissions: Option<Vec<SpacePermission>>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Roles::create_role(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             space_id.unwrap_or(SPACE1),
@@ -907,7 +1221,7 @@ mod tests {
         )
     }
 
-    pub fn _grant_default_role() -> DispatchResult {
+    pub fn _grant_default_role() -> DispatchResultWithPostInfo {
         _grant_role(None, None, None)
     }
 
@@ -915,7 +1229,7 @@ mod tests {
         origin: Option<Origin>,
         role_id: Option<RoleId>,
         users: Option<Vec<User<AccountId>>>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Roles::grant_role(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             role_id.unwrap_or(ROLE1),
@@ -923,14 +1237,14 @@ mod tests {
         )
     }
 
-    pub fn _delete_default_role() -> DispatchResult {
+    pub fn _delete_default_role() -> DispatchResultWithPostInfo {
         _delete_role(None, None)
     }
 
     pub fn _delete_role(
         origin: Option<Origin>,
         role_id: Option<RoleId>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Roles::delete_role(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             role_id.unwrap_or(ROLE1),
@@ -943,7 +1257,7 @@ mod tests {
     #[allow(dead_code)]
     const REPORT1: ReportId = 1;
 
-    pub(crate) fn _report_default_post() -> DispatchResult {
+    pub(crate) fn _report_default_post() -> DispatchResultWithPostInfo {
         _report_entity(None, None, None, None)
     }
 
@@ -952,7 +1266,7 @@ mod tests {
         entity: Option<EntityId<AccountId>>,
         scope: Option<SpaceId>,
         reason: Option<Content>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Moderation::report_entity(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             entity.unwrap_or(EntityId::Post(POST1)),
@@ -967,7 +1281,7 @@ mod tests {
         scope: Option<SpaceId>,
         status: Option<Option<EntityStatus>>,
         report_id_opt: Option<Option<ReportId>>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Moderation::suggest_entity_status(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             entity.unwrap_or(EntityId::Post(POST1)),
@@ -982,7 +1296,7 @@ mod tests {
         entity: Option<EntityId<AccountId>>,
         scope: Option<SpaceId>,
         status_opt: Option<Option<EntityStatus>>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Moderation::update_entity_status(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             entity.unwrap_or(EntityId::Post(POST1)),
@@ -995,7 +1309,7 @@ mod tests {
         origin: Option<Origin>,
         entity: Option<EntityId<AccountId>>,
         scope: Option<SpaceId>,
-    ) -> DispatchResult {
+    ) -> DispatchResultWithPostInfo {
         Moderation::delete_entity_status(
             origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
             entity.unwrap_or(EntityId::Post(POST1)),
@@ -1003,6 +1317,20 @@ mod tests {
         )
     }
 
+    pub(crate) fn _update_moderation_settings(
+        origin: Option<Origin>,
+        space_id: Option<SpaceId>,
+        settings_update: Option<SpaceModerationSettingsUpdate>,
+    ) -> DispatchResultWithPostInfo {
+        Moderation::update_moderation_settings(
+            origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+            space_id.unwrap_or(SPACE1),
+            settings_update.unwrap_or(SpaceModerationSettingsUpdate {
+                autoblock_threshold: Some(Some(1))
+            }),
+        )
+    }
+
     /*------------------------------------------------------------------------------------------------*/
     // Moderation tests
 
@@ -1193,6 +1521,143 @@ mod tests {
     }
     */
 
+    /// Account 3 reports Account 2 in Space 1, then the space owner (Account 1)
+    /// suggests to block Account 2 and makes a final decision to block it.
+    fn report_suggest_and_block_account_2_in_space_1() {
+        assert_ok!(_report_entity(
+            Some(Origin::signed(ACCOUNT3)),
+            Some(EntityId::Account(ACCOUNT2)),
+            None,
+            None
+        ));
+        assert_ok!(_suggest_entity_status(
+            None,
+            Some(EntityId::Account(ACCOUNT2)),
+            None,
+            None,
+            None
+        ));
+        assert_ok!(_update_entity_status(
+            None,
+            Some(EntityId::Account(ACCOUNT2)),
+            None,
+            Some(Some(EntityStatus::Blocked))
+        ));
+    }
+
+    #[test]
+    fn report_suggest_and_block_account_should_work() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_default_follow_space()); // Account 2 follows Space 1
+
+            report_suggest_and_block_account_2_in_space_1();
+
+            assert_eq!(
+                Moderation::status_by_entity_in_space(EntityId::Account(ACCOUNT2), SPACE1),
+                Some(EntityStatus::Blocked)
+            );
+            assert_eq!(Moderation::suggested_statuses(EntityId::Account(ACCOUNT2), SPACE1).len(), 1);
+
+            // Blocked account should be removed from the space followers:
+            assert!(!SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 1);
+        });
+    }
+
+    #[test]
+    fn block_account_should_work_when_account_is_not_space_follower() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            report_suggest_and_block_account_2_in_space_1();
+
+            assert_eq!(
+                Moderation::status_by_entity_in_space(EntityId::Account(ACCOUNT2), SPACE1),
+                Some(EntityStatus::Blocked)
+            );
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 1);
+        });
+    }
+
+    #[test]
+    fn blocked_account_should_not_be_able_to_post_react_or_follow_in_space() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            report_suggest_and_block_account_2_in_space_1();
+
+            assert_noop!(
+                _create_post(Some(Origin::signed(ACCOUNT2)), None, None, None),
+                UtilsError::<TestRuntime>::AccountIsBlocked
+            );
+            assert_noop!(
+                _create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None),
+                UtilsError::<TestRuntime>::AccountIsBlocked
+            );
+            assert_noop!(
+                _create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None),
+                UtilsError::<TestRuntime>::AccountIsBlocked
+            );
+            assert_noop!(
+                _default_follow_space(),
+                UtilsError::<TestRuntime>::AccountIsBlocked
+            );
+        });
+    }
+
+    #[test]
+    fn account_should_be_able_to_follow_space_after_entity_status_deleted() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            report_suggest_and_block_account_2_in_space_1();
+
+            assert_ok!(_delete_entity_status(None, Some(EntityId::Account(ACCOUNT2)), None));
+            assert_ok!(_default_follow_space());
+        });
+    }
+
+    #[test]
+    fn blocked_account_should_be_able_to_react_in_another_space() {
+        ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+            report_suggest_and_block_account_2_in_space_1();
+
+            assert_ok!(_create_post(None, Some(Some(SPACE2)), None, None)); // PostId 2 in Space 2
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(POST2), None));
+        });
+    }
+
+    #[test]
+    fn post_should_be_autoblocked_when_threshold_reached() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_moderation_settings(None, None, None)); // Threshold is 1
+
+            assert_ok!(_report_entity(Some(Origin::signed(ACCOUNT2)), None, None, None));
+            assert_ok!(_suggest_entity_status(None, None, None, None, None));
+
+            assert_eq!(
+                Moderation::status_by_entity_in_space(EntityId::Post(POST1), SPACE1),
+                Some(EntityStatus::Blocked)
+            );
+
+            // Blocked post should be removed from the space:
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert!(post.space_id.is_none());
+            assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 0);
+
+            assert_noop!(
+                _create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None),
+                PostsError::<TestRuntime>::PostHasNoSpaceId
+            );
+        });
+    }
+
+    #[test]
+    fn post_should_not_be_blocked_when_threshold_not_reached() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_report_entity(Some(Origin::signed(ACCOUNT2)), None, None, None));
+            assert_ok!(_suggest_entity_status(None, None, None, None, None));
+
+            assert_eq!(Moderation::status_by_entity_in_space(EntityId::Post(POST1), SPACE1), None);
+            assert_eq!(Posts::post_by_id(POST1).unwrap().space_id, Some(SPACE1));
+        });
+    }
+
     /*---------------------------------------------------------------------------------------------------*/
     // Spaces tests
 
@@ -1438,6 +1903,31 @@ mod tests {
         });
     }
 
+    #[test]
+    fn get_space_history_should_return_newest_records_first() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(space_update(Some(None), None, None))
+            ));
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(space_update(None, Some(updated_space_content()), None))
+            ));
+
+            let history = SpaceHistory::get_space_history(SPACE1, 0, 10);
+            assert_eq!(history.len(), 2);
+
+            assert_eq!(history[0].old_content, Some(space_content_ipfs().into()));
+            assert!(history[0].old_handle.is_none());
+
+            assert_eq!(history[1].old_handle, Some(Some(space_handle())));
+            assert!(history[1].old_content.is_none());
+        });
+    }
+
     #[test]
     fn update_space_should_work_when_one_of_roles_is_permitted() {
         ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(|| {
@@ -1738,24 +2228,322 @@ mod tests {
         });
     }
 
-    // TODO: refactor or remove. Deprecated tests
-    // Find public space ids tests
-    // --------------------------------------------------------------------------------------------
-    /*#[test]
-    fn find_public_space_ids_should_work() {
+    #[test]
+    fn delete_space_should_work() {
         ExtBuilder::build_with_space().execute_with(|| {
-            assert_ok!(_create_space(None, None, Some(Some(space_handle1())), None, None));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), HANDLE_DEPOSIT);
 
-            let space_ids = Spaces::find_public_space_ids(0, 3);
-            assert_eq!(space_ids, vec![SPACE1, SPACE2]);
+            assert_ok!(_delete_default_space());
+
+            assert!(Spaces::space_by_id(SPACE1).is_none());
+            assert!(Spaces::space_ids_by_owner(ACCOUNT1).is_empty());
+            assert!(find_space_id_by_handle(space_handle()).is_none());
+            assert!(Balances::reserved_balance(ACCOUNT1).is_zero());
         });
     }
 
     #[test]
-    fn find_public_space_ids_should_work_with_zero_offset() {
-        ExtBuilder::build_with_space().execute_with(|| {
-            let space_ids = Spaces::find_public_space_ids(0, 1);
-            assert_eq!(space_ids, vec![SPACE1]);
+    fn delete_space_should_move_its_posts_out() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_delete_default_space());
+
+            assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+            assert_eq!(Posts::post_by_id(POST1).unwrap().space_id, None);
+
+            // The post owner is still able to delete the post:
+            assert_ok!(_delete_default_post());
+        });
+    }
+
+    #[test]
+    fn delete_space_should_remove_its_followers() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_default_follow_space()); // Account 2 follows Space 1
+
+            assert_ok!(_delete_default_space());
+
+            assert!(SpaceFollows::space_followers(SPACE1).is_empty());
+            assert!(!SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+            assert!(SpaceFollows::spaces_followed_by_account(ACCOUNT2).is_empty());
+            assert!(SpaceFollows::spaces_followed_by_account(ACCOUNT1).is_empty());
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT2).unwrap().following_spaces_count, 0);
+        });
+    }
+
+    #[test]
+    fn delete_space_should_remove_its_roles() {
+        ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(|| {
+            assert_ok!(_delete_default_space());
+
+            assert!(Roles::role_by_id(ROLE1).is_none());
+            assert!(Roles::role_by_id(ROLE2).is_none());
+            assert!(Roles::users_by_role_id(ROLE1).is_empty());
+            assert!(Roles::role_ids_by_space_id(SPACE1).is_empty());
+            assert!(Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE1).is_empty());
+        });
+    }
+
+    #[test]
+    fn delete_space_should_remove_pending_ownership_transfer() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership());
+
+            assert_ok!(_delete_default_space());
+
+            assert!(SpaceOwnership::pending_space_owner(SPACE1).is_none());
+        });
+    }
+
+    #[test]
+    fn delete_own_subspace_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_subspace(None, Some(Some(SPACE1)), Some(None), None, None)); // SpaceId 2
+
+            assert_ok!(_delete_space(None, Some(SPACE2)));
+
+            assert!(Spaces::space_by_id(SPACE2).is_none());
+            assert_eq!(Spaces::space_ids_by_owner(ACCOUNT1), vec![SPACE1]);
+        });
+    }
+
+    #[test]
+    fn delete_space_should_fail_when_it_has_more_items_than_allowed() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_default_follow_space()); // Account 2 follows Space 1
+
+            // Space 1 has one post and two followers: Account 1 and Account 2.
+            assert_noop!(
+                _delete_space_with_max_items(None, None, 2),
+                SpacesError::<TestRuntime>::TooManyItemsToDeleteWithSpace
+            );
+            assert_ok!(_delete_space_with_max_items(None, None, 3));
+        });
+    }
+
+    #[test]
+    fn delete_space_should_fail_when_space_not_found() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(_delete_default_space(), SpacesError::<TestRuntime>::SpaceNotFound);
+        });
+    }
+
+    #[test]
+    fn delete_space_should_fail_when_account_is_not_space_owner() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _delete_space(Some(Origin::signed(ACCOUNT2)), None),
+                SpacesError::<TestRuntime>::NotASpaceOwner
+            );
+        });
+    }
+
+    #[test]
+    fn delete_subspace_should_fail_when_account_has_no_permission_to_delete_any_subspace() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_subspace(None, Some(Some(SPACE1)), Some(None), None, None)); // SpaceId 2
+
+            assert_noop!(
+                _delete_space(Some(Origin::signed(ACCOUNT2)), Some(SPACE2)),
+                SpacesError::<TestRuntime>::NoPermissionToDeleteAnySubspace
+            );
+        });
+    }
+
+    fn _create_default_subspace(parent_id: SpaceId) -> DispatchResultWithPostInfo {
+        _create_subspace(None, Some(Some(parent_id)), Some(None), None, None)
+    }
+
+    #[test]
+    fn create_subspace_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+
+            assert_eq!(Spaces::space_by_id(SPACE2).unwrap().parent_id, Some(SPACE1));
+            assert_eq!(Spaces::get_subspace_ids(SPACE1), vec![SPACE2]);
+            assert_eq!(Spaces::subspaces_count(SPACE1), 1);
+            assert_eq!(Spaces::get_space_ancestor_ids(SPACE2), vec![SPACE1]);
+        });
+    }
+
+    #[test]
+    fn create_subspace_should_fail_when_max_subspace_depth_reached() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+            assert_ok!(_create_default_subspace(SPACE2)); // SpaceId 3
+            assert_ok!(_create_default_subspace(SPACE3)); // SpaceId 4
+
+            assert_eq!(Spaces::get_space_ancestor_ids(SPACE4), vec![SPACE3, SPACE2, SPACE1]);
+            assert_noop!(
+                _create_default_subspace(SPACE4),
+                SpacesError::<TestRuntime>::MaxSubspaceDepthReached
+            );
+        });
+    }
+
+    #[test]
+    fn create_subspace_should_fail_when_account_has_no_permission_to_override_subspace_permissions() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(SpaceUpdate {
+                    permissions: Some(Some(permissions_where_everyone_can_create_subspaces())),
+                    ..space_update(None, None, None)
+                })
+            ));
+
+            assert_ok!(_create_subspace(
+                Some(Origin::signed(ACCOUNT2)), Some(Some(SPACE1)), Some(None), None, None
+            ));
+            assert_noop!(
+                _create_subspace(
+                    Some(Origin::signed(ACCOUNT2)),
+                    Some(Some(SPACE1)),
+                    Some(None),
+                    None,
+                    Some(Some(permissions_where_everyone_can_create_post()))
+                ),
+                SpacesError::<TestRuntime>::NoPermissionToOverrideSubspacePermissions
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_parent_id_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 3
+
+            assert_ok!(_update_space(None, Some(SPACE2), Some(update_for_space_parent_id(Some(SPACE3)))));
+
+            assert_eq!(Spaces::space_by_id(SPACE2).unwrap().parent_id, Some(SPACE3));
+            assert!(Spaces::get_subspace_ids(SPACE1).is_empty());
+            assert_eq!(Spaces::subspaces_count(SPACE1), 0);
+            assert_eq!(Spaces::get_subspace_ids(SPACE3), vec![SPACE2]);
+            assert_eq!(Spaces::subspaces_count(SPACE3), 1);
+
+            assert_ok!(_update_space(None, Some(SPACE2), Some(update_for_space_parent_id(None))));
+
+            assert_eq!(Spaces::space_by_id(SPACE2).unwrap().parent_id, None);
+            assert!(Spaces::get_subspace_ids(SPACE3).is_empty());
+            assert_eq!(Spaces::subspaces_count(SPACE3), 0);
+        });
+    }
+
+    #[test]
+    fn update_space_parent_id_should_fail_when_it_creates_a_cycle() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+            assert_ok!(_create_default_subspace(SPACE2)); // SpaceId 3
+
+            assert_noop!(
+                _update_space(None, None, Some(update_for_space_parent_id(Some(SPACE1)))),
+                SpacesError::<TestRuntime>::SpaceParentCycle
+            );
+            assert_noop!(
+                _update_space(None, None, Some(update_for_space_parent_id(Some(SPACE3)))),
+                SpacesError::<TestRuntime>::SpaceParentCycle
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_parent_id_should_fail_when_max_subspace_depth_reached() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+            assert_ok!(_create_default_subspace(SPACE2)); // SpaceId 3
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 4
+            assert_ok!(_create_default_subspace(SPACE4)); // SpaceId 5
+
+            // Space 4 with its subspace would be nested 4 levels deep under Space 1:
+            assert_noop!(
+                _update_space(None, Some(SPACE4), Some(update_for_space_parent_id(Some(SPACE3)))),
+                SpacesError::<TestRuntime>::MaxSubspaceDepthReached
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_parent_id_should_fail_when_space_has_too_many_subspaces_to_move() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 3
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 4
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 5
+
+            // MaxSubspacesToMove is 2 in this test runtime:
+            assert_noop!(
+                _update_space(None, None, Some(update_for_space_parent_id(Some(SPACE5)))),
+                SpacesError::<TestRuntime>::TooManySubspacesToMove
+            );
+
+            assert_ok!(_delete_space(None, Some(SPACE4)));
+            assert_ok!(_update_space(None, None, Some(update_for_space_parent_id(Some(SPACE5)))));
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().parent_id, Some(SPACE5));
+        });
+    }
+
+    #[test]
+    fn subspace_should_inherit_permissions_of_parent_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(SpaceUpdate {
+                    permissions: Some(Some(permissions_where_everyone_can_create_post())),
+                    ..space_update(None, None, None)
+                })
+            ));
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+
+            assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), Some(Some(SPACE2)), None, None));
+        });
+    }
+
+    #[test]
+    fn delete_space_should_move_its_subspaces_to_root() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+
+            assert_ok!(_delete_default_space());
+
+            assert_eq!(Spaces::space_by_id(SPACE2).unwrap().parent_id, None);
+            assert!(Spaces::get_subspace_ids(SPACE1).is_empty());
+            assert_eq!(Spaces::subspaces_count(SPACE1), 0);
+        });
+    }
+
+    #[test]
+    fn delete_subspace_should_remove_it_from_subspaces_of_parent() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 2
+            assert_ok!(_create_default_subspace(SPACE1)); // SpaceId 3
+
+            assert_ok!(_delete_space(None, Some(SPACE2)));
+
+            assert_eq!(Spaces::get_subspace_ids(SPACE1), vec![SPACE3]);
+            assert_eq!(Spaces::subspaces_count(SPACE1), 1);
+        });
+    }
+
+    // TODO: refactor or remove. Deprecated tests
+    // Find public space ids tests
+    // --------------------------------------------------------------------------------------------
+    /*#[test]
+    fn find_public_space_ids_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_space(None, None, Some(Some(space_handle1())), None, None));
+
+            let space_ids = Spaces::find_public_space_ids(0, 3);
+            assert_eq!(space_ids, vec![SPACE1, SPACE2]);
+        });
+    }
+
+    #[test]
+    fn find_public_space_ids_should_work_with_zero_offset() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let space_ids = Spaces::find_public_space_ids(0, 1);
+            assert_eq!(space_ids, vec![SPACE1]);
         });
     }
 
@@ -1926,6 +2714,26 @@ mod tests {
         });
     }
 
+    #[test]
+    fn get_post_ids_by_space_id_page_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_post()); // PostId 1
+            assert_ok!(_create_default_post()); // PostId 2
+            assert_ok!(_create_default_post()); // PostId 3
+
+            let first_page = Posts::get_post_ids_by_space_id_page(SPACE1, None, 2);
+            assert_eq!(first_page.len(), 2);
+
+            let cursor = first_page.last().cloned();
+            let second_page = Posts::get_post_ids_by_space_id_page(SPACE1, cursor, 2);
+            assert_eq!(second_page.len(), 1);
+
+            let mut post_ids = [first_page, second_page].concat();
+            post_ids.sort_unstable();
+            assert_eq!(post_ids, vec![POST1, POST2, POST3]);
+        });
+    }
+
     #[test]
     fn create_post_should_work_when_one_of_roles_is_permitted() {
         ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(|| {
@@ -1982,6 +2790,78 @@ mod tests {
         });
     }
 
+    fn build_with_role_granted_to_space_2() -> TestExternalities {
+        let mut ext = ExtBuilder::build_with_space();
+        ext.execute_with(|| {
+            // Create a space #2 from account #2
+            assert_ok!(_create_space(Some(Origin::signed(ACCOUNT2)), Some(None), None, None));
+
+            assert_ok!(_create_role(None, None, None, None, Some(vec![SP::CreatePosts]))); // RoleId 1
+            assert_ok!(_grant_role(None, None, Some(vec![User::Space(SPACE2)])));
+        });
+        ext
+    }
+
+    #[test]
+    fn create_post_should_work_when_owner_of_space_holds_role() {
+        build_with_role_granted_to_space_2().execute_with(|| {
+            assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None)); // PostId 1 in Space 1
+            assert_eq!(Posts::post_by_id(POST1).unwrap().created.account, ACCOUNT2);
+        });
+    }
+
+    #[test]
+    fn create_post_should_work_when_account_represents_space_that_holds_role() {
+        build_with_role_granted_to_space_2().execute_with(|| {
+            assert_noop!(
+                _create_post(Some(Origin::signed(ACCOUNT3)), None, None, None),
+                PostsError::<TestRuntime>::NoPermissionToCreatePosts
+            );
+
+            assert_ok!(_create_role(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(SPACE2),
+                None,
+                None,
+                Some(vec![SP::RepresentSpaceExternally])
+            )); // RoleId 2
+            assert_ok!(_grant_role(Some(Origin::signed(ACCOUNT2)), Some(ROLE2), Some(vec![User::Account(ACCOUNT3)])));
+
+            assert_ok!(_create_post(Some(Origin::signed(ACCOUNT3)), None, None, None));
+        });
+    }
+
+    #[test]
+    fn create_post_should_fail_when_role_is_revoked_from_space() {
+        build_with_role_granted_to_space_2().execute_with(|| {
+            assert_ok!(Roles::revoke_role(Origin::signed(ACCOUNT1), ROLE1, vec![User::Space(SPACE2)]));
+            assert!(!Roles::space_role_holders_by_space_id(SPACE1, SPACE2));
+
+            assert_noop!(
+                _create_post(Some(Origin::signed(ACCOUNT2)), None, None, None),
+                PostsError::<TestRuntime>::NoPermissionToCreatePosts
+            );
+        });
+    }
+
+    #[test]
+    fn delete_space_should_revoke_roles_that_it_holds_in_other_spaces() {
+        build_with_role_granted_to_space_2().execute_with(|| {
+            // Space 2 has one follower (Account 2) and holds one role in Space 1.
+            assert_noop!(
+                _delete_space_with_max_items(Some(Origin::signed(ACCOUNT2)), Some(SPACE2), 1),
+                SpacesError::<TestRuntime>::TooManyItemsToDeleteWithSpace
+            );
+            assert_ok!(_delete_space_with_max_items(Some(Origin::signed(ACCOUNT2)), Some(SPACE2), 2));
+
+            assert!(!Roles::space_role_holders_by_space_id(SPACE1, SPACE2));
+            assert!(Roles::role_ids_by_user_in_space(User::Space(SPACE2), SPACE1).is_empty());
+            assert!(Roles::users_by_role_id(ROLE1).is_empty());
+            assert_eq!(Roles::users_count_by_role_id(ROLE1), 0);
+            assert_eq!(Roles::held_roles_count_by_space_id(SPACE2), 0);
+        });
+    }
+
     #[test]
     fn create_post_should_fail_when_no_right_permission_in_account_roles() {
         ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(|| {
@@ -2028,6 +2908,62 @@ mod tests {
         });
     }
 
+    #[test]
+    fn update_post_should_prune_oldest_history_records() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_post(
+                None,
+                None,
+                Some(post_update(None, Some(updated_post_content()), None))
+            ));
+
+            // Exceed the limit of history records with hidden status updates
+            for &hidden in &[true, false, true] {
+                assert_ok!(_update_post(
+                    None,
+                    None,
+                    Some(post_update(None, None, Some(hidden)))
+                ));
+            }
+
+            let edit_history = PostHistory::edit_history(POST1);
+            assert_eq!(edit_history.len(), MaxEditHistoryRecords::get() as usize);
+
+            // The oldest record with the content update should be pruned
+            assert!(edit_history.iter().all(|record| record.old_data.content.is_none()));
+            assert_eq!(edit_history[0].old_data.hidden, Some(false));
+        });
+    }
+
+    #[test]
+    fn get_post_history_should_return_newest_records_first() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_post(
+                None,
+                None,
+                Some(post_update(None, Some(updated_post_content()), None))
+            ));
+            assert_ok!(_update_post(
+                None,
+                None,
+                Some(post_update(None, None, Some(true)))
+            ));
+
+            let history = PostHistory::get_post_history(POST1, 0, 10);
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0].old_hidden, Some(false));
+            assert!(history[0].old_content.is_none());
+            assert_eq!(history[0].edited.edited_by, ACCOUNT1);
+
+            let page = PostHistory::get_post_history(POST1, 1, 1);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].old_content, Some(post_content_ipfs().into()));
+            assert!(page[0].old_hidden.is_none());
+
+            assert!(PostHistory::get_post_history(POST1, 2, 10).is_empty());
+        });
+    }
+
     fn check_if_post_moved_correctly(
         moved_post_id: PostId,
         old_space_id: SpaceId,
@@ -2337,9 +3273,302 @@ mod tests {
         });
     }
 
-    // TODO: refactor or remove. Deprecated tests
-    // Find public post ids tests
-    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn delete_post_should_work() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_delete_default_post());
+
+            assert!(Posts::post_by_id(POST1).is_none());
+            assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 0);
+        });
+    }
+
+    #[test]
+    fn delete_hidden_post_should_update_space_hidden_posts_count() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_post(None, None, Some(post_update(None, None, Some(true)))));
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().hidden_posts_count, 1);
+
+            assert_ok!(_delete_default_post());
+
+            let space = Spaces::space_by_id(SPACE1).unwrap();
+            assert_eq!(space.posts_count, 0);
+            assert_eq!(space.hidden_posts_count, 0);
+        });
+    }
+
+    #[test]
+    fn delete_post_should_delete_its_replies_and_reactions() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_ok!(_create_default_post_reaction()); // ReactionId 1 on PostId 1
+            assert_ok!(_create_default_comment_reaction()); // ReactionId 2 on PostId 2
+
+            assert_ok!(_delete_default_post());
+
+            assert!(Posts::post_by_id(POST1).is_none());
+            assert!(Posts::post_by_id(POST2).is_none());
+            assert!(Posts::reply_ids_by_post_id(POST1).is_empty());
+
+            assert!(Reactions::reaction_by_id(REACTION1).is_none());
+            assert!(Reactions::reaction_by_id(REACTION2).is_none());
+            assert!(Reactions::reaction_ids_by_post_id(POST1).is_empty());
+            assert!(Reactions::reaction_ids_by_post_id(POST2).is_empty());
+            assert!(!<pallet_reactions::PostReactionIdByAccount<TestRuntime>>::contains_key((ACCOUNT1, POST1)));
+            assert!(!<pallet_reactions::PostReactionIdByAccount<TestRuntime>>::contains_key((ACCOUNT1, POST2)));
+        });
+    }
+
+    #[test]
+    fn delete_post_should_fail_when_it_has_more_replies_than_allowed() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3 reply to PostId 2
+
+            assert_noop!(
+                _delete_post_with_max_replies(None, None, 1),
+                PostsError::<TestRuntime>::TooManyRepliesToDelete
+            );
+            assert_ok!(_delete_post_with_max_replies(None, None, 2));
+
+            assert!(Posts::post_by_id(POST1).is_none());
+            assert!(Posts::post_by_id(POST3).is_none());
+        });
+    }
+
+    #[test]
+    fn delete_post_should_delete_its_edit_history() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_post(None, None, Some(post_update(None, Some(updated_post_content()), None))));
+            assert!(!PostHistory::edit_history(POST1).is_empty());
+
+            assert_ok!(_delete_default_post());
+            assert!(PostHistory::edit_history(POST1).is_empty());
+        });
+    }
+
+    #[test]
+    fn delete_comment_should_update_ancestors_replies_count() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3 reply to PostId 2
+            assert_ok!(_update_comment(
+                None,
+                Some(POST3),
+                Some(post_update(None, None, Some(true)))
+            ));
+
+            let root_post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(root_post.replies_count, 2);
+            assert_eq!(root_post.hidden_replies_count, 1);
+
+            assert_ok!(_delete_post(None, Some(POST3)));
+
+            let root_post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(root_post.replies_count, 1);
+            assert_eq!(root_post.hidden_replies_count, 0);
+
+            let parent_comment = Posts::post_by_id(POST2).unwrap();
+            assert_eq!(parent_comment.replies_count, 0);
+            assert_eq!(parent_comment.hidden_replies_count, 0);
+
+            assert!(Posts::reply_ids_by_post_id(POST2).is_empty());
+            assert!(Posts::post_by_id(POST3).is_none());
+        });
+    }
+
+    #[test]
+    fn delete_comment_should_delete_nested_replies() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3 reply to PostId 2
+
+            assert_ok!(_delete_post(None, Some(POST2)));
+
+            assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 0);
+            assert!(Posts::reply_ids_by_post_id(POST1).is_empty());
+            assert!(Posts::post_by_id(POST2).is_none());
+            assert!(Posts::post_by_id(POST3).is_none());
+        });
+    }
+
+    #[test]
+    fn delete_sharing_post_should_update_original_post() {
+        ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+            assert_ok!(_create_post(
+                None,
+                Some(Some(SPACE2)),
+                Some(extension_shared_post(POST1)),
+                None
+            )); // PostId 2 shares PostId 1 on SpaceId 2
+
+            assert_ok!(_delete_post(None, Some(POST2)));
+
+            assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 0);
+            assert!(Posts::shared_post_ids_by_original_post_id(POST1).is_empty());
+            assert!(Posts::post_ids_by_space_id(SPACE2).is_empty());
+            assert_eq!(Spaces::space_by_id(SPACE2).unwrap().posts_count, 0);
+        });
+    }
+
+    #[test]
+    fn delete_post_should_work_when_post_has_no_space() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_move_post_to_nowhere(POST1));
+            assert_ok!(_delete_default_post());
+
+            assert!(Posts::post_by_id(POST1).is_none());
+        });
+    }
+
+    #[test]
+    fn delete_any_post_should_work_when_one_of_roles_is_permitted() {
+        ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::DeleteAnyPost]).execute_with(|| {
+            assert_ok!(_create_default_post()); // PostId 1
+
+            assert_ok!(_delete_post(Some(Origin::signed(ACCOUNT2)), None));
+            assert!(Posts::post_by_id(POST1).is_none());
+        });
+    }
+
+    #[test]
+    fn delete_post_should_fail_when_post_not_found() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(_delete_default_post(), PostsError::<TestRuntime>::PostNotFound);
+        });
+    }
+
+    #[test]
+    fn delete_post_should_fail_when_account_has_no_permission_to_delete_any_post() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(
+                _delete_post(Some(Origin::signed(ACCOUNT2)), None),
+                PostsError::<TestRuntime>::NoPermissionToDeleteAnyPost
+            );
+        });
+    }
+
+    #[test]
+    fn delete_comment_should_fail_when_account_is_not_a_comment_author() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_noop!(
+                _delete_post(Some(Origin::signed(ACCOUNT2)), Some(POST2)),
+                PostsError::<TestRuntime>::NotACommentAuthor
+            );
+        });
+    }
+
+    #[test]
+    fn delete_post_should_fail_when_post_has_no_space_and_account_is_not_owner() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_move_post_to_nowhere(POST1));
+            assert_noop!(
+                _delete_post(Some(Origin::signed(ACCOUNT2)), None),
+                PostsError::<TestRuntime>::NotAPostOwner
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_lock_comments() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_post_permissions(None, None, None));
+
+            assert_eq!(
+                Posts::post_by_id(POST1).unwrap().permissions,
+                Some(post_permissions_where_comments_are_locked())
+            );
+            assert_noop!(
+                _create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None),
+                PostsError::<TestRuntime>::NoPermissionToCreateComments
+            );
+            assert_noop!(
+                _create_default_comment(),
+                PostsError::<TestRuntime>::NoPermissionToCreateComments
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_let_only_followers_comment() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_post_permissions(
+                None,
+                None,
+                Some(Some(post_permissions_where_only_followers_can_comment()))
+            ));
+
+            assert_noop!(
+                _create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None),
+                PostsError::<TestRuntime>::NoPermissionToCreateComments
+            );
+
+            assert_ok!(_default_follow_space()); // Account 2 follows Space 1
+            assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_reset_to_space_permissions() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_post_permissions(None, None, None));
+            assert_ok!(_update_post_permissions(None, None, Some(None)));
+
+            assert!(Posts::post_by_id(POST1).unwrap().permissions.is_none());
+            assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_fail_when_account_is_not_post_owner() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(
+                _update_post_permissions(Some(Origin::signed(ACCOUNT2)), None, None),
+                PostsError::<TestRuntime>::NotAPostOwner
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_fail_when_post_is_comment() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_noop!(
+                _update_post_permissions(None, Some(POST2), None),
+                PostsError::<TestRuntime>::CannotOverrideCommentPermissions
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_fail_when_account_has_no_permission_to_override_post_permissions() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(SpaceUpdate {
+                    permissions: Some(Some(permissions_where_everyone_can_create_post())),
+                    ..space_update(None, None, None)
+                })
+            ));
+            assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None)); // PostId 1
+
+            assert_noop!(
+                _update_post_permissions(Some(Origin::signed(ACCOUNT2)), None, None),
+                PostsError::<TestRuntime>::NoPermissionToOverridePostPermissions
+            );
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_fail_when_nothing_to_update() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_noop!(
+                _update_post_permissions(None, None, Some(None)),
+                PostsError::<TestRuntime>::NoUpdatesForPostPermissions
+            );
+        });
+    }
+
+    // TODO: refactor or remove. Deprecated tests
+    // Find public post ids tests
+    // --------------------------------------------------------------------------------------------
     /*#[test]
     fn find_public_post_ids_in_space_should_work() {
         ExtBuilder::build_with_post().execute_with(|| {
@@ -2796,74 +4025,486 @@ mod tests {
         });
     }
 
-// Shares tests
-
     #[test]
-    fn share_post_should_work() {
+    fn update_post_reaction_should_update_reaction_counters() {
         ExtBuilder::build_with_post().execute_with(|| {
-            assert_ok!(_create_space(
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+            assert_ok!(_update_post_reaction(
                 Some(Origin::signed(ACCOUNT2)),
-                Some(Some(b"space2_handle".to_vec())),
                 None,
-                None
-            )); // SpaceId 2 by ACCOUNT2
+                REACTION1,
+                Some(reaction_downvote())
+            ));
 
-            assert_ok!(_create_post(
-                Some(Origin::signed(ACCOUNT2)),
-                Some(Some(SPACE2)),
-                Some(extension_shared_post(POST1)),
-                None
-            )); // Share PostId 1 on SpaceId 2 by ACCOUNT2 which is permitted by default in both spaces
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(post.upvotes_count, 0);
+            assert_eq!(post.downvotes_count, 1);
+            assert_eq!(
+                Reactions::reaction_counts_by_target(ReactionTarget::Post(POST1)),
+                vec![(reaction_downvote(), 1)].into_iter().collect()
+            );
+        });
+    }
 
-            // Check storages
-            assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
-            assert_eq!(Posts::post_ids_by_space_id(SPACE2), vec![POST2]);
-            assert_eq!(Posts::next_post_id(), POST3);
+    #[test]
+    fn update_post_reaction_should_fail_when_reaction_is_not_on_this_post() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+            assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 2
 
-            assert_eq!(Posts::shared_post_ids_by_original_post_id(POST1), vec![POST2]);
+            assert_noop!(
+                _update_post_reaction(Some(Origin::signed(ACCOUNT2)), None, REACTION2, Some(reaction_downvote())),
+                ReactionsError::<TestRuntime>::ReactionByAccountNotFound
+            );
+        });
+    }
 
-            // Check whether data stored correctly
-            assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 1);
+    #[test]
+    fn delete_post_reaction_should_work() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
 
-            let shared_post = Posts::post_by_id(POST2).unwrap();
+            assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None, REACTION1));
 
-            assert_eq!(shared_post.space_id, Some(SPACE2));
-            assert_eq!(shared_post.created.account, ACCOUNT2);
-            assert_eq!(shared_post.extension, extension_shared_post(POST1));
+            assert!(Reactions::reaction_by_id(REACTION1).is_none());
+            assert!(Reactions::reaction_ids_by_post_id(POST1).is_empty());
+            assert!(Reactions::post_reaction_id_by_account(ACCOUNT2, POST1).is_none());
+            assert!(Reactions::reaction_counts_by_target(ReactionTarget::Post(POST1)).is_empty());
+            assert_eq!(Posts::post_by_id(POST1).unwrap().upvotes_count, 0);
         });
     }
 
     #[test]
-    fn share_post_should_work_when_one_of_roles_is_permitted() {
-        ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(|| {
-            assert_ok!(_create_space(
-                None, // From ACCOUNT1
-                Some(None), // Provided without any handle
-                None, // With default space content,
-                None
-            ));
-            // SpaceId 2
-            assert_ok!(_create_post(
-                None, // From ACCOUNT1
-                Some(Some(SPACE2)),
-                None, // With RegularPost extension
-                None // With default post content
-            )); // PostId 1 on SpaceId 2
+    fn create_post_reaction_should_work_with_custom_kind() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_update_space_reaction_kinds(None, None, None));
 
-            assert_ok!(_create_post(
+            assert_ok!(_create_post_reaction(
                 Some(Origin::signed(ACCOUNT2)),
-                Some(Some(SPACE1)),
-                Some(extension_shared_post(POST1)),
-                None
-            )); // Share PostId 1 on SpaceId 1 by ACCOUNT2 which is permitted by RoleId 1 from ext
+                None,
+                Some(reaction_custom())
+            )); // ReactionId 1
+
+            assert_eq!(Reactions::reaction_by_id(REACTION1).unwrap().kind, reaction_custom());
+            assert_eq!(
+                Reactions::reaction_counts_by_target(ReactionTarget::Post(POST1)),
+                vec![(reaction_custom(), 1)].into_iter().collect()
+            );
+
+            // Custom reactions don't affect upvotes and downvotes of a post:
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(post.upvotes_count, 0);
+            assert_eq!(post.downvotes_count, 0);
         });
     }
 
     #[test]
-    fn share_post_should_work_for_share_own_post_in_same_own_space() {
+    fn create_post_reaction_should_fail_when_custom_kind_is_not_allowed_in_space() {
         ExtBuilder::build_with_post().execute_with(|| {
-            assert_ok!(_create_post(
-                Some(Origin::signed(ACCOUNT1)),
+            assert_noop!(
+                _create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(reaction_custom())),
+                ReactionsError::<TestRuntime>::CustomReactionKindNotAllowed
+            );
+        });
+    }
+
+    #[test]
+    fn create_space_reaction_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_reaction(None, None, None)); // ReactionId 1 by ACCOUNT2
+
+            let target = ReactionTarget::Space(SPACE1);
+            assert_eq!(Reactions::reaction_ids_by_target(target.clone()), vec![REACTION1]);
+            assert_eq!(Reactions::reaction_id_by_account_and_target((ACCOUNT2, target.clone())), Some(REACTION1));
+            assert_eq!(
+                Reactions::reaction_counts_by_target(target),
+                vec![(reaction_upvote(), 1)].into_iter().collect()
+            );
+        });
+    }
+
+    #[test]
+    fn create_profile_reaction_should_work() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_create_default_profile()); // Profile of ACCOUNT1
+
+            assert_ok!(_create_reaction(None, Some(ReactionTarget::Profile(ACCOUNT1)), None));
+
+            assert_eq!(
+                Reactions::reaction_counts_by_target(ReactionTarget::Profile(ACCOUNT1)),
+                vec![(reaction_upvote(), 1)].into_iter().collect()
+            );
+        });
+    }
+
+    #[test]
+    fn create_profile_reaction_should_fail_with_custom_kind() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_create_default_profile()); // Profile of ACCOUNT1
+
+            assert_noop!(
+                _create_reaction(None, Some(ReactionTarget::Profile(ACCOUNT1)), Some(reaction_custom())),
+                ReactionsError::<TestRuntime>::CustomReactionKindNotAllowed
+            );
+        });
+    }
+
+    #[test]
+    fn create_profile_reaction_should_fail_when_account_has_no_profile() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(
+                _create_reaction(None, Some(ReactionTarget::Profile(ACCOUNT1)), None),
+                ProfilesError::<TestRuntime>::AccountHasNoProfile
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_reaction_kinds_should_fail_when_too_many_kinds() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let kinds = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec(), b"d".to_vec()];
+            assert_noop!(
+                _update_space_reaction_kinds(None, None, Some(kinds)),
+                ReactionsError::<TestRuntime>::TooManyCustomReactionKinds
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_reaction_kinds_should_fail_when_kind_is_invalid() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            for kinds in vec![
+                vec![Vec::new()],
+                vec![b"too_long_kind".to_vec()],
+                vec![custom_reaction_kind(), custom_reaction_kind()],
+            ] {
+                assert_noop!(
+                    _update_space_reaction_kinds(None, None, Some(kinds)),
+                    ReactionsError::<TestRuntime>::InvalidCustomReactionKind
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn update_space_reaction_kinds_should_fail_when_account_has_no_permission() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _update_space_reaction_kinds(Some(Origin::signed(ACCOUNT2)), None, None),
+                ReactionsError::<TestRuntime>::NoPermissionToUpdateReactionKinds
+            );
+        });
+    }
+
+    #[test]
+    fn delete_space_should_remove_its_reactions() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_update_space_reaction_kinds(None, None, None));
+            assert_ok!(_create_reaction(None, None, None)); // ReactionId 1 by ACCOUNT2
+
+            assert_ok!(_delete_default_space());
+
+            let target = ReactionTarget::Space(SPACE1);
+            assert!(Reactions::reaction_by_id(REACTION1).is_none());
+            assert!(Reactions::reaction_ids_by_target(target.clone()).is_empty());
+            assert!(Reactions::reaction_id_by_account_and_target((ACCOUNT2, target.clone())).is_none());
+            assert!(Reactions::reaction_counts_by_target(target).is_empty());
+            assert!(Reactions::reaction_kinds_by_space_id(SPACE1).is_empty());
+        });
+    }
+
+// Scores tests
+
+    fn reputation_of(account: AccountId) -> u32 {
+        Profiles::social_account_by_id(account).unwrap().reputation
+    }
+
+    fn space_score(space_id: SpaceId) -> i32 {
+        Spaces::space_by_id(space_id).unwrap().score
+    }
+
+    fn post_score(post_id: PostId) -> i32 {
+        Posts::post_by_id(post_id).unwrap().score
+    }
+
+    #[test]
+    fn upvote_post_should_increase_scores() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+            assert_eq!(post_score(POST1), 5);
+            assert_eq!(space_score(SPACE1), 5);
+            assert_eq!(reputation_of(ACCOUNT1), 6);
+            assert_eq!(
+                Scores::post_score_by_account((ACCOUNT2, POST1, ScoringAction::UpvotePost)),
+                Some(ScoreDiff { score: 5, reputation: 5 })
+            );
+        });
+    }
+
+    #[test]
+    fn upvote_post_should_be_weighted_by_actor_reputation() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            let mut social_account = Profiles::get_or_new_social_account(ACCOUNT2);
+            social_account.reputation = 8;
+            SocialAccountById::<TestRuntime>::insert(ACCOUNT2, social_account);
+
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+            // log_2(8) + 1 = 4 times the weight of an upvote:
+            assert_eq!(Scores::score_diff_for_action(8, ScoringAction::UpvotePost), 20);
+            assert_eq!(post_score(POST1), 20);
+            assert_eq!(reputation_of(ACCOUNT1), 21);
+        });
+    }
+
+    #[test]
+    fn upvote_own_post_should_not_change_scores() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_default_post_reaction()); // ReactionId 1 by ACCOUNT1
+
+            assert_eq!(post_score(POST1), 0);
+            assert_eq!(space_score(SPACE1), 0);
+            assert!(Scores::post_score_by_account((ACCOUNT1, POST1, ScoringAction::UpvotePost)).is_none());
+        });
+    }
+
+    #[test]
+    fn downvote_post_should_not_decrease_reputation_below_minimum() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                Some(reaction_downvote())
+            )); // ReactionId 1
+
+            assert_eq!(post_score(POST1), -3);
+            assert_eq!(space_score(SPACE1), -3);
+            assert_eq!(reputation_of(ACCOUNT1), 1);
+
+            assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None, REACTION1));
+
+            assert_eq!(post_score(POST1), 0);
+            assert_eq!(space_score(SPACE1), 0);
+            assert_eq!(reputation_of(ACCOUNT1), 1);
+        });
+    }
+
+    #[test]
+    fn update_post_reaction_should_revert_previous_score() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+            assert_ok!(_update_post_reaction(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                REACTION1,
+                Some(reaction_downvote())
+            ));
+
+            assert_eq!(post_score(POST1), -3);
+            assert_eq!(space_score(SPACE1), -3);
+            assert_eq!(reputation_of(ACCOUNT1), 1);
+            assert!(Scores::post_score_by_account((ACCOUNT2, POST1, ScoringAction::UpvotePost)).is_none());
+        });
+    }
+
+    #[test]
+    fn delete_post_reaction_should_revert_score() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+            assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None, REACTION1));
+
+            assert_eq!(post_score(POST1), 0);
+            assert_eq!(space_score(SPACE1), 0);
+            assert_eq!(reputation_of(ACCOUNT1), 1);
+            assert!(Scores::post_score_by_account((ACCOUNT2, POST1, ScoringAction::UpvotePost)).is_none());
+        });
+    }
+
+    #[test]
+    fn upvote_comment_should_not_change_space_score() {
+        ExtBuilder::build_with_comment().execute_with(|| {
+            assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+            assert_eq!(post_score(POST2), 4);
+            assert_eq!(post_score(POST1), 0);
+            assert_eq!(space_score(SPACE1), 0);
+            assert_eq!(reputation_of(ACCOUNT1), 5);
+        });
+    }
+
+    #[test]
+    fn create_comment_should_score_root_post_once() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // PostId 2
+            assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // PostId 3
+
+            assert_eq!(post_score(POST1), 5);
+            assert_eq!(space_score(SPACE1), 5);
+            assert_eq!(reputation_of(ACCOUNT1), 6);
+        });
+    }
+
+    #[test]
+    fn share_post_should_increase_scores() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(Some(b"space2_handle".to_vec())),
+                None,
+                None
+            )); // SpaceId 2 by ACCOUNT2
+
+            assert_ok!(_create_post(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(Some(SPACE2)),
+                Some(extension_shared_post(POST1)),
+                None
+            )); // Share PostId 1 on SpaceId 2 by ACCOUNT2
+
+            assert_eq!(post_score(POST1), 7);
+            assert_eq!(space_score(SPACE1), 7);
+            assert_eq!(space_score(SPACE2), 0);
+            assert_eq!(reputation_of(ACCOUNT1), 8);
+        });
+    }
+
+    #[test]
+    fn move_post_should_move_its_score_to_new_space() {
+        ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+            assert_ok!(_move_post_1_to_space_2());
+
+            assert_eq!(space_score(SPACE1), 0);
+            assert_eq!(space_score(SPACE2), 5);
+
+            // Score of the moved post should be reverted in its new space:
+            assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None, REACTION1));
+            assert_eq!(space_score(SPACE2), 0);
+        });
+    }
+
+    #[test]
+    fn follow_space_should_increase_scores_and_unfollow_should_revert_them() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            // The space owner follows their space on creation, which is not scored:
+            assert_eq!(space_score(SPACE1), 0);
+
+            assert_ok!(_default_follow_space()); // ACCOUNT2 follows SpaceId 1
+
+            assert_eq!(space_score(SPACE1), 7);
+            assert_eq!(reputation_of(ACCOUNT1), 8);
+
+            assert_ok!(_default_unfollow_space());
+
+            assert_eq!(space_score(SPACE1), 0);
+            assert_eq!(reputation_of(ACCOUNT1), 1);
+            assert!(Scores::space_score_by_account((ACCOUNT2, SPACE1, ScoringAction::FollowSpace)).is_none());
+        });
+    }
+
+    #[test]
+    fn unfollow_space_should_revert_reputation_of_owner_that_was_credited() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_follow_space(Some(Origin::signed(ACCOUNT3)), None)); // ACCOUNT3 follows SpaceId 1
+            assert_eq!(reputation_of(ACCOUNT1), 8);
+
+            assert_ok!(_transfer_default_space_ownership()); // Transfer SpaceId 1 to ACCOUNT2
+            assert_ok!(_accept_default_pending_ownership());
+            let reputation_of_new_owner = Profiles::get_or_new_social_account(ACCOUNT2).reputation;
+
+            assert_ok!(_unfollow_space(Some(Origin::signed(ACCOUNT3)), None));
+
+            assert_eq!(reputation_of(ACCOUNT1), 1);
+            assert_eq!(Profiles::get_or_new_social_account(ACCOUNT2).reputation, reputation_of_new_owner);
+        });
+    }
+
+    #[test]
+    fn follow_account_should_increase_reputation_and_unfollow_should_revert_it() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_default_follow_account()); // ACCOUNT2 follows ACCOUNT1
+
+            assert_eq!(reputation_of(ACCOUNT1), 4);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().followers_count, 1);
+
+            assert_ok!(_default_unfollow_account());
+
+            assert_eq!(reputation_of(ACCOUNT1), 1);
+            assert_eq!(Profiles::social_account_by_id(ACCOUNT1).unwrap().followers_count, 0);
+            assert!(Scores::account_reputation_diff_by_account((ACCOUNT2, ACCOUNT1, ScoringAction::FollowAccount)).is_none());
+        });
+    }
+
+// Shares tests
+
+    #[test]
+    fn share_post_should_work() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_space(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(Some(b"space2_handle".to_vec())),
+                None,
+                None
+            )); // SpaceId 2 by ACCOUNT2
+
+            assert_ok!(_create_post(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(Some(SPACE2)),
+                Some(extension_shared_post(POST1)),
+                None
+            )); // Share PostId 1 on SpaceId 2 by ACCOUNT2 which is permitted by default in both spaces
+
+            // Check storages
+            assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
+            assert_eq!(Posts::post_ids_by_space_id(SPACE2), vec![POST2]);
+            assert_eq!(Posts::next_post_id(), POST3);
+
+            assert_eq!(Posts::shared_post_ids_by_original_post_id(POST1), vec![POST2]);
+
+            // Check whether data stored correctly
+            assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 1);
+
+            let shared_post = Posts::post_by_id(POST2).unwrap();
+
+            assert_eq!(shared_post.space_id, Some(SPACE2));
+            assert_eq!(shared_post.created.account, ACCOUNT2);
+            assert_eq!(shared_post.extension, extension_shared_post(POST1));
+        });
+    }
+
+    #[test]
+    fn share_post_should_work_when_one_of_roles_is_permitted() {
+        ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(|| {
+            assert_ok!(_create_space(
+                None, // From ACCOUNT1
+                Some(None), // Provided without any handle
+                None, // With default space content,
+                None
+            ));
+            // SpaceId 2
+            assert_ok!(_create_post(
+                None, // From ACCOUNT1
+                Some(Some(SPACE2)),
+                None, // With RegularPost extension
+                None // With default post content
+            )); // PostId 1 on SpaceId 2
+
+            assert_ok!(_create_post(
+                Some(Origin::signed(ACCOUNT2)),
+                Some(Some(SPACE1)),
+                Some(extension_shared_post(POST1)),
+                None
+            )); // Share PostId 1 on SpaceId 1 by ACCOUNT2 which is permitted by RoleId 1 from ext
+        });
+    }
+
+    #[test]
+    fn share_post_should_work_for_share_own_post_in_same_own_space() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_post(
+                Some(Origin::signed(ACCOUNT1)),
                 Some(Some(SPACE1)),
                 Some(extension_shared_post(POST1)),
                 None
@@ -3037,14 +4678,30 @@ mod tests {
     }
 
     #[test]
-    fn update_profile_should_fail_when_social_account_not_found() {
+    fn get_profile_history_should_return_newest_records_first() {
         ExtBuilder::build().execute_with(|| {
-            assert_noop!(_update_profile(
-                None,
-                Some(profile_content_ipfs())
-            ), ProfilesError::<TestRuntime>::SocialAccountNotFound);
-        });
-    }
+            assert_ok!(_create_default_profile());
+            assert_ok!(_update_profile(None, Some(space_content_ipfs())));
+            assert_ok!(_update_profile(None, Some(post_content_ipfs())));
+
+            let history = ProfileHistory::get_profile_history(ACCOUNT1, 0, 10);
+            assert_eq!(history.len(), 2);
+            assert_eq!(history[0].old_content, Some(space_content_ipfs().into()));
+            assert_eq!(history[1].old_content, Some(profile_content_ipfs().into()));
+
+            assert!(ProfileHistory::get_profile_history(ACCOUNT2, 0, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn update_profile_should_fail_when_social_account_not_found() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(_update_profile(
+                None,
+                Some(profile_content_ipfs())
+            ), ProfilesError::<TestRuntime>::SocialAccountNotFound);
+        });
+    }
 
     #[test]
     fn update_profile_should_fail_when_account_has_no_profile() {
@@ -3080,6 +4737,531 @@ mod tests {
         });
     }
 
+    #[test]
+    fn update_profile_handle_should_work() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_create_default_profile());
+            assert_ok!(_update_profile_handle(None, Some(b"Profile_Handle".to_vec())));
+
+            let profile = Profiles::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
+            assert_eq!(profile.handle, Some(b"Profile_Handle".to_vec()));
+            assert_eq!(Profiles::account_by_profile_handle(profile_handle()), Some(ACCOUNT1));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), HANDLE_DEPOSIT);
+            assert_eq!(Profiles::get_handle_owner(profile_handle()), Some(User::Account(ACCOUNT1)));
+
+            // Check whether profile history is written correctly
+            let profile_history = ProfileHistory::edit_history(ACCOUNT1)[0].clone();
+            assert_eq!(profile_history.old_data.handle, Some(None));
+
+            // Remove the handle
+            assert_ok!(_update_profile_handle(None, None));
+            assert_eq!(Profiles::account_by_profile_handle(profile_handle()), None);
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        });
+    }
+
+    #[test]
+    fn update_profile_handle_should_fail_when_handle_is_reserved_by_space() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_default_profile());
+            assert_noop!(
+                _update_profile_handle(None, Some(space_handle())),
+                ProfilesError::<TestRuntime>::ProfileHandleIsNotUnique
+            );
+        });
+    }
+
+    #[test]
+    fn create_space_should_fail_when_handle_is_reserved_by_profile() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_create_profile(Some(Origin::signed(ACCOUNT2)), None));
+            assert_ok!(_update_profile_handle(Some(Origin::signed(ACCOUNT2)), Some(space_handle())));
+
+            assert_noop!(_create_default_space(), SpacesError::<TestRuntime>::SpaceHandleIsNotUnique);
+            assert_eq!(Profiles::get_handle_owner(space_handle()), Some(User::Account(ACCOUNT2)));
+        });
+    }
+
+    #[test]
+    fn get_handle_owner_should_resolve_space_handle() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_eq!(Profiles::get_handle_owner(space_handle()), Some(User::Space(SPACE1)));
+            assert_eq!(Profiles::get_handle_owner(profile_handle()), None);
+        });
+    }
+
+    #[test]
+    fn force_unreserve_profile_handle_should_work() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_create_default_profile());
+            assert_ok!(_update_profile_handle(None, Some(profile_handle())));
+
+            assert_ok!(Profiles::force_unreserve_handle(Origin::root(), profile_handle()));
+
+            let profile = Profiles::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
+            assert!(profile.handle.is_none());
+            assert_eq!(Profiles::account_by_profile_handle(profile_handle()), None);
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        });
+    }
+
+// Handle registration tests
+
+    #[test]
+    fn create_space_should_reserve_higher_deposit_for_short_handle() {
+        ExtBuilder::build().execute_with(|| {
+            // A handle that is two chars shorter than `PremiumHandleLen` costs four times more:
+            assert_ok!(_create_space(None, Some(Some(b"handle".to_vec())), None, None));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), HANDLE_DEPOSIT * 4);
+
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(space_update(Some(Some(space_handle())), None, None))
+            ));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), HANDLE_DEPOSIT);
+        });
+    }
+
+    #[test]
+    fn create_space_should_fail_when_handle_is_blocked() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(Utils::block_handles(Origin::root(), vec![space_handle()]));
+            assert_noop!(_create_default_space(), UtilsError::<TestRuntime>::HandleIsBlocked);
+
+            assert_ok!(Utils::unblock_handles(Origin::root(), vec![space_handle()]));
+            assert_ok!(_create_default_space());
+        });
+    }
+
+    #[test]
+    fn renew_space_handle_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let expires_at = 1 + HANDLE_REGISTRATION_PERIOD;
+            assert_eq!(Spaces::handle_registration_by_space_id(SPACE1).unwrap().expires_at, Some(expires_at));
+
+            assert_ok!(Spaces::renew_space_handle(Origin::signed(ACCOUNT1), SPACE1));
+            assert_eq!(
+                Spaces::handle_registration_by_space_id(SPACE1).unwrap().expires_at,
+                Some(expires_at + HANDLE_REGISTRATION_PERIOD)
+            );
+
+            // The handle is not released at its previous release block:
+            Spaces::on_initialize(expires_at + HANDLE_GRACE_PERIOD);
+            assert_eq!(find_space_id_by_handle(space_handle()), Some(SPACE1));
+        });
+    }
+
+    #[test]
+    fn renew_space_handle_should_fail_when_renewed_too_early() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(Spaces::renew_space_handle(Origin::signed(ACCOUNT1), SPACE1));
+            assert_noop!(
+                Spaces::renew_space_handle(Origin::signed(ACCOUNT1), SPACE1),
+                UtilsError::<TestRuntime>::HandleRenewedTooEarly
+            );
+        });
+    }
+
+    #[test]
+    fn space_handle_should_be_released_after_grace_period() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let release_block = 1 + HANDLE_REGISTRATION_PERIOD + HANDLE_GRACE_PERIOD;
+
+            Spaces::on_initialize(release_block - 1);
+            assert_eq!(find_space_id_by_handle(space_handle()), Some(SPACE1));
+
+            Spaces::on_initialize(release_block);
+            assert!(Spaces::space_by_id(SPACE1).unwrap().handle.is_none());
+            assert_eq!(find_space_id_by_handle(space_handle()), None);
+            assert!(Spaces::handle_registration_by_space_id(SPACE1).is_none());
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        });
+    }
+
+    #[test]
+    fn space_handles_over_limit_should_be_released_in_next_blocks() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_space(None, Some(Some(space_handle_2())), None, None)); // SpaceId 2
+            assert_ok!(_create_space(None, Some(Some(b"space_handle_3".to_vec())), None, None)); // SpaceId 3
+
+            let released_handles_count = || {
+                [SPACE1, SPACE2, SPACE3].iter()
+                    .filter(|space_id| Spaces::space_by_id(**space_id).unwrap().handle.is_none())
+                    .count()
+            };
+
+            // MaxHandlesToReleasePerBlock is 2 in this test runtime,
+            // and checking a release block takes one of these units:
+            let release_block = 1 + HANDLE_REGISTRATION_PERIOD + HANDLE_GRACE_PERIOD;
+            Spaces::on_initialize(release_block);
+            assert_eq!(released_handles_count(), 1);
+
+            Spaces::on_initialize(release_block + 1);
+            assert_eq!(released_handles_count(), 2);
+
+            Spaces::on_initialize(release_block + 2);
+            assert_eq!(released_handles_count(), 3);
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        });
+    }
+
+    #[test]
+    fn profile_handle_should_be_released_after_grace_period() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_create_default_profile());
+            assert_ok!(_update_profile_handle(None, Some(profile_handle())));
+
+            Profiles::on_initialize(1 + HANDLE_REGISTRATION_PERIOD + HANDLE_GRACE_PERIOD);
+
+            let profile = Profiles::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
+            assert!(profile.handle.is_none());
+            assert_eq!(Profiles::account_by_profile_handle(profile_handle()), None);
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        });
+    }
+
+    #[test]
+    fn create_space_should_work_with_unicode_handle() {
+        ExtBuilder::build().execute_with(|| {
+            let handle = "Блог_Кошек".as_bytes().to_vec();
+            assert_noop!(
+                _create_space(None, Some(Some(handle.clone())), None, None),
+                UtilsError::<TestRuntime>::HandleContainsInvalidChars
+            );
+
+            assert_ok!(Utils::set_unicode_handles_enabled(Origin::root(), true));
+            assert_ok!(_create_space(None, Some(Some(handle.clone())), None, None));
+
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().handle, Some(handle));
+            assert_eq!(find_space_id_by_handle("блог_кошек".as_bytes().to_vec()), Some(SPACE1));
+        });
+    }
+
+    #[test]
+    fn create_space_should_fail_when_unicode_handle_has_fewer_chars_than_allowed() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(Utils::set_unicode_handles_enabled(Origin::root(), true));
+
+            // Two chars, but six bytes:
+            assert_noop!(
+                _create_space(None, Some(Some("猫猫".as_bytes().to_vec())), None, None),
+                UtilsError::<TestRuntime>::HandleIsTooShort
+            );
+        });
+    }
+
+    #[test]
+    fn create_space_should_work_when_unicode_handle_has_more_bytes_than_max_len() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(Utils::set_unicode_handles_enabled(Origin::root(), true));
+
+            let handle = "я".repeat(MaxHandleLen::get() as usize).into_bytes();
+            assert_ok!(_create_space(None, Some(Some(handle)), None, None));
+        });
+    }
+
+    #[test]
+    fn create_space_should_reserve_deposit_by_chars_of_unicode_handle() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(Utils::set_unicode_handles_enabled(Origin::root(), true));
+
+            // Six chars, that are two chars shorter than `PremiumHandleLen`, but twelve bytes:
+            assert_ok!(_create_space(None, Some(Some("котики".as_bytes().to_vec())), None, None));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), HANDLE_DEPOSIT * 4);
+        });
+    }
+
+    #[test]
+    fn update_profile_should_reserve_deposit_by_chars_of_unicode_handle() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(Utils::set_unicode_handles_enabled(Origin::root(), true));
+            assert_ok!(_create_default_profile());
+
+            assert_ok!(_update_profile_handle(None, Some("котики".as_bytes().to_vec())));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), HANDLE_DEPOSIT * 4);
+        });
+    }
+
+    #[test]
+    fn create_space_should_fail_when_handle_looks_like_existing_one() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(Utils::set_unicode_handles_enabled(Origin::root(), true));
+            assert_ok!(_create_space(None, Some(Some(b"papa_space".to_vec())), None, None));
+
+            // The same handle written in Cyrillic letters
+            assert_noop!(
+                _create_space(None, Some(Some("рара_ѕрасе".as_bytes().to_vec())), None, None),
+                SpacesError::<TestRuntime>::SpaceHandleIsNotUnique
+            );
+        });
+    }
+
+    #[test]
+    fn create_space_should_fail_when_handle_has_digit_that_looks_like_letter() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(Utils::set_unicode_handles_enabled(Origin::root(), true));
+            assert_ok!(_create_space(None, Some(Some(b"pal_space".to_vec())), None, None));
+
+            assert_noop!(
+                _create_space(None, Some(Some(b"pa1_space".to_vec())), None, None),
+                SpacesError::<TestRuntime>::SpaceHandleIsNotUnique
+            );
+            // `ра1_ѕрасе` written in Cyrillic letters
+            assert_noop!(
+                _create_space(None, Some(Some("ра1_ѕрасе".as_bytes().to_vec())), None, None),
+                SpacesError::<TestRuntime>::SpaceHandleIsNotUnique
+            );
+        });
+    }
+
+// Content tests
+
+    #[test]
+    fn create_space_should_reserve_storage_deposit() {
+        ExtBuilder::build().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            let content = Content::Raw(b"raw space content".to_vec());
+            assert_ok!(_create_space(None, Some(None), Some(content), None));
+
+            let expected_deposit = 10 + Spaces::space_by_id(SPACE1).unwrap().encoded_size() as u64;
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), expected_deposit);
+
+            let deposit = Utils::storage_deposit_by_item(StorageItem::Space(SPACE1)).unwrap();
+            assert_eq!(deposit.owner, ACCOUNT1);
+            assert_eq!(deposit.amount, expected_deposit);
+        });
+    }
+
+    #[test]
+    fn update_space_should_return_storage_deposit_when_space_shrinks() {
+        ExtBuilder::build().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            let content = Content::Raw(b"raw space content".to_vec());
+            assert_ok!(_create_space(None, Some(None), Some(content), None));
+
+            // The first update makes a space larger, as it adds the `updated` field.
+            assert_ok!(_update_space(None, None, Some(space_update(None, None, Some(true)))));
+            let space = Spaces::space_by_id(SPACE1).unwrap();
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 10 + space.encoded_size() as u64);
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            // The content is 12 bytes shorter:
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(space_update(None, Some(Content::Raw(b"short".to_vec())), None))
+            ));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before - 12);
+
+            assert_ok!(_delete_default_space());
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+            assert!(Utils::storage_deposit_by_item(StorageItem::Space(SPACE1)).is_none());
+        });
+    }
+
+    #[test]
+    fn update_space_should_not_reserve_storage_deposit_for_space_without_deposit() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            // A space created before storage deposits were introduced:
+            pallet_utils::StorageDepositByItem::<TestRuntime>::remove(StorageItem::Space(SPACE1));
+            set_storage_deposits(10, 1);
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(_update_space(
+                None,
+                None,
+                Some(space_update(None, Some(Content::Raw(b"raw space content".to_vec())), None))
+            ));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before);
+        });
+    }
+
+    #[test]
+    fn suggest_entity_status_should_return_storage_deposit_of_report() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            assert_ok!(_report_entity(Some(Origin::signed(ACCOUNT2)), None, None, None));
+            let report = Moderation::report_by_id(REPORT1).unwrap();
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 10 + report.encoded_size() as u64);
+
+            assert_ok!(_suggest_entity_status(None, None, None, None, None));
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+            assert!(Utils::storage_deposit_by_item(StorageItem::Report(REPORT1)).is_none());
+        });
+    }
+
+    #[test]
+    fn create_space_should_fail_when_balance_is_too_low_for_storage_deposit() {
+        ExtBuilder::build().execute_with(|| {
+            StorageDepositPerItem::set(&1_000);
+
+            assert_noop!(
+                _create_space(None, Some(None), None, None),
+                pallet_balances::Error::<TestRuntime>::InsufficientBalance
+            );
+        });
+    }
+
+    #[test]
+    fn create_post_should_fail_when_raw_content_is_too_long() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _create_post(None, None, None, Some(Content::Raw(b"too long raw post content".to_vec()))),
+                UtilsError::<TestRuntime>::RawContentIsTooLong
+            );
+        });
+    }
+
+    #[test]
+    fn delete_post_should_return_storage_deposit() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            set_storage_deposits(10, 1);
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(_create_post(None, None, None, Some(Content::Raw(b"raw post".to_vec()))));
+            let post_len = Posts::post_by_id(POST1).unwrap().encoded_size() as u64;
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 10 + post_len);
+
+            assert_ok!(Posts::delete_post(Origin::signed(ACCOUNT1), POST1, MAX_REPLIES_TO_DELETE));
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before);
+        });
+    }
+
+    #[test]
+    fn update_post_permissions_should_update_storage_deposit() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            set_storage_deposits(10, 1);
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(_create_default_post()); // PostId 1
+
+            assert_ok!(_update_post_permissions(None, None, None));
+            let post_len = Posts::post_by_id(POST1).unwrap().encoded_size() as u64;
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 10 + post_len);
+
+            // Reset to the space permissions, so the post shrinks:
+            assert_ok!(_update_post_permissions(None, None, Some(None)));
+            let post_len = Posts::post_by_id(POST1).unwrap().encoded_size() as u64;
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 10 + post_len);
+        });
+    }
+
+    #[test]
+    fn delete_post_should_return_storage_deposits_of_its_reactions() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None));
+            let reaction_len = Reactions::reaction_by_id(REACTION1).unwrap().encoded_size() as u64;
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 10 + reaction_len);
+
+            assert_ok!(Posts::delete_post(Origin::signed(ACCOUNT1), POST1, MAX_REPLIES_TO_DELETE));
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+        });
+    }
+
+    #[test]
+    fn delete_post_reaction_should_return_storage_deposit() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None));
+            assert!(Balances::reserved_balance(ACCOUNT2) > 0);
+
+            assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None, REACTION1));
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+        });
+    }
+
+    #[test]
+    fn delete_role_should_return_storage_deposit() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            set_storage_deposits(10, 1);
+            let reserved_before = Balances::reserved_balance(ACCOUNT1);
+
+            assert_ok!(_create_default_role());
+            let role_len = Roles::role_by_id(ROLE1).unwrap().encoded_size() as u64;
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before + 10 + role_len);
+
+            assert_ok!(_delete_default_role());
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), reserved_before);
+        });
+    }
+
+    #[test]
+    fn create_profile_should_reserve_storage_deposit() {
+        ExtBuilder::build().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            assert_ok!(_create_default_profile());
+            let profile = Profiles::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 10 + profile.encoded_size() as u64);
+        });
+    }
+
+    #[test]
+    fn accept_pending_ownership_should_move_storage_deposit_to_new_owner() {
+        ExtBuilder::build().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            assert_ok!(_create_space(None, Some(None), None, None));
+            let deposit = Utils::storage_deposit_by_item(StorageItem::Space(SPACE1)).unwrap().amount;
+
+            assert_ok!(_transfer_default_space_ownership());
+            assert_ok!(_accept_default_pending_ownership());
+
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+            assert_eq!(Balances::reserved_balance(ACCOUNT2), deposit);
+            assert_eq!(Utils::storage_deposit_by_item(StorageItem::Space(SPACE1)).unwrap().owner, ACCOUNT2);
+        });
+    }
+
+    #[test]
+    fn quote_storage_deposit_should_work_without_changing_storage() {
+        ExtBuilder::build().execute_with(|| {
+            set_storage_deposits(10, 1);
+
+            let call = Call::Spaces(pallet_spaces::Call::create_space {
+                parent_id_opt: None,
+                handle_opt: None,
+                content: space_content_ipfs(),
+                permissions_opt: None,
+            });
+            let quote = pallet_utils::rpc::quote_storage_deposit::<TestRuntime, Call>(ACCOUNT1, call).unwrap();
+
+            assert!(Spaces::space_by_id(SPACE1).is_none());
+            assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+
+            assert_ok!(_create_space(None, Some(None), None, None));
+            assert_eq!(quote.to_reserve, Balances::reserved_balance(ACCOUNT1));
+            assert_eq!(quote.to_unreserve, 0);
+        });
+    }
+
+    #[test]
+    fn quote_storage_deposit_should_return_none_when_call_fails() {
+        ExtBuilder::build().execute_with(|| {
+            let call = Call::Posts(pallet_posts::Call::delete_post { post_id: POST1, max_replies: MAX_REPLIES_TO_DELETE });
+            assert!(pallet_utils::rpc::quote_storage_deposit::<TestRuntime, Call>(ACCOUNT1, call).is_none());
+        });
+    }
+
+    #[test]
+    fn create_space_should_fail_when_cid_codec_is_not_allowed() {
+        ExtBuilder::build().execute_with(|| {
+            // A CID v1 of `json` content
+            let content = Content::IPFS(b"bagaaierasords4njcts6vs7qvdjfcvgnume4hqohf65zsfguprqphs3icwea".to_vec());
+            assert_noop!(
+                _create_space(None, None, Some(content), None),
+                UtilsError::<TestRuntime>::IpfsCidCodecNotAllowed
+            );
+        });
+    }
+
 // Space following tests
 
     #[test]
@@ -3174,44 +5356,156 @@ mod tests {
     }
 
     #[test]
-    fn follow_account_should_fail_when_account_is_already_following_account() {
-        ExtBuilder::build().execute_with(|| {
-            assert_ok!(_default_follow_account());
+    fn follow_account_should_fail_when_account_is_already_following_account() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_default_follow_account());
+
+            assert_noop!(_default_follow_account(), ProfileFollowsError::<TestRuntime>::AlreadyAccountFollower);
+        });
+    }
+
+    #[test]
+    fn unfollow_account_should_work() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_default_follow_account());
+            // Follow ACCOUNT1 by ACCOUNT2
+            assert_ok!(_default_unfollow_account());
+
+            assert!(ProfileFollows::accounts_followed_by_account(ACCOUNT2).is_empty());
+            assert!(ProfileFollows::account_followers(ACCOUNT1).is_empty());
+            assert_eq!(ProfileFollows::account_followed_by_account((ACCOUNT2, ACCOUNT1)), false);
+        });
+    }
+
+    #[test]
+    fn unfollow_account_should_fail_when_account_tries_to_unfollow_themself() {
+        ExtBuilder::build().execute_with(|| {
+            assert_noop!(_unfollow_account(
+                None,
+                Some(ACCOUNT2)
+            ), ProfileFollowsError::<TestRuntime>::AccountCannotUnfollowItself);
+        });
+    }
+
+    #[test]
+    fn unfollow_account_should_fail_when_account_is_not_following_another_account_yet() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(_default_follow_account());
+            assert_ok!(_default_unfollow_account());
+
+            assert_noop!(_default_unfollow_account(), ProfileFollowsError::<TestRuntime>::NotAccountFollower);
+        });
+    }
+
+// Feed tests
+
+    fn feed_post_ids(account: AccountId, kind_filter: Vec<FlatPostKind>, cursor: Option<PostId>, limit: u16) -> Vec<PostId> {
+        Posts::get_feed_page(account, kind_filter, cursor, limit)
+            .into_iter()
+            .map(|post| post.id)
+            .collect()
+    }
+
+    /// ACCOUNT1 creates POST1 and POST3 in SPACE1, ACCOUNT3 creates POST2 in SPACE2.
+    /// ACCOUNT2 follows SPACE1 and ACCOUNT3.
+    fn add_posts_to_feed_of_account2() {
+        assert_ok!(_create_space(Some(Origin::signed(ACCOUNT3)), Some(None), None, None)); // SPACE2
+        assert_ok!(_create_post(Some(Origin::signed(ACCOUNT3)), Some(Some(SPACE2)), None, None)); // POST2
+        assert_ok!(_create_default_post()); // POST3
+
+        assert_ok!(_default_follow_space());
+        assert_ok!(_follow_account(None, Some(ACCOUNT3)));
+    }
+
+    #[test]
+    fn get_feed_page_should_merge_posts_of_followed_spaces_and_accounts() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            add_posts_to_feed_of_account2();
+
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![], None, 10), vec![POST3, POST2, POST1]);
+            assert!(feed_post_ids(ACCOUNT3, vec![], None, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn get_feed_page_should_paginate_with_cursor() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            add_posts_to_feed_of_account2();
+
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![], None, 2), vec![POST3, POST2]);
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![], Some(POST2), 2), vec![POST1]);
+            assert!(feed_post_ids(ACCOUNT2, vec![], Some(POST1), 2).is_empty());
+        });
+    }
+
+    #[test]
+    fn get_feed_page_should_not_duplicate_post_of_followed_space_and_account() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_default_follow_space());
+            assert_ok!(_default_follow_account());
+
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![], None, 10), vec![POST1]);
+        });
+    }
+
+    #[test]
+    fn get_feed_page_should_filter_by_post_kind_and_skip_comments() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_create_default_comment()); // POST2
+            assert_ok!(_create_post(None, None, Some(extension_shared_post(POST1)), None)); // POST3
+            assert_ok!(_default_follow_space());
 
-            assert_noop!(_default_follow_account(), ProfileFollowsError::<TestRuntime>::AlreadyAccountFollower);
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![], None, 10), vec![POST3, POST1]);
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![FlatPostKind::SharedPost], None, 10), vec![POST3]);
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![FlatPostKind::RegularPost], None, 10), vec![POST1]);
+            assert!(feed_post_ids(ACCOUNT2, vec![FlatPostKind::Comment], None, 10).is_empty());
         });
     }
 
     #[test]
-    fn unfollow_account_should_work() {
-        ExtBuilder::build().execute_with(|| {
-            assert_ok!(_default_follow_account());
-            // Follow ACCOUNT1 by ACCOUNT2
-            assert_ok!(_default_unfollow_account());
+    fn get_feed_page_should_follow_moved_and_deleted_posts() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            add_posts_to_feed_of_account2();
 
-            assert!(ProfileFollows::accounts_followed_by_account(ACCOUNT2).is_empty());
-            assert!(ProfileFollows::account_followers(ACCOUNT1).is_empty());
-            assert_eq!(ProfileFollows::account_followed_by_account((ACCOUNT2, ACCOUNT1)), false);
+            // POST3 of ACCOUNT1 leaves the followed SPACE1
+            assert_ok!(_create_space(None, Some(None), None, None)); // SPACE3
+            assert_ok!(_move_post(None, Some(POST3), Some(Some(SPACE3))));
+            // POST2 of the followed ACCOUNT3 is deleted
+            assert_ok!(_delete_post(Some(Origin::signed(ACCOUNT3)), Some(POST2)));
+
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![], None, 10), vec![POST1]);
         });
     }
 
     #[test]
-    fn unfollow_account_should_fail_when_account_tries_to_unfollow_themself() {
-        ExtBuilder::build().execute_with(|| {
-            assert_noop!(_unfollow_account(
-                None,
-                Some(ACCOUNT2)
-            ), ProfileFollowsError::<TestRuntime>::AccountCannotUnfollowItself);
+    fn get_feed_page_should_skip_posts_moved_out_of_spaces() {
+        ExtBuilder::build_with_post().execute_with(|| {
+            assert_ok!(_default_follow_account());
+
+            assert_ok!(_move_post_to_nowhere(POST1));
+            assert!(feed_post_ids(ACCOUNT2, vec![], None, 10).is_empty());
+
+            assert_ok!(_move_post(None, None, Some(Some(SPACE1))));
+            assert_eq!(feed_post_ids(ACCOUNT2, vec![], None, 10), vec![POST1]);
         });
     }
 
     #[test]
-    fn unfollow_account_should_fail_when_account_is_not_following_another_account_yet() {
-        ExtBuilder::build().execute_with(|| {
+    fn get_feed_page_should_skip_blocked_posts() {
+        ExtBuilder::build_with_post().execute_with(|| {
             assert_ok!(_default_follow_account());
-            assert_ok!(_default_unfollow_account());
 
-            assert_noop!(_default_unfollow_account(), ProfileFollowsError::<TestRuntime>::NotAccountFollower);
+            assert_ok!(
+                _update_entity_status(
+                    None,
+                    Some(EntityId::Post(POST1)),
+                    Some(SPACE1),
+                    Some(Some(EntityStatus::Blocked))
+                )
+            );
+
+            assert!(feed_post_ids(ACCOUNT2, vec![], None, 10).is_empty());
+            assert!(!FeedPostIdsByAccount::<TestRuntime>::contains_key(ACCOUNT1, Posts::feed_key(POST1)));
         });
     }
 
@@ -3239,7 +5533,8 @@ mod tests {
             assert_noop!(_transfer_space_ownership(
                 Some(Origin::signed(ACCOUNT2)),
                 None,
-                Some(ACCOUNT1)
+                Some(ACCOUNT1),
+                None
             ), SpacesError::<TestRuntime>::NotASpaceOwner);
         });
     }
@@ -3250,7 +5545,8 @@ mod tests {
             assert_noop!(_transfer_space_ownership(
                 Some(Origin::signed(ACCOUNT1)),
                 None,
-                Some(ACCOUNT1)
+                Some(ACCOUNT1),
+                None
             ), SpaceOwnershipError::<TestRuntime>::CannotTranferToCurrentOwner);
         });
     }
@@ -3277,6 +5573,115 @@ mod tests {
         });
     }
 
+    #[test]
+    fn accept_pending_ownership_should_make_new_owner_a_space_follower() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership());
+            assert_ok!(_accept_default_pending_ownership());
+
+            assert!(SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+            // The previous owner keeps following the space by default:
+            assert!(SpaceFollows::space_followed_by_account((ACCOUNT1, SPACE1)));
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 2);
+        });
+    }
+
+    #[test]
+    fn accept_pending_ownership_should_move_roles_and_follow_when_requested() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            let old_owner = User::Account(ACCOUNT1);
+            let new_owner = User::Account(ACCOUNT2);
+
+            assert_ok!(_create_default_role());
+            assert_ok!(_grant_role(None, None, Some(vec![old_owner.clone()])));
+
+            assert_ok!(_transfer_space_ownership(None, None, None, Some(true)));
+            assert_ok!(_accept_default_pending_ownership());
+
+            assert!(Roles::role_ids_by_user_in_space(old_owner, SPACE1).is_empty());
+            assert_eq!(Roles::role_ids_by_user_in_space(new_owner.clone(), SPACE1), vec![ROLE1]);
+            assert_eq!(Roles::users_by_role_id(ROLE1), vec![new_owner]);
+            assert_eq!(Roles::users_count_by_role_id(ROLE1), 1);
+
+            assert!(!SpaceFollows::space_followed_by_account((ACCOUNT1, SPACE1)));
+            assert!(SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 1);
+        });
+    }
+
+    #[test]
+    fn pending_ownership_transfer_should_expire() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership());
+
+            let transfer = SpaceOwnership::pending_ownership_transfer(SPACE1).unwrap();
+            let expires_at = System::block_number() + TransferExpirationPeriod::get();
+            assert_eq!(transfer.expires_at, expires_at);
+
+            SpaceOwnership::on_initialize(expires_at - 1);
+            assert_eq!(SpaceOwnership::pending_space_owner(SPACE1), Some(ACCOUNT2));
+
+            SpaceOwnership::on_initialize(expires_at);
+            assert!(SpaceOwnership::pending_space_owner(SPACE1).is_none());
+            assert!(SpaceOwnership::get_incoming_ownership_transfers(ACCOUNT2, None, 10).is_empty());
+
+            assert_noop!(
+                _accept_default_pending_ownership(),
+                SpaceOwnershipError::<TestRuntime>::NoPendingTransferOnSpace
+            );
+        });
+    }
+
+    #[test]
+    fn pending_ownership_transfers_over_limit_should_expire_in_next_blocks() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 1002
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 1003
+
+            let space_ids = [SPACE1, SPACE2, SPACE2 + 1];
+            for space_id in space_ids {
+                assert_ok!(_transfer_space_ownership(None, Some(space_id), None, None));
+            }
+            let expires_at = System::block_number() + TransferExpirationPeriod::get();
+
+            // One unit of MaxExpiredTransfersToProcessPerBlock is spent on checking the block:
+            SpaceOwnership::on_initialize(expires_at);
+            let still_pending: Vec<_> = space_ids.iter().copied()
+                .filter(|space_id| SpaceOwnership::pending_space_owner(*space_id).is_some())
+                .collect();
+            assert_eq!(still_pending.len(), 1);
+
+            System::set_block_number(expires_at + 1);
+            assert_noop!(
+                _accept_pending_ownership(None, Some(still_pending[0])),
+                SpaceOwnershipError::<TestRuntime>::NoPendingTransferOnSpace
+            );
+
+            SpaceOwnership::on_initialize(expires_at + 1);
+            assert!(SpaceOwnership::pending_space_owner(still_pending[0]).is_none());
+            assert!(SpaceOwnership::get_incoming_ownership_transfers(ACCOUNT2, None, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn get_incoming_ownership_transfers_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_transfer_default_space_ownership());
+
+            let transfers = SpaceOwnership::get_incoming_ownership_transfers(ACCOUNT2, None, 10);
+            assert_eq!(transfers.len(), 1);
+            assert_eq!(transfers[0].space_id, SPACE1);
+            assert_eq!(transfers[0].current_owner, ACCOUNT1);
+            assert_eq!(transfers[0].new_owner, ACCOUNT2);
+
+            // A new transfer of the same space replaces the previous one:
+            assert_ok!(_transfer_space_ownership(None, None, Some(ACCOUNT3), None));
+
+            assert!(SpaceOwnership::get_incoming_ownership_transfers(ACCOUNT2, None, 10).is_empty());
+            assert_eq!(SpaceOwnership::get_incoming_ownership_transfers(ACCOUNT3, None, 10).len(), 1);
+        });
+    }
+
     #[test]
     fn accept_pending_ownership_should_fail_when_space_not_found() {
         ExtBuilder::build_with_pending_ownership_transfer_no_space().execute_with(|| {
@@ -3372,4 +5777,287 @@ mod tests {
             ), SpaceOwnershipError::<TestRuntime>::NotAllowedToRejectOwnershipTransfer); // Rejecting a transfer from ACCOUNT2
         });
     }
+
+    // Space owners tests
+
+    #[test]
+    fn update_space_owners_should_work() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+
+            let space = Spaces::space_by_id(SPACE1).unwrap();
+            assert_eq!(space.owner, ACCOUNT1);
+            assert!(space.is_owner(&ACCOUNT2));
+            assert_eq!(Spaces::space_owners(&space), vec![ACCOUNT1, ACCOUNT2]);
+            assert_eq!(Spaces::space_owners_threshold(SPACE1), 2);
+            assert_eq!(Spaces::space_ids_by_owner(ACCOUNT2), vec![SPACE1]);
+        });
+    }
+
+    #[test]
+    fn update_space_owners_should_fail_when_removing_main_owner() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _update_space_owners(None, None, Some(space_owners_update(vec![], vec![ACCOUNT1], None))),
+                SpacesError::<TestRuntime>::CannotRemoveMainSpaceOwner
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_owners_should_fail_when_threshold_exceeds_owners_count() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_noop!(
+                _update_space_owners(None, None, Some(space_owners_update(vec![ACCOUNT2], vec![], Some(3)))),
+                SpacesError::<TestRuntime>::InvalidSpaceOwnersThreshold
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_owners_should_fail_when_too_many_owners() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            // MaxSpaceOwners is 3 in this test runtime, including the main owner:
+            assert_noop!(
+                _update_space_owners(None, None, Some(space_owners_update(vec![ACCOUNT2, ACCOUNT3, 4], vec![], None))),
+                SpacesError::<TestRuntime>::TooManySpaceOwners
+            );
+        });
+    }
+
+    #[test]
+    fn update_space_handle_should_need_approvals_of_space_owners() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+
+            let new_handle = Some(space_handle_2());
+            assert_noop!(
+                _update_space(None, None, Some(update_for_space_handle(new_handle.clone()))),
+                SpacesError::<TestRuntime>::NotEnoughSpaceOwnersApprovals
+            );
+
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::UpdateHandle(new_handle.clone())));
+            assert_ok!(_update_space(None, None, Some(update_for_space_handle(new_handle.clone()))));
+
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().handle, new_handle);
+            assert_eq!(find_space_id_by_handle(space_handle_2()), Some(SPACE1));
+        });
+    }
+
+    #[test]
+    fn update_space_content_should_not_need_approvals_of_space_owners() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+
+            assert_ok!(_update_space(
+                Some(Origin::signed(ACCOUNT2)),
+                None,
+                Some(update_for_space_content(updated_space_content()))
+            ));
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().content, updated_space_content());
+        });
+    }
+
+    #[test]
+    fn transfer_space_ownership_to_co_owner_should_work_with_approvals() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_update_space_owners(None, None, Some(space_owners_update(vec![ACCOUNT2, ACCOUNT3], vec![], Some(3)))));
+
+            assert_noop!(
+                _transfer_default_space_ownership(),
+                SpacesError::<TestRuntime>::NotEnoughSpaceOwnersApprovals
+            );
+
+            let action = SpaceOwnersAction::TransferOwnership(ACCOUNT2);
+            assert_ok!(_approve_space_owners_action(None, None, action.clone()));
+            assert_ok!(_approve_space_owners_action(Some(Origin::signed(ACCOUNT3)), None, action));
+            assert_ok!(_transfer_default_space_ownership());
+            assert_ok!(_accept_default_pending_ownership());
+
+            let space = Spaces::space_by_id(SPACE1).unwrap();
+            assert_eq!(space.owner, ACCOUNT2);
+            assert_eq!(Spaces::space_owners(&space), vec![ACCOUNT2, ACCOUNT3]);
+            assert_eq!(Spaces::space_owners_threshold(SPACE1), 2);
+            assert!(Spaces::space_ids_by_owner(ACCOUNT1).is_empty());
+        });
+    }
+
+    #[test]
+    fn update_space_hidden_should_need_approvals_of_space_owners() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+
+            assert_noop!(
+                _update_space(Some(Origin::signed(ACCOUNT2)), None, Some(space_update(None, None, Some(true)))),
+                SpacesError::<TestRuntime>::NotEnoughSpaceOwnersApprovals
+            );
+
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::UpdateHidden(true)));
+            assert_ok!(_update_space(Some(Origin::signed(ACCOUNT2)), None, Some(space_update(None, None, Some(true)))));
+            assert!(Spaces::space_by_id(SPACE1).unwrap().hidden);
+        });
+    }
+
+    #[test]
+    fn update_space_parent_should_need_approvals_of_space_owners() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_create_space(None, Some(None), None, None)); // SpaceId 1002
+            assert_ok!(_add_default_space_co_owner());
+
+            assert_noop!(
+                _update_space(None, None, Some(update_for_space_parent_id(Some(SPACE2)))),
+                SpacesError::<TestRuntime>::NotEnoughSpaceOwnersApprovals
+            );
+
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::UpdateParent(Some(SPACE2))));
+            assert_ok!(_update_space(None, None, Some(update_for_space_parent_id(Some(SPACE2)))));
+            assert_eq!(Spaces::space_by_id(SPACE1).unwrap().parent_id, Some(SPACE2));
+        });
+    }
+
+    #[test]
+    fn delete_space_should_fail_when_co_owner_has_no_approvals() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+
+            assert_noop!(
+                _delete_space(Some(Origin::signed(ACCOUNT2)), None),
+                SpacesError::<TestRuntime>::NotEnoughSpaceOwnersApprovals
+            );
+            assert!(Spaces::space_by_id(SPACE1).is_some());
+        });
+    }
+
+    #[test]
+    fn delete_space_should_work_with_approvals_of_space_owners() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+
+            assert_ok!(_approve_space_owners_action(Some(Origin::signed(ACCOUNT1)), None, SpaceOwnersAction::DeleteSpace));
+            assert_ok!(_delete_space(Some(Origin::signed(ACCOUNT2)), None));
+            assert!(Spaces::space_by_id(SPACE1).is_none());
+        });
+    }
+
+    #[test]
+    fn approve_space_owners_action_should_fail_when_too_many_actions_are_pending() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+
+            // MaxPendingSpaceOwnersActions is 2 in this test runtime:
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::UpdateHidden(true)));
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::DeleteSpace));
+            assert_noop!(
+                _approve_space_owners_action(None, None, SpaceOwnersAction::UpdateParent(None)),
+                SpacesError::<TestRuntime>::TooManyPendingSpaceOwnersActions
+            );
+
+            // Another approval of a pending action doesn't add a new one:
+            assert_ok!(_approve_space_owners_action(
+                Some(Origin::signed(ACCOUNT1)), None, SpaceOwnersAction::UpdateHidden(true)
+            ));
+            assert_eq!(Spaces::pending_space_owners_actions_count(SPACE1), 2);
+
+            // An executed action is no longer pending:
+            assert_ok!(_update_space(Some(Origin::signed(ACCOUNT2)), None, Some(space_update(None, None, Some(true)))));
+            assert_eq!(Spaces::pending_space_owners_actions_count(SPACE1), 1);
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::UpdateParent(None)));
+
+            assert_ok!(Spaces::revoke_space_owners_action_approval(
+                Origin::signed(ACCOUNT2), SPACE1, SpaceOwnersAction::UpdateParent(None)
+            ));
+            assert_eq!(Spaces::pending_space_owners_actions_count(SPACE1), 1);
+        });
+    }
+
+    #[test]
+    fn delete_space_should_count_and_remove_pending_space_owners_actions() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_default_space_co_owner());
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::UpdateHidden(true)));
+            assert_ok!(_approve_space_owners_action(None, None, SpaceOwnersAction::DeleteSpace));
+
+            // Space 1 has one follower (Account 1) and two pending owners' actions.
+            assert_noop!(
+                _delete_space_with_max_items(None, None, 2),
+                SpacesError::<TestRuntime>::TooManyItemsToDeleteWithSpace
+            );
+            assert_ok!(_delete_space_with_max_items(None, None, 3));
+
+            assert_eq!(Spaces::pending_space_owners_actions_count(SPACE1), 0);
+            assert!(pallet_spaces::SpaceOwnersActionApprovals::<TestRuntime>::iter_prefix(SPACE1).next().is_none());
+        });
+    }
+
+    #[test]
+    fn delete_space_should_remove_space_co_owners() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_update_space_owners(None, None, None));
+            assert_ok!(_delete_default_space());
+
+            assert!(Spaces::space_ids_by_owner(ACCOUNT2).is_empty());
+            assert!(pallet_spaces::SpaceCoOwners::<TestRuntime>::iter_prefix(SPACE1).next().is_none());
+        });
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    // Session keys tests
+
+    const SESSION_KEY1: AccountId = 11;
+
+    fn _add_session_key(pallet_name: &[u8]) -> DispatchResultWithPostInfo {
+        SessionKeys::add_key(
+            Origin::signed(ACCOUNT1),
+            SESSION_KEY1,
+            MaxSessionKeyTimeToLive::get(),
+            vec![SessionKeyScope::Pallet(pallet_name.to_vec())],
+        )?;
+        SessionKeys::accept_key(Origin::signed(SESSION_KEY1), ACCOUNT1)
+    }
+
+    fn _create_default_post_with_session_key() -> DispatchResultWithPostInfo {
+        let call = Call::Posts(pallet_posts::Call::create_post {
+            space_id_opt: Some(SPACE1),
+            extension: extension_regular_post(),
+            content: post_content_ipfs(),
+        });
+        SessionKeys::proxy(Origin::signed(SESSION_KEY1), Box::new(call))
+    }
+
+    #[test]
+    fn create_post_with_session_key_should_be_created_by_owner() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_session_key(b"Posts"));
+            assert_ok!(_create_default_post_with_session_key());
+
+            let post = Posts::post_by_id(POST1).unwrap();
+            assert_eq!(post.created.account, ACCOUNT1);
+            assert_eq!(post.owner, ACCOUNT1);
+            assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
+        });
+    }
+
+    #[test]
+    fn create_post_with_session_key_should_fail_when_posts_are_out_of_scope() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_session_key(b"Reactions"));
+            assert_noop!(
+                _create_default_post_with_session_key(),
+                SessionKeysError::<TestRuntime>::CallNotAllowedForSessionKey
+            );
+        });
+    }
+
+    #[test]
+    fn create_post_with_session_key_should_fail_after_key_is_removed() {
+        ExtBuilder::build_with_space().execute_with(|| {
+            assert_ok!(_add_session_key(b"Posts"));
+            assert_ok!(SessionKeys::remove_key(Origin::signed(ACCOUNT1), SESSION_KEY1));
+            assert_noop!(
+                _create_default_post_with_session_key(),
+                SessionKeysError::<TestRuntime>::SessionKeyNotFound
+            );
+        });
+    }
 }
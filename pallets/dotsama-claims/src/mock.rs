@@ -93,6 +93,15 @@ impl pallet_utils::Config for Test {
     type Currency = Balances;
     type MinHandleLen = ();
     type MaxHandleLen = ();
+    type PremiumHandleLen = ();
+    type HandleRegistrationPeriod = ();
+    type HandleGracePeriod = ();
+    type MaxHandlesToReleasePerBlock = ();
+    type MaxRawContentLen = ();
+    type StorageDepositPerItem = ();
+    type StorageDepositPerByte = ();
+    type MaxItemsToMigratePerBlock = ();
+    type MultiBlockMigrations = ();
 }
 
 parameter_types! {
@@ -0,0 +1,13 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    pub trait EnergyApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec
+    {
+        /// Energy that `account` can still spend on fees of social calls.
+        fn get_remaining_energy(account: AccountId) -> Balance;
+    }
+}
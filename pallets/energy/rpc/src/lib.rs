@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use codec::Codec;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+
+use pallet_utils::rpc::map_rpc_error;
+pub use energy_runtime_api::EnergyApi as EnergyRuntimeApi;
+
+#[rpc]
+pub trait EnergyApi<BlockHash, AccountId, Balance> {
+    #[rpc(name = "energy_getRemainingEnergy")]
+    fn get_remaining_energy(&self, at: Option<BlockHash>, account: AccountId) -> Result<Balance>;
+}
+
+pub struct Energy<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> Energy<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance> EnergyApi<<Block as BlockT>::Hash, AccountId, Balance>
+    for Energy<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    Balance: Codec,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: EnergyRuntimeApi<Block, AccountId, Balance>,
+{
+    fn get_remaining_energy(&self, at: Option<<Block as BlockT>::Hash>, account: AccountId) -> Result<Balance> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_remaining_energy(&at, account);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+}
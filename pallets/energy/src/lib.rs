@@ -0,0 +1,330 @@
+//! # Energy Module
+//!
+//! Pallet that lets accounts pay fees of social calls (posts, reactions, follows)
+//! with energy instead of their balance.
+//!
+//! An account has energy only if it exists and has some reputation,
+//! so that fresh keypairs can't send social calls for free.
+//! Its energy capacity grows with that reputation.
+//! Spent energy regenerates linearly, so an account gets its full capacity back
+//! in `EnergyRegenerationPeriod` blocks after it stopped spending.
+//!
+//! Energy is spent by the `ChargeEnergy` signed extension, while `EnergyFeeAdapter`
+//! makes sure that the transaction payment pallet doesn't charge the same fee from the balance.
+//! The energy of the signer is spent, unless `EnergyPayer` names another account,
+//! e.g. the owner of a session key that signed a call on its behalf.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode};
+use frame_support::{
+    traits::Contains,
+    weights::{DispatchInfo, PostDispatchInfo},
+};
+use pallet_transaction_payment::OnChargeTransaction;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, Saturating, SignedExtension, Zero},
+    transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+    DispatchResult, Perbill, RuntimeDebug,
+};
+use sp_std::{fmt::Debug, marker::PhantomData};
+
+use df_traits::ReputationProvider;
+
+pub use pallet::*;
+
+pub type BalanceOf<T> = pallet_transaction_payment::BalanceOf<T>;
+
+/// The account whose energy pays the fee of a call signed by a given account.
+pub trait EnergyPayer<AccountId, Call> {
+    fn energy_payer(signer: &AccountId, call: &Call) -> AccountId;
+}
+
+/// Every signer pays with its own energy.
+impl<AccountId: Clone, Call> EnergyPayer<AccountId, Call> for () {
+    fn energy_payer(signer: &AccountId, _call: &Call) -> AccountId {
+        signer.clone()
+    }
+}
+
+/// Energy that an account has spent, as of the block it was spent in last time.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SpentEnergy<Balance, BlockNumber> {
+    pub amount: Balance,
+    pub updated_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+        /// Calls whose fees can be paid with energy.
+        type SocialCalls: Contains<<Self as frame_system::Config>::Call>;
+
+        /// The account whose energy pays the fee of a call, if not the signer.
+        type EnergyPayer: EnergyPayer<Self::AccountId, <Self as frame_system::Config>::Call>;
+
+        /// Reputation of an account that increases its energy capacity.
+        type Reputation: ReputationProvider<Self::AccountId>;
+
+        /// The energy capacity of every account that has some reputation.
+        #[pallet::constant]
+        type BaseEnergyCapacity: Get<BalanceOf<Self>>;
+
+        /// The energy capacity an account gets for each point of its reputation.
+        #[pallet::constant]
+        type EnergyPerReputationPoint: Get<BalanceOf<Self>>;
+
+        /// The number of blocks in which a fully spent energy regenerates.
+        #[pallet::constant]
+        type EnergyRegenerationPeriod: Get<Self::BlockNumber>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(
+                !T::EnergyRegenerationPeriod::get().is_zero(),
+                "Energy regeneration period must be greater than zero",
+            );
+        }
+    }
+
+    /// Accounts that have no record here have their full energy capacity.
+    #[pallet::storage]
+    #[pallet::getter(fn spent_energy_by_account)]
+    pub type SpentEnergyByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SpentEnergy<BalanceOf<T>, T::BlockNumber>>;
+}
+
+impl<T: Config> Pallet<T> {
+    /// Accounts that don't exist or have no reputation have no energy.
+    pub fn energy_capacity(account: &T::AccountId) -> BalanceOf<T> {
+        let reputation = T::Reputation::reputation(account);
+        if reputation.is_zero() || !<frame_system::Pallet<T>>::account_exists(account) {
+            return Zero::zero();
+        }
+
+        T::BaseEnergyCapacity::get()
+            .saturating_add(T::EnergyPerReputationPoint::get().saturating_mul(reputation.into()))
+    }
+
+    /// Energy spent by an account, less the part that has regenerated since then.
+    fn current_spent_energy(account: &T::AccountId) -> BalanceOf<T> {
+        match Self::spent_energy_by_account(account) {
+            Some(spent) => {
+                let period = T::EnergyRegenerationPeriod::get();
+                let elapsed = <frame_system::Pallet<T>>::block_number()
+                    .saturating_sub(spent.updated_at)
+                    .min(period);
+
+                let regenerated = Perbill::from_rational(elapsed, period) * Self::energy_capacity(account);
+                spent.amount.saturating_sub(regenerated)
+            }
+            None => Zero::zero(),
+        }
+    }
+
+    pub fn remaining_energy(account: &T::AccountId) -> BalanceOf<T> {
+        Self::energy_capacity(account).saturating_sub(Self::current_spent_energy(account))
+    }
+
+    /// Whether a fee (without a tip) of a given call can be paid with the energy of an account.
+    pub fn can_pay_with_energy(
+        account: &T::AccountId,
+        call: &<T as frame_system::Config>::Call,
+        fee: BalanceOf<T>,
+    ) -> bool {
+        T::SocialCalls::contains(call)
+            && !fee.is_zero()
+            && Self::remaining_energy(account) >= fee
+    }
+
+    fn update_spent_energy(account: &T::AccountId, amount: BalanceOf<T>) {
+        if amount.is_zero() {
+            SpentEnergyByAccount::<T>::remove(account);
+        } else {
+            SpentEnergyByAccount::<T>::insert(account, SpentEnergy {
+                amount,
+                updated_at: <frame_system::Pallet<T>>::block_number(),
+            });
+        }
+    }
+
+    pub(crate) fn spend_energy(account: &T::AccountId, amount: BalanceOf<T>) {
+        let spent = Self::current_spent_energy(account).saturating_add(amount);
+        Self::update_spent_energy(account, spent);
+    }
+
+    pub(crate) fn refund_energy(account: &T::AccountId, amount: BalanceOf<T>) {
+        let spent = Self::current_spent_energy(account).saturating_sub(amount);
+        Self::update_spent_energy(account, spent);
+    }
+}
+
+/// Transaction fee handler that leaves the fee of a social call to `ChargeEnergy`
+/// if its energy payer has enough energy to pay it. A tip is still charged by `OCT`.
+///
+/// `OCT` must charge nothing when its `LiquidityInfo` is default, like `CurrencyAdapter` does.
+pub struct EnergyFeeAdapter<T, OCT>(PhantomData<(T, OCT)>);
+
+impl<T: Config, OCT: OnChargeTransaction<T>> OnChargeTransaction<T> for EnergyFeeAdapter<T, OCT> {
+    type Balance = OCT::Balance;
+    /// Whether the fee is paid with energy, and what `OCT` has withdrawn.
+    type LiquidityInfo = (bool, OCT::LiquidityInfo);
+
+    fn withdraw_fee(
+        who: &T::AccountId,
+        call: &<T as frame_system::Config>::Call,
+        info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let fee_without_tip = fee.saturating_sub(tip).saturated_into::<u128>().saturated_into();
+
+        let payer = T::EnergyPayer::energy_payer(who, call);
+        if Pallet::<T>::can_pay_with_energy(&payer, call, fee_without_tip) {
+            OCT::withdraw_fee(who, call, info, tip, tip).map(|liquidity| (true, liquidity))
+        } else {
+            OCT::withdraw_fee(who, call, info, fee, tip).map(|liquidity| (false, liquidity))
+        }
+    }
+
+    fn correct_and_deposit_fee(
+        who: &T::AccountId,
+        info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+        post_info: &PostDispatchInfoOf<<T as frame_system::Config>::Call>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let (paid_with_energy, liquidity) = already_withdrawn;
+        let corrected_fee = if paid_with_energy { tip } else { corrected_fee };
+        OCT::correct_and_deposit_fee(who, info, post_info, corrected_fee, tip, liquidity)
+    }
+}
+
+/// Pay the fee of a social call with the energy of its energy payer, if there is enough of it.
+/// Otherwise the fee is charged by the transaction payment pallet as usual.
+///
+/// Goes after `ChargeTransactionPayment`, whose `OnChargeTransaction` must be `EnergyFeeAdapter`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeEnergy<T: Config + Send + Sync>(PhantomData<T>)
+    where
+        <T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>;
+
+impl<T: Config + Send + Sync> Debug for ChargeEnergy<T>
+    where
+        <T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        write!(f, "ChargeEnergy")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> ChargeEnergy<T>
+    where
+        <T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+    /// Create new `SignedExtension` to pay fees of social calls with energy.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for ChargeEnergy<T>
+    where
+        <T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+    type AccountId = T::AccountId;
+    type Call = <T as frame_system::Config>::Call;
+    type AdditionalSigned = ();
+    /// The energy payer and the energy it spent on the fee.
+    type Pre = Option<(T::AccountId, BalanceOf<T>)>;
+
+    const IDENTIFIER: &'static str = "ChargeEnergy";
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(())
+    }
+
+    /// Reject a social call early if its signer doesn't exist and its energy payer
+    /// doesn't have enough energy to pay for it.
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity {
+        if !T::SocialCalls::contains(call) {
+            return Ok(ValidTransaction::default());
+        }
+
+        let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, Zero::zero());
+        let payer = T::EnergyPayer::energy_payer(who, call);
+        if !Pallet::<T>::can_pay_with_energy(&payer, call, fee)
+            && !<frame_system::Pallet<T>>::account_exists(who)
+        {
+            return Err(InvalidTransaction::Payment.into());
+        }
+
+        Ok(ValidTransaction::default())
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, Zero::zero());
+
+        let payer = T::EnergyPayer::energy_payer(who, call);
+        if !Pallet::<T>::can_pay_with_energy(&payer, call, fee) {
+            return Ok(None);
+        }
+
+        Pallet::<T>::spend_energy(&payer, fee);
+        Ok(Some((payer, fee)))
+    }
+
+    fn post_dispatch(
+        pre: Self::Pre,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        len: usize,
+        _result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        if let Some((who, spent)) = pre {
+            let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+                len as u32, info, post_info, Zero::zero(),
+            );
+            Pallet::<T>::refund_energy(&who, spent.saturating_sub(actual_fee));
+        }
+        Ok(())
+    }
+}
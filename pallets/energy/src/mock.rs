@@ -0,0 +1,219 @@
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup}, testing::Header,
+    transaction_validity::{TransactionValidity, TransactionValidityError},
+};
+
+use crate as pallet_energy;
+
+use frame_support::{
+    parameter_types,
+    traits::Contains,
+    weights::{DispatchClass, DispatchInfo, IdentityFee, PostDispatchInfo, Weight},
+};
+use frame_system::limits::BlockWeights;
+use pallet_transaction_payment::{ChargeTransactionPayment, CurrencyAdapter};
+use sp_runtime::traits::SignedExtension;
+
+use df_traits::ReputationProvider;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
+        Energy: pallet_energy::{Pallet, Storage},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    // Makes a fee equal to the weight plus the length of an extrinsic.
+    pub MockBlockWeights: BlockWeights = BlockWeights::builder()
+        .base_block(0)
+        .for_class(DispatchClass::all(), |weights| weights.base_extrinsic = 0)
+        .build_or_panic();
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = MockBlockWeights;
+    type BlockLength = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type Event = Event;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+}
+
+parameter_types! {
+    pub const TransactionByteFee: Balance = 1;
+    pub const OperationalFeeMultiplier: u8 = 5;
+}
+
+impl pallet_transaction_payment::Config for Test {
+    type OnChargeTransaction = pallet_energy::EnergyFeeAdapter<Test, CurrencyAdapter<Balances, ()>>;
+    type TransactionByteFee = TransactionByteFee;
+    type OperationalFeeMultiplier = OperationalFeeMultiplier;
+    type WeightToFee = IdentityFee<Balance>;
+    type FeeMultiplierUpdate = ();
+}
+
+/// Remarks are the only social calls in this mock.
+pub struct MockSocialCalls;
+impl Contains<Call> for MockSocialCalls {
+    fn contains(call: &Call) -> bool {
+        matches!(call, Call::System(frame_system::Call::remark { .. }))
+    }
+}
+
+parameter_types! {
+    pub storage MockReputation: u32 = 1;
+}
+
+impl ReputationProvider<AccountId> for MockReputation {
+    fn reputation(_account: &AccountId) -> u32 {
+        Self::get()
+    }
+}
+
+/// Calls signed by `SESSION_KEY` are paid with the energy of `ACCOUNT1`.
+pub struct MockEnergyPayer;
+impl pallet_energy::EnergyPayer<AccountId, Call> for MockEnergyPayer {
+    fn energy_payer(signer: &AccountId, _call: &Call) -> AccountId {
+        if *signer == SESSION_KEY { ACCOUNT1 } else { *signer }
+    }
+}
+
+parameter_types! {
+    pub const BaseEnergyCapacity: Balance = 1_000;
+    pub const EnergyPerReputationPoint: Balance = 100;
+    pub const EnergyRegenerationPeriod: u64 = 100;
+}
+
+impl pallet_energy::Config for Test {
+    type SocialCalls = MockSocialCalls;
+    type EnergyPayer = MockEnergyPayer;
+    type Reputation = MockReputation;
+    type BaseEnergyCapacity = BaseEnergyCapacity;
+    type EnergyPerReputationPoint = EnergyPerReputationPoint;
+    type EnergyRegenerationPeriod = EnergyRegenerationPeriod;
+}
+
+pub(crate) type AccountId = u64;
+pub(crate) type Balance = u64;
+
+pub(crate) const ACCOUNT1: AccountId = 1;
+pub(crate) const ACCOUNT2: AccountId = 2;
+pub(crate) const SESSION_KEY: AccountId = 3;
+
+pub(crate) const INITIAL_BALANCE: Balance = 10_000;
+
+pub(crate) const EXTRINSIC_LEN: usize = 10;
+
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build() -> TestExternalities {
+        let mut storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(ACCOUNT1, INITIAL_BALANCE)],
+        }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+
+        ext
+    }
+}
+
+pub(crate) fn social_call() -> Call {
+    Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+pub(crate) fn transfer_call() -> Call {
+    Call::Balances(pallet_balances::Call::transfer { dest: ACCOUNT1, value: 1 })
+}
+
+pub(crate) fn info_with_weight(weight: Weight) -> DispatchInfo {
+    DispatchInfo { weight, ..Default::default() }
+}
+
+pub(crate) fn post_info_with_actual_weight(actual_weight: Option<Weight>) -> PostDispatchInfo {
+    PostDispatchInfo { actual_weight, ..Default::default() }
+}
+
+/// Run the signed extensions of a call the same way the executive does,
+/// `ChargeTransactionPayment` first and `ChargeEnergy` next.
+pub(crate) fn charge_and_dispatch(
+    call: Call,
+    info: DispatchInfo,
+    post_info: PostDispatchInfo,
+    tip: Balance,
+) -> Result<(), TransactionValidityError> {
+    charge_and_dispatch_by(ACCOUNT1, call, info, post_info, tip)
+}
+
+pub(crate) fn charge_and_dispatch_by(
+    signer: AccountId,
+    call: Call,
+    info: DispatchInfo,
+    post_info: PostDispatchInfo,
+    tip: Balance,
+) -> Result<(), TransactionValidityError> {
+    let payment_pre = ChargeTransactionPayment::<Test>::from(tip)
+        .pre_dispatch(&signer, &call, &info, EXTRINSIC_LEN)?;
+    let energy_pre = pallet_energy::ChargeEnergy::<Test>::new()
+        .pre_dispatch(&signer, &call, &info, EXTRINSIC_LEN)?;
+
+    ChargeTransactionPayment::<Test>::post_dispatch(payment_pre, &info, &post_info, EXTRINSIC_LEN, &Ok(()))?;
+    pallet_energy::ChargeEnergy::<Test>::post_dispatch(energy_pre, &info, &post_info, EXTRINSIC_LEN, &Ok(()))
+}
+
+pub(crate) fn validate_energy(who: AccountId, call: Call, info: DispatchInfo) -> TransactionValidity {
+    pallet_energy::ChargeEnergy::<Test>::new().validate(&who, &call, &info, EXTRINSIC_LEN)
+}
@@ -0,0 +1,141 @@
+use crate::mock::*;
+use frame_support::assert_ok;
+use sp_runtime::transaction_validity::InvalidTransaction;
+
+// A fee is the weight of a call plus the length of its extrinsic in the mock.
+const FEE: Balance = 100 + EXTRINSIC_LEN as Balance;
+
+#[test]
+fn social_call_should_be_paid_with_energy() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(charge_and_dispatch(social_call(), info_with_weight(100), post_info_with_actual_weight(None), 0));
+
+        assert_eq!(Balances::free_balance(ACCOUNT1), INITIAL_BALANCE);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1) - FEE);
+    });
+}
+
+#[test]
+fn other_calls_should_be_paid_with_balance() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(charge_and_dispatch(transfer_call(), info_with_weight(100), post_info_with_actual_weight(None), 0));
+
+        assert_eq!(Balances::free_balance(ACCOUNT1), INITIAL_BALANCE - FEE);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1));
+        assert!(Energy::spent_energy_by_account(ACCOUNT1).is_none());
+    });
+}
+
+#[test]
+fn social_call_should_be_paid_with_balance_when_energy_is_insufficient() {
+    ExtBuilder::build().execute_with(|| {
+        let info = info_with_weight(Energy::energy_capacity(&ACCOUNT1));
+        let fee = Energy::energy_capacity(&ACCOUNT1) + EXTRINSIC_LEN as Balance;
+
+        assert_ok!(charge_and_dispatch(social_call(), info, post_info_with_actual_weight(None), 0));
+
+        assert_eq!(Balances::free_balance(ACCOUNT1), INITIAL_BALANCE - fee);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1));
+    });
+}
+
+#[test]
+fn unused_energy_should_be_refunded_after_dispatch() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(charge_and_dispatch(social_call(), info_with_weight(100), post_info_with_actual_weight(Some(40)), 0));
+
+        let actual_fee = 40 + EXTRINSIC_LEN as Balance;
+        assert_eq!(Balances::free_balance(ACCOUNT1), INITIAL_BALANCE);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1) - actual_fee);
+    });
+}
+
+#[test]
+fn tip_should_be_paid_with_balance_when_fee_is_paid_with_energy() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(charge_and_dispatch(social_call(), info_with_weight(100), post_info_with_actual_weight(None), 5));
+
+        assert_eq!(Balances::free_balance(ACCOUNT1), INITIAL_BALANCE - 5);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1) - FEE);
+    });
+}
+
+#[test]
+fn energy_should_regenerate_over_time() {
+    ExtBuilder::build().execute_with(|| {
+        let info = info_with_weight(Energy::energy_capacity(&ACCOUNT1) - EXTRINSIC_LEN as Balance);
+        assert_ok!(charge_and_dispatch(social_call(), info, post_info_with_actual_weight(None), 0));
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), 0);
+
+        System::set_block_number(1 + EnergyRegenerationPeriod::get() / 4);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1) / 4);
+
+        System::set_block_number(1 + EnergyRegenerationPeriod::get() * 2);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1));
+    });
+}
+
+#[test]
+fn energy_capacity_should_grow_with_reputation() {
+    ExtBuilder::build().execute_with(|| {
+        MockReputation::set(&3);
+
+        assert_eq!(
+            Energy::energy_capacity(&ACCOUNT1),
+            BaseEnergyCapacity::get() + 3 * EnergyPerReputationPoint::get()
+        );
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1));
+    });
+}
+
+#[test]
+fn account_without_reputation_should_have_no_energy() {
+    ExtBuilder::build().execute_with(|| {
+        MockReputation::set(&0);
+
+        assert_eq!(Energy::energy_capacity(&ACCOUNT1), 0);
+        assert_ok!(charge_and_dispatch(social_call(), info_with_weight(100), post_info_with_actual_weight(None), 0));
+
+        assert_eq!(Balances::free_balance(ACCOUNT1), INITIAL_BALANCE - FEE);
+        assert!(Energy::spent_energy_by_account(ACCOUNT1).is_none());
+    });
+}
+
+#[test]
+fn account_that_does_not_exist_should_have_no_energy() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(Energy::energy_capacity(&ACCOUNT2), 0);
+        assert_eq!(
+            validate_energy(ACCOUNT2, social_call(), info_with_weight(100)),
+            Err(InvalidTransaction::Payment.into())
+        );
+    });
+}
+
+#[test]
+fn validate_should_accept_social_call_of_account_with_energy() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(validate_energy(ACCOUNT1, social_call(), info_with_weight(100)));
+        assert_ok!(validate_energy(ACCOUNT2, transfer_call(), info_with_weight(100)));
+    });
+}
+
+#[test]
+fn social_call_should_be_paid_with_energy_of_energy_payer() {
+    ExtBuilder::build().execute_with(|| {
+        // `SESSION_KEY` doesn't exist and has no balance, but `ACCOUNT1` pays for it.
+        assert_ok!(validate_energy(SESSION_KEY, social_call(), info_with_weight(100)));
+        assert_ok!(charge_and_dispatch_by(
+            SESSION_KEY,
+            social_call(),
+            info_with_weight(100),
+            post_info_with_actual_weight(Some(40)),
+            0,
+        ));
+
+        let actual_fee = 40 + EXTRINSIC_LEN as Balance;
+        assert_eq!(Balances::free_balance(ACCOUNT1), INITIAL_BALANCE);
+        assert_eq!(Energy::remaining_energy(&ACCOUNT1), Energy::energy_capacity(&ACCOUNT1) - actual_fee);
+        assert!(Energy::spent_energy_by_account(SESSION_KEY).is_none());
+    });
+}
@@ -0,0 +1,89 @@
+//! Faucets pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks};
+use sp_runtime::traits::Bounded;
+
+const FAUCET_SEED: u32 = 0;
+const MAX_FAUCETS_TO_REMOVE: u32 = 100;
+
+fn faucet_with_free_balance<T: Config>(index: u32) -> T::AccountId {
+    let faucet: T::AccountId = account("faucet", index, FAUCET_SEED);
+    T::Currency::make_free_balance_be(&faucet, BalanceOf::<T>::max_value());
+    faucet
+}
+
+fn drip_limit<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().saturating_mul(100u32.into())
+}
+
+fn period_limit<T: Config>() -> BalanceOf<T> {
+    drip_limit::<T>().saturating_mul(10u32.into())
+}
+
+fn add_faucet<T: Config>(faucet: T::AccountId) -> DispatchResult {
+    Pallet::<T>::add_faucet(RawOrigin::Root.into(), faucet, 100u32.into(), period_limit::<T>(), drip_limit::<T>())
+}
+
+benchmarks! {
+    add_faucet {
+        let faucet = faucet_with_free_balance::<T>(0);
+    }: _(RawOrigin::Root, faucet.clone(), 100u32.into(), period_limit::<T>(), drip_limit::<T>())
+    verify {
+        assert!(Pallet::<T>::faucet_by_account(faucet).is_some());
+    }
+
+    update_faucet {
+        let faucet = faucet_with_free_balance::<T>(0);
+        add_faucet::<T>(faucet.clone())?;
+
+        let update = FaucetUpdate {
+            enabled: Some(false),
+            period: Some(200u32.into()),
+            period_limit: Some(period_limit::<T>().saturating_mul(2u32.into())),
+            drip_limit: Some(drip_limit::<T>().saturating_mul(2u32.into())),
+        };
+    }: _(RawOrigin::Root, faucet.clone(), update)
+    verify {
+        let faucet = Pallet::<T>::faucet_by_account(faucet).ok_or("Faucet was not found")?;
+        assert!(!faucet.enabled);
+    }
+
+    remove_faucets {
+        let f in 1 .. MAX_FAUCETS_TO_REMOVE;
+
+        let mut faucets: Vec<T::AccountId> = Vec::new();
+        for i in 0..f {
+            let faucet = faucet_with_free_balance::<T>(i);
+            add_faucet::<T>(faucet.clone())?;
+            faucets.push(faucet);
+        }
+    }: _(RawOrigin::Root, faucets.clone())
+    verify {
+        for faucet in faucets {
+            assert!(Pallet::<T>::faucet_by_account(faucet).is_none());
+        }
+    }
+
+    drip {
+        let faucet = faucet_with_free_balance::<T>(0);
+        add_faucet::<T>(faucet.clone())?;
+
+        let recipient: T::AccountId = account("recipient", 0, FAUCET_SEED);
+    }: _(RawOrigin::Signed(faucet.clone()), recipient.clone(), drip_limit::<T>())
+    verify {
+        assert_eq!(T::Currency::free_balance(&recipient), drip_limit::<T>());
+        let faucet = Pallet::<T>::faucet_by_account(faucet).ok_or("Faucet was not found")?;
+        assert_eq!(faucet.dripped_in_current_period, drip_limit::<T>());
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::ExtBuilder::build(),
+        crate::mock::Test,
+    );
+}
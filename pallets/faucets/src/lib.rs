@@ -15,13 +15,12 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage,
     dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
     ensure,
-    traits::{Currency, ExistenceRequirement, Get},
+    traits::{Currency, ExistenceRequirement},
     weights::Pays,
 };
-use frame_system::{self as system, ensure_root, ensure_signed};
+use frame_system::{ensure_root, ensure_signed};
 use sp_runtime::RuntimeDebug;
 use sp_runtime::traits::{Saturating, Zero};
 use sp_std::{
@@ -29,6 +28,12 @@ use sp_std::{
     prelude::*,
 };
 
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 
@@ -57,45 +62,66 @@ pub struct FaucetUpdate<BlockNumber, Balance> {
     pub drip_limit: Option<Balance>,
 }
 
-type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance;
+type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config {
+pub use pallet::*;
 
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
 
-    type Currency: Currency<Self::AccountId>;
-}
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
 
-decl_storage! {
-    trait Store for Module<T: Config> as FaucetsModule {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-        /// Get a faucet data by its account id.
-        pub FaucetByAccount get(fn faucet_by_account):
-            map hasher(twox_64_concat) T::AccountId // Faucet account
-            => Option<Faucet<T>>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+
+        type Currency: Currency<Self::AccountId>;
     }
-}
 
-decl_event!(
-    pub enum Event<T> where
-        AccountId = <T as system::Config>::AccountId,
-        Balance = BalanceOf<T>
-    {
-        FaucetAdded(AccountId),
-        FaucetUpdated(AccountId),
-        FaucetsRemoved(Vec<AccountId>),
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<FaucetByAccount<T>, Faucet<T>>()
+        }
+    }
+
+    /// Get a faucet data by its account id.
+    #[pallet::storage]
+    #[pallet::getter(fn faucet_by_account)]
+    pub type FaucetByAccount<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, Faucet<T>, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        FaucetAdded(T::AccountId),
+        FaucetUpdated(T::AccountId),
+        FaucetsRemoved(Vec<T::AccountId>),
         Dripped(
-            AccountId, // Faucet account
-            AccountId, // Recipient account
-            Balance    // Amount dripped
+            T::AccountId, // Faucet account
+            T::AccountId, // Recipient account
+            BalanceOf<T>  // Amount dripped
         ),
     }
-);
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    #[pallet::error]
+    pub enum Error<T> {
         FaucetNotFound,
         FaucetAlreadyAdded,
         NoFreeBalanceOnFaucet,
@@ -116,24 +142,17 @@ decl_error! {
         PeriodLimitReached,
         DripLimitReached,
     }
-}
-
-decl_module! {
-    pub struct Module<T: Config> for enum Call where origin: T::Origin {
-        // Initializing errors
-        type Error = Error<T>;
-
-        // Initializing events
-        fn deposit_event() = default;
 
-        #[weight = 50_000 + T::DbWeight::get().reads_writes(2, 1)]
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(<T as Config>::WeightInfo::add_faucet())]
         pub fn add_faucet(
-            origin,
+            origin: OriginFor<T>,
             faucet: T::AccountId,
             period: T::BlockNumber,
             period_limit: BalanceOf<T>,
             drip_limit: BalanceOf<T>,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
 
             ensure_root(origin)?;
 
@@ -160,16 +179,16 @@ decl_module! {
             );
 
             FaucetByAccount::<T>::insert(faucet.clone(), new_faucet);
-            Self::deposit_event(RawEvent::FaucetAdded(faucet));
-            Ok(())
+            Self::deposit_event(Event::FaucetAdded(faucet));
+            Ok(().into())
         }
 
-        #[weight = 50_000 + T::DbWeight::get().reads_writes(1, 1)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_faucet())]
         pub fn update_faucet(
-            origin,
+            origin: OriginFor<T>,
             faucet: T::AccountId,
             update: FaucetUpdate<T::BlockNumber, BalanceOf<T>>
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
 
             ensure_root(origin)?;
 
@@ -227,15 +246,15 @@ decl_module! {
             ensure!(should_update, Error::<T>::NothingToUpdate);
 
             FaucetByAccount::<T>::insert(faucet.clone(), settings);
-            Self::deposit_event(RawEvent::FaucetUpdated(faucet));
-            Ok(())
+            Self::deposit_event(Event::FaucetUpdated(faucet));
+            Ok(().into())
         }
 
-        #[weight = 20_000 + T::DbWeight::get().reads_writes(0, 0) + 20_000 * faucets.len() as u64]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_faucets(faucets.len() as u32))]
         pub fn remove_faucets(
-            origin,
+            origin: OriginFor<T>,
             faucets: Vec<T::AccountId>
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
 
             ensure_root(origin)?;
 
@@ -246,13 +265,13 @@ decl_module! {
                 FaucetByAccount::<T>::remove(faucet);
             }
 
-            Self::deposit_event(RawEvent::FaucetsRemoved(faucets));
-            Ok(())
+            Self::deposit_event(Event::FaucetsRemoved(faucets));
+            Ok(().into())
         }
 
-        #[weight = 50_000 + T::DbWeight::get().reads_writes(3, 1)]
+        #[pallet::weight(<T as Config>::WeightInfo::drip())]
         pub fn drip(
-            origin, // Should be a faucet account
+            origin: OriginFor<T>, // Should be a faucet account
             recipient: T::AccountId,
             amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
@@ -295,13 +314,13 @@ decl_module! {
 
             FaucetByAccount::<T>::insert(&faucet, settings);
 
-            Self::deposit_event(RawEvent::Dripped(faucet, recipient, amount));
+            Self::deposit_event(Event::Dripped(faucet, recipient, amount));
             Ok(Pays::No.into())
         }
     }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
 
     pub fn require_faucet(faucet: &T::AccountId) -> Result<Faucet<T>, DispatchError> {
         Ok(Self::faucet_by_account(faucet).ok_or(Error::<T>::FaucetNotFound)?)
@@ -12,7 +12,7 @@ use crate as faucets;
 use frame_support::{
     parameter_types,
     assert_ok,
-    dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    dispatch::DispatchResultWithPostInfo,
     traits::Everything,
 };
 use frame_system as system;
@@ -102,10 +102,20 @@ impl pallet_utils::Config for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type PremiumHandleLen = ();
+    type HandleRegistrationPeriod = ();
+    type HandleGracePeriod = ();
+    type MaxHandlesToReleasePerBlock = ();
+    type MaxRawContentLen = ();
+    type StorageDepositPerItem = ();
+    type StorageDepositPerByte = ();
+    type MaxItemsToMigratePerBlock = ();
+    type MultiBlockMigrations = ();
 }
 
 impl Config for Test {
     type Event = Event;
+    type WeightInfo = ();
     type Currency = Balances;
 }
 
@@ -212,7 +222,7 @@ pub(crate) const fn default_faucet_update() -> FaucetUpdate<BlockNumber, Balance
     }
 }
 
-pub(crate) fn _add_default_faucet() -> DispatchResult {
+pub(crate) fn _add_default_faucet() -> DispatchResultWithPostInfo {
     _add_faucet(None, None, None)
 }
 
@@ -220,7 +230,7 @@ pub(crate) fn _add_faucet(
     origin: Option<Origin>,
     faucet_account: Option<AccountId>,
     settings_opt: Option<Faucet<Test>>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     let settings = settings_opt.unwrap_or_else(default_faucet);
     Faucets::add_faucet(
         origin.unwrap_or_else(Origin::root),
@@ -231,11 +241,11 @@ pub(crate) fn _add_faucet(
     )
 }
 
-pub(crate) fn _update_default_faucet() -> DispatchResult {
+pub(crate) fn _update_default_faucet() -> DispatchResultWithPostInfo {
     _update_faucet(None, None, None)
 }
 
-pub(crate) fn _update_faucet_settings(settings: FaucetUpdate<BlockNumber, Balance>) -> DispatchResult {
+pub(crate) fn _update_faucet_settings(settings: FaucetUpdate<BlockNumber, Balance>) -> DispatchResultWithPostInfo {
     _update_faucet(None, None, Some(settings))
 }
 
@@ -243,7 +253,7 @@ pub(crate) fn _update_faucet(
     origin: Option<Origin>,
     faucet_account: Option<AccountId>,
     update: Option<FaucetUpdate<BlockNumber, Balance>>
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Faucets::update_faucet(
         origin.unwrap_or_else(Origin::root),
         faucet_account.unwrap_or(FAUCET1),
@@ -251,14 +261,14 @@ pub(crate) fn _update_faucet(
     )
 }
 
-pub(crate) fn _remove_default_faucet() -> DispatchResult {
+pub(crate) fn _remove_default_faucet() -> DispatchResultWithPostInfo {
     _remove_faucets(None, None)
 }
 
 pub(crate) fn _remove_faucets(
     origin: Option<Origin>,
     faucet_accounts: Option<Vec<AccountId>>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Faucets::remove_faucets(
         origin.unwrap_or_else(Origin::root),
         faucet_accounts.unwrap_or_else(|| vec![FAUCET1])
@@ -0,0 +1,67 @@
+//! Hand-written weights for pallet_faucets
+//!
+//! These weights are estimated from the storage reads and writes of the extrinsics
+//! and are not benchmarked. Replace this file with the output of
+//! `./scripts/run-benchmark-on.sh pallet_faucets ./pallets/faucets/src` run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_faucets.
+pub trait WeightInfo {
+	fn add_faucet() -> Weight;
+	fn update_faucet() -> Weight;
+	fn remove_faucets(f: u32, ) -> Weight;
+	fn drip() -> Weight;
+}
+
+/// Weights for pallet_faucets using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn add_faucet() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn update_faucet() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_faucets(f: u32, ) -> Weight {
+		(20_000 as Weight)
+			.saturating_add((20_000 as Weight).saturating_mul(f as Weight))
+	}
+	fn drip() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn add_faucet() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn update_faucet() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_faucets(f: u32, ) -> Weight {
+		(20_000 as Weight)
+			.saturating_add((20_000 as Weight).saturating_mul(f as Weight))
+	}
+	fn drip() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
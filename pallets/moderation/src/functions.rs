@@ -1,12 +1,12 @@
 use crate::*;
 
 use frame_support::dispatch::DispatchError;
-use pallet_posts::Module as Posts;
+use pallet_posts::Pallet as Posts;
 use pallet_spaces::Space;
-use pallet_space_follows::Module as SpaceFollows;
+use pallet_space_follows::Pallet as SpaceFollows;
 use df_traits::moderation::*;
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
     pub fn require_report(report_id: ReportId) -> Result<Report<T>, DispatchError> {
         Ok(Self::report_by_id(report_id).ok_or(Error::<T>::ReportNotFound)?)
     }
@@ -50,8 +50,12 @@ impl<T: Config> Module<T> {
         // TODO: think, what and where we should change something if entity is moved
         match entity {
             EntityId::Content(_) => (),
-            EntityId::Account(account_id)
-                => SpaceFollows::<T>::unfollow_space_by_account(account_id.clone(), scope)?,
+            EntityId::Account(account_id) => {
+                // A blocked account may have never followed this space.
+                if SpaceFollows::<T>::space_followed_by_account((account_id.clone(), scope)) {
+                    SpaceFollows::<T>::unfollow_space_by_account(account_id.clone(), scope)?;
+                }
+            },
             EntityId::Space(space_id) => Spaces::<T>::try_move_space_to_root(*space_id)?,
             EntityId::Post(post_id) => Posts::<T>::delete_post_from_space(*post_id)?,
         }
@@ -111,7 +115,7 @@ impl<T: Config> SuggestedStatus<T> {
 }
 
 // TODO: maybe simplify using one common trait?
-impl<T: Config> IsAccountBlocked<T::AccountId> for Module<T> {
+impl<T: Config> IsAccountBlocked<T::AccountId> for Pallet<T> {
     fn is_blocked_account(account: T::AccountId, scope: SpaceId) -> bool {
         let entity = EntityId::Account(account);
 
@@ -125,7 +129,7 @@ impl<T: Config> IsAccountBlocked<T::AccountId> for Module<T> {
     }
 }
 
-impl<T: Config> IsSpaceBlocked for Module<T> {
+impl<T: Config> IsSpaceBlocked for Pallet<T> {
     fn is_blocked_space(space_id: SpaceId, scope: SpaceId) -> bool {
         let entity = EntityId::Space(space_id);
 
@@ -139,7 +143,7 @@ impl<T: Config> IsSpaceBlocked for Module<T> {
     }
 }
 
-impl<T: Config> IsPostBlocked<PostId> for Module<T> {
+impl<T: Config> IsPostBlocked<PostId> for Pallet<T> {
     fn is_blocked_post(post_id: PostId, scope: SpaceId) -> bool {
         let entity = EntityId::Post(post_id);
 
@@ -153,7 +157,7 @@ impl<T: Config> IsPostBlocked<PostId> for Module<T> {
     }
 }
 
-impl<T: Config> IsContentBlocked for Module<T> {
+impl<T: Config> IsContentBlocked for Pallet<T> {
     fn is_blocked_content(content: Content, scope: SpaceId) -> bool {
         let entity = EntityId::Content(content);
 
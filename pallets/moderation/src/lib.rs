@@ -15,6 +15,9 @@
 //!
 //! - A post cannot be added to a space if an IPFS CID of this post is blocked in this space.
 //! - An account cannot create posts in a space if this account is blocked in this space.
+//! - An account cannot react to posts or follow a space if this account is blocked in this space.
+//! - A blocked account is removed from the followers of this space.
+//! - A blocked post is removed from this space.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -23,14 +26,14 @@ use scale_info::TypeInfo;
 use sp_std::prelude::*;
 use sp_runtime::RuntimeDebug;
 use frame_support::{
-    decl_module, decl_storage, decl_event, decl_error, ensure,
-    dispatch::DispatchResult,
+    ensure,
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
     traits::Get,
 };
-use frame_system::{self as system, ensure_signed};
+use frame_system::ensure_signed;
 
-use pallet_utils::{Content, WhoAndWhen, SpaceId, Module as Utils, PostId};
-use pallet_spaces::Module as Spaces;
+use pallet_utils::{Content, WhoAndWhen, SpaceId, Pallet as Utils, PostId, StorageItem};
+use pallet_spaces::Pallet as Spaces;
 
 // TODO: move all tests to df-integration-tests
 #[cfg(test)]
@@ -98,85 +101,108 @@ pub struct SpaceModerationSettingsUpdate {
     pub autoblock_threshold: Option<Option<u16>>
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_posts::Config
-    + pallet_spaces::Config
-    + pallet_space_follows::Config
-    + pallet_utils::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
-
-    type DefaultAutoblockThreshold: Get<u16>;
-}
+pub use pallet::*;
 
-pub const FIRST_REPORT_ID: u64 = 1;
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
 
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as ModerationModule {
-
-        /// The next moderation report id.
-        pub NextReportId get(fn next_report_id): ReportId = FIRST_REPORT_ID;
-
-        /// Report details by its id (key).
-        pub ReportById get(fn report_by_id):
-            map hasher(twox_64_concat) ReportId
-            => Option<Report<T>>;
-
-        /// Report id if entity (key 1) was reported by a specific account (key 2)
-        pub ReportIdByAccount get(fn report_id_by_account):
-            map hasher(twox_64_concat) (EntityId<T::AccountId>, T::AccountId)
-            => Option<ReportId>;
-
-        /// Ids of all reports in this space (key).
-        pub ReportIdsBySpaceId get(fn report_ids_by_space_id):
-            map hasher(twox_64_concat) SpaceId
-            => Vec<ReportId>;
-
-        /// Ids of all reports related to a specific entity (key 1) sent to this space (key 2).
-        pub ReportIdsByEntityInSpace get(fn report_ids_by_entity_in_space): double_map
-            hasher(twox_64_concat) EntityId<T::AccountId>,
-            hasher(twox_64_concat) SpaceId
-            => Vec<ReportId>;
-
-        /// An entity (key 1) status (`Blocked` or `Allowed`) in this space (key 2).
-        pub StatusByEntityInSpace get(fn status_by_entity_in_space): double_map
-            hasher(twox_64_concat) EntityId<T::AccountId>,
-            hasher(twox_64_concat) SpaceId
-            => Option<EntityStatus>;
-
-        /// Entity (key 1) statuses suggested by space (key 2) moderators.
-        pub SuggestedStatusesByEntityInSpace get(fn suggested_statuses): double_map
-            hasher(twox_64_concat) EntityId<T::AccountId>,
-            hasher(twox_64_concat) SpaceId
-            => Vec<SuggestedStatus<T>>;
-
-        /// A custom moderation settings for a certain space (key).
-        pub ModerationSettings get(fn moderation_settings):
-            map hasher(twox_64_concat) SpaceId
-            => Option<SpaceModerationSettings>;
-    }
-}
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
-// The pallet's events
-decl_event!(
-    pub enum Event<T> where
-        AccountId = <T as system::Config>::AccountId,
-        EntityId = EntityId<<T as system::Config>::AccountId>
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_posts::Config
+        + pallet_spaces::Config
+        + pallet_space_follows::Config
+        + pallet_utils::Config
     {
-        EntityReported(AccountId, SpaceId, EntityId, ReportId),
-        EntityStatusSuggested(AccountId, SpaceId, EntityId, Option<EntityStatus>),
-        EntityStatusUpdated(AccountId, SpaceId, EntityId, Option<EntityStatus>),
-        EntityStatusDeleted(AccountId, SpaceId, EntityId),
-        ModerationSettingsUpdated(AccountId, SpaceId),
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        #[pallet::constant]
+        type DefaultAutoblockThreshold: Get<u16>;
     }
-);
 
-// The pallet's errors
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<ReportById<T>, Report<T>>()
+        }
+    }
+
+    #[pallet::type_value]
+    pub fn DefaultForNextReportId() -> ReportId {
+        FIRST_REPORT_ID
+    }
+
+    /// The next moderation report id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_report_id)]
+    pub type NextReportId<T: Config> = StorageValue<_, ReportId, ValueQuery, DefaultForNextReportId>;
+
+    /// Report details by its id (key).
+    #[pallet::storage]
+    #[pallet::getter(fn report_by_id)]
+    pub type ReportById<T: Config> = StorageMap<_, Twox64Concat, ReportId, Report<T>, OptionQuery>;
+
+    /// Report id if entity (key 1) was reported by a specific account (key 2)
+    #[pallet::storage]
+    #[pallet::getter(fn report_id_by_account)]
+    pub type ReportIdByAccount<T: Config> =
+        StorageMap<_, Twox64Concat, (EntityId<T::AccountId>, T::AccountId), ReportId, OptionQuery>;
+
+    /// Ids of all reports in this space (key).
+    #[pallet::storage]
+    #[pallet::getter(fn report_ids_by_space_id)]
+    pub type ReportIdsBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, Vec<ReportId>, ValueQuery>;
+
+    /// Ids of all reports related to a specific entity (key 1) sent to this space (key 2).
+    #[pallet::storage]
+    #[pallet::getter(fn report_ids_by_entity_in_space)]
+    pub type ReportIdsByEntityInSpace<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EntityId<T::AccountId>, Twox64Concat, SpaceId, Vec<ReportId>, ValueQuery>;
+
+    /// An entity (key 1) status (`Blocked` or `Allowed`) in this space (key 2).
+    #[pallet::storage]
+    #[pallet::getter(fn status_by_entity_in_space)]
+    pub type StatusByEntityInSpace<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EntityId<T::AccountId>, Twox64Concat, SpaceId, EntityStatus, OptionQuery>;
+
+    /// Entity (key 1) statuses suggested by space (key 2) moderators.
+    #[pallet::storage]
+    #[pallet::getter(fn suggested_statuses)]
+    pub type SuggestedStatusesByEntityInSpace<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EntityId<T::AccountId>, Twox64Concat, SpaceId, Vec<SuggestedStatus<T>>, ValueQuery>;
+
+    /// A custom moderation settings for a certain space (key).
+    #[pallet::storage]
+    #[pallet::getter(fn moderation_settings)]
+    pub type ModerationSettings<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, SpaceModerationSettings, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        EntityReported(T::AccountId, SpaceId, EntityId<T::AccountId>, ReportId),
+        EntityStatusSuggested(T::AccountId, SpaceId, EntityId<T::AccountId>, Option<EntityStatus>),
+        EntityStatusUpdated(T::AccountId, SpaceId, EntityId<T::AccountId>, Option<EntityStatus>),
+        EntityStatusDeleted(T::AccountId, SpaceId, EntityId<T::AccountId>),
+        ModerationSettingsUpdated(T::AccountId, SpaceId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
         /// The account has already reported this entity.
         AlreadyReportedEntity,
         /// The entity has no status in this space. Nothing to delete.
@@ -207,30 +233,18 @@ decl_error! {
         /// Entity status has already been suggested by this moderator account.
         AlreadySuggestedEntityStatus,
     }
-}
-
-// The pallet's dispatchable functions.
-decl_module! {
-    /// The module declaration.
-    pub struct Module<T: Config> for enum Call where origin: T::Origin {
-
-        const DefaultAutoblockThreshold: u16 = T::DefaultAutoblockThreshold::get();
-
-        // Initializing errors
-        type Error = Error<T>;
-
-        // Initializing events
-        fn deposit_event() = default;
 
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
         /// Report any entity by any person with mandatory reason.
         /// `entity` scope and the `scope` provided mustn't differ
-        #[weight = 10_000 + T::DbWeight::get().reads_writes(6, 5)]
+        #[pallet::weight(10_000 + T::DbWeight::get().reads_writes(7, 7))]
         pub fn report_entity(
-            origin,
+            origin: OriginFor<T>,
             entity: EntityId<T::AccountId>,
             scope: SpaceId,
             reason: Content
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             // TODO check this func, if looks strange
@@ -247,26 +261,31 @@ decl_module! {
             let report_id = Self::next_report_id();
             let new_report = Report::<T>::new(report_id, who.clone(), entity.clone(), scope, reason);
 
+            // Reports are never deleted, so a storage deposit of a report stays reserved
+            // until a moderator suggests an entity status on this report.
+            Utils::<T>::reserve_storage_deposit(&who, StorageItem::Report(report_id), &new_report)?;
+
             ReportById::<T>::insert(report_id, new_report);
             ReportIdByAccount::<T>::insert((&entity, &who), report_id);
-            ReportIdsBySpaceId::mutate(scope, |ids| ids.push(report_id));
+            ReportIdsBySpaceId::<T>::mutate(scope, |ids| ids.push(report_id));
             ReportIdsByEntityInSpace::<T>::mutate(&entity, scope, |ids| ids.push(report_id));
-            NextReportId::mutate(|n| { *n += 1; });
+            NextReportId::<T>::mutate(|n| { *n += 1; });
 
-            Self::deposit_event(RawEvent::EntityReported(who, scope, entity, report_id));
-            Ok(())
+            Self::deposit_event(Event::EntityReported(who, scope, entity, report_id));
+            Ok(().into())
         }
 
         /// Leave a feedback on the report either it's confirmation or ignore.
         /// `origin` - any permitted account (e.g. Space owner or moderator that's set via role)
-        #[weight = 10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */]
+        /// A storage deposit of the report, if `report_id_opt` is provided, is returned to the reporter.
+        #[pallet::weight(10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */)]
         pub fn suggest_entity_status(
-            origin,
+            origin: OriginFor<T>,
             entity: EntityId<T::AccountId>,
             scope: SpaceId, // TODO make scope as Option, but either scope or report_id_opt should be Some
             status: Option<EntityStatus>,
             report_id_opt: Option<ReportId>
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             if let Some(report_id) = report_id_opt {
@@ -306,18 +325,22 @@ decl_module! {
 
             SuggestedStatusesByEntityInSpace::<T>::insert(entity.clone(), scope, suggestions);
 
-            Self::deposit_event(RawEvent::EntityStatusSuggested(who, scope, entity, status));
-            Ok(())
+            if let Some(report_id) = report_id_opt {
+                Utils::<T>::release_storage_deposit(StorageItem::Report(report_id));
+            }
+
+            Self::deposit_event(Event::EntityStatusSuggested(who, scope, entity, status));
+            Ok(().into())
         }
 
         /// Allows a space owner/admin to update the final moderation status of a reported entity.
-        #[weight = 10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */]
+        #[pallet::weight(10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */)]
         pub fn update_entity_status(
-            origin,
+            origin: OriginFor<T>,
             entity: EntityId<T::AccountId>,
             scope: SpaceId,
             status_opt: Option<EntityStatus>
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             // TODO: add `forbid_content` parameter and track entity Content blocking via OCW
@@ -338,17 +361,17 @@ decl_module! {
                 StatusByEntityInSpace::<T>::remove(entity.clone(), scope);
             }
 
-            Self::deposit_event(RawEvent::EntityStatusUpdated(who, scope, entity, status_opt));
-            Ok(())
+            Self::deposit_event(Event::EntityStatusUpdated(who, scope, entity, status_opt));
+            Ok(().into())
         }
 
         /// Allows a space owner/admin to delete a current status of a reported entity.
-        #[weight = 10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */]
+        #[pallet::weight(10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */)]
         pub fn delete_entity_status(
-            origin,
+            origin: OriginFor<T>,
             entity: EntityId<T::AccountId>,
             scope: SpaceId
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             let status = Self::status_by_entity_in_space(&entity, scope);
@@ -359,19 +382,17 @@ decl_module! {
 
             StatusByEntityInSpace::<T>::remove(&entity, scope);
 
-            Self::deposit_event(RawEvent::EntityStatusDeleted(who, scope, entity));
-            Ok(())
+            Self::deposit_event(Event::EntityStatusDeleted(who, scope, entity));
+            Ok(().into())
         }
 
-        // todo: add ability to delete report_ids
-
         // TODO rename to update_settings?
-        #[weight = 10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */]
-        fn update_moderation_settings(
-            origin,
+        #[pallet::weight(10_000 /* TODO + T::DbWeight::get().reads_writes(_, _) */)]
+        pub fn update_moderation_settings(
+            origin: OriginFor<T>,
             space_id: SpaceId,
             update: SpaceModerationSettingsUpdate
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
             let has_updates = update.autoblock_threshold.is_some();
@@ -400,10 +421,13 @@ decl_module! {
             }
 
             if should_update {
-                ModerationSettings::insert(space_id, settings);
-                Self::deposit_event(RawEvent::ModerationSettingsUpdated(who, space_id));
+                ModerationSettings::<T>::insert(space_id, settings);
+                Self::deposit_event(Event::ModerationSettingsUpdated(who, space_id));
             }
-            Ok(())
+            Ok(().into())
         }
     }
 }
+
+pub const FIRST_REPORT_ID: u64 = 1;
+
@@ -2,7 +2,7 @@ use super::*;
 
 use crate as moderation;
 
-use frame_support::{assert_ok, dispatch::DispatchResult, parameter_types, StorageMap, traits::Everything};
+use frame_support::{assert_ok, dispatch::DispatchResultWithPostInfo, parameter_types, traits::Everything};
 use frame_system as system;
 
 use sp_core::H256;
@@ -37,6 +37,7 @@ frame_support::construct_runtime!(
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
         Moderation: moderation::{Pallet, Call, Storage, Event<T>},
 		Posts: pallet_posts::{Pallet, Call, Storage, Event<T>},
+		ProfileFollows: pallet_profile_follows::{Pallet, Call, Storage, Event<T>},
         Profiles: pallet_profiles::{Pallet, Call, Storage, Event<T>},
 		Roles: pallet_roles::{Pallet, Call, Storage, Event<T>},
 		SpaceFollows: pallet_space_follows::{Pallet, Call, Storage, Event<T>},
@@ -96,6 +97,15 @@ impl pallet_utils::Config for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type PremiumHandleLen = ();
+    type HandleRegistrationPeriod = ();
+    type HandleGracePeriod = ();
+    type MaxHandlesToReleasePerBlock = ();
+    type MaxRawContentLen = ();
+    type StorageDepositPerItem = ();
+    type StorageDepositPerByte = ();
+    type MaxItemsToMigratePerBlock = ();
+    type MultiBlockMigrations = ();
 }
 
 parameter_types! {
@@ -118,20 +128,35 @@ impl pallet_permissions::Config for Test {
     type DefaultSpacePermissions = DefaultSpacePermissions;
 }
 
+parameter_types! {
+    pub const MaxSubspaceDepth: u32 = 10;
+    pub const MaxSubspacesToMove: u32 = 100;
+    pub const MaxSpaceOwners: u16 = 20;
+    pub const MaxPendingSpaceOwnersActions: u32 = 10;
+}
+
 impl pallet_spaces::Config for Test {
     type Event = Event;
+    type WeightInfo = ();
     type Currency = Balances;
     type Roles = Roles;
     type SpaceFollows = SpaceFollows;
     type BeforeSpaceCreated = SpaceFollows;
     type AfterSpaceUpdated = ();
+    type BeforeSpaceDeleted = ();
     type IsAccountBlocked = Moderation;
     type IsContentBlocked = Moderation;
+    type ProfileHandles = ();
     type HandleDeposit = ();
+    type MaxSubspaceDepth = MaxSubspaceDepth;
+    type MaxSubspacesToMove = MaxSubspacesToMove;
+    type MaxSpaceOwners = MaxSpaceOwners;
+    type MaxPendingSpaceOwnersActions = MaxPendingSpaceOwnersActions;
 }
 
 impl pallet_space_follows::Config for Test {
     type Event = Event;
+    type WeightInfo = ();
     type BeforeSpaceFollowed = ();
     type BeforeSpaceUnfollowed = ();
 }
@@ -142,27 +167,46 @@ parameter_types! {
 
 impl pallet_posts::Config for Test {
     type Event = Event;
+    type WeightInfo = ();
     type MaxCommentDepth = MaxCommentDepth;
     type AfterPostUpdated = ();
+    type AfterPostDeleted = ();
+    type PostScores = ();
     type IsPostBlocked = Moderation;
 }
 
 parameter_types! {
     pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+    pub const MaxSpaceRoleHoldersPerSpace: u32 = 10;
+    pub const MaxExpiredGrantsToProcessPerBlock: u32 = 10;
 }
 
 impl pallet_roles::Config for Test {
     type Event = Event;
+    type WeightInfo = ();
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type MaxSpaceRoleHoldersPerSpace = MaxSpaceRoleHoldersPerSpace;
+    type MaxExpiredGrantsToProcessPerBlock = MaxExpiredGrantsToProcessPerBlock;
     type Spaces = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = Moderation;
     type IsContentBlocked = Moderation;
 }
 
+impl pallet_profile_follows::Config for Test {
+    type Event = Event;
+    type WeightInfo = ();
+    type BeforeAccountFollowed = ();
+    type BeforeAccountUnfollowed = ();
+}
+
 impl pallet_profiles::Config for Test {
     type Event = Event;
+    type WeightInfo = ();
     type AfterProfileUpdated = ();
+    type Currency = Balances;
+    type SpaceHandles = ();
+    type HandleDeposit = ();
 }
 
 parameter_types! {
@@ -321,7 +365,7 @@ pub(crate) fn create_space_and_post() {
     ));
 }
 
-pub(crate) fn _report_default_post() -> DispatchResult {
+pub(crate) fn _report_default_post() -> DispatchResultWithPostInfo {
     _report_entity(None, None, None, None)
 }
 
@@ -330,7 +374,7 @@ pub(crate) fn _report_entity(
     entity: Option<EntityId<AccountId>>,
     scope: Option<SpaceId>,
     reason: Option<Content>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Moderation::report_entity(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT_SCOPE_OWNER)),
         entity.unwrap_or(EntityId::Post(POST1)),
@@ -339,7 +383,7 @@ pub(crate) fn _report_entity(
     )
 }
 
-pub(crate) fn _suggest_blocked_status_for_post() -> DispatchResult {
+pub(crate) fn _suggest_blocked_status_for_post() -> DispatchResultWithPostInfo {
     _suggest_entity_status(None, None, None, None, None)
 }
 
@@ -350,7 +394,7 @@ pub(crate) fn _suggest_entity_status(
     scope: Option<SpaceId>,
     status: Option<Option<EntityStatus>>,
     report_id_opt: Option<Option<ReportId>>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Moderation::suggest_entity_status(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT_SCOPE_OWNER)),
         entity.unwrap_or(EntityId::Post(POST1)),
@@ -360,7 +404,7 @@ pub(crate) fn _suggest_entity_status(
     )
 }
 
-pub(crate) fn _update_post_status_to_allowed() -> DispatchResult {
+pub(crate) fn _update_post_status_to_allowed() -> DispatchResultWithPostInfo {
     _update_entity_status(None, None, None, None)
 }
 
@@ -369,7 +413,7 @@ pub(crate) fn _update_entity_status(
     entity: Option<EntityId<AccountId>>,
     scope: Option<SpaceId>,
     status_opt: Option<Option<EntityStatus>>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Moderation::update_entity_status(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT_SCOPE_OWNER)),
         entity.unwrap_or(EntityId::Post(POST1)),
@@ -378,7 +422,7 @@ pub(crate) fn _update_entity_status(
     )
 }
 
-pub(crate) fn _delete_post_status() -> DispatchResult {
+pub(crate) fn _delete_post_status() -> DispatchResultWithPostInfo {
     _delete_entity_status(None, None, None)
 }
 
@@ -386,7 +430,7 @@ pub(crate) fn _delete_entity_status(
     origin: Option<Origin>,
     entity: Option<EntityId<AccountId>>,
     scope: Option<SpaceId>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Moderation::delete_entity_status(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT_SCOPE_OWNER)),
         entity.unwrap_or(EntityId::Post(POST1)),
@@ -394,7 +438,7 @@ pub(crate) fn _delete_entity_status(
     )
 }
 
-pub(crate) fn _update_autoblock_threshold_in_moderation_settings() -> DispatchResult {
+pub(crate) fn _update_autoblock_threshold_in_moderation_settings() -> DispatchResultWithPostInfo {
     _update_moderation_settings(None, None, None)
 }
 
@@ -402,7 +446,7 @@ pub(crate) fn _update_moderation_settings(
     origin: Option<Origin>,
     space_id: Option<SpaceId>,
     settings_update: Option<SpaceModerationSettingsUpdate>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Moderation::update_moderation_settings(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT_SCOPE_OWNER)),
         space_id.unwrap_or(SPACE1),
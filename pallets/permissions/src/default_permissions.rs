@@ -26,6 +26,7 @@ parameter_types! {
       SP::Upvote,
       SP::Downvote,
       SP::Share,
+      SP::React,
     ].into_iter().collect()),
 
     // Followers can do everything that everyone else can.
@@ -4,16 +4,12 @@ use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Serialize, Deserialize};
-use frame_support::{
-  decl_module,
-  traits::Get
-};
+use frame_support::traits::Get;
 use sp_runtime::RuntimeDebug;
 use sp_std::{
   collections::btree_set::BTreeSet,
   prelude::*
 };
-use frame_system::{self as system};
 
 use pallet_utils::SpaceId;
 
@@ -86,6 +82,9 @@ pub enum SpacePermission {
 
   /// Allows to update space settings across different pallets.
   UpdateSpaceSettings,
+
+  /// React with custom reaction kinds (e.g. emojis) to this space, its posts and comments.
+  React,
 }
 
 pub type SpacePermissionSet = BTreeSet<SpacePermission>;
@@ -126,23 +125,56 @@ impl Default for SpacePermissions {
   }
 }
 
+impl SpacePermissions {
+  /// Take the permission sets that are not overridden here from the permissions of a parent space.
+  pub fn inherit(self, parent: SpacePermissions) -> SpacePermissions {
+    SpacePermissions {
+      none: self.none.or(parent.none),
+      everyone: self.everyone.or(parent.everyone),
+      follower: self.follower.or(parent.follower),
+      space_owner: self.space_owner.or(parent.space_owner),
+    }
+  }
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
 pub struct SpacePermissionsContext {
   pub space_id: SpaceId,
   pub is_space_owner: bool,
   pub is_space_follower: bool,
-  pub space_perms: Option<SpacePermissions>
+  pub space_perms: Option<SpacePermissions>,
+  /// Permissions of a post that override the space-level ones for actions on this post.
+  pub post_perms: Option<SpacePermissions>,
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config {
-  type DefaultSpacePermissions: Get<SpacePermissions>;
-}
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+  use super::*;
+  use frame_support::pallet_prelude::*;
+  use frame_system::pallet_prelude::*;
+
+  /// The current storage version.
+  const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
-    const DefaultSpacePermissions: SpacePermissions = T::DefaultSpacePermissions::get();
+  /// The pallet's configuration trait.
+  #[pallet::config]
+  pub trait Config: frame_system::Config {
+    #[pallet::constant]
+    type DefaultSpacePermissions: Get<SpacePermissions>;
   }
+
+  #[pallet::pallet]
+  #[pallet::generate_store(pub(super) trait Store)]
+  #[pallet::storage_version(STORAGE_VERSION)]
+  pub struct Pallet<T>(_);
+
+  #[pallet::hooks]
+  impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+  #[pallet::call]
+  impl<T: Config> Pallet<T> {}
 }
 
 impl SpacePermission {
@@ -156,7 +188,7 @@ impl SpacePermission {
   }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
 
   fn get_overrides_or_defaults(
     overrides: Option<SpacePermissionSet>,
@@ -172,10 +204,11 @@ impl<T: Config> Module<T> {
 
   fn resolve_space_perms(
     space_perms: Option<SpacePermissions>,
+    post_perms: Option<SpacePermissions>,
   ) -> SpacePermissions {
 
     let defaults = T::DefaultSpacePermissions::get();
-    let overrides = space_perms.unwrap_or_default();
+    let overrides = post_perms.unwrap_or_default().inherit(space_perms.unwrap_or_default());
 
     SpacePermissions {
       none: Self::get_overrides_or_defaults(overrides.none, defaults.none),
@@ -190,7 +223,7 @@ impl<T: Config> Module<T> {
     permission: SpacePermission,
   ) -> Option<bool> {
 
-    let perms_by_role = Self::resolve_space_perms(ctx.space_perms);
+    let perms_by_role = Self::resolve_space_perms(ctx.space_perms, ctx.post_perms);
 
     // Check if this permission is forbidden:
     if permission.is_present_in_role(perms_by_role.none) {
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use pallet_post_history::rpc::FlatPostHistoryRecord;
+use pallet_utils::PostId;
+
+sp_api::decl_runtime_apis! {
+    pub trait PostHistoryApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec
+    {
+        fn get_post_history(post_id: PostId, offset: u64, limit: u16) -> Vec<FlatPostHistoryRecord<AccountId, BlockNumber>>;
+    }
+}
@@ -2,14 +2,14 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use frame_support::{decl_module, decl_storage};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::Vec;
-use frame_system::{self as system};
 
-use pallet_posts::{Post, PostUpdate, AfterPostUpdated};
+use pallet_posts::{Post, PostUpdate, AfterPostDeleted, AfterPostUpdated};
 use pallet_utils::{WhoAndWhen, PostId};
 
+pub mod rpc;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct PostHistoryRecord<T: Config> {
@@ -17,22 +17,49 @@ pub struct PostHistoryRecord<T: Config> {
     pub old_data: PostUpdate,
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_posts::Config
-{}
-
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as PostHistoryModule {
-        pub EditHistory get(fn edit_history):
-            map hasher(twox_64_concat) PostId => Vec<PostHistoryRecord<T>>;
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_posts::Config
+    {
+        /// The maximum number of edit history records stored per post.
+        /// The oldest records are pruned when this limit is exceeded.
+        #[pallet::constant]
+        type MaxHistoryRecords: Get<u32>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<EditHistory<T>, Vec<PostHistoryRecord<T>>>()
+        }
     }
-}
 
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {}
+    #[pallet::storage]
+    #[pallet::getter(fn edit_history)]
+    pub type EditHistory<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, Vec<PostHistoryRecord<T>>, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {}
 }
 
 impl<T: Config> PostHistoryRecord<T> {
@@ -44,9 +71,21 @@ impl<T: Config> PostHistoryRecord<T> {
     }
 }
 
-impl<T: Config> AfterPostUpdated<T> for Module<T> {
+impl<T: Config> AfterPostUpdated<T> for Pallet<T> {
     fn after_post_updated(sender: T::AccountId, post: &Post<T>, old_data: PostUpdate) {
-        <EditHistory<T>>::mutate(post.id, |ids|
-            ids.push(PostHistoryRecord::<T>::new(sender, old_data)));
+        EditHistory::<T>::mutate(post.id, |records| {
+            records.push(PostHistoryRecord::<T>::new(sender, old_data));
+
+            let max_records = T::MaxHistoryRecords::get() as usize;
+            if records.len() > max_records {
+                records.drain(..records.len() - max_records);
+            }
+        });
+    }
+}
+
+impl<T: Config> AfterPostDeleted<T> for Pallet<T> {
+    fn after_post_deleted(post: &Post<T>) {
+        EditHistory::<T>::remove(post.id);
     }
 }
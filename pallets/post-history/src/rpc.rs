@@ -0,0 +1,55 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+use pallet_utils::{PostId, SpaceId, rpc::{FlatContent, FlatEditedWhoAndWhen, ShouldSkip}};
+
+use crate::{Pallet, PostHistoryRecord, Config};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatPostHistoryRecord<AccountId, BlockNumber> {
+    #[cfg_attr(feature = "std", serde(flatten))]
+    pub edited: FlatEditedWhoAndWhen<AccountId, BlockNumber>,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_space_id: Option<SpaceId>,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_content: Option<FlatContent>,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_hidden: Option<bool>,
+}
+
+impl<T: Config> From<PostHistoryRecord<T>> for FlatPostHistoryRecord<T::AccountId, T::BlockNumber> {
+    fn from(from: PostHistoryRecord<T>) -> Self {
+        let PostHistoryRecord { edited, old_data } = from;
+
+        Self {
+            edited: edited.into(),
+            old_space_id: old_data.space_id,
+            old_content: old_data.content.map(|content| content.into()),
+            old_hidden: old_data.hidden,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns edit history records of a post starting from the most recent one.
+    pub fn get_post_history(
+        post_id: PostId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatPostHistoryRecord<T::AccountId, T::BlockNumber>> {
+        Self::edit_history(post_id)
+            .into_iter()
+            .rev()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|record| record.into())
+            .collect()
+    }
+}
@@ -62,6 +62,15 @@ pub trait PostsApi<BlockHash, AccountId, BlockNumber> {
         post_ids: Vec<PostId>,
     ) -> Result<BTreeMap<PostId, Vec<PostId>>>;
 
+    #[rpc(name = "posts_getReplyIdsByParentIdPage")]
+    fn get_reply_ids_by_parent_id_page(
+        &self,
+        at: Option<BlockHash>,
+        parent_id: PostId,
+        cursor: Option<PostId>,
+        limit: u16,
+    ) -> Result<Vec<PostId>>;
+
     #[rpc(name = "posts_getRepliesByParentId")]
     fn get_replies_by_parent_id(
         &self,
@@ -94,6 +103,15 @@ pub trait PostsApi<BlockHash, AccountId, BlockNumber> {
         space_id: SpaceId,
     ) -> Result<Vec<PostId>>;
 
+    #[rpc(name = "posts_getPostIdsBySpaceIdPage")]
+    fn get_post_ids_by_space_id_page(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+        cursor: Option<PostId>,
+        limit: u16,
+    ) -> Result<Vec<PostId>>;
+
     #[rpc(name = "posts_nextPostId")]
     fn get_next_post_id(&self, at: Option<BlockHash>) -> Result<PostId>;
 
@@ -105,6 +123,16 @@ pub trait PostsApi<BlockHash, AccountId, BlockNumber> {
         offset: u64,
         limit: u16,
     ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "posts_getFeedPage")]
+    fn get_feed_page(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        kind_filter: Vec<FlatPostKind>,
+        cursor: Option<PostId>,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>>;
 }
 
 pub struct Posts<C, M> {
@@ -202,6 +230,20 @@ where
         runtime_api_result.map_err(map_rpc_error)
     }
 
+    fn get_reply_ids_by_parent_id_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        parent_id: PostId,
+        cursor: Option<PostId>,
+        limit: u16,
+    ) -> Result<Vec<PostId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_reply_ids_by_parent_id_page(&at, parent_id, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
     fn get_replies_by_parent_id(
         &self,
         at: Option<<Block as BlockT>::Hash>,
@@ -246,6 +288,20 @@ where
         runtime_api_result.map_err(map_rpc_error)
     }
 
+    fn get_post_ids_by_space_id_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        space_id: SpaceId,
+        cursor: Option<PostId>,
+        limit: u16,
+    ) -> Result<Vec<PostId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_post_ids_by_space_id_page(&at, space_id, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
     fn get_next_post_id(&self, at: Option<<Block as BlockT>::Hash>) -> Result<u64> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
@@ -267,4 +323,19 @@ where
         let runtime_api_result = api.get_feed(&at, account, offset, limit);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_feed_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        kind_filter: Vec<FlatPostKind>,
+        cursor: Option<PostId>,
+        limit: u16,
+    ) -> Result<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_feed_page(&at, account, kind_filter, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -0,0 +1,111 @@
+//! Posts pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use sp_std::vec;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_support::traits::Currency;
+use sp_runtime::traits::Bounded;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use pallet_spaces::Pallet as Spaces;
+use pallet_utils::{BalanceOf, mock_functions::{updated_content_ipfs, valid_content_ipfs}};
+
+fn fund_account<T: Config>(account: &T::AccountId) {
+    <T as pallet_utils::Config>::Currency::make_free_balance_be(account, BalanceOf::<T>::max_value());
+}
+
+fn create_space<T: Config>(owner: T::AccountId) -> Result<SpaceId, &'static str> {
+    let space_id = Spaces::<T>::next_space_id();
+    fund_account::<T>(&owner);
+    Spaces::<T>::create_space(RawOrigin::Signed(owner).into(), None, None, valid_content_ipfs(), None)?;
+
+    Ok(space_id)
+}
+
+fn create_post_in_space<T: Config>(owner: T::AccountId, space_id: SpaceId) -> Result<PostId, &'static str> {
+    let post_id = Pallet::<T>::next_post_id();
+    Pallet::<T>::create_post(
+        RawOrigin::Signed(owner).into(),
+        Some(space_id),
+        PostExtension::RegularPost,
+        valid_content_ipfs()
+    )?;
+
+    Ok(post_id)
+}
+
+benchmarks! {
+    create_post {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let post_id = Pallet::<T>::next_post_id();
+    }: _(RawOrigin::Signed(caller.clone()), Some(space_id), PostExtension::RegularPost, valid_content_ipfs())
+    verify {
+        let post = Pallet::<T>::post_by_id(post_id).ok_or("Post was not created")?;
+        assert_eq!(post.owner, caller);
+        assert_eq!(Pallet::<T>::post_ids_by_space_id(space_id), vec![post_id]);
+    }
+
+    update_post {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let post_id = create_post_in_space::<T>(caller.clone(), space_id)?;
+
+        let update = PostUpdate {
+            space_id: None,
+            content: Some(updated_content_ipfs()),
+            hidden: Some(true),
+        };
+    }: _(RawOrigin::Signed(caller), post_id, update)
+    verify {
+        let post = Pallet::<T>::post_by_id(post_id).ok_or("Post was not found")?;
+        assert_eq!(post.content, updated_content_ipfs());
+        assert!(post.hidden);
+    }
+
+    move_post {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let new_space_id = create_space::<T>(caller.clone())?;
+        let post_id = create_post_in_space::<T>(caller.clone(), space_id)?;
+    }: _(RawOrigin::Signed(caller), post_id, Some(new_space_id))
+    verify {
+        let post = Pallet::<T>::post_by_id(post_id).ok_or("Post was not found")?;
+        assert_eq!(post.space_id, Some(new_space_id));
+        assert!(Pallet::<T>::post_ids_by_space_id(space_id).is_empty());
+    }
+
+    delete_post {
+        let r in 0 .. 100;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let post_id = create_post_in_space::<T>(caller.clone(), space_id)?;
+
+        for _ in 0 .. r {
+            let comment_ext = Comment { parent_id: None, root_post_id: post_id };
+            Pallet::<T>::create_post(
+                RawOrigin::Signed(caller.clone()).into(),
+                None,
+                PostExtension::Comment(comment_ext),
+                valid_content_ipfs()
+            )?;
+        }
+    }: _(RawOrigin::Signed(caller), post_id, r)
+    verify {
+        assert!(Pallet::<T>::post_by_id(post_id).is_none());
+        assert!(Pallet::<T>::post_ids_by_space_id(space_id).is_empty());
+    }
+
+    update_post_permissions {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let post_id = create_post_in_space::<T>(caller.clone(), space_id)?;
+    }: _(RawOrigin::Signed(caller), post_id, Some(SpacePermissions::default()))
+    verify {
+        let post = Pallet::<T>::post_by_id(post_id).ok_or("Post was not found")?;
+        assert!(post.permissions.is_some());
+    }
+}
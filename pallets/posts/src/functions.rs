@@ -27,7 +27,8 @@ impl<T: Config> Post<T> {
             shares_count: 0,
             upvotes_count: 0,
             downvotes_count: 0,
-            score: 0
+            score: 0,
+            permissions: None,
         }
     }
 
@@ -75,7 +76,7 @@ impl<T: Config> Post<T> {
             PostExtension::RegularPost | PostExtension::SharedPost(_) =>
                 Ok(self.clone()),
             PostExtension::Comment(comment) =>
-                Module::require_post(comment.root_post_id),
+                Pallet::require_post(comment.root_post_id),
         }
     }
 
@@ -147,6 +148,10 @@ impl<T: Config> Post<T> {
         self.downvotes_count = self.downvotes_count.saturating_sub(1);
     }
 
+    pub fn change_score(&mut self, diff: i32) {
+        self.score = self.score.saturating_add(diff);
+    }
+
     pub fn is_public(&self) -> bool {
         !self.hidden && self.content.is_some()
     }
@@ -166,7 +171,46 @@ impl Default for PostUpdate {
     }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
+
+    /// Get the ids of all direct replies of a given post, sorted by id.
+    pub fn reply_ids_by_post_id(post_id: PostId) -> Vec<PostId> {
+        let mut reply_ids: Vec<PostId> = ReplyIdsByPostId::<T>::iter_prefix(post_id)
+            .map(|(reply_id, _)| reply_id)
+            .collect();
+
+        reply_ids.sort_unstable();
+        reply_ids
+    }
+
+    /// Get the ids of all root posts in a given space, sorted by id.
+    pub fn post_ids_by_space_id(space_id: SpaceId) -> Vec<PostId> {
+        let mut post_ids: Vec<PostId> = PostIdsBySpaceId::<T>::iter_prefix(space_id)
+            .map(|(post_id, _)| post_id)
+            .collect();
+
+        post_ids.sort_unstable();
+        post_ids
+    }
+
+    /// Encode a post id as a feed key, so that newer posts go first in the storage order.
+    pub fn feed_key(post_id: PostId) -> FeedKey {
+        PostId::MAX.saturating_sub(post_id).to_be_bytes()
+    }
+
+    pub fn post_id_by_feed_key(feed_key: FeedKey) -> PostId {
+        PostId::MAX.saturating_sub(PostId::from_be_bytes(feed_key))
+    }
+
+    pub(crate) fn add_post_to_space_indexes(space_id: SpaceId, post_id: PostId) {
+        PostIdsBySpaceId::<T>::insert(space_id, post_id, true);
+        FeedPostIdsBySpaceId::<T>::insert(space_id, Self::feed_key(post_id), true);
+    }
+
+    pub(crate) fn remove_post_from_space_indexes(space_id: SpaceId, post_id: PostId) {
+        PostIdsBySpaceId::<T>::remove(space_id, post_id);
+        FeedPostIdsBySpaceId::<T>::remove(space_id, Self::feed_key(post_id));
+    }
 
     pub fn ensure_account_can_update_post(
         editor: &T::AccountId,
@@ -198,18 +242,64 @@ impl<T: Config> Module<T> {
           }
         }
 
-        Spaces::ensure_account_has_space_permission(
+        Self::ensure_account_has_post_permission(
           editor.clone(),
+          post,
           space,
           permission_to_check,
           permission_error
         )
     }
 
+    pub fn ensure_account_can_delete_post(
+        account: &T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>
+    ) -> DispatchResult {
+        let is_owner = post.is_owner(&account);
+
+        let (permission_to_check, permission_error) = if post.is_comment() {
+            ensure!(is_owner, Error::<T>::NotACommentAuthor);
+            (SpacePermission::DeleteOwnComments, Error::<T>::NoPermissionToDeleteOwnComments)
+        } else if is_owner {
+            (SpacePermission::DeleteOwnPosts, Error::<T>::NoPermissionToDeleteOwnPosts)
+        } else {
+            (SpacePermission::DeleteAnyPost, Error::<T>::NoPermissionToDeleteAnyPost)
+        };
+
+        Self::ensure_account_has_post_permission(
+            account.clone(),
+            post,
+            space,
+            permission_to_check,
+            permission_error.into()
+        )
+    }
+
+    /// Check that an account has a permission in a space, where the permission overrides
+    /// of a root post of a given post take precedence over the permissions of the space.
+    pub fn ensure_account_has_post_permission(
+        account: T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>,
+        permission: SpacePermission,
+        error: DispatchError,
+    ) -> DispatchResult {
+        let post_perms = post.get_root_post().ok().and_then(|root_post| root_post.permissions);
+
+        Spaces::ensure_account_has_post_permission(
+            account,
+            space,
+            post_perms,
+            permission,
+            error
+        )
+    }
+
     /// Check that there is a `Post` with such `post_id` in the storage
     /// or return`PostNotFound` error.
     pub fn ensure_post_exists(post_id: PostId) -> DispatchResult {
-        ensure!(<PostById<T>>::contains_key(post_id), Error::<T>::PostNotFound);
+        ensure!(PostById::<T>::contains_key(post_id), Error::<T>::PostNotFound);
         Ok(())
     }
 
@@ -226,10 +316,10 @@ impl<T: Config> Module<T> {
         original_post.inc_shares();
 
         let original_post_id = original_post.id;
-        PostById::insert(original_post_id, original_post.clone());
-        SharedPostIdsByOriginalPostId::mutate(original_post_id, |ids| ids.push(shared_post_id));
+        PostById::<T>::insert(original_post_id, original_post.clone());
+        SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| ids.push(shared_post_id));
 
-        Self::deposit_event(RawEvent::PostShared(account, original_post_id));
+        Self::deposit_event(Event::PostShared(account, original_post_id));
 
         Ok(())
     }
@@ -248,7 +338,7 @@ impl<T: Config> Module<T> {
         post_id: PostId,
         f: F
     ) -> Result<Post<T>, DispatchError> {
-        <PostById<T>>::mutate(post_id, |post_opt| {
+        PostById::<T>::mutate(post_id, |post_opt| {
             if let Some(ref mut post) = post_opt.clone() {
                 f(post);
                 *post_opt = Some(post.clone());
@@ -337,8 +427,8 @@ impl<T: Config> Module<T> {
         root_post.inc_replies();
 
         Self::for_each_post_ancestor(commented_post_id, |post| post.inc_replies())?;
-        PostById::insert(root_post.id, root_post);
-        ReplyIdsByPostId::mutate(commented_post_id, |reply_ids| reply_ids.push(new_post_id));
+        PostById::<T>::insert(root_post.id, root_post);
+        ReplyIdsByPostId::<T>::insert(commented_post_id, new_post_id, true);
 
         Ok(())
     }
@@ -355,8 +445,9 @@ impl<T: Config> Module<T> {
         ensure!(!original_post.is_sharing_post(), Error::<T>::CannotShareSharingPost);
 
         // Check if it's allowed to share a post from the space of original post.
-        Spaces::ensure_account_has_space_permission(
+        Self::ensure_account_has_post_permission(
             creator.clone(),
+            original_post,
             &original_post.get_space()?,
             SpacePermission::Share,
             Error::<T>::NoPermissionToShare.into()
@@ -380,6 +471,14 @@ impl<T: Config> Module<T> {
         }).map(|_| ())
     }
 
+    /// The score of a space includes the scores of its root posts,
+    /// so it should follow a post when the post leaves or joins the space.
+    fn change_space_score(space_id: SpaceId, diff: i32) {
+        if diff != 0 {
+            let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| space.change_score(diff));
+        }
+    }
+
     pub(crate) fn move_post_to_space(
         editor: T::AccountId,
         post: &mut Post<T>,
@@ -418,8 +517,12 @@ impl<T: Config> Module<T> {
                         post,
                         |counter| *counter = counter.saturating_sub(1)
                     )?;
+                    Self::change_space_score(old_space_id, post.score.saturating_neg());
 
-                    PostIdsBySpaceId::mutate(old_space_id, |post_ids| remove_from_vec(post_ids, post.id));
+                    Self::remove_post_from_space_indexes(old_space_id, post.id);
+                } else {
+                    // A post that is out of spaces is not in the feed of its owner.
+                    FeedPostIdsByAccount::<T>::insert(&post.owner, Self::feed_key(post.id), true);
                 }
 
                 // Increase the number of posts on the new space
@@ -428,8 +531,9 @@ impl<T: Config> Module<T> {
                     post,
                     |counter| *counter = counter.saturating_add(1)
                 )?;
+                Self::change_space_score(new_space_id, post.score);
 
-                PostIdsBySpaceId::mutate(new_space_id, |post_ids| post_ids.push(post.id));
+                Self::add_post_to_space_indexes(new_space_id, post.id);
 
                 post.space_id = Some(new_space_id);
                 PostById::<T>::insert(post.id, post);
@@ -470,9 +574,11 @@ impl<T: Config> Module<T> {
                 &post,
                 |counter| *counter = counter.saturating_sub(1)
             )?;
+            Self::change_space_score(space_id, post.score.saturating_neg());
 
             post.space_id = None;
-            PostIdsBySpaceId::mutate(space_id, |post_ids| remove_from_vec(post_ids, post_id));
+            Self::remove_post_from_space_indexes(space_id, post_id);
+            FeedPostIdsByAccount::<T>::remove(&post.owner, Self::feed_key(post_id));
         }
 
         PostById::<T>::insert(post.id, post);
@@ -480,6 +586,93 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Get a post followed by all its nested replies,
+    /// or fail if there are more than `max_replies` of them.
+    fn get_post_with_replies(post: Post<T>, max_replies: u32) -> Result<Vec<Post<T>>, DispatchError> {
+        let mut posts = sp_std::vec![post];
+
+        let mut i = 0;
+        while i < posts.len() {
+            for reply_id in ReplyIdsByPostId::<T>::iter_key_prefix(posts[i].id) {
+                // The first element is the post itself, not a reply.
+                ensure!(posts.len() <= max_replies as usize, Error::<T>::TooManyRepliesToDelete);
+                if let Some(reply) = Self::post_by_id(reply_id) {
+                    posts.push(reply);
+                }
+            }
+            i += 1;
+        }
+
+        Ok(posts)
+    }
+
+    /// Remove a post with all its nested replies (at most `max_replies`) from the storage
+    /// and update the counters of the post ancestors, its space and an original post
+    /// (if it's a sharing post). Returns the number of deleted replies.
+    pub(crate) fn delete_post_with_replies(post: Post<T>, max_replies: u32) -> Result<u32, DispatchError> {
+        // The first element is the post itself.
+        let posts_to_delete = Self::get_post_with_replies(post.clone(), max_replies)?;
+
+        match post.extension {
+            PostExtension::Comment(comment_ext) => {
+                let deleted_replies = posts_to_delete.len() as u16;
+                let deleted_hidden_replies = posts_to_delete.iter().filter(|p| p.hidden).count() as u16;
+
+                let dec_replies_count = move |p: &mut Post<T>| {
+                    p.replies_count = p.replies_count.saturating_sub(deleted_replies);
+                    p.hidden_replies_count = p.hidden_replies_count.saturating_sub(deleted_hidden_replies);
+                };
+
+                let root_post = &mut Self::require_post(comment_ext.root_post_id)?;
+                dec_replies_count(root_post);
+                PostById::<T>::insert(root_post.id, root_post.clone());
+
+                if let Some(parent_id) = comment_ext.parent_id {
+                    Self::for_each_post_ancestor(parent_id, dec_replies_count)?;
+                }
+
+                let commented_post_id = comment_ext.parent_id.unwrap_or(root_post.id);
+                ReplyIdsByPostId::<T>::remove(commented_post_id, post.id);
+            },
+            PostExtension::RegularPost | PostExtension::SharedPost(_) => {
+                if let Some(space_id) = post.space_id {
+                    // Decrease the number of posts on the space
+                    Self::mutate_posts_count_on_space(
+                        space_id,
+                        &post,
+                        |counter| *counter = counter.saturating_sub(1)
+                    )?;
+                    Self::change_space_score(space_id, post.score.saturating_neg());
+
+                    Self::remove_post_from_space_indexes(space_id, post.id);
+                }
+
+                FeedPostIdsByAccount::<T>::remove(&post.owner, Self::feed_key(post.id));
+
+                if let PostExtension::SharedPost(original_post_id) = post.extension {
+                    // An original post could be deleted before its sharing post.
+                    if Self::mutate_post_by_id(original_post_id, Post::<T>::dec_shares).is_ok() {
+                        SharedPostIdsByOriginalPostId::<T>::mutate(
+                            original_post_id,
+                            |post_ids| remove_from_vec(post_ids, post.id)
+                        );
+                    }
+                }
+            },
+        }
+
+        for deleted_post in posts_to_delete.iter() {
+            Utils::<T>::release_storage_deposit(StorageItem::Post(deleted_post.id));
+            PostById::<T>::remove(deleted_post.id);
+            ReplyIdsByPostId::<T>::remove_prefix(deleted_post.id, None);
+            SharedPostIdsByOriginalPostId::<T>::remove(deleted_post.id);
+
+            T::AfterPostDeleted::after_post_deleted(deleted_post);
+        }
+
+        Ok(posts_to_delete.len().saturating_sub(1) as u32)
+    }
+
     /// Rewrite ancestor counters when Post hidden status changes
     /// Warning: This will affect storage state!
     pub(crate) fn update_counters_on_comment_hidden_change(
@@ -497,8 +690,25 @@ impl<T: Config> Module<T> {
         Self::for_each_post_ancestor(commented_post_id, |post| update_hidden_replies(post))?;
 
         update_hidden_replies(root_post);
-        PostById::insert(root_post.id, root_post);
+        PostById::<T>::insert(root_post.id, root_post);
 
         Ok(())
     }
 }
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+    fn items_to_clean_up(space_id: SpaceId) -> u32 {
+        Spaces::<T>::space_by_id(space_id).map_or(0, |space| space.posts_count)
+    }
+
+    fn before_space_deleted(space_id: SpaceId) {
+        // Posts are not deleted with the space, so their owners can move them to another space.
+        for (post_id, _) in PostIdsBySpaceId::<T>::drain_prefix(space_id) {
+            let _ = Self::mutate_post_by_id(post_id, |post| {
+                post.space_id = None;
+                FeedPostIdsByAccount::<T>::remove(&post.owner, Self::feed_key(post_id));
+            });
+        }
+        FeedPostIdsBySpaceId::<T>::remove_prefix(space_id, None);
+    }
+}
@@ -1,7 +1,7 @@
 //! # Posts Module
 //!
 //! Posts are the second crucial component of Subsocial after Spaces. This module allows you to
-//! create, update, move (between spaces), and hide posts as well as manage owner(s).
+//! create, update, move (between spaces), hide, and delete posts as well as manage owner(s).
 //!
 //! Posts can be compared to existing entities on web 2.0 platforms such as:
 //! - Posts on Facebook,
@@ -10,6 +10,10 @@
 //! - Articles on Medium,
 //! - Shared links on Reddit,
 //! - Questions and answers on Stack Overflow.
+//!
+//! A post owner can override the permissions of their post, e.g. to lock comments on it.
+//! The permission overrides of a post take precedence over the permissions of its space
+//! for actions on this post and its comments.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -18,24 +22,33 @@ use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Serialize, Deserialize};
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, fail,
-    dispatch::{DispatchError, DispatchResult}, ensure, traits::Get,
+    fail,
+    dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo}, ensure, traits::Get,
 };
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
-use frame_system::{self as system, ensure_signed};
+use frame_system::ensure_signed;
 
-use df_traits::moderation::{IsAccountBlocked, IsContentBlocked, IsPostBlocked};
-use pallet_permissions::SpacePermission;
-use pallet_spaces::{Module as Spaces, Space, SpaceById};
+use df_traits::{
+    BeforeSpaceDeleted,
+    moderation::{IsAccountBlocked, IsContentBlocked, IsPostBlocked},
+};
+use pallet_permissions::{Pallet as Permissions, SpacePermission, SpacePermissions};
+use pallet_spaces::{Pallet as Spaces, Space, SpaceById};
 use pallet_utils::{
-    Module as Utils, Error as UtilsError,
-    SpaceId, WhoAndWhen, Content, PostId
+    Pallet as Utils, Error as UtilsError,
+    SpaceId, WhoAndWhen, Content, PostId, StorageItem
 };
 
 pub mod functions;
+pub mod migrations;
 
 pub mod rpc;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 /// Information about a post's owner, its' related space, content, and visibility.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -80,6 +93,9 @@ pub struct Post<T: Config> {
     pub downvotes_count: u16,
 
     pub score: i32,
+
+    /// Permissions that override the permissions of a space for this post and its comments.
+    pub permissions: Option<SpacePermissions>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -116,71 +132,118 @@ impl Default for PostExtension {
     }
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_space_follows::Config
-    + pallet_spaces::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+/// A post id encoded as a storage key of a feed index, so that the keys under the same
+/// space or account are iterated from the newest post to the oldest one.
+/// Use `Pallet::feed_key` and `Pallet::post_id_by_feed_key` to convert it.
+pub type FeedKey = [u8; 8];
 
-    /// Max comments depth
-    type MaxCommentDepth: Get<u32>;
+pub use pallet::*;
 
-    type AfterPostUpdated: AfterPostUpdated<Self>;
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
 
-    type IsPostBlocked: IsPostBlocked<PostId>;
-}
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
-#[impl_trait_for_tuples::impl_for_tuples(10)]
-pub trait AfterPostUpdated<T: Config> {
-    fn after_post_updated(account: T::AccountId, post: &Post<T>, old_data: PostUpdate);
-}
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_space_follows::Config
+        + pallet_profile_follows::Config
+        + pallet_spaces::Config
+    {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-pub const FIRST_POST_ID: u64 = 1;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
 
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as PostsModule {
+        /// Max comments depth
+        #[pallet::constant]
+        type MaxCommentDepth: Get<u32>;
 
-        /// The next post id.
-        pub NextPostId get(fn next_post_id): PostId = FIRST_POST_ID;
+        type AfterPostUpdated: AfterPostUpdated<Self>;
 
-        /// Get the details of a post by its' id.
-        pub PostById get(fn post_by_id):
-            map hasher(twox_64_concat) PostId => Option<Post<T>>;
+        type AfterPostDeleted: AfterPostDeleted<Self>;
 
-        /// Get the ids of all direct replies by their parent's post id.
-        pub ReplyIdsByPostId get(fn reply_ids_by_post_id):
-            map hasher(twox_64_concat) PostId => Vec<PostId>;
+        type PostScores: PostScores<Self>;
 
-        /// Get the ids of all posts in a given space, by the space's id.
-        pub PostIdsBySpaceId get(fn post_ids_by_space_id):
-            map hasher(twox_64_concat) SpaceId => Vec<PostId>;
+        type IsPostBlocked: IsPostBlocked<PostId>;
+    }
 
-        // TODO rename 'Shared...' to 'Sharing...'
-        /// Get the ids of all posts that have shared a given original post id.
-        pub SharedPostIdsByOriginalPostId get(fn shared_post_ids_by_original_post_id):
-            map hasher(twox_64_concat) PostId => Vec<PostId>;
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<PostById<T>, Post<T>>()
+        }
     }
-}
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId,
-    {
-        PostCreated(AccountId, PostId),
-        PostUpdated(AccountId, PostId),
-        PostDeleted(AccountId, PostId),
-        PostShared(AccountId, PostId),
-        PostMoved(AccountId, PostId),
+    #[pallet::type_value]
+    pub fn DefaultForNextPostId() -> PostId {
+        FIRST_POST_ID
     }
-);
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    /// The next post id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_post_id)]
+    pub type NextPostId<T: Config> = StorageValue<_, PostId, ValueQuery, DefaultForNextPostId>;
+
+    /// Get the details of a post by its' id.
+    #[pallet::storage]
+    #[pallet::getter(fn post_by_id)]
+    pub type PostById<T: Config> = StorageMap<_, Twox64Concat, PostId, Post<T>, OptionQuery>;
+
+    /// Whether a post is a direct reply to a given parent post, by (parent post id, reply id).
+    #[pallet::storage]
+    pub type ReplyIdsByPostId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, PostId, Twox64Concat, PostId, bool, ValueQuery>;
+
+    /// Whether a root post is in a given space, by (space id, post id).
+    #[pallet::storage]
+    pub type PostIdsBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, PostId, bool, ValueQuery>;
+
+    /// Whether a root post is in a given space, by (space id, feed key).
+    /// Unlike `PostIdsBySpaceId`, the posts are ordered from the newest to the oldest.
+    #[pallet::storage]
+    pub type FeedPostIdsBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Identity, FeedKey, bool, ValueQuery>;
+
+    /// Whether a root post is created by a given account, by (account, feed key).
+    /// The posts are ordered from the newest to the oldest.
+    #[pallet::storage]
+    pub type FeedPostIdsByAccount<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Identity, FeedKey, bool, ValueQuery>;
+
+    // TODO rename 'Shared...' to 'Sharing...'
+    /// Get the ids of all posts that have shared a given original post id.
+    #[pallet::storage]
+    #[pallet::getter(fn shared_post_ids_by_original_post_id)]
+    pub type SharedPostIdsByOriginalPostId<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, Vec<PostId>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        PostCreated(T::AccountId, PostId),
+        PostUpdated(T::AccountId, PostId),
+        PostDeleted(T::AccountId, PostId),
+        PostShared(T::AccountId, PostId),
+        PostMoved(T::AccountId, PostId),
+    }
 
+    #[pallet::error]
+    pub enum Error<T> {
         // Post related errors:
 
         /// Post was not found by id.
@@ -236,186 +299,295 @@ decl_error! {
         NoPermissionToUpdateOwnPosts,
         /// A comment owner is not allowed to update their own comments in this space.
         NoPermissionToUpdateOwnComments,
+        /// User has no permission to delete any posts in this space.
+        NoPermissionToDeleteAnyPost,
+        /// A post owner is not allowed to delete their own posts in this space.
+        NoPermissionToDeleteOwnPosts,
+        /// A comment owner is not allowed to delete their own comments in this space.
+        NoPermissionToDeleteOwnComments,
+        /// User has no permission to override permissions of posts in this space.
+        NoPermissionToOverridePostPermissions,
+        /// Permissions can be overridden only on root posts, not on comments.
+        CannotOverrideCommentPermissions,
+        /// New post permissions don't differ from the old ones.
+        NoUpdatesForPostPermissions,
+        /// A post has more replies than the caller allowed to delete with it.
+        TooManyRepliesToDelete,
     }
-}
 
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a post or a comment. A deposit for `Raw` content is reserved from its creator.
+        #[pallet::weight(<T as Config>::WeightInfo::create_post())]
+        #[transactional]
+        pub fn create_post(
+            origin: OriginFor<T>,
+            space_id_opt: Option<SpaceId>,
+            extension: PostExtension,
+            content: Content
+        ) -> DispatchResultWithPostInfo {
+            let creator = ensure_signed(origin)?;
+
+            Utils::<T>::is_valid_content(content.clone())?;
+
+            let new_post_id = Self::next_post_id();
+            let new_post: Post<T> = Post::new(new_post_id, creator.clone(), space_id_opt, extension, content.clone());
+
+            // Get space from either space_id_opt or Comment if a comment provided
+            let space = &mut new_post.get_space()?;
+            ensure!(!space.hidden, Error::<T>::CannotCreateInHiddenScope);
+
+            ensure!(T::IsAccountBlocked::is_allowed_account(creator.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+            ensure!(T::IsContentBlocked::is_allowed_content(content, space.id), UtilsError::<T>::ContentIsBlocked);
+
+            let root_post = &mut new_post.get_root_post()?;
+            ensure!(!root_post.hidden, Error::<T>::CannotCreateInHiddenScope);
+
+            // Check whether account has permission to create Post (by extension)
+            let mut permission_to_check = SpacePermission::CreatePosts;
+            let mut error_on_permission_failed = Error::<T>::NoPermissionToCreatePosts;
+
+            if let PostExtension::Comment(_) = extension {
+                permission_to_check = SpacePermission::CreateComments;
+                error_on_permission_failed = Error::<T>::NoPermissionToCreateComments;
+            }
 
-    const MaxCommentDepth: u32 = T::MaxCommentDepth::get();
+            Spaces::ensure_account_has_post_permission(
+                creator.clone(),
+                &space,
+                root_post.permissions.clone(),
+                permission_to_check,
+                error_on_permission_failed.into()
+            )?;
 
-    // Initializing errors
-    type Error = Error<T>;
+            Utils::<T>::reserve_storage_deposit(&creator, StorageItem::Post(new_post_id), &new_post)?;
 
-    // Initializing events
-    fn deposit_event() = default;
+            match extension {
+                PostExtension::RegularPost => space.inc_posts(),
+                PostExtension::SharedPost(post_id) => Self::create_sharing_post(&creator, new_post_id, post_id, space)?,
+                PostExtension::Comment(comment_ext) => Self::create_comment(new_post_id, comment_ext, root_post)?,
+            }
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(8, 8)]
-    pub fn create_post(
-      origin,
-      space_id_opt: Option<SpaceId>,
-      extension: PostExtension,
-      content: Content
-    ) -> DispatchResult {
-      let creator = ensure_signed(origin)?;
+            if new_post.is_root_post() {
+                SpaceById::insert(space.id, space.clone());
+                Self::add_post_to_space_indexes(space.id, new_post_id);
+                FeedPostIdsByAccount::<T>::insert(&creator, Self::feed_key(new_post_id), true);
+            }
 
-      Utils::<T>::is_valid_content(content.clone())?;
+            PostById::<T>::insert(new_post_id, new_post);
+            NextPostId::<T>::mutate(|n| { *n += 1; });
 
-      let new_post_id = Self::next_post_id();
-      let new_post: Post<T> = Post::new(new_post_id, creator.clone(), space_id_opt, extension, content.clone());
+            match extension {
+                PostExtension::SharedPost(post_id) => T::PostScores::score_post_on_new_share(creator.clone(), post_id),
+                PostExtension::Comment(_) => T::PostScores::score_root_post_on_new_comment(creator.clone(), root_post.id),
+                PostExtension::RegularPost => (),
+            }
 
-      // Get space from either space_id_opt or Comment if a comment provided
-      let space = &mut new_post.get_space()?;
-      ensure!(!space.hidden, Error::<T>::CannotCreateInHiddenScope);
+            Self::deposit_event(Event::PostCreated(creator, new_post_id));
+            Ok(().into())
+        }
 
-      ensure!(T::IsAccountBlocked::is_allowed_account(creator.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
-      ensure!(T::IsContentBlocked::is_allowed_content(content, space.id), UtilsError::<T>::ContentIsBlocked);
+        #[pallet::weight(<T as Config>::WeightInfo::update_post())]
+        #[transactional]
+        pub fn update_post(origin: OriginFor<T>, post_id: PostId, update: PostUpdate) -> DispatchResultWithPostInfo {
+            let editor = ensure_signed(origin)?;
 
-      let root_post = &mut new_post.get_root_post()?;
-      ensure!(!root_post.hidden, Error::<T>::CannotCreateInHiddenScope);
+            let has_updates =
+                update.content.is_some() ||
+                update.hidden.is_some();
 
-      // Check whether account has permission to create Post (by extension)
-      let mut permission_to_check = SpacePermission::CreatePosts;
-      let mut error_on_permission_failed = Error::<T>::NoPermissionToCreatePosts;
+            ensure!(has_updates, Error::<T>::NoUpdatesForPost);
 
-      if let PostExtension::Comment(_) = extension {
-        permission_to_check = SpacePermission::CreateComments;
-        error_on_permission_failed = Error::<T>::NoPermissionToCreateComments;
-      }
+            let mut post = Self::require_post(post_id)?;
+            let mut space_opt = post.try_get_space();
 
-      Spaces::ensure_account_has_space_permission(
-        creator.clone(),
-        &space,
-        permission_to_check,
-        error_on_permission_failed.into()
-      )?;
+            if let Some(space) = &space_opt {
+                ensure!(T::IsAccountBlocked::is_allowed_account(editor.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+                Self::ensure_account_can_update_post(&editor, &post, space)?;
+            }
 
-      match extension {
-        PostExtension::RegularPost => space.inc_posts(),
-        PostExtension::SharedPost(post_id) => Self::create_sharing_post(&creator, new_post_id, post_id, space)?,
-        PostExtension::Comment(comment_ext) => Self::create_comment(new_post_id, comment_ext, root_post)?,
-      }
+            let mut is_update_applied = false;
+            let mut old_data = PostUpdate::default();
 
-      if new_post.is_root_post() {
-        SpaceById::insert(space.id, space.clone());
-        PostIdsBySpaceId::mutate(space.id, |ids| ids.push(new_post_id));
-      }
+            if let Some(content) = update.content {
+                if content != post.content {
+                    Utils::<T>::is_valid_content(content.clone())?;
 
-      PostById::insert(new_post_id, new_post);
-      NextPostId::mutate(|n| { *n += 1; });
+                    if let Some(space) = &space_opt {
+                        ensure!(
+                            T::IsContentBlocked::is_allowed_content(content.clone(), space.id),
+                            UtilsError::<T>::ContentIsBlocked
+                        );
+                    }
 
-      Self::deposit_event(RawEvent::PostCreated(creator, new_post_id));
-      Ok(())
-    }
+                    old_data.content = Some(post.content.clone());
+                    post.content = content;
+                    is_update_applied = true;
+                }
+            }
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(5, 3)]
-    pub fn update_post(origin, post_id: PostId, update: PostUpdate) -> DispatchResult {
-      let editor = ensure_signed(origin)?;
+            if let Some(hidden) = update.hidden {
+                if hidden != post.hidden {
+                    space_opt = space_opt.map(|mut space| {
+                        if hidden {
+                            space.inc_hidden_posts();
+                        } else {
+                            space.dec_hidden_posts();
+                        }
+
+                        space
+                    });
+
+                    if let PostExtension::Comment(comment_ext) = post.extension {
+                        Self::update_counters_on_comment_hidden_change(&comment_ext, hidden)?;
+                    }
+
+                    old_data.hidden = Some(post.hidden);
+                    post.hidden = hidden;
+                    is_update_applied = true;
+                }
+            }
 
-      let has_updates =
-        update.content.is_some() ||
-        update.hidden.is_some();
+            // Update this post only if at least one field should be updated:
+            if is_update_applied {
+                post.updated = Some(WhoAndWhen::<T>::new(editor.clone()));
 
-      ensure!(has_updates, Error::<T>::NoUpdatesForPost);
+                if let Some(space) = space_opt {
+                    <SpaceById<T>>::insert(space.id, space);
+                }
 
-      let mut post = Self::require_post(post_id)?;
-      let mut space_opt = post.try_get_space();
+                Utils::<T>::update_storage_deposit(StorageItem::Post(post.id), &post)?;
 
-      if let Some(space) = &space_opt {
-        ensure!(T::IsAccountBlocked::is_allowed_account(editor.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
-        Self::ensure_account_can_update_post(&editor, &post, space)?;
-      }
+                PostById::<T>::insert(post.id, post.clone());
+                T::AfterPostUpdated::after_post_updated(editor.clone(), &post, old_data);
 
-      let mut is_update_applied = false;
-      let mut old_data = PostUpdate::default();
+                Self::deposit_event(Event::PostUpdated(editor, post_id));
+            }
+            Ok(().into())
+        }
 
-      if let Some(content) = update.content {
-        if content != post.content {
-          Utils::<T>::is_valid_content(content.clone())?;
+        #[pallet::weight(<T as Config>::WeightInfo::move_post())]
+        pub fn move_post(origin: OriginFor<T>, post_id: PostId, new_space_id: Option<SpaceId>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-          if let Some(space) = &space_opt {
-            ensure!(
-              T::IsContentBlocked::is_allowed_content(content.clone(), space.id),
-              UtilsError::<T>::ContentIsBlocked
-            );
-          }
+            let post = &mut Self::require_post(post_id)?;
 
-          old_data.content = Some(post.content.clone());
-          post.content = content;
-          is_update_applied = true;
-        }
-      }
+            ensure!(new_space_id != post.space_id, Error::<T>::CannotMoveToSameSpace);
 
-      if let Some(hidden) = update.hidden {
-        if hidden != post.hidden {
-          space_opt = space_opt.map(|mut space| {
-            if hidden {
-              space.inc_hidden_posts();
+            if let Some(space) = post.try_get_space() {
+                Self::ensure_account_can_update_post(&who, &post, &space)?;
             } else {
-              space.dec_hidden_posts();
+                post.ensure_owner(&who)?;
             }
 
-            space
-          });
+            let old_space_id = post.space_id;
 
-          if let PostExtension::Comment(comment_ext) = post.extension {
-            Self::update_counters_on_comment_hidden_change(&comment_ext, hidden)?;
-          }
+            if let Some(space_id) = new_space_id {
+                Self::move_post_to_space(who.clone(), post, space_id)?;
+            } else {
+                Self::delete_post_from_space(post_id)?;
+            }
 
-          old_data.hidden = Some(post.hidden);
-          post.hidden = hidden;
-          is_update_applied = true;
-        }
-      }
+            let historical_data = PostUpdate {
+                space_id: old_space_id,
+                content: None,
+                hidden: None,
+            };
 
-      // Update this post only if at least one field should be updated:
-      if is_update_applied {
-        post.updated = Some(WhoAndWhen::<T>::new(editor.clone()));
+            T::AfterPostUpdated::after_post_updated(who.clone(), &post, historical_data);
 
-        if let Some(space) = space_opt {
-          <SpaceById<T>>::insert(space.id, space);
+            Self::deposit_event(Event::PostMoved(who, post_id));
+            Ok(().into())
         }
 
-        <PostById<T>>::insert(post.id, post.clone());
-        T::AfterPostUpdated::after_post_updated(editor.clone(), &post, old_data);
+        /// Delete a post together with all its replies (comments) at any depth.
+        ///
+        /// `max_replies` is the maximum number of replies that can be deleted with this post.
+        /// The weight of this call is based on it, and the call fails if the post has more replies.
+        ///
+        /// Moderation statuses and reports of deleted posts are kept as a record of moderation,
+        /// and so are the scoring records of their reactions, which are indexed by accounts.
+        /// Post ids are never reused, so these records can't affect new posts.
+        #[pallet::weight(<T as Config>::WeightInfo::delete_post(*max_replies))]
+        #[transactional]
+        pub fn delete_post(origin: OriginFor<T>, post_id: PostId, max_replies: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+
+            if let Some(space) = post.try_get_space() {
+                ensure!(T::IsAccountBlocked::is_allowed_account(who.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+                Self::ensure_account_can_delete_post(&who, &post, &space)?;
+            } else {
+                post.ensure_owner(&who)?;
+            }
 
-        Self::deposit_event(RawEvent::PostUpdated(editor, post_id));
-      }
-      Ok(())
-    }
+            let deleted_replies = Self::delete_post_with_replies(post, max_replies)?;
+
+            Self::deposit_event(Event::PostDeleted(who, post_id));
+            Ok(Some(<T as Config>::WeightInfo::delete_post(deleted_replies)).into())
+        }
 
-    #[weight = T::DbWeight::get().reads(1) + 50_000]
-    pub fn move_post(origin, post_id: PostId, new_space_id: Option<SpaceId>) -> DispatchResult {
-      let who = ensure_signed(origin)?;
+        /// Override the permissions of a root post or reset them with `None`,
+        /// so that the permissions of its space are used again.
+        #[pallet::weight(<T as Config>::WeightInfo::update_post_permissions())]
+        pub fn update_post_permissions(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            permissions_opt: Option<SpacePermissions>
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      let post = &mut Self::require_post(post_id)?;
+            let mut post = Self::require_post(post_id)?;
+            post.ensure_owner(&owner)?;
+            ensure!(post.is_root_post(), Error::<T>::CannotOverrideCommentPermissions);
 
-      ensure!(new_space_id != post.space_id, Error::<T>::CannotMoveToSameSpace);
+            let space = post.get_space()?;
+            ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
 
-      if let Some(space) = post.try_get_space() {
-        Self::ensure_account_can_update_post(&who, &post, &space)?;
-      } else {
-        post.ensure_owner(&who)?;
-      }
+            Spaces::ensure_account_has_space_permission(
+                owner.clone(),
+                &space,
+                SpacePermission::OverridePostPermissions,
+                Error::<T>::NoPermissionToOverridePostPermissions.into()
+            )?;
 
-      let old_space_id = post.space_id;
+            let permissions = permissions_opt.map(Permissions::<T>::override_permissions);
+            ensure!(post.permissions != permissions, Error::<T>::NoUpdatesForPostPermissions);
 
-      if let Some(space_id) = new_space_id {
-        Self::move_post_to_space(who.clone(), post, space_id)?;
-      } else {
-        Self::delete_post_from_space(post_id)?;
-      }
+            post.permissions = permissions;
+            post.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
 
-      let historical_data = PostUpdate {
-        space_id: old_space_id,
-        content: None,
-        hidden: None,
-      };
+            Utils::<T>::update_storage_deposit(StorageItem::Post(post_id), &post)?;
 
-      T::AfterPostUpdated::after_post_updated(who.clone(), &post, historical_data);
+            PostById::<T>::insert(post_id, post);
 
-      Self::deposit_event(RawEvent::PostMoved(who, post_id));
-      Ok(())
+            Self::deposit_event(Event::PostUpdated(owner, post_id));
+            Ok(().into())
+        }
     }
-  }
 }
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+pub trait AfterPostUpdated<T: Config> {
+    fn after_post_updated(account: T::AccountId, post: &Post<T>, old_data: PostUpdate);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+pub trait AfterPostDeleted<T: Config> {
+    fn after_post_deleted(post: &Post<T>);
+}
+
+/// Handler that will be called after a post is shared or commented.
+pub trait PostScores<T: Config> {
+    fn score_post_on_new_share(account: T::AccountId, original_post_id: PostId);
+    fn score_root_post_on_new_comment(account: T::AccountId, root_post_id: PostId);
+}
+
+impl<T: Config> PostScores<T> for () {
+    fn score_post_on_new_share(_account: T::AccountId, _original_post_id: PostId) {}
+    fn score_root_post_on_new_comment(_account: T::AccountId, _root_post_id: PostId) {}
+}
+
+pub const FIRST_POST_ID: u64 = 1;
+
@@ -0,0 +1,103 @@
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+use pallet_utils::start_moving_vec_map_to_double_map;
+
+use super::*;
+
+/// `Post` as it was stored before the `permissions` field was added.
+#[derive(Encode, Decode)]
+struct OldPost<T: Config> {
+    id: PostId,
+    created: WhoAndWhen<T>,
+    updated: Option<WhoAndWhen<T>>,
+    owner: T::AccountId,
+    extension: PostExtension,
+    space_id: Option<SpaceId>,
+    content: Content,
+    hidden: bool,
+    replies_count: u16,
+    hidden_replies_count: u16,
+    shares_count: u16,
+    upvotes_count: u16,
+    downvotes_count: u16,
+    score: i32,
+}
+
+/// Storage version 2 adds the `permissions` field to posts.
+pub fn add_permissions_to_posts<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut posts_migrated: u64 = 0;
+
+    PostById::<T>::translate::<OldPost<T>, _>(|_, old_post| {
+        posts_migrated += 1;
+
+        Some(Post {
+            id: old_post.id,
+            created: old_post.created,
+            updated: old_post.updated,
+            owner: old_post.owner,
+            extension: old_post.extension,
+            space_id: old_post.space_id,
+            content: old_post.content,
+            hidden: old_post.hidden,
+            replies_count: old_post.replies_count,
+            hidden_replies_count: old_post.hidden_replies_count,
+            shares_count: old_post.shares_count,
+            upvotes_count: old_post.upvotes_count,
+            downvotes_count: old_post.downvotes_count,
+            score: old_post.score,
+            permissions: None,
+        })
+    });
+
+    StorageVersion::new(2).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(posts_migrated + 1, posts_migrated + 1)
+}
+
+/// Storage version 3 moves `ReplyIdsByPostId` and `PostIdsBySpaceId` from `Vec`s of ids to double maps.
+/// The ids are moved over several blocks by `pallet_utils::MoveVecMapToDoubleMap`.
+pub fn move_post_indexes_to_double_maps<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(3) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let weight = start_moving_vec_map_to_double_map::<T, ReplyIdsByPostId<T>, PostId, PostId>()
+        .saturating_add(start_moving_vec_map_to_double_map::<T, PostIdsBySpaceId<T>, SpaceId, PostId>());
+
+    StorageVersion::new(3).put::<Pallet<T>>();
+
+    weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+}
+
+/// Storage version 4 fills `FeedPostIdsBySpaceId` and `FeedPostIdsByAccount`
+/// with the existing root posts that are in spaces.
+pub fn build_feed_indexes<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(4) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut posts_read: u64 = 0;
+    let mut keys_written: u64 = 0;
+
+    for (post_id, post) in PostById::<T>::iter() {
+        posts_read += 1;
+
+        if post.is_comment() {
+            continue;
+        }
+
+        if let Some(space_id) = post.space_id {
+            let feed_key = Pallet::<T>::feed_key(post_id);
+            FeedPostIdsBySpaceId::<T>::insert(space_id, feed_key, true);
+            FeedPostIdsByAccount::<T>::insert(&post.owner, feed_key, true);
+            keys_written += 2;
+        }
+    }
+
+    StorageVersion::new(4).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(posts_read + 1, keys_written + 1)
+}
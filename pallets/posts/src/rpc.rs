@@ -1,14 +1,21 @@
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::collections::{binary_heap::BinaryHeap, btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::{vec, prelude::*};
 
-use pallet_space_follows::Module as SpaceFollows;
-use pallet_spaces::Module as Spaces;
-use pallet_utils::{bool_to_option, PostId, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}, SpaceId};
-
-use crate::{Module, Post, PostExtension, FIRST_POST_ID, Config};
+use pallet_profile_follows::Pallet as ProfileFollows;
+use pallet_space_follows::Pallet as SpaceFollows;
+use pallet_spaces::Pallet as Spaces;
+use pallet_utils::{
+    bool_to_option, paginate_double_map_keys, PostId,
+    rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}, SpaceId,
+};
+
+use crate::{
+    Pallet, Post, PostExtension, PostIdsBySpaceId, ReplyIdsByPostId, FIRST_POST_ID, Config,
+    FeedPostIdsByAccount, FeedPostIdsBySpaceId,
+};
 pub type RepliesByPostId<AccountId, BlockNumber> = BTreeMap<PostId, Vec<FlatPost<AccountId, BlockNumber>>>;
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
@@ -129,7 +136,13 @@ impl<T: Config> From<Post<T>> for FlatPost<T::AccountId, T::BlockNumber> {
     }
 }
 
-impl<T: Config> Module<T> {
+/// A source of posts in a feed of an account.
+enum FeedSource<AccountId> {
+    FollowedSpace(SpaceId),
+    FollowedAccount(AccountId),
+}
+
+impl<T: Config> Pallet<T> {
     fn get_posts_by_ids_with_filter<F: FnMut(&Post<T>) -> bool>(
         all_post_ids: Vec<PostId>,
         offset: u64,
@@ -209,6 +222,11 @@ impl<T: Config> Module<T> {
         Self::reply_ids_by_post_id(parent_id)
     }
 
+    /// Get up to `limit` reply ids of a given post, starting after the `cursor` reply id.
+    pub fn get_reply_ids_by_parent_id_page(parent_id: PostId, cursor: Option<PostId>, limit: u16) -> Vec<PostId> {
+        paginate_double_map_keys::<ReplyIdsByPostId<T>, _, _, _>(&parent_id, cursor, limit.into())
+    }
+
     pub fn get_replies_by_parent_id(parent_id: PostId, offset: u64, limit: u16) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
         let reply_ids = Self::get_reply_ids_by_parent_id(parent_id);
         Self::get_posts_by_ids(reply_ids, offset, limit)
@@ -295,19 +313,114 @@ impl<T: Config> Module<T> {
         vec![]
     }
 
+    /// Get up to `limit` root post ids of a given space, starting after the `cursor` post id.
+    pub fn get_post_ids_by_space_id_page(space_id: SpaceId, cursor: Option<PostId>, limit: u16) -> Vec<PostId> {
+        paginate_double_map_keys::<PostIdsBySpaceId<T>, _, _, _>(&space_id, cursor, limit.into())
+    }
+
     pub fn get_next_post_id() -> PostId {
         Self::next_post_id()
     }
 
-    pub fn get_feed(account: T::AccountId, offset: u64, limit: u16) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
-        let mut post_ids: Vec<PostId> = SpaceFollows::<T>::spaces_followed_by_account(account)
-            .iter()
-            .flat_map(Self::post_ids_by_space_id)
+    /// Get the id of the newest root post of a feed source that is older than the `cursor` post.
+    fn next_post_id_of_feed_source(source: &FeedSource<T::AccountId>, cursor: Option<PostId>) -> Option<PostId> {
+        let cursor_key = cursor.map(Self::feed_key);
+
+        let mut feed_keys = match source {
+            FeedSource::FollowedSpace(space_id) =>
+                paginate_double_map_keys::<FeedPostIdsBySpaceId<T>, _, _, _>(space_id, cursor_key, 1),
+            FeedSource::FollowedAccount(account) =>
+                paginate_double_map_keys::<FeedPostIdsByAccount<T>, _, _, _>(account, cursor_key, 1),
+        };
+
+        feed_keys.pop().map(Self::post_id_by_feed_key)
+    }
+
+    /// Merge the root posts of the spaces and accounts followed by `account` from the newest
+    /// to the oldest one, starting right after the `cursor` post id. The first `offset` posts
+    /// that pass the `filter` are skipped.
+    fn get_feed_with_filter<F: FnMut(&Post<T>) -> bool>(
+        account: T::AccountId,
+        cursor: Option<PostId>,
+        offset: u64,
+        limit: u16,
+        mut filter: F,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let sources: Vec<FeedSource<T::AccountId>> = SpaceFollows::<T>::spaces_followed_by_account(account.clone())
+            .into_iter()
+            .map(FeedSource::FollowedSpace)
+            .chain(
+                ProfileFollows::<T>::accounts_followed_by_account(account)
+                    .into_iter()
+                    .map(FeedSource::FollowedAccount)
+            )
             .collect();
 
-        // Sort post ids in a descending order
-        post_ids.sort_by(|a, b| b.cmp(a));
+        // The newest not yet merged post id of every source, together with the source index.
+        let mut heads: BinaryHeap<(PostId, usize)> = sources.iter()
+            .enumerate()
+            .filter_map(|(index, source)|
+                Self::next_post_id_of_feed_source(source, cursor).map(|post_id| (post_id, index))
+            )
+            .collect();
+
+        let mut posts = Vec::new();
+        let mut to_skip = offset;
+
+        while posts.len() < limit as usize {
+            let post_id = match heads.peek() {
+                Some((post_id, _)) => *post_id,
+                None => break,
+            };
+
+            // The same post can come from both a followed space and a followed account.
+            while let Some((head_post_id, index)) = heads.peek().cloned() {
+                if head_post_id != post_id {
+                    break;
+                }
+
+                heads.pop();
+                if let Some(next_post_id) = Self::next_post_id_of_feed_source(&sources[index], Some(post_id)) {
+                    heads.push((next_post_id, index));
+                }
+            }
+
+            if let Some(post) = Self::post_by_id(post_id) {
+                if post.is_public() && filter(&post) {
+                    if to_skip > 0 {
+                        to_skip -= 1;
+                    } else {
+                        posts.push(post.into());
+                    }
+                }
+            }
+        }
+
+        posts
+    }
+
+    pub fn get_feed(account: T::AccountId, offset: u64, limit: u16) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        Self::get_feed_with_filter(account, None, offset, limit, |_| true)
+    }
+
+    /// Get up to `limit` public root posts from the spaces and accounts followed by `account`,
+    /// from the newest to the oldest one, starting right after the `cursor` post id.
+    /// Pass the id of the last returned post as the `cursor` to get the next page.
+    ///
+    /// If `kind_filter` is not empty, only posts of these kinds are returned.
+    /// Comments are never included in a feed.
+    pub fn get_feed_page(
+        account: T::AccountId,
+        kind_filter: Vec<FlatPostKind>,
+        cursor: Option<PostId>,
+        limit: u16,
+    ) -> Vec<FlatPost<T::AccountId, T::BlockNumber>> {
+        let no_filter = kind_filter.is_empty();
+        let kind_filter_set: BTreeSet<_> = kind_filter.into_iter().collect();
 
-        Self::get_posts_by_ids_with_filter(post_ids, offset, limit, |post| post.is_public() && !post.is_comment())
+        Self::get_feed_with_filter(account, cursor, 0, limit, |post| {
+            let kind: FlatPostKind = post.clone().into();
+            no_filter || kind_filter_set.contains(&kind)
+        })
     }
 }
@@ -0,0 +1,84 @@
+//! Hand-written weights for pallet_posts
+//!
+//! These weights are estimated from the storage reads and writes of the extrinsics
+//! and are not benchmarked. Replace this file with the output of
+//! `./scripts/run-benchmark-on.sh pallet_posts ./pallets/posts/src` run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_posts.
+pub trait WeightInfo {
+	fn create_post() -> Weight;
+	fn update_post() -> Weight;
+	fn move_post() -> Weight;
+	fn delete_post(r: u32, ) -> Weight;
+	fn update_post_permissions() -> Weight;
+}
+
+/// Weights for pallet_posts using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_post() -> Weight {
+		(100_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn update_post() -> Weight {
+		(100_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn move_post() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+	fn delete_post(r: u32, ) -> Weight {
+		(100_000 as Weight)
+			.saturating_add((50_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(r as Weight)))
+	}
+	fn update_post_permissions() -> Weight {
+		(100_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_post() -> Weight {
+		(100_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn update_post() -> Weight {
+		(100_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn move_post() -> Weight {
+		(50_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
+	fn delete_post(r: u32, ) -> Weight {
+		(100_000 as Weight)
+			.saturating_add((50_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(r as Weight)))
+	}
+	fn update_post_permissions() -> Weight {
+		(100_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -8,5 +8,7 @@ sp_api::decl_runtime_apis! {
         AccountId: Codec
     {
         fn filter_followed_accounts(account: AccountId, maybe_following: Vec<AccountId>) -> Vec<AccountId>;
+
+        fn get_account_followers_page(account: AccountId, cursor: Option<AccountId>, limit: u16) -> Vec<AccountId>;
     }
 }
@@ -18,6 +18,15 @@ pub trait ProfileFollowsApi<BlockHash, AccountId> {
         account: AccountId,
         maybe_following: Vec<AccountId>,
     ) -> Result<Vec<AccountId>>;
+
+    #[rpc(name = "profileFollows_getAccountFollowersPage")]
+    fn get_account_followers_page(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        cursor: Option<AccountId>,
+        limit: u16,
+    ) -> Result<Vec<AccountId>>;
 }
 
 pub struct ProfileFollows<C, M> {
@@ -54,4 +63,18 @@ where
         let runtime_api_result = api.filter_followed_accounts(&at, account, maybe_following);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_account_followers_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        cursor: Option<AccountId>,
+        limit: u16,
+    ) -> Result<Vec<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_account_followers_page(&at, account, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
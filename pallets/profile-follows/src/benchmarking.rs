@@ -0,0 +1,31 @@
+//! Profile follows pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+
+fn account_to_follow<T: Config>() -> T::AccountId {
+    account("account_to_follow", 0, 0)
+}
+
+benchmarks! {
+    follow_account {
+        let caller: T::AccountId = whitelisted_caller();
+        let account = account_to_follow::<T>();
+    }: _(RawOrigin::Signed(caller.clone()), account.clone())
+    verify {
+        assert!(Pallet::<T>::account_followed_by_account((caller, account)));
+    }
+
+    unfollow_account {
+        let caller: T::AccountId = whitelisted_caller();
+        let account = account_to_follow::<T>();
+        Pallet::<T>::follow_account(RawOrigin::Signed(caller.clone()).into(), account.clone())?;
+    }: _(RawOrigin::Signed(caller.clone()), account.clone())
+    verify {
+        assert!(!Pallet::<T>::account_followed_by_account((caller, account)));
+    }
+}
@@ -1,56 +1,87 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure,
-    dispatch::DispatchResult,
-    traits::Get
+    ensure,
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
 };
 use sp_std::prelude::*;
-use frame_system::{self as system, ensure_signed};
+use frame_system::ensure_signed;
 
-use pallet_profiles::{Module as Profiles, SocialAccountById};
+use pallet_profiles::{Pallet as Profiles, SocialAccountById};
 use pallet_utils::remove_from_vec;
 
+pub mod migrations;
 pub mod rpc;
+pub mod weights;
+pub use weights::WeightInfo;
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_profiles::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
-    type BeforeAccountFollowed: BeforeAccountFollowed<Self>;
+pub use pallet::*;
 
-    type BeforeAccountUnfollowed: BeforeAccountUnfollowed<Self>;
-}
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_profiles::Config
+    {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as ProfileFollowsModule {
-        pub AccountFollowers get(fn account_followers):
-            map hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
 
-        pub AccountFollowedByAccount get(fn account_followed_by_account):
-            map hasher(blake2_128_concat) (T::AccountId, T::AccountId) => bool;
+        type BeforeAccountFollowed: BeforeAccountFollowed<Self>;
 
-        pub AccountsFollowedByAccount get(fn accounts_followed_by_account):
-            map hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+        type BeforeAccountUnfollowed: BeforeAccountUnfollowed<Self>;
     }
-}
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId,
-    {
-        AccountFollowed(/* follower */ AccountId, /* following */ AccountId),
-        AccountUnfollowed(/* follower */ AccountId, /* unfollowing */ AccountId),
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()
+        }
     }
-);
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    /// Whether an account follows a given account, by (followed account, follower account).
+    #[pallet::storage]
+    pub type AccountFollowers<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn account_followed_by_account)]
+    pub type AccountFollowedByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::AccountId, T::AccountId), bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn accounts_followed_by_account)]
+    pub type AccountsFollowedByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        AccountFollowed(/* follower */ T::AccountId, /* following */ T::AccountId),
+        AccountUnfollowed(/* follower */ T::AccountId, /* unfollowing */ T::AccountId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
         /// Follower social account was not found by id.
         FollowerAccountNotFound,
         /// Social account that is being followed was not found by id.
@@ -66,69 +97,80 @@ decl_error! {
         /// Account (Alice) is not a follower of another account (Bob).
         NotAccountFollower,
     }
-}
 
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(<T as Config>::WeightInfo::follow_account())]
+        pub fn follow_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResultWithPostInfo {
+            let follower = ensure_signed(origin)?;
 
-    // Initializing errors
-    type Error = Error<T>;
+            ensure!(follower != account, Error::<T>::AccountCannotFollowItself);
+            ensure!(!AccountFollowedByAccount::<T>::contains_key((follower.clone(), account.clone())),
+                Error::<T>::AlreadyAccountFollower);
 
-    // Initializing events
-    fn deposit_event() = default;
+            let mut follower_account = Profiles::get_or_new_social_account(follower.clone());
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 4)]
-    pub fn follow_account(origin, account: T::AccountId) -> DispatchResult {
-      let follower = ensure_signed(origin)?;
+            // The handler can change the followed account (e.g. its reputation),
+            // so the followed account should be read after it.
+            T::BeforeAccountFollowed::before_account_followed(
+                follower.clone(), follower_account.reputation, account.clone())?;
 
-      ensure!(follower != account, Error::<T>::AccountCannotFollowItself);
-      ensure!(!<AccountFollowedByAccount<T>>::contains_key((follower.clone(), account.clone())),
-        Error::<T>::AlreadyAccountFollower);
+            let mut followed_account = Profiles::get_or_new_social_account(account.clone());
 
-      let mut follower_account = Profiles::get_or_new_social_account(follower.clone());
-      let mut followed_account = Profiles::get_or_new_social_account(account.clone());
+            follower_account.inc_following_accounts();
+            followed_account.inc_followers();
 
-      follower_account.inc_following_accounts();
-      followed_account.inc_followers();
+            <SocialAccountById<T>>::insert(follower.clone(), follower_account);
+            <SocialAccountById<T>>::insert(account.clone(), followed_account);
+            AccountsFollowedByAccount::<T>::mutate(follower.clone(), |ids| ids.push(account.clone()));
+            AccountFollowers::<T>::insert(account.clone(), follower.clone(), true);
+            AccountFollowedByAccount::<T>::insert((follower.clone(), account.clone()), true);
 
-      T::BeforeAccountFollowed::before_account_followed(
-        follower.clone(), follower_account.reputation, account.clone())?;
+            Self::deposit_event(Event::AccountFollowed(follower, account));
+            Ok(().into())
+        }
 
-      <SocialAccountById<T>>::insert(follower.clone(), follower_account);
-      <SocialAccountById<T>>::insert(account.clone(), followed_account);
-      <AccountsFollowedByAccount<T>>::mutate(follower.clone(), |ids| ids.push(account.clone()));
-      <AccountFollowers<T>>::mutate(account.clone(), |ids| ids.push(follower.clone()));
-      <AccountFollowedByAccount<T>>::insert((follower.clone(), account.clone()), true);
+        #[pallet::weight(<T as Config>::WeightInfo::unfollow_account())]
+        pub fn unfollow_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResultWithPostInfo {
+            let follower = ensure_signed(origin)?;
 
-      Self::deposit_event(RawEvent::AccountFollowed(follower, account));
-      Ok(())
-    }
+            ensure!(follower != account, Error::<T>::AccountCannotUnfollowItself);
+            ensure!(AccountFollowedByAccount::<T>::contains_key((follower.clone(), account.clone())), Error::<T>::NotAccountFollower);
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 4)]
-    pub fn unfollow_account(origin, account: T::AccountId) -> DispatchResult {
-      let follower = ensure_signed(origin)?;
+            let mut follower_account = Profiles::social_account_by_id(follower.clone()).ok_or(Error::<T>::FollowerAccountNotFound)?;
+            ensure!(<SocialAccountById<T>>::contains_key(account.clone()), Error::<T>::FollowedAccountNotFound);
 
-      ensure!(follower != account, Error::<T>::AccountCannotUnfollowItself);
-      ensure!(<AccountFollowedByAccount<T>>::contains_key((follower.clone(), account.clone())), Error::<T>::NotAccountFollower);
+            // The handler can change the followed account (e.g. its reputation),
+            // so the followed account should be read after it.
+            T::BeforeAccountUnfollowed::before_account_unfollowed(follower.clone(), account.clone())?;
 
-      let mut follower_account = Profiles::social_account_by_id(follower.clone()).ok_or(Error::<T>::FollowerAccountNotFound)?;
-      let mut followed_account = Profiles::social_account_by_id(account.clone()).ok_or(Error::<T>::FollowedAccountNotFound)?;
+            let mut followed_account = Profiles::social_account_by_id(account.clone()).ok_or(Error::<T>::FollowedAccountNotFound)?;
 
-      follower_account.dec_following_accounts();
-      followed_account.dec_followers();
+            follower_account.dec_following_accounts();
+            followed_account.dec_followers();
 
-      T::BeforeAccountUnfollowed::before_account_unfollowed(follower.clone(), account.clone())?;
+            <SocialAccountById<T>>::insert(follower.clone(), follower_account);
+            <SocialAccountById<T>>::insert(account.clone(), followed_account);
+            AccountsFollowedByAccount::<T>::mutate(follower.clone(), |account_ids| remove_from_vec(account_ids, account.clone()));
+            AccountFollowers::<T>::remove(account.clone(), follower.clone());
+            AccountFollowedByAccount::<T>::remove((follower.clone(), account.clone()));
+
+            Self::deposit_event(Event::AccountUnfollowed(follower, account));
+            Ok(().into())
+        }
+    }
+}
 
-      <SocialAccountById<T>>::insert(follower.clone(), follower_account);
-      <SocialAccountById<T>>::insert(account.clone(), followed_account);
-      <AccountsFollowedByAccount<T>>::mutate(follower.clone(), |account_ids| remove_from_vec(account_ids, account.clone()));
-      <AccountFollowers<T>>::mutate(account.clone(), |account_ids| remove_from_vec(account_ids, follower.clone()));
-      <AccountFollowedByAccount<T>>::remove((follower.clone(), account.clone()));
+impl<T: Config> Pallet<T> {
+    /// Get the followers of a given account, sorted by account id.
+    pub fn account_followers(account: T::AccountId) -> Vec<T::AccountId> {
+        let mut followers: Vec<T::AccountId> = AccountFollowers::<T>::iter_prefix(account)
+            .map(|(follower, _)| follower)
+            .collect();
 
-      Self::deposit_event(RawEvent::AccountUnfollowed(follower, account));
-      Ok(())
+        followers.sort();
+        followers
     }
-  }
 }
 
 /// Handler that will be called right before the account is followed.
@@ -1,11 +1,22 @@
 use sp_std::prelude::*;
 
-use crate::{Module, Config};
+use pallet_utils::paginate_double_map_keys;
 
-impl<T: Config> Module<T> {
+use crate::{AccountFollowers, Pallet, Config};
+
+impl<T: Config> Pallet<T> {
     pub fn filter_followed_accounts(account: T::AccountId, maybe_following: Vec<T::AccountId>) -> Vec<T::AccountId> {
         maybe_following.iter()
             .filter(|maybe_following| Self::account_followed_by_account((&account, maybe_following)))
             .cloned().collect()
     }
+
+    /// Get up to `limit` followers of a given account, starting after the `cursor` account.
+    pub fn get_account_followers_page(
+        account: T::AccountId,
+        cursor: Option<T::AccountId>,
+        limit: u16,
+    ) -> Vec<T::AccountId> {
+        paginate_double_map_keys::<AccountFollowers<T>, _, _, _>(&account, cursor, limit.into())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+//! Hand-written weights for pallet_profile_follows
+//!
+//! These weights are estimated from the storage reads and writes of the extrinsics
+//! and are not benchmarked. Replace this file with the output of
+//! `./scripts/run-benchmark-on.sh pallet_profile_follows ./pallets/profile-follows/src` run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_profile_follows.
+pub trait WeightInfo {
+	fn follow_account() -> Weight;
+	fn unfollow_account() -> Weight;
+}
+
+/// Weights for pallet_profile_follows using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn follow_account() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn unfollow_account() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn follow_account() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn unfollow_account() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use codec::Codec;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+
+use pallet_profile_history::rpc::FlatProfileHistoryRecord;
+use pallet_utils::rpc::map_rpc_error;
+pub use profile_history_runtime_api::ProfileHistoryApi as ProfileHistoryRuntimeApi;
+
+#[rpc]
+pub trait ProfileHistoryApi<BlockHash, AccountId, BlockNumber> {
+    #[rpc(name = "profileHistory_getProfileHistory")]
+    fn get_profile_history(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatProfileHistoryRecord<AccountId, BlockNumber>>>;
+}
+
+pub struct ProfileHistory<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> ProfileHistory<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, BlockNumber> ProfileHistoryApi<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for ProfileHistory<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    BlockNumber: Codec,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: ProfileHistoryRuntimeApi<Block, AccountId, BlockNumber>,
+{
+    fn get_profile_history(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatProfileHistoryRecord<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_profile_history(&at, account, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+}
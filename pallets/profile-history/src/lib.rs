@@ -2,14 +2,15 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use frame_support::{decl_module, decl_storage};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::Vec;
-use frame_system::{self as system};
 
 use pallet_utils::WhoAndWhen;
 use pallet_profiles::{Profile, ProfileUpdate, AfterProfileUpdated};
 
+pub mod migrations;
+pub mod rpc;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct ProfileHistoryRecord<T: Config> {
@@ -17,22 +18,49 @@ pub struct ProfileHistoryRecord<T: Config> {
     pub old_data: ProfileUpdate,
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_profiles::Config
-{}
-
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as ProfileHistoryModule {
-        pub EditHistory get(fn edit_history):
-            map hasher(blake2_128_concat) T::AccountId => Vec<ProfileHistoryRecord<T>>;
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_profiles::Config
+    {
+        /// The maximum number of edit history records stored per profile.
+        /// The oldest records are pruned when this limit is exceeded.
+        #[pallet::constant]
+        type MaxHistoryRecords: Get<u32>;
     }
-}
 
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {}
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<EditHistory<T>, Vec<ProfileHistoryRecord<T>>>()
+        }
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn edit_history)]
+    pub type EditHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<ProfileHistoryRecord<T>>, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {}
 }
 
 impl<T: Config> ProfileHistoryRecord<T> {
@@ -44,9 +72,15 @@ impl<T: Config> ProfileHistoryRecord<T> {
     }
 }
 
-impl<T: Config> AfterProfileUpdated<T> for Module<T> {
+impl<T: Config> AfterProfileUpdated<T> for Pallet<T> {
     fn after_profile_updated(sender: T::AccountId, _profile: &Profile<T>, old_data: ProfileUpdate) {
-        <EditHistory<T>>::mutate(sender.clone(), |ids|
-            ids.push(ProfileHistoryRecord::<T>::new(sender, old_data)));
+        EditHistory::<T>::mutate(sender.clone(), |records| {
+            records.push(ProfileHistoryRecord::<T>::new(sender, old_data));
+
+            let max_records = T::MaxHistoryRecords::get() as usize;
+            if records.len() > max_records {
+                records.drain(..records.len() - max_records);
+            }
+        });
     }
 }
@@ -0,0 +1,42 @@
+use frame_support::traits::{Get, GetStorageVersion, StorageVersion};
+
+use pallet_utils::Content;
+
+use super::*;
+
+/// `ProfileUpdate` as it was stored before the `handle` field was added.
+#[derive(Encode, Decode)]
+struct OldProfileUpdate {
+    content: Option<Content>,
+}
+
+#[derive(Encode, Decode)]
+struct OldProfileHistoryRecord<T: Config> {
+    edited: WhoAndWhen<T>,
+    old_data: OldProfileUpdate,
+}
+
+/// Storage version 2 adds the `handle` field to the old data of history records.
+pub fn add_handles_to_history_records<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut histories_migrated: u64 = 0;
+
+    EditHistory::<T>::translate::<Vec<OldProfileHistoryRecord<T>>, _>(|_, old_records| {
+        histories_migrated += 1;
+
+        Some(old_records.into_iter().map(|old_record| ProfileHistoryRecord {
+            edited: old_record.edited,
+            old_data: ProfileUpdate {
+                handle: None,
+                content: old_record.old_data.content,
+            },
+        }).collect())
+    });
+
+    StorageVersion::new(2).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(histories_migrated + 1, histories_migrated + 1)
+}
@@ -0,0 +1,61 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+use pallet_utils::rpc::{FlatContent, FlatEditedWhoAndWhen, ShouldSkip};
+
+use crate::{Pallet, ProfileHistoryRecord, Config};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatProfileHistoryRecord<AccountId, BlockNumber> {
+    #[cfg_attr(feature = "std", serde(flatten))]
+    pub edited: FlatEditedWhoAndWhen<AccountId, BlockNumber>,
+
+    /// `Some(None)` means that the profile had no handle before this edit.
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip", serialize_with = "bytes_to_string"))]
+    pub old_handle: Option<Option<Vec<u8>>>,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_content: Option<FlatContent>,
+}
+
+#[cfg(feature = "std")]
+fn bytes_to_string<S>(field: &Option<Option<Vec<u8>>>, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+    match field.clone().flatten() {
+        // If Bytes slice is invalid, then empty string will be returned
+        Some(bytes) => serializer.serialize_str(std::str::from_utf8(&bytes).unwrap_or_default()),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl<T: Config> From<ProfileHistoryRecord<T>> for FlatProfileHistoryRecord<T::AccountId, T::BlockNumber> {
+    fn from(from: ProfileHistoryRecord<T>) -> Self {
+        let ProfileHistoryRecord { edited, old_data } = from;
+
+        Self {
+            edited: edited.into(),
+            old_handle: old_data.handle,
+            old_content: old_data.content.map(|content| content.into()),
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns edit history records of a profile starting from the most recent one.
+    pub fn get_profile_history(
+        account: T::AccountId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatProfileHistoryRecord<T::AccountId, T::BlockNumber>> {
+        Self::edit_history(account)
+            .into_iter()
+            .rev()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|record| record.into())
+            .collect()
+    }
+}
@@ -4,6 +4,7 @@ use codec::Codec;
 use sp_std::vec::Vec;
 
 use pallet_profiles::rpc::FlatSocialAccount;
+use pallet_utils::User;
 
 sp_api::decl_runtime_apis! {
     pub trait ProfilesApi<AccountId, BlockNumber> where
@@ -13,5 +14,7 @@ sp_api::decl_runtime_apis! {
         fn get_social_accounts_by_ids(
             account_ids: Vec<AccountId>
         ) -> Vec<FlatSocialAccount<AccountId, BlockNumber>>;
+
+        fn get_handle_owner(handle: Vec<u8>) -> Option<User<AccountId>>;
     }
 }
@@ -7,7 +7,7 @@ use jsonrpc_derive::rpc;
 use sp_api::ProvideRuntimeApi;
 
 use pallet_profiles::rpc::FlatSocialAccount;
-use pallet_utils::rpc::map_rpc_error;
+use pallet_utils::{User, rpc::map_rpc_error};
 pub use profiles_runtime_api::ProfilesApi as ProfilesRuntimeApi;
 
 #[rpc]
@@ -18,6 +18,13 @@ pub trait ProfilesApi<BlockHash, AccountId, BlockNumber> {
         at: Option<BlockHash>,
         account_ids: Vec<AccountId>,
     ) -> Result<Vec<FlatSocialAccount<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "profiles_getHandleOwner")]
+    fn get_handle_owner(
+        &self,
+        at: Option<BlockHash>,
+        handle: Vec<u8>,
+    ) -> Result<Option<User<AccountId>>>;
 }
 
 pub struct Profiles<C, M> {
@@ -50,4 +57,12 @@ where
         let runtime_api_result = api.get_social_accounts_by_ids(&at, account_ids);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_handle_owner(&self, at: Option<<Block as BlockT>::Hash>, handle: Vec<u8>) -> Result<Option<User<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_handle_owner(&at, handle);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -0,0 +1,79 @@
+//! Profiles pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use sp_runtime::traits::Bounded;
+use pallet_utils::mock_functions::{updated_content_ipfs, valid_content_ipfs};
+
+fn profile_handle() -> Vec<u8> {
+    b"profile_handle".to_vec()
+}
+
+fn caller_with_profile<T: Config>() -> Result<T::AccountId, &'static str> {
+    let caller: T::AccountId = whitelisted_caller();
+    <T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+    Pallet::<T>::create_profile(RawOrigin::Signed(caller.clone()).into(), valid_content_ipfs())?;
+    Ok(caller)
+}
+
+benchmarks! {
+    create_profile {
+        let caller: T::AccountId = whitelisted_caller();
+        <T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+    }: _(RawOrigin::Signed(caller.clone()), valid_content_ipfs())
+    verify {
+        let social_account = Pallet::<T>::social_account_by_id(caller).ok_or("Social account was not created")?;
+        let profile = social_account.profile.ok_or("Profile was not created")?;
+        assert_eq!(profile.content, valid_content_ipfs());
+    }
+
+    update_profile {
+        let caller = caller_with_profile::<T>()?;
+
+        let update = ProfileUpdate {
+            handle: Some(Some(profile_handle())),
+            content: Some(updated_content_ipfs()),
+        };
+    }: _(RawOrigin::Signed(caller.clone()), update)
+    verify {
+        let social_account = Pallet::<T>::social_account_by_id(caller.clone()).ok_or("Social account was not found")?;
+        let profile = social_account.profile.ok_or("Profile was not found")?;
+        assert_eq!(profile.content, updated_content_ipfs());
+        assert_eq!(Pallet::<T>::account_by_profile_handle(profile_handle()), Some(caller));
+    }
+
+    force_unreserve_handle {
+        let caller = caller_with_profile::<T>()?;
+        let update = ProfileUpdate {
+            handle: Some(Some(profile_handle())),
+            content: None,
+        };
+        Pallet::<T>::update_profile(RawOrigin::Signed(caller.clone()).into(), update)?;
+    }: _(RawOrigin::Root, profile_handle())
+    verify {
+        assert_eq!(Pallet::<T>::account_by_profile_handle(profile_handle()), None);
+        let social_account = Pallet::<T>::social_account_by_id(caller).ok_or("Social account was not found")?;
+        assert!(social_account.profile.ok_or("Profile was not found")?.handle.is_none());
+    }
+
+    renew_profile_handle {
+        let caller = caller_with_profile::<T>()?;
+        let update = ProfileUpdate {
+            handle: Some(Some(profile_handle())),
+            content: None,
+        };
+        Pallet::<T>::update_profile(RawOrigin::Signed(caller.clone()).into(), update)?;
+        let expires_at = Pallet::<T>::handle_registration_by_account(&caller)
+            .and_then(|registration| registration.expires_at)
+            .ok_or("Profile handle does not expire")?;
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        let registration = Pallet::<T>::handle_registration_by_account(&caller)
+            .ok_or("Profile handle registration was not found")?;
+        assert!(registration.expires_at > Some(expires_at));
+    }
+}
@@ -3,17 +3,25 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure,
-    dispatch::DispatchResult,
-    traits::Get
+    ensure,
+    dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
+    traits::{Currency, Get, ReservableCurrency},
+    weights::Pays,
 };
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{RuntimeDebug, traits::{One, Saturating}};
 use sp_std::prelude::*;
-use frame_system::{self as system, ensure_signed};
+use frame_system::{ensure_signed, ensure_root};
 
-use pallet_utils::{Module as Utils, WhoAndWhen, Content};
+use df_traits::{HandleOwnerProvider, ReputationProvider};
+use pallet_utils::{Pallet as Utils, Error as UtilsError, User, WhoAndWhen, Content, HandleRegistration, StorageItem};
 
+pub mod migrations;
 pub mod rpc;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -30,43 +38,113 @@ pub struct SocialAccount<T: Config> {
 pub struct Profile<T: Config> {
     pub created: WhoAndWhen<T>,
     pub updated: Option<WhoAndWhen<T>>,
+
+    /// Unique username of an account. Profile handles follow the same rules as space handles
+    /// and share one namespace with them.
+    pub handle: Option<Vec<u8>>,
+
     pub content: Content
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[allow(clippy::option_option)]
 pub struct ProfileUpdate {
+    pub handle: Option<Option<Vec<u8>>>,
     pub content: Option<Content>,
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-    type AfterProfileUpdated: AfterProfileUpdated<Self>;
-}
+pub use pallet::*;
 
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as ProfilesModule {
-        pub SocialAccountById get(fn social_account_by_id):
-            map hasher(blake2_128_concat) T::AccountId => Option<SocialAccount<T>>;
-    }
-}
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId,
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
     {
-        ProfileCreated(AccountId),
-        ProfileUpdated(AccountId),
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+
+        type AfterProfileUpdated: AfterProfileUpdated<Self>;
+
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Space handles, which share one namespace with profile handles.
+        type SpaceHandles: HandleOwnerProvider<Self::AccountId>;
+
+        /// The amount reserved from an account for its profile handle.
+        /// Shorter handles than `PremiumHandleLen` cost more (see `Utils::handle_deposit`).
+        #[pallet::constant]
+        type HandleDeposit: Get<BalanceOf<Self>>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::release_expired_handles(now)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<SocialAccountById<T>, SocialAccount<T>>()
+        }
     }
-);
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    #[pallet::storage]
+    #[pallet::getter(fn social_account_by_id)]
+    pub type SocialAccountById<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SocialAccount<T>, OptionQuery>;
+
+    /// Find an account by its lower-cased profile handle.
+    #[pallet::storage]
+    #[pallet::getter(fn account_by_profile_handle)]
+    pub type AccountByProfileHandle<T: Config> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, T::AccountId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn handle_registration_by_account)]
+    pub type HandleRegistrationByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, HandleRegistration<T::BlockNumber, BalanceOf<T>>, OptionQuery>;
+
+    /// Accounts whose profile handles are released at a given block, unless they are renewed.
+    #[pallet::storage]
+    pub type ProfileHandlesByReleaseBlock<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    /// The earliest block whose profile handles may have not been released yet.
+    #[pallet::storage]
+    pub type NextHandleReleaseBlock<T: Config> = StorageValue<_, T::BlockNumber>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        ProfileCreated(T::AccountId),
+        ProfileUpdated(T::AccountId),
+        ProfileHandleRenewed(T::AccountId),
+        /// A profile handle was not renewed in time and was released. \[account, handle\]
+        ProfileHandleReleased(T::AccountId, Vec<u8>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
         /// Social account was not found by id.
         SocialAccountNotFound,
         /// Profile is already created for this account.
@@ -75,74 +153,124 @@ decl_error! {
         NoUpdatesForProfile,
         /// Account has no profile yet.
         AccountHasNoProfile,
+        /// Profile handle is not unique: it's already reserved by another profile or space.
+        ProfileHandleIsNotUnique,
+        /// Profile has no handle to renew.
+        ProfileHasNoHandle,
     }
-}
 
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(<T as Config>::WeightInfo::create_profile())]
+        pub fn create_profile(origin: OriginFor<T>, content: Content) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-    // Initializing errors
-    type Error = Error<T>;
+            Utils::<T>::is_valid_content(content.clone())?;
 
-    // Initializing events
-    fn deposit_event() = default;
+            let mut social_account = Self::get_or_new_social_account(owner.clone());
+            ensure!(social_account.profile.is_none(), Error::<T>::ProfileAlreadyCreated);
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(1, 2)]
-    pub fn create_profile(origin, content: Content) -> DispatchResult {
-      let owner = ensure_signed(origin)?;
+            let profile = Profile {
+                created: WhoAndWhen::<T>::new(owner.clone()),
+                updated: None,
+                handle: None,
+                content
+            };
+            Utils::<T>::reserve_storage_deposit(&owner, StorageItem::Profile(owner.clone()), &profile)?;
 
-      Utils::<T>::is_valid_content(content.clone())?;
+            social_account.profile = Some(profile);
+            SocialAccountById::<T>::insert(owner.clone(), social_account);
 
-      let mut social_account = Self::get_or_new_social_account(owner.clone());
-      ensure!(social_account.profile.is_none(), Error::<T>::ProfileAlreadyCreated);
-
-      social_account.profile = Some(
-        Profile {
-          created: WhoAndWhen::<T>::new(owner.clone()),
-          updated: None,
-          content
+            Self::deposit_event(Event::ProfileCreated(owner));
+            Ok(().into())
         }
-      );
-      <SocialAccountById<T>>::insert(owner.clone(), social_account);
 
-      Self::deposit_event(RawEvent::ProfileCreated(owner));
-      Ok(())
-    }
+        #[pallet::weight(<T as Config>::WeightInfo::update_profile())]
+        #[transactional]
+        pub fn update_profile(origin: OriginFor<T>, update: ProfileUpdate) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+
+            let has_updates =
+                update.handle.is_some() ||
+                update.content.is_some();
+
+            ensure!(has_updates, Error::<T>::NoUpdatesForProfile);
+
+            let mut social_account = Self::social_account_by_id(owner.clone()).ok_or(Error::<T>::SocialAccountNotFound)?;
+            let mut profile = social_account.profile.ok_or(Error::<T>::AccountHasNoProfile)?;
+            let mut is_update_applied = false;
+            let mut old_data = ProfileUpdate::default();
+
+            if let Some(content) = update.content {
+                if content != profile.content {
+                    Utils::<T>::is_valid_content(content.clone())?;
+                    old_data.content = Some(profile.content);
+                    profile.content = content;
+                    is_update_applied = true;
+                }
+            }
+
+            if let Some(handle_opt) = update.handle {
+                if handle_opt != profile.handle {
+                    Self::update_handle(&owner, profile.handle.clone(), handle_opt.clone())?;
+                    old_data.handle = Some(profile.handle);
+                    profile.handle = handle_opt;
+                    is_update_applied = true;
+                }
+            }
+
+            if is_update_applied {
+                profile.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
+                Utils::<T>::update_storage_deposit(StorageItem::Profile(owner.clone()), &profile)?;
+                social_account.profile = Some(profile.clone());
+
+                SocialAccountById::<T>::insert(owner.clone(), social_account);
+                T::AfterProfileUpdated::after_profile_updated(owner.clone(), &profile, old_data);
+
+                Self::deposit_event(Event::ProfileUpdated(owner));
+            }
+            Ok(().into())
+        }
 
-    #[weight = 100_000 + T::DbWeight::get().reads_writes(1, 2)]
-    pub fn update_profile(origin, update: ProfileUpdate) -> DispatchResult {
-      let owner = ensure_signed(origin)?;
+        /// Remove a handle from a profile and return the handle deposit to its account.
+        #[pallet::weight(<T as Config>::WeightInfo::force_unreserve_handle())]
+        pub fn force_unreserve_handle(origin: OriginFor<T>, handle: Vec<u8>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
 
-      let has_updates = update.content.is_some();
+            let lowercased_handle = Utils::<T>::lowercase_handle(handle);
 
-      ensure!(has_updates, Error::<T>::NoUpdatesForProfile);
+            if let Some(account) = Self::account_by_profile_handle(&lowercased_handle) {
+                Self::unreserve_handle(&account, lowercased_handle);
 
-      let mut social_account = Self::social_account_by_id(owner.clone()).ok_or(Error::<T>::SocialAccountNotFound)?;
-      let mut profile = social_account.profile.ok_or(Error::<T>::AccountHasNoProfile)?;
-      let mut is_update_applied = false;
-      let mut old_data = ProfileUpdate::default();
+                SocialAccountById::<T>::mutate(&account, |social_account_opt| {
+                    if let Some(profile) = social_account_opt.as_mut().and_then(|acc| acc.profile.as_mut()) {
+                        profile.handle = None;
+                    }
+                });
+            }
 
-      if let Some(content) = update.content {
-        if content != profile.content {
-          Utils::<T>::is_valid_content(content.clone())?;
-          old_data.content = Some(profile.content);
-          profile.content = content;
-          is_update_applied = true;
+            Ok(Pays::No.into())
         }
-      }
 
-      if is_update_applied {
-        profile.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
-        social_account.profile = Some(profile.clone());
+        /// Extend the registration of a profile handle for one more `HandleRegistrationPeriod`.
+        /// An expired handle can be renewed until its grace period is over.
+        #[pallet::weight(<T as Config>::WeightInfo::renew_profile_handle())]
+        pub fn renew_profile_handle(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+
+            let social_account = Self::social_account_by_id(&owner).ok_or(Error::<T>::SocialAccountNotFound)?;
+            let profile = social_account.profile.ok_or(Error::<T>::AccountHasNoProfile)?;
+            ensure!(profile.handle.is_some(), Error::<T>::ProfileHasNoHandle);
 
-        <SocialAccountById<T>>::insert(owner.clone(), social_account);
-        T::AfterProfileUpdated::after_profile_updated(owner.clone(), &profile, old_data);
+            let mut registration = Self::remove_handle_registration(&owner)
+                .ok_or(UtilsError::<T>::HandleDoesNotExpire)?;
+            Utils::<T>::renew_handle_registration(&mut registration)?;
+            Self::insert_handle_registration(&owner, registration);
 
-        Self::deposit_event(RawEvent::ProfileUpdated(owner));
-      }
-      Ok(())
+            Self::deposit_event(Event::ProfileHandleRenewed(owner));
+            Ok(().into())
+        }
     }
-  }
 }
 
 impl <T: Config> SocialAccount<T> {
@@ -185,12 +313,13 @@ impl<T: Config> SocialAccount<T> {
 impl Default for ProfileUpdate {
     fn default() -> Self {
         ProfileUpdate {
+            handle: None,
             content: None
         }
     }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
     pub fn get_or_new_social_account(account: T::AccountId) -> SocialAccount<T> {
         Self::social_account_by_id(account).unwrap_or(
             SocialAccount {
@@ -202,6 +331,154 @@ impl<T: Config> Module<T> {
             }
         )
     }
+
+    /// Lowercase a handle and ensure that it's unique,
+    /// i.e. no profile or space reserved this handle yet.
+    fn lowercase_and_ensure_unique_handle(handle: Vec<u8>) -> Result<Vec<u8>, DispatchError> {
+        let handle_in_lowercase = Utils::<T>::lowercase_and_validate_a_handle(handle)?;
+
+        // Check if a handle is unique across all profiles' and spaces' handles:
+        ensure!(Self::account_by_profile_handle(&handle_in_lowercase).is_none(), Error::<T>::ProfileHandleIsNotUnique);
+        ensure!(T::SpaceHandles::handle_owner(&handle_in_lowercase).is_none(), Error::<T>::ProfileHandleIsNotUnique);
+
+        Ok(handle_in_lowercase)
+    }
+
+    /// Register a profile handle and reserve a deposit, that depends on the handle length.
+    fn reserve_handle(account: &T::AccountId, handle: Vec<u8>) -> DispatchResult {
+        // A deposit depends on the length of the handle itself, not of its key.
+        let deposit = Utils::<T>::handle_deposit(T::HandleDeposit::get(), &handle);
+        let handle_in_lowercase = Self::lowercase_and_ensure_unique_handle(handle)?;
+        <T as Config>::Currency::reserve(account, deposit)?;
+        AccountByProfileHandle::<T>::insert(handle_in_lowercase, account);
+        Self::insert_handle_registration(account, Utils::<T>::new_handle_registration(deposit));
+        Ok(())
+    }
+
+    fn unreserve_handle(account: &T::AccountId, handle_in_lowercase: Vec<u8>) {
+        if let Some(registration) = Self::remove_handle_registration(account) {
+            <T as Config>::Currency::unreserve(account, registration.deposit);
+        }
+        AccountByProfileHandle::<T>::remove(handle_in_lowercase);
+    }
+
+    fn insert_handle_registration(account: &T::AccountId, registration: HandleRegistration<T::BlockNumber, BalanceOf<T>>) {
+        if let Some(release_block) = Utils::<T>::handle_release_block(&registration) {
+            ProfileHandlesByReleaseBlock::<T>::insert(release_block, account, true);
+        }
+        HandleRegistrationByAccount::<T>::insert(account, registration);
+    }
+
+    fn remove_handle_registration(account: &T::AccountId) -> Option<HandleRegistration<T::BlockNumber, BalanceOf<T>>> {
+        let registration = HandleRegistrationByAccount::<T>::take(account)?;
+        if let Some(release_block) = Utils::<T>::handle_release_block(&registration) {
+            ProfileHandlesByReleaseBlock::<T>::remove(release_block, account);
+        }
+        Some(registration)
+    }
+
+    /// Release the profile handles whose grace period is over by the block `now`
+    /// and return their handle deposits to the accounts. At most `MaxHandlesToReleasePerBlock`
+    /// handles are released, the rest are released in the next blocks.
+    fn release_expired_handles(now: T::BlockNumber) -> Weight {
+        let mut budget = T::MaxHandlesToReleasePerBlock::get() as usize;
+        let mut block = NextHandleReleaseBlock::<T>::get().unwrap_or(now);
+
+        let mut blocks_checked: u64 = 0;
+        let mut released: u64 = 0;
+
+        while block <= now && budget > 0 {
+            budget -= 1;
+            blocks_checked += 1;
+
+            let accounts: Vec<T::AccountId> =
+                ProfileHandlesByReleaseBlock::<T>::iter_key_prefix(block).take(budget).collect();
+            budget = budget.saturating_sub(accounts.len());
+
+            for account in accounts {
+                ProfileHandlesByReleaseBlock::<T>::remove(block, &account);
+                Self::release_expired_handle(account);
+                released += 1;
+            }
+
+            if ProfileHandlesByReleaseBlock::<T>::iter_key_prefix(block).next().is_some() {
+                break;
+            }
+            block = block.saturating_add(One::one());
+        }
+
+        NextHandleReleaseBlock::<T>::put(block);
+
+        T::DbWeight::get().reads_writes(
+            blocks_checked.saturating_add(released.saturating_mul(3)).saturating_add(1),
+            released.saturating_mul(5).saturating_add(1),
+        )
+    }
+
+    fn release_expired_handle(account: T::AccountId) {
+        if let Some(registration) = HandleRegistrationByAccount::<T>::take(&account) {
+            <T as Config>::Currency::unreserve(&account, registration.deposit);
+        }
+
+        let mut released_handle = None;
+        SocialAccountById::<T>::mutate(&account, |social_account_opt| {
+            if let Some(profile) = social_account_opt.as_mut().and_then(|acc| acc.profile.as_mut()) {
+                released_handle = profile.handle.take();
+                // A profile only shrinks here, so its storage deposit can only be partially returned.
+                let _ = Utils::<T>::update_storage_deposit(StorageItem::Profile(account.clone()), &*profile);
+            }
+        });
+
+        if let Some(handle) = released_handle {
+            AccountByProfileHandle::<T>::remove(Utils::<T>::lowercase_handle(handle.clone()));
+            Self::deposit_event(Event::ProfileHandleReleased(account, handle));
+        }
+    }
+
+    /// Replace, reserve or unreserve a profile handle of a given account.
+    /// A new handle is registered from scratch, as its deposit depends on the handle length.
+    fn update_handle(
+        account: &T::AccountId,
+        old_handle_opt: Option<Vec<u8>>,
+        new_handle_opt: Option<Vec<u8>>,
+    ) -> DispatchResult {
+        match (old_handle_opt, new_handle_opt) {
+            (Some(old_handle), Some(new_handle)) => {
+                let old_handle_lc = Utils::<T>::lowercase_handle(old_handle);
+
+                // Only the case of letters is changed, so the handle stays reserved as is:
+                if old_handle_lc == Utils::<T>::lowercase_handle(new_handle.clone()) {
+                    return Ok(());
+                }
+
+                // Validate data first
+                Self::lowercase_and_ensure_unique_handle(new_handle.clone())?;
+
+                Self::unreserve_handle(account, old_handle_lc);
+                Self::reserve_handle(account, new_handle)?;
+            }
+            (Some(old_handle), None) => {
+                Self::unreserve_handle(account, Utils::<T>::lowercase_handle(old_handle));
+            }
+            (None, Some(new_handle)) => {
+                Self::reserve_handle(account, new_handle)?;
+            }
+            (None, None) => {}
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> HandleOwnerProvider<T::AccountId> for Pallet<T> {
+    fn handle_owner(handle: &[u8]) -> Option<User<T::AccountId>> {
+        Self::account_by_profile_handle(handle.to_vec()).map(User::Account)
+    }
+}
+
+impl<T: Config> ReputationProvider<T::AccountId> for Pallet<T> {
+    fn reputation(account: &T::AccountId) -> u32 {
+        Self::social_account_by_id(account).map_or(0, |social_account| social_account.reputation)
+    }
 }
 
 #[impl_trait_for_tuples::impl_for_tuples(10)]
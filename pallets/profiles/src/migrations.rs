@@ -0,0 +1,51 @@
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+use super::*;
+
+/// `Profile` as it was stored before the `handle` field was added.
+#[derive(Encode, Decode)]
+struct OldProfile<T: Config> {
+    created: WhoAndWhen<T>,
+    updated: Option<WhoAndWhen<T>>,
+    content: Content,
+}
+
+/// `SocialAccount` as it was stored before the `handle` field was added to `Profile`.
+#[derive(Encode, Decode)]
+struct OldSocialAccount<T: Config> {
+    followers_count: u32,
+    following_accounts_count: u16,
+    following_spaces_count: u16,
+    reputation: u32,
+    profile: Option<OldProfile<T>>,
+}
+
+/// Storage version 2 adds the `handle` field to profiles.
+pub fn add_handles_to_profiles<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut accounts_migrated: u64 = 0;
+
+    SocialAccountById::<T>::translate::<OldSocialAccount<T>, _>(|_, old_account| {
+        accounts_migrated += 1;
+
+        Some(SocialAccount {
+            followers_count: old_account.followers_count,
+            following_accounts_count: old_account.following_accounts_count,
+            following_spaces_count: old_account.following_spaces_count,
+            reputation: old_account.reputation,
+            profile: old_account.profile.map(|old_profile| Profile {
+                created: old_profile.created,
+                updated: old_profile.updated,
+                handle: None,
+                content: old_profile.content,
+            }),
+        })
+    });
+
+    StorageVersion::new(2).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(accounts_migrated + 1, accounts_migrated + 1)
+}
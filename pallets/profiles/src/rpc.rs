@@ -3,11 +3,12 @@ use codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 use sp_std::prelude::*;
 
-use pallet_utils::rpc::{FlatContent, FlatWhoAndWhen};
+use pallet_utils::{User, Pallet as Utils, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}};
+use df_traits::HandleOwnerProvider;
 
 use frame_system::Pallet as SystemPallet;
 
-use crate::{Module, Profile, SocialAccount, Config};
+use crate::{Pallet, Profile, SocialAccount, Config};
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
@@ -15,10 +16,21 @@ use crate::{Module, Profile, SocialAccount, Config};
 pub struct FlatProfile<AccountId, BlockNumber> {
     #[cfg_attr(feature = "std", serde(flatten))]
     pub who_and_when: FlatWhoAndWhen<AccountId, BlockNumber>,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip", serialize_with = "bytes_to_string"))]
+    pub handle: Option<Vec<u8>>,
     #[cfg_attr(feature = "std", serde(flatten))]
     pub content: FlatContent,
 }
 
+#[cfg(feature = "std")]
+fn bytes_to_string<S>(field: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+    let field_unwrapped = field.clone().unwrap_or_default();
+    // If Bytes slice is invalid, then empty string will be returned
+    serializer.serialize_str(
+        std::str::from_utf8(&field_unwrapped).unwrap_or_default()
+    )
+}
+
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
@@ -33,10 +45,11 @@ pub struct FlatSocialAccount<AccountId, BlockNumber> {
 
 impl<T: Config> From<Profile<T>> for FlatProfile<T::AccountId, T::BlockNumber> {
     fn from(from: Profile<T>) -> Self {
-        let Profile { created, updated, content } = from;
+        let Profile { created, updated, handle, content } = from;
 
         Self {
             who_and_when: (created, updated).into(),
+            handle,
             content: content.into(),
         }
     }
@@ -59,7 +72,7 @@ impl<T: Config> From<SocialAccount<T>> for FlatSocialAccount<T::AccountId, T::Bl
     }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
     pub fn get_social_accounts_by_ids(
         account_ids: Vec<T::AccountId>
     ) -> Vec<FlatSocialAccount<T::AccountId, T::BlockNumber>> {
@@ -75,6 +88,15 @@ impl<T: Config> Module<T> {
                    .collect()
     }
 
+    /// Find a profile (account) or a space that reserved a given handle.
+    pub fn get_handle_owner(handle: Vec<u8>) -> Option<User<T::AccountId>> {
+        let handle_in_lowercase = Utils::<T>::lowercase_handle(handle);
+
+        Self::account_by_profile_handle(&handle_in_lowercase)
+            .map(User::Account)
+            .or_else(|| T::SpaceHandles::handle_owner(&handle_in_lowercase))
+    }
+
     pub fn get_account_data(account: T::AccountId) -> T::AccountData {
         SystemPallet::<T>::account(&account).data
     }
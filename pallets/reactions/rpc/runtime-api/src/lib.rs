@@ -7,9 +7,10 @@ use sp_std::collections::btree_map::BTreeMap;
 use pallet_reactions::{
     ReactionId,
     ReactionKind,
+    ReactionTarget,
     rpc::FlatReaction,
 };
-use pallet_utils::PostId;
+use pallet_utils::{PostId, SpaceId};
 
 sp_api::decl_runtime_apis! {
     pub trait ReactionsApi<AccountId, BlockNumber> where
@@ -28,5 +29,23 @@ sp_api::decl_runtime_apis! {
             post_ids: Vec<PostId>,
             reactor: AccountId,
         ) -> BTreeMap<PostId, ReactionKind>;
+
+        fn get_reactions_by_target(
+            target: ReactionTarget<AccountId>,
+            limit: u64,
+            offset: u64
+        ) -> Vec<FlatReaction<AccountId, BlockNumber>>;
+
+        fn get_reaction_ids_by_target_page(
+            target: ReactionTarget<AccountId>,
+            cursor: Option<ReactionId>,
+            limit: u16
+        ) -> Vec<ReactionId>;
+
+        fn get_reaction_counts_by_target(target: ReactionTarget<AccountId>) -> BTreeMap<ReactionKind, u32>;
+
+        fn get_reaction_counts_by_post_ids(post_ids: Vec<PostId>) -> BTreeMap<PostId, BTreeMap<ReactionKind, u32>>;
+
+        fn get_space_reaction_kinds(space_id: SpaceId) -> Vec<Vec<u8>>;
     }
 }
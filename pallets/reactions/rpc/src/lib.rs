@@ -6,8 +6,8 @@ use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 use sp_api::ProvideRuntimeApi;
 
-use pallet_reactions::{ReactionId, ReactionKind, rpc::FlatReaction};
-use pallet_utils::{PostId, rpc::map_rpc_error};
+use pallet_reactions::{ReactionId, ReactionKind, ReactionTarget, rpc::FlatReaction};
+use pallet_utils::{PostId, SpaceId, rpc::map_rpc_error};
 pub use reactions_runtime_api::ReactionsApi as ReactionsRuntimeApi;
 
 #[rpc]
@@ -35,6 +35,45 @@ pub trait ReactionsApi<BlockHash, AccountId, BlockNumber> {
         post_ids: Vec<PostId>,
         reactor: AccountId,
     ) -> Result<BTreeMap<PostId, ReactionKind>>;
+
+    #[rpc(name = "reactions_getReactionsByTarget")]
+    fn get_reactions_by_target(
+        &self,
+        at: Option<BlockHash>,
+        target: ReactionTarget<AccountId>,
+        limit: u64,
+        offset: u64,
+    ) -> Result<Vec<FlatReaction<AccountId, BlockNumber>>>;
+
+    #[rpc(name = "reactions_getReactionIdsByTargetPage")]
+    fn get_reaction_ids_by_target_page(
+        &self,
+        at: Option<BlockHash>,
+        target: ReactionTarget<AccountId>,
+        cursor: Option<ReactionId>,
+        limit: u16,
+    ) -> Result<Vec<ReactionId>>;
+
+    #[rpc(name = "reactions_getReactionCountsByTarget")]
+    fn get_reaction_counts_by_target(
+        &self,
+        at: Option<BlockHash>,
+        target: ReactionTarget<AccountId>,
+    ) -> Result<BTreeMap<ReactionKind, u32>>;
+
+    #[rpc(name = "reactions_getReactionCountsByPostIds")]
+    fn get_reaction_counts_by_post_ids(
+        &self,
+        at: Option<BlockHash>,
+        post_ids: Vec<PostId>,
+    ) -> Result<BTreeMap<PostId, BTreeMap<ReactionKind, u32>>>;
+
+    #[rpc(name = "reactions_getSpaceReactionKinds")]
+    fn get_space_reaction_kinds(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+    ) -> Result<Vec<Vec<u8>>>;
 }
 
 pub struct Reactions<C, M> {
@@ -98,4 +137,68 @@ where
         let runtime_api_result = api.get_reaction_kinds_by_post_ids_and_reactor(&at, post_ids, reactor);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_reactions_by_target(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        target: ReactionTarget<AccountId>,
+        limit: u64,
+        offset: u64,
+    ) -> Result<Vec<FlatReaction<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_reactions_by_target(&at, target, limit, offset);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_reaction_ids_by_target_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        target: ReactionTarget<AccountId>,
+        cursor: Option<ReactionId>,
+        limit: u16,
+    ) -> Result<Vec<ReactionId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_reaction_ids_by_target_page(&at, target, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_reaction_counts_by_target(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        target: ReactionTarget<AccountId>,
+    ) -> Result<BTreeMap<ReactionKind, u32>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_reaction_counts_by_target(&at, target);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_reaction_counts_by_post_ids(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        post_ids: Vec<PostId>,
+    ) -> Result<BTreeMap<PostId, BTreeMap<ReactionKind, u32>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_reaction_counts_by_post_ids(&at, post_ids);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_space_reaction_kinds(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        space_id: SpaceId,
+    ) -> Result<Vec<Vec<u8>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_space_reaction_kinds(&at, space_id);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -0,0 +1,133 @@
+//! Reactions pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_support::traits::Currency;
+use sp_runtime::traits::Bounded;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use pallet_posts::{Pallet as Posts, PostExtension};
+use pallet_utils::{BalanceOf, mock_functions::valid_content_ipfs};
+
+fn fund_account<T: Config>(account: &T::AccountId) {
+    <T as pallet_utils::Config>::Currency::make_free_balance_be(account, BalanceOf::<T>::max_value());
+}
+
+fn create_space<T: Config>(owner: T::AccountId) -> Result<SpaceId, &'static str> {
+    let space_id = Spaces::<T>::next_space_id();
+    fund_account::<T>(&owner);
+    Spaces::<T>::create_space(RawOrigin::Signed(owner).into(), None, None, valid_content_ipfs(), None)?;
+
+    Ok(space_id)
+}
+
+fn create_post<T: Config>(owner: T::AccountId) -> Result<PostId, &'static str> {
+    let space_id = create_space::<T>(owner.clone())?;
+
+    let post_id = Posts::<T>::next_post_id();
+    Posts::<T>::create_post(
+        RawOrigin::Signed(owner).into(),
+        Some(space_id),
+        PostExtension::RegularPost,
+        valid_content_ipfs()
+    )?;
+
+    Ok(post_id)
+}
+
+fn reaction_owner<T: Config>() -> T::AccountId {
+    let owner: T::AccountId = account("reaction_owner", 0, 0);
+    fund_account::<T>(&owner);
+    owner
+}
+
+/// Create an upvote on a given target on behalf of `reaction_owner`.
+fn create_reaction<T: Config>(target: ReactionTarget<T::AccountId>) -> Result<ReactionId, &'static str> {
+    let reaction_id = Pallet::<T>::next_reaction_id();
+    Pallet::<T>::create_reaction(RawOrigin::Signed(reaction_owner::<T>()).into(), target, ReactionKind::Upvote)?;
+
+    Ok(reaction_id)
+}
+
+benchmarks! {
+    create_post_reaction {
+        let caller: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&caller);
+        let post_id = create_post::<T>(reaction_owner::<T>())?;
+        let reaction_id = Pallet::<T>::next_reaction_id();
+    }: _(RawOrigin::Signed(caller.clone()), post_id, ReactionKind::Upvote)
+    verify {
+        let target = ReactionTarget::Post(post_id);
+        assert_eq!(Pallet::<T>::reaction_id_by_account_and_target((caller, target)), Some(reaction_id));
+    }
+
+    update_post_reaction {
+        let caller: T::AccountId = reaction_owner::<T>();
+        let post_id = create_post::<T>(whitelisted_caller())?;
+        let reaction_id = create_reaction::<T>(ReactionTarget::Post(post_id))?;
+    }: _(RawOrigin::Signed(caller), post_id, reaction_id, ReactionKind::Downvote)
+    verify {
+        let reaction = Pallet::<T>::reaction_by_id(reaction_id).ok_or("Reaction was not found")?;
+        assert_eq!(reaction.kind, ReactionKind::Downvote);
+    }
+
+    delete_post_reaction {
+        let caller: T::AccountId = reaction_owner::<T>();
+        let post_id = create_post::<T>(whitelisted_caller())?;
+        let reaction_id = create_reaction::<T>(ReactionTarget::Post(post_id))?;
+    }: _(RawOrigin::Signed(caller), post_id, reaction_id)
+    verify {
+        assert!(Pallet::<T>::reaction_by_id(reaction_id).is_none());
+    }
+
+    create_reaction {
+        let caller: T::AccountId = whitelisted_caller();
+        fund_account::<T>(&caller);
+        let space_id = create_space::<T>(reaction_owner::<T>())?;
+        let target = ReactionTarget::Space(space_id);
+        let reaction_id = Pallet::<T>::next_reaction_id();
+    }: _(RawOrigin::Signed(caller.clone()), target.clone(), ReactionKind::Upvote)
+    verify {
+        assert_eq!(Pallet::<T>::reaction_id_by_account_and_target((caller, target)), Some(reaction_id));
+    }
+
+    update_reaction {
+        let caller: T::AccountId = reaction_owner::<T>();
+        let space_id = create_space::<T>(whitelisted_caller())?;
+        let target = ReactionTarget::Space(space_id);
+        let reaction_id = create_reaction::<T>(target.clone())?;
+    }: _(RawOrigin::Signed(caller), target, reaction_id, ReactionKind::Downvote)
+    verify {
+        let reaction = Pallet::<T>::reaction_by_id(reaction_id).ok_or("Reaction was not found")?;
+        assert_eq!(reaction.kind, ReactionKind::Downvote);
+    }
+
+    delete_reaction {
+        let caller: T::AccountId = reaction_owner::<T>();
+        let space_id = create_space::<T>(whitelisted_caller())?;
+        let target = ReactionTarget::Space(space_id);
+        let reaction_id = create_reaction::<T>(target.clone())?;
+    }: _(RawOrigin::Signed(caller), target, reaction_id)
+    verify {
+        assert!(Pallet::<T>::reaction_by_id(reaction_id).is_none());
+    }
+
+    update_space_reaction_kinds {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+
+        let max_kind_len = T::MaxCustomReactionKindLen::get() as usize;
+        let kinds: Vec<Vec<u8>> = (0..T::MaxCustomReactionKinds::get())
+            .map(|i| {
+                let mut kind = i.to_le_bytes().to_vec();
+                kind.resize(max_kind_len, b'k');
+                kind
+            })
+            .collect();
+    }: _(RawOrigin::Signed(caller), space_id, kinds.clone())
+    verify {
+        assert_eq!(Pallet::<T>::reaction_kinds_by_space_id(space_id), kinds);
+    }
+}
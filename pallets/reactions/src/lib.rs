@@ -1,35 +1,53 @@
+//! # Reactions Module
+//!
+//! Reactions can be left on posts (including comments), spaces and profiles.
+//! Besides of upvotes and downvotes, an account can react with a custom kind (e.g. an emoji)
+//! from the set of kinds that a space allows. Profiles can be only upvoted or downvoted.
+//!
+//! The number of reactions of each kind is counted per reacted entity.
+
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure,
-    dispatch::DispatchResult,
+    ensure,
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
     traits::Get
 };
-use frame_system::{self as system, ensure_signed};
+use frame_system::ensure_signed;
 
 #[cfg(feature = "std")]
-use serde::Deserialize;
+use serde::{Serialize, Deserialize};
 use sp_runtime::{RuntimeDebug, DispatchError};
-use sp_std::prelude::*;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
-use df_traits::moderation::IsAccountBlocked;
+use df_traits::{BeforeSpaceDeleted, moderation::IsAccountBlocked};
 use pallet_permissions::SpacePermission;
-use pallet_posts::{Module as Posts, PostById};
-use pallet_spaces::Module as Spaces;
-use pallet_utils::{Error as UtilsError, remove_from_vec, WhoAndWhen, PostId};
+use pallet_posts::{Pallet as Posts, Post, AfterPostDeleted};
+use pallet_profiles::{Pallet as Profiles, Error as ProfilesError};
+use pallet_spaces::Pallet as Spaces;
+use pallet_utils::{Pallet as Utils, Error as UtilsError, StorageItem, WhoAndWhen, PostId, SpaceId};
 
+pub mod migrations;
 pub mod rpc;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 pub type ReactionId = u64;
 
-#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Deserialize))]
 #[cfg_attr(feature = "std", serde(untagged))]
 pub enum ReactionKind {
     Upvote,
     Downvote,
+    /// A custom reaction kind (e.g. an emoji) that is allowed in a space
+    /// by `ReactionKindsBySpaceId`.
+    Custom(Vec<u8>),
 }
 
 impl Default for ReactionKind {
@@ -38,6 +56,16 @@ impl Default for ReactionKind {
     }
 }
 
+/// An entity that can be reacted to.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ReactionTarget<AccountId> {
+    Post(PostId),
+    Space(SpaceId),
+    /// A profile of a given account.
+    Profile(AccountId),
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct Reaction<T: Config> {
@@ -51,48 +79,119 @@ pub struct Reaction<T: Config> {
     pub kind: ReactionKind,
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_posts::Config
-    + pallet_spaces::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
-}
+pub use pallet::*;
 
-pub const FIRST_REACTION_ID: u64 = 1;
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
 
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as ReactionsModule {
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
-        /// The next reaction id.
-        pub NextReactionId get(fn next_reaction_id): ReactionId = FIRST_REACTION_ID;
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_posts::Config
+        + pallet_profiles::Config
+        + pallet_spaces::Config
+    {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
 
-        pub ReactionById get(fn reaction_by_id):
-            map hasher(twox_64_concat) ReactionId => Option<Reaction<T>>;
+        /// The maximum number of custom reaction kinds that can be allowed in a space.
+        #[pallet::constant]
+        type MaxCustomReactionKinds: Get<u32>;
 
-        pub ReactionIdsByPostId get(fn reaction_ids_by_post_id):
-            map hasher(twox_64_concat) PostId => Vec<ReactionId>;
+        /// The maximum length of a custom reaction kind in bytes.
+        #[pallet::constant]
+        type MaxCustomReactionKindLen: Get<u32>;
 
-        pub PostReactionIdByAccount get(fn post_reaction_id_by_account):
-            map hasher(twox_64_concat) (T::AccountId, PostId) => ReactionId;
+        type ReactionScores: ReactionScores<Self>;
     }
-}
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId,
-    {
-        PostReactionCreated(AccountId, PostId, ReactionId, ReactionKind),
-        PostReactionUpdated(AccountId, PostId, ReactionId, ReactionKind),
-        PostReactionDeleted(AccountId, PostId, ReactionId, ReactionKind),
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<ReactionById<T>, Reaction<T>>()
+        }
+    }
+
+    #[pallet::type_value]
+    pub fn DefaultForNextReactionId() -> ReactionId {
+        FIRST_REACTION_ID
+    }
+
+    /// The next reaction id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_reaction_id)]
+    pub type NextReactionId<T: Config> =
+        StorageValue<_, ReactionId, ValueQuery, DefaultForNextReactionId>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reaction_by_id)]
+    pub type ReactionById<T: Config> =
+        StorageMap<_, Twox64Concat, ReactionId, Reaction<T>, OptionQuery>;
+
+    /// Whether a reaction was left on a given entity, by (reacted entity, reaction id).
+    #[pallet::storage]
+    pub type ReactionIdsByTarget<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, ReactionTarget<T::AccountId>, Twox64Concat, ReactionId, bool, ValueQuery>;
+
+    /// Get the id of a reaction that an account left on a given entity.
+    #[pallet::storage]
+    #[pallet::getter(fn reaction_id_by_account_and_target)]
+    pub type ReactionIdByAccountAndTarget<T: Config> =
+        StorageMap<_, Twox64Concat, (T::AccountId, ReactionTarget<T::AccountId>), ReactionId, OptionQuery>;
+
+    /// Get the number of reactions of each kind on a given entity.
+    #[pallet::storage]
+    #[pallet::getter(fn reaction_counts_by_target)]
+    pub type ReactionCountsByTarget<T: Config> =
+        StorageMap<_, Twox64Concat, ReactionTarget<T::AccountId>, BTreeMap<ReactionKind, u32>, ValueQuery>;
+
+    /// Get the custom reaction kinds that are allowed in a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn reaction_kinds_by_space_id)]
+    pub type ReactionKindsBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, Vec<Vec<u8>>, ValueQuery>;
+
+    // TODO delete these storages and corresponding migration, after the migration executed.
+    /// Deprecated: moved to `ReactionIdsByTarget`.
+    #[pallet::storage]
+    pub type ReactionIdsByPostId<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, Vec<ReactionId>, ValueQuery>;
+
+    /// Deprecated: moved to `ReactionIdByAccountAndTarget`.
+    #[pallet::storage]
+    pub type PostReactionIdByAccount<T: Config> =
+        StorageMap<_, Twox64Concat, (T::AccountId, PostId), ReactionId, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        PostReactionCreated(T::AccountId, PostId, ReactionId, ReactionKind),
+        PostReactionUpdated(T::AccountId, PostId, ReactionId, ReactionKind),
+        PostReactionDeleted(T::AccountId, PostId, ReactionId, ReactionKind),
+        ReactionCreated(T::AccountId, ReactionTarget<T::AccountId>, ReactionId, ReactionKind),
+        ReactionUpdated(T::AccountId, ReactionTarget<T::AccountId>, ReactionId, ReactionKind),
+        ReactionDeleted(T::AccountId, ReactionTarget<T::AccountId>, ReactionId, ReactionKind),
+        SpaceReactionKindsUpdated(T::AccountId, SpaceId),
     }
-);
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    #[pallet::error]
+    pub enum Error<T> {
         /// Reaction was not found by id.
         ReactionNotFound,
         /// Account has already reacted to this post/comment.
@@ -113,157 +212,398 @@ decl_error! {
         NoPermissionToUpvote,
         /// User has no permission to downvote posts/comments in this space.
         NoPermissionToDownvote,
+        /// User has no permission to react with custom reaction kinds in this space.
+        NoPermissionToReact,
+
+        /// This custom reaction kind is not allowed here.
+        CustomReactionKindNotAllowed,
+        /// Too many custom reaction kinds provided for a space.
+        TooManyCustomReactionKinds,
+        /// Custom reaction kind is empty, too long or duplicated.
+        InvalidCustomReactionKind,
+        /// User has no permission to update custom reaction kinds of this space.
+        NoPermissionToUpdateReactionKinds,
     }
-}
 
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
-
-    // Initializing errors
-    type Error = Error<T>;
-
-    // Initializing events
-    fn deposit_event() = default;
-
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(6, 5)]
-    pub fn create_post_reaction(origin, post_id: PostId, kind: ReactionKind) -> DispatchResult {
-      let owner = ensure_signed(origin)?;
-
-      let post = &mut Posts::require_post(post_id)?;
-      ensure!(
-        !<PostReactionIdByAccount<T>>::contains_key((owner.clone(), post_id)),
-        Error::<T>::AccountAlreadyReacted
-      );
-
-      let space = post.get_space()?;
-      ensure!(!space.hidden, Error::<T>::CannotReactWhenSpaceHidden);
-      ensure!(Posts::<T>::is_root_post_visible(post_id)?, Error::<T>::CannotReactWhenPostHidden);
-
-      ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
-
-      match kind {
-        ReactionKind::Upvote => {
-          Spaces::ensure_account_has_space_permission(
-            owner.clone(),
-            &post.get_space()?,
-            SpacePermission::Upvote,
-            Error::<T>::NoPermissionToUpvote.into()
-          )?;
-          post.inc_upvotes();
-        },
-        ReactionKind::Downvote => {
-          Spaces::ensure_account_has_space_permission(
-            owner.clone(),
-            &post.get_space()?,
-            SpacePermission::Downvote,
-            Error::<T>::NoPermissionToDownvote.into()
-          )?;
-          post.inc_downvotes();
-        }
-      }
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(<T as Config>::WeightInfo::create_post_reaction())]
+        pub fn create_post_reaction(origin: OriginFor<T>, post_id: PostId, kind: ReactionKind) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      <PostById<T>>::insert(post_id, post.clone());
-      let reaction_id = Self::insert_new_reaction(owner.clone(), kind);
-      ReactionIdsByPostId::mutate(post.id, |ids| ids.push(reaction_id));
-      <PostReactionIdByAccount<T>>::insert((owner.clone(), post_id), reaction_id);
+            let reaction_id = Self::do_create_reaction(owner.clone(), ReactionTarget::Post(post_id), kind.clone())?;
 
-      Self::deposit_event(RawEvent::PostReactionCreated(owner, post_id, reaction_id, kind));
-      Ok(())
-    }
+            Self::deposit_event(Event::PostReactionCreated(owner, post_id, reaction_id, kind));
+            Ok(().into())
+        }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(3, 2)]
-    pub fn update_post_reaction(origin, post_id: PostId, reaction_id: ReactionId, new_kind: ReactionKind) -> DispatchResult {
-      let owner = ensure_signed(origin)?;
+        #[pallet::weight(<T as Config>::WeightInfo::update_post_reaction())]
+        pub fn update_post_reaction(origin: OriginFor<T>, post_id: PostId, reaction_id: ReactionId, new_kind: ReactionKind) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      ensure!(
-        <PostReactionIdByAccount<T>>::contains_key((owner.clone(), post_id)),
-        Error::<T>::ReactionByAccountNotFound
-      );
+            Self::do_update_reaction(owner.clone(), ReactionTarget::Post(post_id), reaction_id, new_kind.clone())?;
 
-      let mut reaction = Self::require_reaction(reaction_id)?;
-      let post = &mut Posts::require_post(post_id)?;
+            Self::deposit_event(Event::PostReactionUpdated(owner, post_id, reaction_id, new_kind));
+            Ok(().into())
+        }
 
-      ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
-      ensure!(reaction.kind != new_kind, Error::<T>::SameReaction);
+        #[pallet::weight(<T as Config>::WeightInfo::delete_post_reaction())]
+        pub fn delete_post_reaction(origin: OriginFor<T>, post_id: PostId, reaction_id: ReactionId) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      if let Some(space_id) = post.try_get_space_id() {
-        ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id), UtilsError::<T>::AccountIsBlocked);
-      }
+            let kind = Self::do_delete_reaction(owner.clone(), ReactionTarget::Post(post_id), reaction_id)?;
 
-      reaction.kind = new_kind;
-      reaction.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
+            Self::deposit_event(Event::PostReactionDeleted(owner, post_id, reaction_id, kind));
+            Ok(().into())
+        }
 
-      match new_kind {
-        ReactionKind::Upvote => {
-          post.inc_upvotes();
-          post.dec_downvotes();
-        },
-        ReactionKind::Downvote => {
-          post.inc_downvotes();
-          post.dec_upvotes();
-        },
-      }
+        /// React to a post, a space or a profile.
+        #[pallet::weight(<T as Config>::WeightInfo::create_reaction())]
+        pub fn create_reaction(origin: OriginFor<T>, target: ReactionTarget<T::AccountId>, kind: ReactionKind) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      <ReactionById<T>>::insert(reaction_id, reaction);
-      <PostById<T>>::insert(post_id, post);
+            let reaction_id = Self::do_create_reaction(owner.clone(), target.clone(), kind.clone())?;
 
-      Self::deposit_event(RawEvent::PostReactionUpdated(owner, post_id, reaction_id, new_kind));
-      Ok(())
-    }
+            Self::deposit_event(Event::ReactionCreated(owner, target, reaction_id, kind));
+            Ok(().into())
+        }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(4, 4)]
-    pub fn delete_post_reaction(origin, post_id: PostId, reaction_id: ReactionId) -> DispatchResult {
-      let owner = ensure_signed(origin)?;
+        #[pallet::weight(<T as Config>::WeightInfo::update_reaction())]
+        pub fn update_reaction(
+            origin: OriginFor<T>,
+            target: ReactionTarget<T::AccountId>,
+            reaction_id: ReactionId,
+            new_kind: ReactionKind
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      ensure!(
-        <PostReactionIdByAccount<T>>::contains_key((owner.clone(), post_id)),
-        Error::<T>::ReactionByAccountNotFound
-      );
+            Self::do_update_reaction(owner.clone(), target.clone(), reaction_id, new_kind.clone())?;
 
-      // TODO extract Self::require_reaction(reaction_id)?;
-      let reaction = Self::require_reaction(reaction_id)?;
-      let post = &mut Posts::require_post(post_id)?;
+            Self::deposit_event(Event::ReactionUpdated(owner, target, reaction_id, new_kind));
+            Ok(().into())
+        }
 
-      ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
-      if let Some(space_id) = post.try_get_space_id() {
-        ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id), UtilsError::<T>::AccountIsBlocked);
-      }
+        #[pallet::weight(<T as Config>::WeightInfo::delete_reaction())]
+        pub fn delete_reaction(origin: OriginFor<T>, target: ReactionTarget<T::AccountId>, reaction_id: ReactionId) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      match reaction.kind {
-        ReactionKind::Upvote => post.dec_upvotes(),
-        ReactionKind::Downvote => post.dec_downvotes(),
-      }
+            let kind = Self::do_delete_reaction(owner.clone(), target.clone(), reaction_id)?;
 
-      <PostById<T>>::insert(post_id, post.clone());
-      <ReactionById<T>>::remove(reaction_id);
-      ReactionIdsByPostId::mutate(post.id, |ids| remove_from_vec(ids, reaction_id));
-      <PostReactionIdByAccount<T>>::remove((owner.clone(), post_id));
+            Self::deposit_event(Event::ReactionDeleted(owner, target, reaction_id, kind));
+            Ok(().into())
+        }
 
-      Self::deposit_event(RawEvent::PostReactionDeleted(owner, post_id, reaction_id, reaction.kind));
-      Ok(())
+        /// Set custom reaction kinds (e.g. emojis) that can be used in a space,
+        /// in addition to upvotes and downvotes. Pass an empty list to allow only upvotes and downvotes.
+        #[pallet::weight(<T as Config>::WeightInfo::update_space_reaction_kinds())]
+        pub fn update_space_reaction_kinds(origin: OriginFor<T>, space_id: SpaceId, kinds: Vec<Vec<u8>>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                kinds.len() <= T::MaxCustomReactionKinds::get() as usize,
+                Error::<T>::TooManyCustomReactionKinds
+            );
+
+            let max_kind_len = T::MaxCustomReactionKindLen::get() as usize;
+            for (i, kind) in kinds.iter().enumerate() {
+                ensure!(
+                    !kind.is_empty() && kind.len() <= max_kind_len && !kinds[..i].contains(kind),
+                    Error::<T>::InvalidCustomReactionKind
+                );
+            }
+
+            let space = Spaces::<T>::require_space(space_id)?;
+            Spaces::ensure_account_has_space_permission(
+                who.clone(),
+                &space,
+                SpacePermission::UpdateSpaceSettings,
+                Error::<T>::NoPermissionToUpdateReactionKinds.into()
+            )?;
+
+            if kinds.is_empty() {
+                ReactionKindsBySpaceId::<T>::remove(space_id);
+            } else {
+                ReactionKindsBySpaceId::<T>::insert(space_id, kinds);
+            }
+
+            Self::deposit_event(Event::SpaceReactionKindsUpdated(who, space_id));
+            Ok(().into())
+        }
     }
-  }
 }
 
-impl<T: Config> Module<T> {
+/// Handler that will be called after a reaction is created or before it is removed.
+pub trait ReactionScores<T: Config> {
+    fn score_on_reaction(actor: T::AccountId, target: &ReactionTarget<T::AccountId>, kind: &ReactionKind);
+    fn revert_score_on_reaction(actor: T::AccountId, target: &ReactionTarget<T::AccountId>, kind: &ReactionKind);
+}
+
+impl<T: Config> ReactionScores<T> for () {
+    fn score_on_reaction(_actor: T::AccountId, _target: &ReactionTarget<T::AccountId>, _kind: &ReactionKind) {}
+    fn revert_score_on_reaction(_actor: T::AccountId, _target: &ReactionTarget<T::AccountId>, _kind: &ReactionKind) {}
+}
+
+pub const FIRST_REACTION_ID: u64 = 1;
+
+impl<T: Config> Pallet<T> {
 
-    pub fn insert_new_reaction(account: T::AccountId, kind: ReactionKind) -> ReactionId {
+    /// Insert a new reaction and reserve a storage deposit for it from its owner.
+    pub fn insert_new_reaction(account: T::AccountId, kind: ReactionKind) -> Result<ReactionId, DispatchError> {
         let id = Self::next_reaction_id();
         let reaction: Reaction<T> = Reaction {
             id,
-            created: WhoAndWhen::<T>::new(account),
+            created: WhoAndWhen::<T>::new(account.clone()),
             updated: None,
             kind,
         };
 
-        <ReactionById<T>>::insert(id, reaction);
-        NextReactionId::mutate(|n| { *n += 1; });
+        Utils::<T>::reserve_storage_deposit(&account, StorageItem::Reaction(id), &reaction)?;
+
+        ReactionById::<T>::insert(id, reaction);
+        NextReactionId::<T>::mutate(|n| { *n += 1; });
 
-        id
+        Ok(id)
     }
 
     /// Get `Reaction` by id from the storage or return `ReactionNotFound` error.
     pub fn require_reaction(reaction_id: ReactionId) -> Result<Reaction<T>, DispatchError> {
         Ok(Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?)
     }
+
+    /// Get the ids of all reactions on a given entity, sorted by id.
+    pub fn reaction_ids_by_target(target: ReactionTarget<T::AccountId>) -> Vec<ReactionId> {
+        let mut reaction_ids: Vec<ReactionId> = ReactionIdsByTarget::<T>::iter_prefix(target)
+            .map(|(reaction_id, _)| reaction_id)
+            .collect();
+
+        reaction_ids.sort_unstable();
+        reaction_ids
+    }
+
+    pub fn reaction_ids_by_post_id(post_id: PostId) -> Vec<ReactionId> {
+        Self::reaction_ids_by_target(ReactionTarget::Post(post_id))
+    }
+
+    pub fn post_reaction_id_by_account(account: T::AccountId, post_id: PostId) -> Option<ReactionId> {
+        Self::reaction_id_by_account_and_target((account, ReactionTarget::Post(post_id)))
+    }
+
+    fn do_create_reaction(
+        owner: T::AccountId,
+        target: ReactionTarget<T::AccountId>,
+        kind: ReactionKind,
+    ) -> Result<ReactionId, DispatchError> {
+        let account_and_target = (owner.clone(), target.clone());
+        ensure!(
+          !ReactionIdByAccountAndTarget::<T>::contains_key(&account_and_target),
+          Error::<T>::AccountAlreadyReacted
+        );
+
+        Self::ensure_account_can_react(&owner, &target, &kind)?;
+
+        let reaction_id = Self::insert_new_reaction(owner.clone(), kind.clone())?;
+
+        Self::inc_reaction_counters(&target, &kind);
+        T::ReactionScores::score_on_reaction(owner, &target, &kind);
+
+        ReactionIdsByTarget::<T>::insert(&target, reaction_id, true);
+        ReactionIdByAccountAndTarget::<T>::insert(account_and_target, reaction_id);
+
+        Ok(reaction_id)
+    }
+
+    fn do_update_reaction(
+        owner: T::AccountId,
+        target: ReactionTarget<T::AccountId>,
+        reaction_id: ReactionId,
+        new_kind: ReactionKind,
+    ) -> DispatchResult {
+        let mut reaction = Self::require_reaction_by_account_and_target(&owner, &target, reaction_id)?;
+        ensure!(reaction.kind != new_kind, Error::<T>::SameReaction);
+
+        Self::ensure_account_can_react(&owner, &target, &new_kind)?;
+
+        let old_kind = sp_std::mem::replace(&mut reaction.kind, new_kind);
+        reaction.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
+
+        // A custom reaction kind can be longer or shorter than the previous one.
+        Utils::<T>::update_storage_deposit(StorageItem::Reaction(reaction_id), &reaction)?;
+
+        Self::dec_reaction_counters(&target, &old_kind);
+        T::ReactionScores::revert_score_on_reaction(owner.clone(), &target, &old_kind);
+
+        Self::inc_reaction_counters(&target, &reaction.kind);
+        T::ReactionScores::score_on_reaction(owner, &target, &reaction.kind);
+
+        ReactionById::<T>::insert(reaction_id, reaction);
+
+        Ok(())
+    }
+
+    /// Delete a reaction and return its kind.
+    fn do_delete_reaction(
+        owner: T::AccountId,
+        target: ReactionTarget<T::AccountId>,
+        reaction_id: ReactionId,
+    ) -> Result<ReactionKind, DispatchError> {
+        let reaction = Self::require_reaction_by_account_and_target(&owner, &target, reaction_id)?;
+
+        if let Some(space_id) = Self::try_get_target_space_id(&target) {
+            ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id), UtilsError::<T>::AccountIsBlocked);
+        }
+
+        Self::dec_reaction_counters(&target, &reaction.kind);
+        T::ReactionScores::revert_score_on_reaction(owner.clone(), &target, &reaction.kind);
+
+        ReactionById::<T>::remove(reaction_id);
+        Utils::<T>::release_storage_deposit(StorageItem::Reaction(reaction_id));
+        ReactionIdsByTarget::<T>::remove(&target, reaction_id);
+        ReactionIdByAccountAndTarget::<T>::remove((owner, target));
+
+        Ok(reaction.kind)
+    }
+
+    /// Get a reaction that an account left on a given entity
+    /// or return `ReactionByAccountNotFound` error.
+    fn require_reaction_by_account_and_target(
+        owner: &T::AccountId,
+        target: &ReactionTarget<T::AccountId>,
+        reaction_id: ReactionId,
+    ) -> Result<Reaction<T>, DispatchError> {
+        let reaction_id_by_account = Self::reaction_id_by_account_and_target((owner.clone(), target.clone()));
+        ensure!(reaction_id_by_account == Some(reaction_id), Error::<T>::ReactionByAccountNotFound);
+
+        let reaction = Self::require_reaction(reaction_id)?;
+        ensure!(*owner == reaction.created.account, Error::<T>::NotReactionOwner);
+
+        Ok(reaction)
+    }
+
+    fn try_get_target_space_id(target: &ReactionTarget<T::AccountId>) -> Option<SpaceId> {
+        match target {
+            ReactionTarget::Post(post_id) =>
+                Posts::<T>::post_by_id(post_id).and_then(|post| post.try_get_space_id()),
+            ReactionTarget::Space(space_id) => Some(*space_id),
+            ReactionTarget::Profile(_) => None,
+        }
+    }
+
+    fn ensure_account_can_react(
+        account: &T::AccountId,
+        target: &ReactionTarget<T::AccountId>,
+        kind: &ReactionKind,
+    ) -> DispatchResult {
+        let (permission, error) = match kind {
+            ReactionKind::Upvote => (SpacePermission::Upvote, Error::<T>::NoPermissionToUpvote),
+            ReactionKind::Downvote => (SpacePermission::Downvote, Error::<T>::NoPermissionToDownvote),
+            ReactionKind::Custom(_) => (SpacePermission::React, Error::<T>::NoPermissionToReact),
+        };
+
+        match target {
+            ReactionTarget::Post(post_id) => {
+                let post = Posts::<T>::require_post(*post_id)?;
+                let space = post.get_space()?;
+                ensure!(!space.hidden, Error::<T>::CannotReactWhenSpaceHidden);
+                ensure!(Posts::<T>::is_root_post_visible(*post_id)?, Error::<T>::CannotReactWhenPostHidden);
+
+                ensure!(T::IsAccountBlocked::is_allowed_account(account.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+                Self::ensure_reaction_kind_allowed_in_space(kind, space.id)?;
+
+                Posts::ensure_account_has_post_permission(account.clone(), &post, &space, permission, error.into())
+            },
+            ReactionTarget::Space(space_id) => {
+                let space = Spaces::<T>::require_space(*space_id)?;
+                ensure!(!space.hidden, Error::<T>::CannotReactWhenSpaceHidden);
+
+                ensure!(T::IsAccountBlocked::is_allowed_account(account.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+                Self::ensure_reaction_kind_allowed_in_space(kind, space.id)?;
+
+                Spaces::ensure_account_has_space_permission(account.clone(), &space, permission, error.into())
+            },
+            ReactionTarget::Profile(profile_owner) => {
+                let has_profile = Profiles::<T>::social_account_by_id(profile_owner)
+                    .map_or(false, |social_account| social_account.profile.is_some());
+                ensure!(has_profile, ProfilesError::<T>::AccountHasNoProfile);
+
+                // Only upvotes and downvotes are allowed on profiles, as there is no space
+                // that could configure custom reaction kinds for them.
+                ensure!(!matches!(kind, ReactionKind::Custom(_)), Error::<T>::CustomReactionKindNotAllowed);
+                Ok(())
+            },
+        }
+    }
+
+    fn ensure_reaction_kind_allowed_in_space(kind: &ReactionKind, space_id: SpaceId) -> DispatchResult {
+        if let ReactionKind::Custom(custom_kind) = kind {
+            ensure!(
+                Self::reaction_kinds_by_space_id(space_id).contains(custom_kind),
+                Error::<T>::CustomReactionKindNotAllowed
+            );
+        }
+        Ok(())
+    }
+
+    fn inc_reaction_counters(target: &ReactionTarget<T::AccountId>, kind: &ReactionKind) {
+        ReactionCountsByTarget::<T>::mutate(target, |counts| {
+            let count = counts.get(kind).copied().unwrap_or_default();
+            counts.insert(kind.clone(), count.saturating_add(1));
+        });
+
+        if let ReactionTarget::Post(post_id) = target {
+            let _ = Posts::<T>::mutate_post_by_id(*post_id, |post| match kind {
+                ReactionKind::Upvote => post.inc_upvotes(),
+                ReactionKind::Downvote => post.inc_downvotes(),
+                ReactionKind::Custom(_) => (),
+            });
+        }
+    }
+
+    fn dec_reaction_counters(target: &ReactionTarget<T::AccountId>, kind: &ReactionKind) {
+        ReactionCountsByTarget::<T>::mutate(target, |counts| {
+            let count = counts.get(kind).copied().unwrap_or_default().saturating_sub(1);
+            if count == 0 {
+                counts.remove(kind);
+            } else {
+                counts.insert(kind.clone(), count);
+            }
+        });
+
+        if let ReactionTarget::Post(post_id) = target {
+            let _ = Posts::<T>::mutate_post_by_id(*post_id, |post| match kind {
+                ReactionKind::Upvote => post.dec_upvotes(),
+                ReactionKind::Downvote => post.dec_downvotes(),
+                ReactionKind::Custom(_) => (),
+            });
+        }
+    }
+
+    /// Remove all reactions that were left on a given entity.
+    fn remove_reactions_by_target(target: ReactionTarget<T::AccountId>) {
+        for (reaction_id, _) in ReactionIdsByTarget::<T>::drain_prefix(&target) {
+            if let Some(reaction) = ReactionById::<T>::take(reaction_id) {
+                ReactionIdByAccountAndTarget::<T>::remove((reaction.created.account, target.clone()));
+                Utils::<T>::release_storage_deposit(StorageItem::Reaction(reaction_id));
+            }
+        }
+        ReactionCountsByTarget::<T>::remove(target);
+    }
+}
+
+impl<T: Config> AfterPostDeleted<T> for Pallet<T> {
+    fn after_post_deleted(post: &Post<T>) {
+        Self::remove_reactions_by_target(ReactionTarget::Post(post.id));
+    }
+}
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+    fn items_to_clean_up(space_id: SpaceId) -> u32 {
+        Self::reaction_counts_by_target(ReactionTarget::Space(space_id))
+            .values()
+            .fold(0u32, |total, count| total.saturating_add(*count))
+    }
+
+    fn before_space_deleted(space_id: SpaceId) {
+        Self::remove_reactions_by_target(ReactionTarget::Space(space_id));
+        ReactionKindsBySpaceId::<T>::remove(space_id);
+    }
 }
@@ -0,0 +1,36 @@
+
+use super::*;
+
+/// Move post reactions from the deprecated `ReactionIdsByPostId` and `PostReactionIdByAccount`
+/// storages to the generic ones, and count the reactions of each kind per post.
+pub fn move_post_reactions_to_generic_storages<T: Config>() -> frame_support::weights::Weight {
+    let mut reads: u64 = 0;
+    let mut writes: u64 = 0;
+
+    for (post_id, reaction_ids) in ReactionIdsByPostId::<T>::drain() {
+        let target = ReactionTarget::Post(post_id);
+
+        let mut counts = BTreeMap::<ReactionKind, u32>::new();
+        for reaction_id in reaction_ids.iter() {
+            if let Some(reaction) = Pallet::<T>::reaction_by_id(reaction_id) {
+                let count = counts.entry(reaction.kind).or_default();
+                *count = count.saturating_add(1);
+            }
+        }
+        reads += 1 + reaction_ids.len() as u64;
+
+        ReactionCountsByTarget::<T>::insert(&target, counts);
+        for reaction_id in reaction_ids.iter() {
+            ReactionIdsByTarget::<T>::insert(&target, reaction_id, true);
+        }
+        writes += 2 + reaction_ids.len() as u64;
+    }
+
+    for ((account, post_id), reaction_id) in PostReactionIdByAccount::<T>::drain() {
+        ReactionIdByAccountAndTarget::<T>::insert((account, ReactionTarget::Post(post_id)), reaction_id);
+        reads += 1;
+        writes += 2;
+    }
+
+    T::DbWeight::get().reads_writes(reads, writes)
+}
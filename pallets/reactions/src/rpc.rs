@@ -5,9 +5,9 @@ use sp_runtime::traits::Zero;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::prelude::*;
 
-use pallet_utils::{PostId, rpc::FlatWhoAndWhen};
+use pallet_utils::{paginate_double_map_keys, PostId, SpaceId, rpc::FlatWhoAndWhen};
 
-use crate::{Module, Reaction, ReactionId, ReactionKind, Config};
+use crate::{Pallet, Reaction, ReactionId, ReactionIdsByTarget, ReactionKind, ReactionTarget, Config};
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
@@ -22,9 +22,10 @@ pub struct FlatReaction<AccountId, BlockNumber> {
 #[cfg(feature = "std")]
 impl Serialize for ReactionKind {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-        let reaction_kind_bytes: &[u8; 1] = match self {
+        let reaction_kind_bytes: &[u8] = match self {
             ReactionKind::Upvote => b"U",
-            ReactionKind::Downvote => b"D"
+            ReactionKind::Downvote => b"D",
+            ReactionKind::Custom(kind) => kind,
         };
 
         serializer.serialize_str(
@@ -45,7 +46,7 @@ impl<T: Config> From<Reaction<T>> for FlatReaction<T::AccountId, T::BlockNumber>
     }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
     pub fn get_reactions_by_ids(
         reaction_ids: Vec<ReactionId>
     ) -> Vec<FlatReaction<T::AccountId, T::BlockNumber>> {
@@ -59,10 +60,18 @@ impl<T: Config> Module<T> {
         post_id: PostId,
         limit: u64,
         offset: u64,
+    ) -> Vec<FlatReaction<T::AccountId, T::BlockNumber>> {
+        Self::get_reactions_by_target(ReactionTarget::Post(post_id), limit, offset)
+    }
+
+    pub fn get_reactions_by_target(
+        target: ReactionTarget<T::AccountId>,
+        limit: u64,
+        offset: u64,
     ) -> Vec<FlatReaction<T::AccountId, T::BlockNumber>> {
         let mut reactions = Vec::new();
 
-        let reaction_ids: Vec<PostId> = Self::reaction_ids_by_post_id(&post_id);
+        let reaction_ids: Vec<ReactionId> = Self::reaction_ids_by_target(target);
         let mut i = reaction_ids.len().saturating_sub(1 + offset as usize);
 
         while reactions.len() < limit as usize {
@@ -80,19 +89,44 @@ impl<T: Config> Module<T> {
         reactions
     }
 
+    /// Get up to `limit` reaction ids on a given entity, starting after the `cursor` reaction id.
+    pub fn get_reaction_ids_by_target_page(
+        target: ReactionTarget<T::AccountId>,
+        cursor: Option<ReactionId>,
+        limit: u16,
+    ) -> Vec<ReactionId> {
+        paginate_double_map_keys::<ReactionIdsByTarget<T>, _, _, _>(&target, cursor, limit.into())
+    }
+
     pub fn get_reaction_kinds_by_post_ids_and_reactor(
         post_ids: Vec<PostId>,
         reactor: T::AccountId,
     ) -> BTreeMap<PostId, ReactionKind> {
-        let res = post_ids.iter()
+        post_ids.iter()
             .filter_map(|post_id| Some(*post_id).zip(
-                Option::from(Self::post_reaction_id_by_account((&reactor, post_id)))
-                    .filter(|v| *v != 0)
+                Self::post_reaction_id_by_account(reactor.clone(), *post_id)
                     .and_then(|reaction_id|
                         Self::require_reaction(reaction_id).ok().map(|reaction| reaction.kind)
                     )
-            ));
+            ))
+            .collect()
+    }
+
+    pub fn get_reaction_counts_by_target(
+        target: ReactionTarget<T::AccountId>,
+    ) -> BTreeMap<ReactionKind, u32> {
+        Self::reaction_counts_by_target(target)
+    }
+
+    pub fn get_reaction_counts_by_post_ids(
+        post_ids: Vec<PostId>,
+    ) -> BTreeMap<PostId, BTreeMap<ReactionKind, u32>> {
+        post_ids.into_iter()
+            .map(|post_id| (post_id, Self::reaction_counts_by_target(ReactionTarget::Post(post_id))))
+            .collect()
+    }
 
-        res.clone().collect()
+    pub fn get_space_reaction_kinds(space_id: SpaceId) -> Vec<Vec<u8>> {
+        Self::reaction_kinds_by_space_id(space_id)
     }
 }
\ No newline at end of file
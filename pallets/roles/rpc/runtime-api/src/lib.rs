@@ -2,7 +2,8 @@
 
 use codec::Codec;
 use sp_std::vec::Vec;
-use pallet_utils::SpaceId;
+use pallet_roles::RoleId;
+use pallet_utils::{SpaceId, User};
 use pallet_permissions::SpacePermission;
 
 sp_api::decl_runtime_apis! {
@@ -14,5 +15,7 @@ sp_api::decl_runtime_apis! {
         fn get_accounts_with_any_role_in_space(space_id: SpaceId) -> Vec<AccountId>;
 
         fn get_space_ids_for_account_with_any_role(account_id: AccountId) -> Vec<SpaceId>;
+
+        fn get_users_by_role_id_page(role_id: RoleId, cursor: Option<User<AccountId>>, limit: u16) -> Vec<User<AccountId>>;
     }
 }
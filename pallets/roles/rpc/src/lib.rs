@@ -5,7 +5,8 @@ use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
 use sp_api::ProvideRuntimeApi;
-use pallet_utils::{SpaceId, rpc::map_rpc_error};
+use pallet_roles::RoleId;
+use pallet_utils::{SpaceId, User, rpc::map_rpc_error};
 use pallet_permissions::SpacePermission;
 
 pub use roles_runtime_api::RolesApi as RolesRuntimeApi;
@@ -33,6 +34,15 @@ pub trait RolesApi<BlockHash, AccountId> {
         at: Option<BlockHash>,
         account_id: AccountId
     ) -> Result<Vec<SpaceId>>;
+
+    #[rpc(name = "roles_getUsersByRoleIdPage")]
+    fn get_users_by_role_id_page(
+        &self,
+        at: Option<BlockHash>,
+        role_id: RoleId,
+        cursor: Option<User<AccountId>>,
+        limit: u16,
+    ) -> Result<Vec<User<AccountId>>>;
 }
 
 pub struct Roles<C, M> {
@@ -93,4 +103,18 @@ where
         let runtime_api_result = api.get_space_ids_for_account_with_any_role(&at, account_id);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_users_by_role_id_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        role_id: RoleId,
+        cursor: Option<User<AccountId>>,
+        limit: u16,
+    ) -> Result<Vec<User<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_users_by_role_id_page(&at, role_id, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
@@ -0,0 +1,173 @@
+//! Roles pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use sp_std::vec;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_support::traits::{Currency, Hooks};
+use sp_runtime::traits::{Bounded, One};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use pallet_spaces::Pallet as Spaces;
+use pallet_utils::{BalanceOf, mock_functions::{updated_content_ipfs, valid_content_ipfs}};
+
+fn fund_account<T: Config>(account: &T::AccountId) {
+    <T as pallet_utils::Config>::Currency::make_free_balance_be(account, BalanceOf::<T>::max_value());
+}
+
+fn create_space<T: Config + pallet_spaces::Config>(owner: T::AccountId) -> Result<SpaceId, &'static str> {
+    let space_id = Spaces::<T>::next_space_id();
+    fund_account::<T>(&owner);
+    Spaces::<T>::create_space(RawOrigin::Signed(owner).into(), None, None, valid_content_ipfs(), None)?;
+
+    Ok(space_id)
+}
+
+fn create_role<T: Config>(owner: T::AccountId, space_id: SpaceId) -> Result<RoleId, &'static str> {
+    let role_id = Pallet::<T>::next_role_id();
+    Pallet::<T>::create_role(
+        RawOrigin::Signed(owner).into(),
+        space_id,
+        None,
+        valid_content_ipfs(),
+        vec![SpacePermission::ManageRoles]
+    )?;
+
+    Ok(role_id)
+}
+
+fn role_user<T: Config>() -> User<T::AccountId> {
+    User::Account(account("role_user", 0, 0))
+}
+
+fn role_users<T: Config>(count: u32) -> Vec<User<T::AccountId>> {
+    (0..count).map(|i| User::Account(account("role_user", i, 0))).collect()
+}
+
+benchmarks! {
+    where_clause { where T: pallet_spaces::Config }
+
+    create_role {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = Pallet::<T>::next_role_id();
+    }: _(RawOrigin::Signed(caller), space_id, None, valid_content_ipfs(), vec![SpacePermission::ManageRoles])
+    verify {
+        assert!(Pallet::<T>::role_by_id(role_id).is_some());
+        assert_eq!(Pallet::<T>::role_ids_by_space_id(space_id), vec![role_id]);
+    }
+
+    update_role {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = create_role::<T>(caller.clone(), space_id)?;
+
+        let update = RoleUpdate {
+            disabled: Some(true),
+            content: Some(updated_content_ipfs()),
+            permissions: Some(vec![SpacePermission::CreatePosts].into_iter().collect()),
+        };
+    }: _(RawOrigin::Signed(caller), role_id, update)
+    verify {
+        let role = Pallet::<T>::role_by_id(role_id).ok_or("Role was not found")?;
+        assert!(role.disabled);
+        assert_eq!(role.content, updated_content_ipfs());
+    }
+
+    delete_role {
+        let u in 0 .. T::MaxUsersToProcessPerDeleteRole::get().into();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = create_role::<T>(caller.clone(), space_id)?;
+        if u > 0 {
+            Pallet::<T>::grant_role(RawOrigin::Signed(caller.clone()).into(), role_id, role_users::<T>(u))?;
+        }
+    }: _(RawOrigin::Signed(caller), role_id)
+    verify {
+        assert!(Pallet::<T>::role_by_id(role_id).is_none());
+        assert!(Pallet::<T>::users_by_role_id(role_id).is_empty());
+    }
+
+    grant_role {
+        let u in 1 .. 100;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = create_role::<T>(caller.clone(), space_id)?;
+    }: _(RawOrigin::Signed(caller), role_id, role_users::<T>(u))
+    verify {
+        assert_eq!(Pallet::<T>::users_count_by_role_id(role_id), u);
+        assert_eq!(Pallet::<T>::role_ids_by_user_in_space(role_user::<T>(), space_id), vec![role_id]);
+    }
+
+    revoke_role {
+        let u in 1 .. 100;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = create_role::<T>(caller.clone(), space_id)?;
+        Pallet::<T>::grant_role(RawOrigin::Signed(caller.clone()).into(), role_id, role_users::<T>(u))?;
+    }: _(RawOrigin::Signed(caller), role_id, role_users::<T>(u))
+    verify {
+        assert!(Pallet::<T>::users_by_role_id(role_id).is_empty());
+        assert!(Pallet::<T>::role_ids_by_user_in_space(role_user::<T>(), space_id).is_empty());
+    }
+
+    grant_role_temporarily {
+        let u in 1 .. 100;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = create_role::<T>(caller.clone(), space_id)?;
+        let time_to_live = T::BlockNumber::one();
+    }: _(RawOrigin::Signed(caller), role_id, role_users::<T>(u), time_to_live)
+    verify {
+        assert_eq!(Pallet::<T>::users_count_by_role_id(role_id), u);
+        assert!(Pallet::<T>::role_grant_expires_at(role_id, role_user::<T>()).is_some());
+    }
+
+    remove_expired_grants_and_roles {
+        // Checking the expiration block takes one unit of the limit.
+        let n in 1 .. T::MaxExpiredGrantsToProcessPerBlock::get().saturating_sub(1);
+
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = create_role::<T>(caller.clone(), space_id)?;
+
+        Pallet::<T>::grant_role_temporarily(
+            RawOrigin::Signed(caller).into(),
+            role_id,
+            role_users::<T>(n),
+            T::BlockNumber::one(),
+        )?;
+
+        let expires_at = frame_system::Pallet::<T>::block_number() + T::BlockNumber::one();
+        frame_system::Pallet::<T>::set_block_number(expires_at);
+    }: {
+        Pallet::<T>::on_initialize(expires_at);
+    }
+    verify {
+        assert!(Pallet::<T>::users_by_role_id(role_id).is_empty());
+    }
+
+    continue_role_deletions {
+        let n in 1 .. T::MaxUsersToProcessPerDeleteRole::get().into();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>(caller.clone())?;
+        let role_id = create_role::<T>(caller.clone(), space_id)?;
+
+        // The first `MaxUsersToProcessPerDeleteRole` users are removed by `delete_role` itself.
+        let users_count = u32::from(T::MaxUsersToProcessPerDeleteRole::get()) + n;
+        Pallet::<T>::grant_role(RawOrigin::Signed(caller.clone()).into(), role_id, role_users::<T>(users_count))?;
+        Pallet::<T>::delete_role(RawOrigin::Signed(caller).into(), role_id)?;
+    }: {
+        Pallet::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+    }
+    verify {
+        assert!(Pallet::<T>::role_by_id(role_id).is_none());
+        assert!(Pallet::<T>::users_by_role_id(role_id).is_empty());
+    }
+}
@@ -1,14 +1,285 @@
 use super::*;
 
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, weights::Weight};
+use sp_runtime::traits::{One, Saturating};
+use sp_std::collections::btree_map::BTreeMap;
 use pallet_permissions::SpacePermissionsContext;
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
+
+  /// Get all users (account or space ids) that a given role has been granted to, sorted.
+  pub fn users_by_role_id(role_id: RoleId) -> Vec<User<T::AccountId>> {
+      let mut users: Vec<User<T::AccountId>> = UsersByRoleId::<T>::iter_prefix(role_id)
+          .map(|(user, _)| user)
+          .collect();
+
+      users.sort();
+      users
+  }
+
+  /// Move all roles of a `from` user in a given space to a `to` user.
+  /// Roles that are already granted to the `to` user are only revoked from the `from` user.
+  pub fn move_user_roles_in_space(space_id: SpaceId, from: &User<T::AccountId>, to: &User<T::AccountId>) {
+      for role_id in RoleIdsByUserInSpace::<T>::take(from, space_id) {
+          UsersByRoleId::<T>::remove(role_id, from);
+          let grant_expires_at = RoleGrantExpiresAt::<T>::take(role_id, from);
+
+          if UsersByRoleId::<T>::contains_key(role_id, to) {
+              UsersCountByRoleId::<T>::mutate(role_id, |count| *count = count.saturating_sub(1));
+          } else {
+              UsersByRoleId::<T>::insert(role_id, to, true);
+              RoleIdsByUserInSpace::<T>::mutate(to, space_id, |role_ids| role_ids.push(role_id));
+              Self::set_grant_expiry(role_id, to, grant_expires_at);
+          }
+      }
+
+      Self::update_space_role_holder(from, space_id);
+      Self::update_space_role_holder(to, space_id);
+  }
+
+  /// Grant a role to users, either permanently or until `expires_at` block.
+  pub(crate) fn do_grant_role(
+    who: T::AccountId,
+    role_id: RoleId,
+    users: Vec<User<T::AccountId>>,
+    expires_at: Option<T::BlockNumber>,
+  ) -> DispatchResult {
+
+    ensure!(!users.is_empty(), Error::<T>::NoUsersProvided);
+    let users_set: BTreeSet<User<T::AccountId>> = Utils::<T>::convert_users_vec_to_btree_set(users)?;
+
+    let role = Self::require_role(role_id)?;
+    Self::ensure_role_is_not_being_deleted(role_id)?;
+
+    Self::ensure_role_manager(who.clone(), role.space_id)?;
+
+    for user in users_set.iter() {
+      if let User::Space(holder_id) = user {
+        Self::ensure_space_can_hold_role(*holder_id, role.space_id)?;
+      }
+
+      if !UsersByRoleId::<T>::contains_key(role_id, user) {
+        UsersByRoleId::<T>::insert(role_id, user, true);
+        UsersCountByRoleId::<T>::mutate(role_id, |count| *count = count.saturating_add(1));
+      }
+      if !Self::role_ids_by_user_in_space(user.clone(), role.space_id).contains(&role_id) {
+        RoleIdsByUserInSpace::<T>::mutate(user.clone(), role.space_id, |roles| { roles.push(role_id); })
+      }
+      Self::set_grant_expiry(role_id, user, expires_at);
+      Self::update_space_role_holder(user, role.space_id);
+    }
+
+    Self::deposit_event(Event::RoleGranted(who, role_id, users_set.iter().cloned().collect()));
+    Ok(())
+  }
+
+  /// Make a grant of a role to a user expire at a given block, or make it permanent if `None`.
+  fn set_grant_expiry(role_id: RoleId, user: &User<T::AccountId>, expires_at: Option<T::BlockNumber>) {
+    if let Some(old_expires_at) = RoleGrantExpiresAt::<T>::take(role_id, user) {
+      RoleGrantsExpiringAt::<T>::remove(old_expires_at, (role_id, user.clone()));
+    }
+
+    if let Some(expires_at) = expires_at {
+      RoleGrantExpiresAt::<T>::insert(role_id, user, expires_at);
+      RoleGrantsExpiringAt::<T>::insert(expires_at, (role_id, user.clone()), true);
+    }
+  }
+
+  /// Check whether a grant of a role to a user has expired, but was not removed yet.
+  pub fn is_grant_expired(role_id: RoleId, user: &User<T::AccountId>) -> bool {
+    Self::role_grant_expires_at(role_id, user)
+      .map_or(false, |expires_at| expires_at <= <system::Pallet<T>>::block_number())
+  }
+
+  /// Remove the role grants and delete the roles that expired by the block `now`.
+  ///
+  /// Every checked block, removed grant and deleted role or revoked user of an expired role
+  /// counts towards `MaxExpiredGrantsToProcessPerBlock`. Whatever doesn't fit into this limit
+  /// is processed in the next blocks.
+  pub(crate) fn remove_expired_grants_and_roles(now: T::BlockNumber) -> Weight {
+    let mut budget = T::MaxExpiredGrantsToProcessPerBlock::get() as usize;
+    let mut block = NextRoleExpirationBlock::<T>::get().unwrap_or(now);
+    let mut processed: usize = 0;
+
+    while block <= now && budget > 0 {
+      budget -= 1;
+      processed += 1;
+
+      let grants: Vec<_> = RoleGrantsExpiringAt::<T>::iter_key_prefix(block).take(budget).collect();
+      budget = budget.saturating_sub(grants.len());
+      processed = processed.saturating_add(grants.len());
+
+      let mut expired_users_by_role: BTreeMap<RoleId, Vec<User<T::AccountId>>> = BTreeMap::new();
+      for (role_id, user) in grants {
+        RoleGrantsExpiringAt::<T>::remove(block, (role_id, user.clone()));
+
+        // A grant could have been revoked since it was scheduled.
+        if !Self::is_grant_expired(role_id, &user) {
+          continue;
+        }
+
+        if let Some(role) = Self::role_by_id(role_id) {
+          role.revoke_from_users(vec![user.clone()]);
+          expired_users_by_role.entry(role_id).or_default().push(user);
+        }
+      }
+
+      for (role_id, users) in expired_users_by_role {
+        Self::deposit_event(Event::RoleGrantsExpired(role_id, users));
+      }
+
+      if RoleGrantsExpiringAt::<T>::iter_key_prefix(block).next().is_some() {
+        break;
+      }
+
+      let role_ids: Vec<RoleId> = RolesExpiringAt::<T>::iter_key_prefix(block).take(budget).collect();
+      for role_id in role_ids {
+        if budget == 0 {
+          break;
+        }
+
+        let role = match Self::role_by_id(role_id) {
+          Some(role) => role,
+          None => {
+            RolesExpiringAt::<T>::remove(block, role_id);
+            continue;
+          }
+        };
+
+        budget -= 1;
+        let users: Vec<_> = UsersByRoleId::<T>::iter_key_prefix(role_id).take(budget).collect();
+        budget -= users.len();
+        processed = processed.saturating_add(users.len() + 1);
+
+        role.revoke_from_users(users);
+
+        if UsersByRoleId::<T>::iter_key_prefix(role_id).next().is_none() {
+          RolesExpiringAt::<T>::remove(block, role_id);
+          role.remove();
+          Self::deposit_event(Event::RoleExpired(role_id));
+        }
+      }
+
+      if RolesExpiringAt::<T>::iter_key_prefix(block).next().is_some() {
+        break;
+      }
+      block = block.saturating_add(One::one());
+    }
+
+    NextRoleExpirationBlock::<T>::put(block);
+
+    T::WeightInfo::remove_expired_grants_and_roles(processed as u32)
+  }
+
+  pub fn ensure_role_is_not_being_deleted(role_id: RoleId) -> DispatchResult {
+    ensure!(!RoleDeletionsInProgress::<T>::contains_key(role_id), Error::<T>::RoleIsBeingDeleted);
+    Ok(())
+  }
+
+  /// Revoke a role that is being deleted from at most `max_users` of its users,
+  /// and delete the role if no users left. Returns the number of users the role was revoked from.
+  pub(crate) fn continue_role_deletion(role: Role<T>, max_users: usize) -> usize {
+    let users: Vec<_> = UsersByRoleId::<T>::iter_key_prefix(role.id).take(max_users).collect();
+    let revoked_count = users.len();
+
+    role.revoke_from_users(users);
+
+    let users_left = Self::users_count_by_role_id(role.id);
+    if users_left == 0 || revoked_count < max_users {
+      if let Some(who) = Self::role_deletion_requested_by(role.id) {
+        Self::deposit_event(Event::RoleDeleted(who, role.id));
+      }
+      role.remove();
+    } else {
+      Self::deposit_event(Event::RoleDeletionProgressed(role.id, users_left));
+    }
+
+    revoked_count
+  }
+
+  /// Continue the deletion of roles that had too many users to be deleted at once.
+  /// At most `MaxUsersToProcessPerDeleteRole` users are processed per block.
+  pub(crate) fn continue_role_deletions() -> Weight {
+    let mut budget = T::MaxUsersToProcessPerDeleteRole::get() as usize;
+
+    let role_ids: Vec<RoleId> = RoleDeletionsInProgress::<T>::iter_keys().take(budget).collect();
+    let mut processed = role_ids.len();
+
+    for role_id in role_ids {
+      if budget == 0 {
+        break;
+      }
+
+      match Self::role_by_id(role_id) {
+        Some(role) => {
+          let revoked_count = Self::continue_role_deletion(role, budget);
+          budget = budget.saturating_sub(revoked_count);
+          processed = processed.saturating_add(revoked_count);
+        }
+        // The role could have been deleted together with its space or once it expired.
+        None => RoleDeletionsInProgress::<T>::remove(role_id),
+      }
+    }
+
+    T::WeightInfo::continue_role_deletions(processed as u32)
+  }
+
+  /// Ensure that a space exists and that it can hold a role in another space.
+  pub fn ensure_space_can_hold_role(holder_id: SpaceId, space_id: SpaceId) -> DispatchResult {
+      T::Spaces::get_space(holder_id)?;
+
+      if !SpaceRoleHoldersBySpaceId::<T>::get(space_id, holder_id) {
+          let holders_count = SpaceRoleHoldersBySpaceId::<T>::iter_key_prefix(space_id).count();
+          ensure!(
+              holders_count < T::MaxSpaceRoleHoldersPerSpace::get() as usize,
+              Error::<T>::TooManySpaceRoleHolders
+          );
+      }
+      Ok(())
+  }
+
+  /// Keep `SpaceRoleHoldersBySpaceId` and the counts of held roles in sync with the roles
+  /// of a user, if this user is a space.
+  pub(crate) fn update_space_role_holder(user: &User<T::AccountId>, space_id: SpaceId) {
+      if let User::Space(holder_id) = user {
+          let roles_count = Self::role_ids_by_user_in_space(user, space_id).len() as u32;
+          let old_roles_count = HeldRolesCountBySpaceInSpace::<T>::get(holder_id, space_id);
+          HeldRolesCountBySpaceId::<T>::mutate(holder_id, |count| {
+              *count = count.saturating_sub(old_roles_count).saturating_add(roles_count)
+          });
+
+          if roles_count > 0 {
+              SpaceRoleHoldersBySpaceId::<T>::insert(space_id, holder_id, true);
+              HeldRolesCountBySpaceInSpace::<T>::insert(holder_id, space_id, roles_count);
+          } else {
+              SpaceRoleHoldersBySpaceId::<T>::remove(space_id, holder_id);
+              HeldRolesCountBySpaceInSpace::<T>::remove(holder_id, space_id);
+          }
+      }
+  }
+
+  /// Revoke all roles that a given space holds in other spaces.
+  fn revoke_roles_held_by_space(holder_id: SpaceId) {
+      let holder = User::Space(holder_id);
+      let space_ids: Vec<SpaceId> = HeldRolesCountBySpaceInSpace::<T>::iter_key_prefix(holder_id).collect();
+
+      for space_id in space_ids {
+          for role_id in Self::role_ids_by_user_in_space(&holder, space_id) {
+              if let Some(role) = Self::role_by_id(role_id) {
+                  role.revoke_from_users(vec![holder.clone()]);
+              }
+          }
+
+          // Clean up the ids of roles that no longer exist, if any.
+          RoleIdsByUserInSpace::<T>::remove(&holder, space_id);
+          Self::update_space_role_holder(&holder, space_id);
+      }
+  }
 
   /// Check that there is a `Role` with such `role_id` in the storage
   /// or return`RoleNotFound` error.
   pub fn ensure_role_exists(role_id: RoleId) -> DispatchResult {
-      ensure!(<RoleById<T>>::contains_key(role_id), Error::<T>::RoleNotFound);
+      ensure!(RoleById::<T>::contains_key(role_id), Error::<T>::RoleNotFound);
       Ok(())
   }
 
@@ -45,12 +316,14 @@ impl<T: Config> Module<T> {
 
     match &user {
       User::Account(account) => {
-        is_owner = *account == space.owner;
+        is_owner = space.owners.contains(account);
 
         // No need to check if a user is follower, if they already are an owner:
         is_follower = is_owner || T::SpaceFollows::is_space_follower(account.clone(), space_id);
       }
-      User::Space(_) => (/* Not implemented yet. */),
+      // A space can neither own nor follow another space,
+      // it can only hold roles in it (see `has_permission_in_space_roles`).
+      User::Space(_) => (),
     }
 
     Self::ensure_user_has_space_permission(
@@ -59,7 +332,8 @@ impl<T: Config> Module<T> {
         space_id,
         is_space_owner: is_owner,
         is_space_follower: is_follower,
-        space_perms: space.permissions
+        space_perms: space.permissions,
+        post_perms: None,
       },
       permission,
       error
@@ -90,6 +364,8 @@ impl<T: Config> Module<T> {
     )
   }
 
+  /// Besides its own roles, an account can use the roles that other spaces hold in a given space,
+  /// if it is allowed to represent those spaces (see `can_represent_space`).
   fn has_permission_in_space_roles(
     user: User<T::AccountId>,
     space_id: SpaceId,
@@ -97,11 +373,34 @@ impl<T: Config> Module<T> {
     error: DispatchError,
   ) -> DispatchResult {
 
+    if Self::has_permission_in_own_roles(&user, space_id, &permission) {
+      return Ok(());
+    }
+
+    if let User::Account(account) = user {
+      for holder_id in SpaceRoleHoldersBySpaceId::<T>::iter_key_prefix(space_id) {
+        if Self::has_permission_in_own_roles(&User::Space(holder_id), space_id, &permission)
+          && Self::can_represent_space(&account, holder_id)
+        {
+          return Ok(());
+        }
+      }
+    }
+
+    Err(error)
+  }
+
+  fn has_permission_in_own_roles(
+    user: &User<T::AccountId>,
+    space_id: SpaceId,
+    permission: &SpacePermission,
+  ) -> bool {
+
     let role_ids = Self::role_ids_by_user_in_space(user, space_id);
 
     for role_id in role_ids {
       if let Some(role) = Self::role_by_id(role_id) {
-        if role.disabled {
+        if role.disabled || Self::is_grant_expired(role_id, user) {
           continue;
         }
 
@@ -112,13 +411,27 @@ impl<T: Config> Module<T> {
           }
         }
 
-        if !is_expired && role.permissions.contains(&permission) {
-          return Ok(());
+        if !is_expired && role.permissions.contains(permission) {
+          return true;
         }
       }
     }
 
-    Err(error)
+    false
+  }
+
+  /// An account can act on behalf of a space outside of it, if it is an owner of this space
+  /// or has `RepresentSpaceExternally` permission in one of its own roles in this space.
+  pub fn can_represent_space(account: &T::AccountId, space_id: SpaceId) -> bool {
+    let is_owner = T::Spaces::get_space(space_id)
+      .map(|space| space.owners.contains(account))
+      .unwrap_or(false);
+
+    is_owner || Self::has_permission_in_own_roles(
+      &User::Account(account.clone()),
+      space_id,
+      &SpacePermission::RepresentSpaceExternally,
+    )
   }
 }
 
@@ -132,7 +445,7 @@ impl<T: Config> Role<T> {
     permissions: BTreeSet<SpacePermission>,
   ) -> Result<Self, DispatchError> {
 
-    let role_id = Module::<T>::next_role_id();
+    let role_id = Pallet::<T>::next_role_id();
 
     let mut expires_at: Option<T::BlockNumber> = None;
     if let Some(ttl) = time_to_live {
@@ -166,27 +479,44 @@ impl<T: Config> Role<T> {
   }
 
   pub fn revoke_from_users(&self, users: Vec<User<T::AccountId>>) {
-    let mut users_by_role = <UsersByRoleId<T>>::take(self.id);
-
     for user in users.iter() {
-      let role_idx_by_user_opt = Module::<T>::role_ids_by_user_in_space(&user, self.space_id).iter()
+      if let Some(expires_at) = RoleGrantExpiresAt::<T>::take(self.id, user) {
+        RoleGrantsExpiringAt::<T>::remove(expires_at, (self.id, user.clone()));
+      }
+
+      let role_idx_by_user_opt = Pallet::<T>::role_ids_by_user_in_space(&user, self.space_id).iter()
         .position(|x| { *x == self.id });
 
       if let Some(role_idx) = role_idx_by_user_opt {
-        <RoleIdsByUserInSpace<T>>::mutate(user, self.space_id, |n| { n.swap_remove(role_idx) });
+        RoleIdsByUserInSpace::<T>::mutate(user, self.space_id, |n| { n.swap_remove(role_idx) });
+        Pallet::<T>::update_space_role_holder(user, self.space_id);
       }
 
-      let user_idx_by_role_opt = users_by_role.iter().position(|x| { x == user });
-
-      if let Some(user_idx) = user_idx_by_role_opt {
-        users_by_role.swap_remove(user_idx);
+      if UsersByRoleId::<T>::contains_key(self.id, user) {
+        UsersByRoleId::<T>::remove(self.id, user);
+        UsersCountByRoleId::<T>::mutate(self.id, |count| *count = count.saturating_sub(1));
       }
     }
-    <UsersByRoleId<T>>::insert(self.id, users_by_role);
+  }
+
+  /// Delete this role and release its storage deposit.
+  /// The role should be revoked from all its users before.
+  pub fn remove(&self) {
+    let role_idx_by_space_opt = Pallet::<T>::role_ids_by_space_id(self.space_id).iter()
+      .position(|x| { *x == self.id });
+
+    if let Some(role_idx) = role_idx_by_space_opt {
+      RoleIdsBySpaceId::<T>::mutate(self.space_id, |n| { n.swap_remove(role_idx) });
+    }
+
+    Utils::<T>::release_storage_deposit(StorageItem::Role(self.id));
+    RoleById::<T>::remove(self.id);
+    UsersCountByRoleId::<T>::remove(self.id);
+    RoleDeletionsInProgress::<T>::remove(self.id);
   }
 }
 
-impl<T: Config> PermissionChecker for Module<T> {
+impl<T: Config> PermissionChecker for Pallet<T> {
   type AccountId = T::AccountId;
 
   fn ensure_user_has_space_permission(
@@ -204,3 +534,30 @@ impl<T: Config> PermissionChecker for Module<T> {
     )
   }
 }
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+  /// Roles of a space and their grants, as well as grants of roles that this space holds in other spaces.
+  fn items_to_clean_up(space_id: SpaceId) -> u32 {
+    Self::role_ids_by_space_id(space_id)
+      .into_iter()
+      .fold(Self::held_roles_count_by_space_id(space_id), |total, role_id| {
+        total.saturating_add(1).saturating_add(Self::users_count_by_role_id(role_id))
+      })
+  }
+
+  fn before_space_deleted(space_id: SpaceId) {
+    Self::revoke_roles_held_by_space(space_id);
+
+    for role_id in RoleIdsBySpaceId::<T>::take(space_id) {
+      for (user, _) in UsersByRoleId::<T>::drain_prefix(role_id) {
+        RoleIdsByUserInSpace::<T>::remove(&user, space_id);
+        Self::update_space_role_holder(&user, space_id);
+      }
+      RoleGrantExpiresAt::<T>::remove_prefix(role_id, None);
+      RoleDeletionsInProgress::<T>::remove(role_id);
+      UsersCountByRoleId::<T>::remove(role_id);
+      RoleById::<T>::remove(role_id);
+      Utils::<T>::release_storage_deposit(StorageItem::Role(role_id));
+    }
+  }
+}
@@ -7,30 +7,41 @@
 //! you would create a role "Editor" with permissions such as `CreatePosts`, `UpdateAnyPost`,
 //! and `HideAnyComment`. Then you would grant this role to the specific accounts you would like
 //! to make editors.
+//!
+//! A role can also be granted to another space, e.g. to a partner publication. Then the owners
+//! of that space and its members with `RepresentSpaceExternally` permission can act under this role.
+//!
+//! A role can be granted temporarily, e.g. to have a moderator for a week. Expired grants and
+//! expired roles are removed automatically at the beginning of a block.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage,
     ensure,
     traits::Get,
-    dispatch::DispatchResult
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
 };
 use sp_runtime::RuntimeDebug;
 use sp_std::{collections::btree_set::BTreeSet, prelude::*};
-use frame_system::{self as system, ensure_signed};
+use frame_system::ensure_signed;
 
 use df_traits::{
-    PermissionChecker, SpaceFollowsProvider, SpaceForRolesProvider,
+    BeforeSpaceDeleted, PermissionChecker, SpaceFollowsProvider, SpaceForRolesProvider,
     moderation::{IsAccountBlocked, IsContentBlocked},
 };
-use pallet_permissions::{Module as Permissions, SpacePermission, SpacePermissionSet};
-use pallet_utils::{Module as Utils, Error as UtilsError, SpaceId, User, WhoAndWhen, Content};
+use pallet_permissions::{Pallet as Permissions, SpacePermission, SpacePermissionSet};
+use pallet_utils::{Pallet as Utils, Error as UtilsError, SpaceId, StorageItem, User, WhoAndWhen, Content};
 
 pub mod functions;
+pub mod migrations;
 pub mod rpc;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[cfg(test)]
 mod mock;
@@ -60,7 +71,7 @@ pub struct Role<T: Config> {
 
     /// An optional block number at which this role will expire. If `expires_at` is `Some`
     /// and the current block is greater or equal to its value, the permissions associated
-    /// with a given role will have no affect.
+    /// with a given role will have no affect. An expired role is deleted in `on_initialize`.
     pub expires_at: Option<T::BlockNumber>,
 
     /// Content can optionally contain additional information associated with a role,
@@ -79,42 +90,176 @@ pub struct RoleUpdate {
     pub permissions: Option<SpacePermissionSet>,
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_permissions::Config
-    + pallet_utils::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+pub use pallet::*;
 
-    /// When deleting a role via `delete_role()` dispatch, this parameter is checked.
-    /// If the number of users that own a given role is greater or equal to this number,
-    /// then `TooManyUsersToDeleteRole` error will be returned and the dispatch will fail.
-    type MaxUsersToProcessPerDeleteRole: Get<u16>;
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, Zero};
 
-    type Spaces: SpaceForRolesProvider<AccountId=Self::AccountId>;
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
-    type SpaceFollows: SpaceFollowsProvider<AccountId=Self::AccountId>;
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_permissions::Config
+        + pallet_utils::Config
+    {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-    type IsAccountBlocked: IsAccountBlocked<Self::AccountId>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
 
-    type IsContentBlocked: IsContentBlocked;
-}
+        /// The maximum number of users from which a role can be revoked by `delete_role()` dispatch
+        /// or in one block. If a role has more users than this number, then `delete_role()` disables it,
+        /// and the rest of its users are removed in `on_initialize` of the next blocks.
+        #[pallet::constant]
+        type MaxUsersToProcessPerDeleteRole: Get<u16>;
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId
-    {
-        RoleCreated(AccountId, SpaceId, RoleId),
-        RoleUpdated(AccountId, RoleId),
-        RoleDeleted(AccountId, RoleId),
-        RoleGranted(AccountId, RoleId, Vec<User<AccountId>>),
-        RoleRevoked(AccountId, RoleId, Vec<User<AccountId>>),
+        /// The maximum number of other spaces that can hold roles in one space.
+        #[pallet::constant]
+        type MaxSpaceRoleHoldersPerSpace: Get<u32>;
+
+        /// The maximum number of expired role grants that can be removed in one block,
+        /// where each checked block and each user of an expired role also count towards this limit.
+        /// Expired grants that don't fit into this limit are removed in the next blocks.
+        #[pallet::constant]
+        type MaxExpiredGrantsToProcessPerBlock: Get<u32>;
+
+        type Spaces: SpaceForRolesProvider<AccountId=Self::AccountId>;
+
+        type SpaceFollows: SpaceFollowsProvider<AccountId=Self::AccountId>;
+
+        type IsAccountBlocked: IsAccountBlocked<Self::AccountId>;
+
+        type IsContentBlocked: IsContentBlocked;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::remove_expired_grants_and_roles(now)
+                .saturating_add(Self::continue_role_deletions())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<RoleById<T>, Role<T>>()
+        }
+    }
+
+    #[pallet::type_value]
+    pub fn DefaultForNextRoleId() -> RoleId {
+        FIRST_ROLE_ID
+    }
+
+    /// The next role id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_role_id)]
+    pub type NextRoleId<T: Config> = StorageValue<_, RoleId, ValueQuery, DefaultForNextRoleId>;
+
+    /// Get the details of a role by its' id.
+    #[pallet::storage]
+    #[pallet::getter(fn role_by_id)]
+    pub type RoleById<T: Config> = StorageMap<_, Twox64Concat, RoleId, Role<T>, OptionQuery>;
+
+    /// Whether a given role has been granted to a user (account or space id),
+    /// by (role id, user).
+    #[pallet::storage]
+    pub type UsersByRoleId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, RoleId, Blake2_128Concat, User<T::AccountId>, bool, ValueQuery>;
+
+    /// Get the number of users that a given role has been granted to.
+    #[pallet::storage]
+    #[pallet::getter(fn users_count_by_role_id)]
+    pub type UsersCountByRoleId<T: Config> = StorageMap<_, Twox64Concat, RoleId, u32, ValueQuery>;
+
+    /// Get a list of all role ids available in a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn role_ids_by_space_id)]
+    pub type RoleIdsBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, Vec<RoleId>, ValueQuery>;
+
+    /// Get a list of all role ids owned by a given user (account or space id)
+    /// within a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn role_ids_by_user_in_space)]
+    pub type RoleIdsByUserInSpace<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, User<T::AccountId>, Twox64Concat, SpaceId, Vec<RoleId>, ValueQuery>;
+
+    /// Whether a space (the second key) holds at least one role in a given space (the first key).
+    #[pallet::storage]
+    #[pallet::getter(fn space_role_holders_by_space_id)]
+    pub type SpaceRoleHoldersBySpaceId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, SpaceId, bool, ValueQuery>;
+
+    /// The number of roles that a space (the first key) holds in another space (the second key).
+    #[pallet::storage]
+    pub type HeldRolesCountBySpaceInSpace<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    /// The number of roles that a given space holds in all other spaces.
+    #[pallet::storage]
+    #[pallet::getter(fn held_roles_count_by_space_id)]
+    pub type HeldRolesCountBySpaceId<T: Config> = StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    /// An optional block at which a grant of a role (the first key) to a user (the second key)
+    /// expires. Grants without an expiry stay until they are revoked.
+    #[pallet::storage]
+    #[pallet::getter(fn role_grant_expires_at)]
+    pub type RoleGrantExpiresAt<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, RoleId, Blake2_128Concat, User<T::AccountId>, T::BlockNumber>;
+
+    /// Role grants that should be removed in `on_initialize` of a given block.
+    #[pallet::storage]
+    pub type RoleGrantsExpiringAt<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Blake2_128Concat, (RoleId, User<T::AccountId>), bool, ValueQuery>;
+
+    /// Roles that should be deleted in `on_initialize` of a given block.
+    #[pallet::storage]
+    pub type RolesExpiringAt<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, RoleId, bool, ValueQuery>;
+
+    /// The earliest block whose expired role grants and roles may have not been removed yet.
+    #[pallet::storage]
+    pub type NextRoleExpirationBlock<T: Config> = StorageValue<_, T::BlockNumber>;
+
+    /// Roles that have too many users to be deleted in one transaction, and accounts
+    /// that requested their deletion. See `MaxUsersToProcessPerDeleteRole`.
+    #[pallet::storage]
+    #[pallet::getter(fn role_deletion_requested_by)]
+    pub type RoleDeletionsInProgress<T: Config> = StorageMap<_, Twox64Concat, RoleId, T::AccountId>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        RoleCreated(T::AccountId, SpaceId, RoleId),
+        RoleUpdated(T::AccountId, RoleId),
+        RoleDeleted(T::AccountId, RoleId),
+        RoleGranted(T::AccountId, RoleId, Vec<User<T::AccountId>>),
+        RoleRevoked(T::AccountId, RoleId, Vec<User<T::AccountId>>),
+        /// Grants of a role to these users have expired and were removed.
+        RoleGrantsExpired(RoleId, Vec<User<T::AccountId>>),
+        /// A role has expired and was deleted together with all its grants.
+        RoleExpired(RoleId),
+        /// A role has too many users to be deleted at once, so it was disabled
+        /// and will be deleted in the next blocks.
+        RoleDeletionStarted(T::AccountId, RoleId),
+        /// Role was revoked from a part of its users during the deletion. \[role_id, users_left\]
+        RoleDeletionProgressed(RoleId, u32),
     }
-);
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    #[pallet::error]
+    pub enum Error<T> {
         /// Role was not found by id.
         RoleNotFound,
 
@@ -136,7 +281,7 @@ decl_error! {
         NoUsersProvided,
 
         /// Canot remove a role from this many users in a single transaction.
-        /// See `MaxUsersToProcessPerDeleteRole` parameter of this trait.
+        /// Not returned anymore: such roles are deleted in several blocks.
         TooManyUsersToDeleteRole,
 
         /// Cannot disable a role that is already disabled.
@@ -144,218 +289,216 @@ decl_error! {
 
         /// Cannot enable a role that is already enabled.
         RoleAlreadyEnabled,
-    }
-}
-
-pub const FIRST_ROLE_ID: u64 = 1;
-
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as PermissionsModule {
-
-        /// The next role id.
-        pub NextRoleId get(fn next_role_id): RoleId = FIRST_ROLE_ID;
-
-        /// Get the details of a role by its' id.
-        pub RoleById get(fn role_by_id):
-            map hasher(twox_64_concat) RoleId => Option<Role<T>>;
 
-        /// Get a list of all users (account or space ids) that a given role has been granted to.
-        pub UsersByRoleId get(fn users_by_role_id):
-            map hasher(twox_64_concat) RoleId => Vec<User<T::AccountId>>;
+        /// Too many other spaces already hold roles in this space.
+        /// See `MaxSpaceRoleHoldersPerSpace` parameter of this trait.
+        TooManySpaceRoleHolders,
 
-        /// Get a list of all role ids available in a given space.
-        pub RoleIdsBySpaceId get(fn role_ids_by_space_id):
-            map hasher(twox_64_concat) SpaceId => Vec<RoleId>;
+        /// Time to live of a role grant should be greater than zero.
+        ZeroTimeToLive,
 
-        /// Get a list of all role ids owned by a given user (account or space id)
-        /// within a given space.
-        pub RoleIdsByUserInSpace get(fn role_ids_by_user_in_space): double_map
-            hasher(blake2_128_concat) User<T::AccountId>,
-            hasher(twox_64_concat) SpaceId
-            => Vec<RoleId>;
+        /// This role is being deleted, so it cannot be updated or granted.
+        RoleIsBeingDeleted,
     }
-}
-
-// The pallet's dispatchable functions.
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
-
-    const MaxUsersToProcessPerDeleteRole: u16 = T::MaxUsersToProcessPerDeleteRole::get();
-
-    // Initializing errors
-    type Error = Error<T>;
-
-    // Initializing events
-    fn deposit_event() = default;
-
-    /// Create a new role, with a list of permissions, within a given space.
-    ///
-    /// `content` can optionally contain additional information associated with a role,
-    /// such as a name, description, and image for a role. This may be useful for end users.
-    ///
-    /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 3)]
-    pub fn create_role(
-      origin,
-      space_id: SpaceId,
-      time_to_live: Option<T::BlockNumber>,
-      content: Content,
-      permissions: Vec<SpacePermission>
-    ) -> DispatchResult {
-      let who = ensure_signed(origin)?;
 
-      ensure!(!permissions.is_empty(), Error::<T>::NoPermissionsProvided);
-
-      Utils::<T>::is_valid_content(content.clone())?;
-      ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), space_id), UtilsError::<T>::ContentIsBlocked);
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new role, with a list of permissions, within a given space.
+        ///
+        /// `content` can optionally contain additional information associated with a role,
+        /// such as a name, description, and image for a role. This may be useful for end users.
+        ///
+        /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
+        #[pallet::weight(<T as Config>::WeightInfo::create_role())]
+        pub fn create_role(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            time_to_live: Option<T::BlockNumber>,
+            content: Content,
+            permissions: Vec<SpacePermission>
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!permissions.is_empty(), Error::<T>::NoPermissionsProvided);
+
+            Utils::<T>::is_valid_content(content.clone())?;
+            ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), space_id), UtilsError::<T>::ContentIsBlocked);
+
+            Self::ensure_role_manager(who.clone(), space_id)?;
+
+            let permissions_set = permissions.into_iter().collect();
+            let new_role = Role::<T>::new(who.clone(), space_id, time_to_live, content, permissions_set)?;
+            Utils::<T>::reserve_storage_deposit(&who, StorageItem::Role(new_role.id), &new_role)?;
+
+            // TODO review strange code:
+            let next_role_id = new_role.id.checked_add(1).ok_or(Error::<T>::RoleIdOverflow)?;
+            NextRoleId::<T>::put(next_role_id);
+
+            RoleById::<T>::insert(new_role.id, new_role.clone());
+            RoleIdsBySpaceId::<T>::mutate(space_id, |role_ids| { role_ids.push(new_role.id) });
+
+            if let Some(expires_at) = new_role.expires_at {
+                RolesExpiringAt::<T>::insert(expires_at, new_role.id, true);
+            }
+
+            Self::deposit_event(Event::RoleCreated(who, space_id, new_role.id));
+            Ok(().into())
+        }
 
-      Self::ensure_role_manager(who.clone(), space_id)?;
+        /// Update an existing role by a given id.
+        /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
+        #[pallet::weight(<T as Config>::WeightInfo::update_role())]
+        pub fn update_role(origin: OriginFor<T>, role_id: RoleId, update: RoleUpdate) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-      let permissions_set = permissions.into_iter().collect();
-      let new_role = Role::<T>::new(who.clone(), space_id, time_to_live, content, permissions_set)?;
+            let has_updates =
+                update.disabled.is_some() ||
+                update.content.is_some() ||
+                update.permissions.is_some();
 
-      // TODO review strange code:
-      let next_role_id = new_role.id.checked_add(1).ok_or(Error::<T>::RoleIdOverflow)?;
-      NextRoleId::put(next_role_id);
+            ensure!(has_updates, Error::<T>::NoUpdatesProvided);
 
-      <RoleById<T>>::insert(new_role.id, new_role.clone());
-      RoleIdsBySpaceId::mutate(space_id, |role_ids| { role_ids.push(new_role.id) });
+            let mut role = Self::require_role(role_id)?;
+            Self::ensure_role_is_not_being_deleted(role_id)?;
 
-      Self::deposit_event(RawEvent::RoleCreated(who, space_id, new_role.id));
-      Ok(())
-    }
+            Self::ensure_role_manager(who.clone(), role.space_id)?;
 
-    /// Update an existing role by a given id.
-    /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
-    pub fn update_role(origin, role_id: RoleId, update: RoleUpdate) -> DispatchResult {
-      let who = ensure_signed(origin)?;
+            let mut is_update_applied = false;
 
-      let has_updates =
-        update.disabled.is_some() ||
-        update.content.is_some() ||
-        update.permissions.is_some();
+            if let Some(disabled) = update.disabled {
+                if disabled != role.disabled {
+                    role.set_disabled(disabled)?;
+                    is_update_applied = true;
+                }
+            }
 
-      ensure!(has_updates, Error::<T>::NoUpdatesProvided);
+            if let Some(content) = update.content {
+                if content != role.content {
+                    Utils::<T>::is_valid_content(content.clone())?;
+                    ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), role.space_id), UtilsError::<T>::ContentIsBlocked);
 
-      let mut role = Self::require_role(role_id)?;
+                    role.content = content;
+                    is_update_applied = true;
+                }
+            }
 
-      Self::ensure_role_manager(who.clone(), role.space_id)?;
+            if let Some(permissions) = update.permissions {
+                if !permissions.is_empty() {
+                    let permissions_diff: Vec<_> = permissions.symmetric_difference(&role.permissions).cloned().collect();
 
-      let mut is_update_applied = false;
+                    if !permissions_diff.is_empty() {
+                        role.permissions = permissions;
+                        is_update_applied = true;
+                    }
+                }
+            }
 
-      if let Some(disabled) = update.disabled {
-        if disabled != role.disabled {
-          role.set_disabled(disabled)?;
-          is_update_applied = true;
-        }
-      }
+            if is_update_applied {
+                role.updated = Some(WhoAndWhen::<T>::new(who.clone()));
 
-      if let Some(content) = update.content {
-        if content != role.content {
-          Utils::<T>::is_valid_content(content.clone())?;
-          ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), role.space_id), UtilsError::<T>::ContentIsBlocked);
+                // A storage deposit is held by the role creator, even if the role is updated by another manager.
+                Utils::<T>::update_storage_deposit(StorageItem::Role(role_id), &role)?;
 
-          role.content = content;
-          is_update_applied = true;
+                RoleById::<T>::insert(role_id, role);
+                Self::deposit_event(Event::RoleUpdated(who, role_id));
+            }
+            Ok(().into())
         }
-      }
 
-      if let Some(permissions) = update.permissions {
-        if !permissions.is_empty() {
-          let permissions_diff: Vec<_> = permissions.symmetric_difference(&role.permissions).cloned().collect();
+        /// Delete a given role and clean all associated storage items.
+        /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
+        ///
+        /// If the role has more than `MaxUsersToProcessPerDeleteRole` users, then it is disabled
+        /// and revoked from the first part of them. The rest of the users are removed in the next blocks,
+        /// after which the role is deleted.
+        #[pallet::weight(<T as Config>::WeightInfo::delete_role(T::MaxUsersToProcessPerDeleteRole::get().into()))]
+        pub fn delete_role(origin: OriginFor<T>, role_id: RoleId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-          if !permissions_diff.is_empty() {
-            role.permissions = permissions;
-            is_update_applied = true;
-          }
-        }
-      }
-
-      if is_update_applied {
-        role.updated = Some(WhoAndWhen::<T>::new(who.clone()));
+            let mut role = Self::require_role(role_id)?;
+            Self::ensure_role_is_not_being_deleted(role_id)?;
 
-        <RoleById<T>>::insert(role_id, role);
-        Self::deposit_event(RawEvent::RoleUpdated(who, role_id));
-      }
-      Ok(())
-    }
+            Self::ensure_role_manager(who.clone(), role.space_id)?;
 
-    /// Delete a given role and clean all associated storage items.
-    /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
-    #[weight = 1_000_000 + T::DbWeight::get().reads_writes(6, 5)]
-    pub fn delete_role(origin, role_id: RoleId) -> DispatchResult {
-      let who = ensure_signed(origin)?;
+            let max_users = T::MaxUsersToProcessPerDeleteRole::get() as u32;
+            let users_count = Self::users_count_by_role_id(role_id);
+            if users_count <= max_users {
+                let users = Self::users_by_role_id(role_id);
 
-      let role = Self::require_role(role_id)?;
+                role.revoke_from_users(users);
+                role.remove();
 
-      Self::ensure_role_manager(who.clone(), role.space_id)?;
+                Self::deposit_event(Event::RoleDeleted(who, role_id));
+                return Ok(Some(<T as Config>::WeightInfo::delete_role(users_count)).into());
+            }
 
-      let users = Self::users_by_role_id(role_id);
-      ensure!(
-        users.len() <= T::MaxUsersToProcessPerDeleteRole::get() as usize,
-        Error::<T>::TooManyUsersToDeleteRole
-      );
+            // Permissions of the role should stop working before all its users are removed.
+            role.disabled = true;
+            RoleById::<T>::insert(role_id, role.clone());
+            RoleDeletionsInProgress::<T>::insert(role_id, who.clone());
 
-      let role_idx_by_space_opt = Self::role_ids_by_space_id(role.space_id).iter()
-        .position(|x| { *x == role_id });
+            Self::deposit_event(Event::RoleDeletionStarted(who, role_id));
 
-      if let Some(role_idx) = role_idx_by_space_opt {
-        RoleIdsBySpaceId::mutate(role.space_id, |n| { n.swap_remove(role_idx) });
-      }
+            Self::continue_role_deletion(role, max_users as usize);
+            Ok(().into())
+        }
 
-      role.revoke_from_users(users);
+        /// Grant a given role to a list of users.
+        /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
+        ///
+        /// A role granted to another space can be used by the owners of that space
+        /// and by the accounts that have `RepresentSpaceExternally` permission in it.
+        ///
+        /// If a user already has this role granted temporarily, the grant becomes permanent.
+        #[pallet::weight(<T as Config>::WeightInfo::grant_role(users.len() as u32))]
+        #[transactional]
+        pub fn grant_role(origin: OriginFor<T>, role_id: RoleId, users: Vec<User<T::AccountId>>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            Self::do_grant_role(who, role_id, users, None)?;
+            Ok(().into())
+        }
 
-      <RoleById<T>>::remove(role_id);
-      <UsersByRoleId<T>>::remove(role_id);
+        /// Grant a given role to a list of users for a given number of blocks,
+        /// e.g. to make someone a moderator for a week.
+        /// The grants are removed automatically once they expire.
+        /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
+        #[pallet::weight(<T as Config>::WeightInfo::grant_role_temporarily(users.len() as u32))]
+        #[transactional]
+        pub fn grant_role_temporarily(
+            origin: OriginFor<T>,
+            role_id: RoleId,
+            users: Vec<User<T::AccountId>>,
+            time_to_live: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!time_to_live.is_zero(), Error::<T>::ZeroTimeToLive);
+            let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(time_to_live);
+
+            Self::do_grant_role(who, role_id, users, Some(expires_at))?;
+            Ok(().into())
+        }
 
-      Self::deposit_event(RawEvent::RoleDeleted(who, role_id));
-      Ok(())
-    }
+        /// Revoke a given role from a list of users.
+        /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_role(users.len() as u32))]
+        pub fn revoke_role(origin: OriginFor<T>, role_id: RoleId, users: Vec<User<T::AccountId>>) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-    /// Grant a given role to a list of users.
-    /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
-    #[weight = 1_000_000 + T::DbWeight::get().reads_writes(4, 2)]
-    pub fn grant_role(origin, role_id: RoleId, users: Vec<User<T::AccountId>>) -> DispatchResult {
-      let who = ensure_signed(origin)?;
+            ensure!(!users.is_empty(), Error::<T>::NoUsersProvided);
 
-      ensure!(!users.is_empty(), Error::<T>::NoUsersProvided);
-      let users_set: BTreeSet<User<T::AccountId>> = Utils::<T>::convert_users_vec_to_btree_set(users)?;
+            let role = Self::require_role(role_id)?;
 
-      let role = Self::require_role(role_id)?;
+            Self::ensure_role_manager(who.clone(), role.space_id)?;
 
-      Self::ensure_role_manager(who.clone(), role.space_id)?;
+            role.revoke_from_users(users.clone());
 
-      for user in users_set.iter() {
-        if !Self::users_by_role_id(role_id).contains(&user) {
-          <UsersByRoleId<T>>::mutate(role_id, |users| { users.push(user.clone()); });
+            Self::deposit_event(Event::RoleRevoked(who, role_id, users));
+            Ok(().into())
         }
-        if !Self::role_ids_by_user_in_space(user.clone(), role.space_id).contains(&role_id) {
-          <RoleIdsByUserInSpace<T>>::mutate(user.clone(), role.space_id, |roles| { roles.push(role_id); })
-        }
-      }
-
-      Self::deposit_event(RawEvent::RoleGranted(who, role_id, users_set.iter().cloned().collect()));
-      Ok(())
     }
+}
 
-    /// Revoke a given role from a list of users.
-    /// Only the space owner or a user with `ManageRoles` permission can call this dispatch.
-    #[weight = 1_000_000 + T::DbWeight::get().reads_writes(4, 2)]
-    pub fn revoke_role(origin, role_id: RoleId, users: Vec<User<T::AccountId>>) -> DispatchResult {
-      let who = ensure_signed(origin)?;
-
-      ensure!(!users.is_empty(), Error::<T>::NoUsersProvided);
-
-      let role = Self::require_role(role_id)?;
-
-      Self::ensure_role_manager(who.clone(), role.space_id)?;
-
-      role.revoke_from_users(users.clone());
+pub const FIRST_ROLE_ID: u64 = 1;
 
-      Self::deposit_event(RawEvent::RoleRevoked(who, role_id, users));
-      Ok(())
-    }
-  }
-}
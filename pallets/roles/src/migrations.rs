@@ -0,0 +1,57 @@
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+use sp_std::marker::PhantomData;
+
+use pallet_utils::{start_moving_vec_map_to_double_map, OnKeysMovedToDoubleMap};
+
+use super::*;
+
+/// Storage version 2 moves `UsersByRoleId` from `Vec`s of users to a double map.
+/// The users are moved over several blocks by `pallet_utils::MoveVecMapToDoubleMap`,
+/// which counts them with `CountUsersMovedToDoubleMap`.
+pub fn move_users_by_role_id_to_double_map<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let weight = start_moving_vec_map_to_double_map::<T, UsersByRoleId<T>, RoleId, User<T::AccountId>>();
+
+    StorageVersion::new(2).put::<Pallet<T>>();
+
+    weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+}
+
+/// Adds the users moved from a `Vec` of `UsersByRoleId` to `UsersCountByRoleId`.
+pub struct CountUsersMovedToDoubleMap<T>(PhantomData<T>);
+
+impl<T: Config> OnKeysMovedToDoubleMap<RoleId> for CountUsersMovedToDoubleMap<T> {
+    fn on_keys_moved(role_id: &RoleId, new_keys: u32) -> frame_support::weights::Weight {
+        UsersCountByRoleId::<T>::mutate(role_id, |count| *count = count.saturating_add(new_keys));
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+}
+
+/// Storage version 3 schedules the deletion of the existing roles that have `expires_at`,
+/// as only the roles created since `RolesExpiringAt` was added are scheduled at their creation.
+/// Roles that already expired are deleted from the current block on.
+pub fn schedule_expiring_roles<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(3) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let now = <frame_system::Pallet<T>>::block_number();
+    let mut roles_iterated: u64 = 0;
+    let mut roles_scheduled: u64 = 0;
+
+    for (role_id, role) in RoleById::<T>::iter() {
+        roles_iterated += 1;
+
+        if let Some(expires_at) = role.expires_at {
+            RolesExpiringAt::<T>::insert(expires_at.max(now), role_id, true);
+            roles_scheduled += 1;
+        }
+    }
+
+    StorageVersion::new(3).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(roles_iterated + 2, roles_scheduled + 1)
+}
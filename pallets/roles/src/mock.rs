@@ -12,8 +12,8 @@ use sp_runtime::{
 };
 use frame_support::{
     parameter_types, assert_ok,
-    dispatch::{DispatchResult, DispatchError},
-    traits::Everything,
+    dispatch::{DispatchResultWithPostInfo, DispatchError},
+    traits::{Everything, Hooks},
 };
 use frame_system as system;
 
@@ -111,6 +111,15 @@ impl pallet_utils::Config for Test {
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type PremiumHandleLen = ();
+    type HandleRegistrationPeriod = ();
+    type HandleGracePeriod = ();
+    type MaxHandlesToReleasePerBlock = ();
+    type MaxRawContentLen = ();
+    type StorageDepositPerItem = ();
+    type StorageDepositPerByte = ();
+    type MaxItemsToMigratePerBlock = ();
+    type MultiBlockMigrations = ();
 }
 
 use pallet_permissions::default_permissions::DefaultSpacePermissions;
@@ -121,11 +130,16 @@ impl pallet_permissions::Config for Test {
 
 parameter_types! {
   pub const MaxUsersToProcessPerDeleteRole: u16 = 20;
+  pub const MaxSpaceRoleHoldersPerSpace: u32 = 2;
+  pub const MaxExpiredGrantsToProcessPerBlock: u32 = 3;
 }
 
 impl Config for Test {
     type Event = Event;
+    type WeightInfo = ();
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type MaxSpaceRoleHoldersPerSpace = MaxSpaceRoleHoldersPerSpace;
+    type MaxExpiredGrantsToProcessPerBlock = MaxExpiredGrantsToProcessPerBlock;
     type Spaces = Roles;
     type SpaceFollows = Roles;
     type IsAccountBlocked = ();
@@ -135,21 +149,21 @@ impl Config for Test {
 pub type AccountId = u64;
 pub type BlockNumber = u64;
 
-impl<T: Config> SpaceForRolesProvider for Module<T> {
+impl<T: Config> SpaceForRolesProvider for Pallet<T> {
     type AccountId = AccountId;
 
     // This function should return an error every time Space doesn't exist by SpaceId
     // Currently, we have a list of valid space id's to check
     fn get_space(id: SpaceId) -> Result<SpaceForRoles<Self::AccountId>, DispatchError> {
         if self::valid_space_ids().contains(&id) {
-            return Ok(SpaceForRoles { owner: ACCOUNT1, permissions: None })
+            return Ok(SpaceForRoles { owners: vec![ACCOUNT1], permissions: None })
         }
 
         Err("SpaceNotFound".into())
     }
 }
 
-impl<T: Config> SpaceFollowsProvider for Module<T> {
+impl<T: Config> SpaceFollowsProvider for Pallet<T> {
     type AccountId = AccountId;
 
     fn is_space_follower(_account: Self::AccountId, _space_id: u64) -> bool {
@@ -211,6 +225,8 @@ pub(crate) const ROLE2: RoleId = 2;
 pub(crate) const ROLE3: RoleId = 3;
 pub(crate) const ROLE4: RoleId = 4;
 
+pub(crate) const TIME_TO_LIVE: BlockNumber = 10;
+
 pub(crate) const SPACE1: SpaceId = 1;
 pub(crate) const SPACE2: SpaceId = 2;
 
@@ -259,7 +275,7 @@ pub(crate) fn role_update(disabled: Option<bool>, content: Option<Content>, perm
 }
 
 
-pub(crate) fn _create_default_role() -> DispatchResult {
+pub(crate) fn _create_default_role() -> DispatchResultWithPostInfo {
     _create_role(None, None, None, None, None)
 }
 
@@ -269,7 +285,7 @@ pub(crate) fn _create_role(
     time_to_live: Option<Option<BlockNumber>>,
     content: Option<Content>,
     permissions: Option<Vec<SpacePermission>>,
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Roles::create_role(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
         space_id.unwrap_or(SPACE1),
@@ -279,7 +295,7 @@ pub(crate) fn _create_role(
     )
 }
 
-pub(crate) fn _update_default_role() -> DispatchResult {
+pub(crate) fn _update_default_role() -> DispatchResultWithPostInfo {
     _update_role(None, None, None)
 }
 
@@ -287,7 +303,7 @@ pub(crate) fn _update_role(
     origin: Option<Origin>,
     role_id: Option<RoleId>,
     update: Option<RoleUpdate>
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Roles::update_role(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
         role_id.unwrap_or(ROLE1),
@@ -299,7 +315,7 @@ pub(crate) fn _update_role(
     )
 }
 
-pub(crate) fn _grant_default_role() -> DispatchResult {
+pub(crate) fn _grant_default_role() -> DispatchResultWithPostInfo {
     _grant_role(None, None, None)
 }
 
@@ -307,7 +323,7 @@ pub(crate) fn _grant_role(
     origin: Option<Origin>,
     role_id: Option<RoleId>,
     users: Option<Vec<User<AccountId>>>
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Roles::grant_role(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
         role_id.unwrap_or(ROLE1),
@@ -315,7 +331,27 @@ pub(crate) fn _grant_role(
     )
 }
 
-pub(crate) fn _revoke_default_role() -> DispatchResult {
+pub(crate) fn _grant_role_temporarily(
+    origin: Option<Origin>,
+    role_id: Option<RoleId>,
+    users: Option<Vec<User<AccountId>>>,
+    time_to_live: Option<BlockNumber>,
+) -> DispatchResultWithPostInfo {
+    Roles::grant_role_temporarily(
+        origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+        role_id.unwrap_or(ROLE1),
+        users.unwrap_or_else(|| vec![User::Account(ACCOUNT2)]),
+        time_to_live.unwrap_or(TIME_TO_LIVE),
+    )
+}
+
+/// Move to a given block and remove the role grants and roles that expire at it.
+pub(crate) fn run_to_block(n: BlockNumber) {
+    System::set_block_number(n);
+    Roles::on_initialize(n);
+}
+
+pub(crate) fn _revoke_default_role() -> DispatchResultWithPostInfo {
     _revoke_role(None, None, None)
 }
 
@@ -323,7 +359,7 @@ pub(crate) fn _revoke_role(
     origin: Option<Origin>,
     role_id: Option<RoleId>,
     users: Option<Vec<User<AccountId>>>
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Roles::revoke_role(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
         role_id.unwrap_or(ROLE1),
@@ -331,14 +367,14 @@ pub(crate) fn _revoke_role(
     )
 }
 
-pub(crate) fn _delete_default_role() -> DispatchResult {
+pub(crate) fn _delete_default_role() -> DispatchResultWithPostInfo {
     _delete_role(None, None)
 }
 
 pub(crate) fn _delete_role(
     origin: Option<Origin>,
     role_id: Option<RoleId>
-) -> DispatchResult {
+) -> DispatchResultWithPostInfo {
     Roles::delete_role(
         origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
         role_id.unwrap_or(ROLE1)
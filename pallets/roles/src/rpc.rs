@@ -1,13 +1,12 @@
-use crate::{Module, Config, Role, RoleIdsByUserInSpace};
+use crate::{Pallet, Config, Role, RoleId, RoleIdsByUserInSpace, UsersByRoleId};
 
-use frame_support::storage::IterableStorageDoubleMap;
 use sp_std::prelude::*;
 use sp_std::collections::{ btree_set::BTreeSet };
 
-use pallet_utils::{SpaceId, User};
+use pallet_utils::{paginate_double_map_keys, SpaceId, User};
 use pallet_permissions::{SpacePermission};
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
     pub fn get_space_permissions_by_account(
         account: T::AccountId,
         space_id: SpaceId
@@ -24,7 +23,7 @@ impl<T: Config> Module<T> {
     pub fn get_accounts_with_any_role_in_space(space_id: SpaceId) -> Vec<T::AccountId> {
 
         Self::role_ids_by_space_id(space_id)
-            .iter()
+            .into_iter()
             .flat_map(Self::users_by_role_id)
             .filter_map(|user| user.maybe_account())
             .collect::<BTreeSet<_>>()
@@ -44,4 +43,13 @@ impl<T: Config> Module<T> {
 
         space_ids
     }
+
+    /// Get up to `limit` users that a given role has been granted to, starting after the `cursor` user.
+    pub fn get_users_by_role_id_page(
+        role_id: RoleId,
+        cursor: Option<User<T::AccountId>>,
+        limit: u16,
+    ) -> Vec<User<T::AccountId>> {
+        paginate_double_map_keys::<UsersByRoleId<T>, _, _, _>(&role_id, cursor, limit.into())
+    }
 }
\ No newline at end of file
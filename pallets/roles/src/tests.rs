@@ -3,6 +3,7 @@ use crate::*;
 
 use frame_support::{
     assert_ok, assert_noop,
+    traits::StorageVersion,
 };
 use pallet_utils::{Error as UtilsError};
 
@@ -430,6 +431,54 @@ fn grant_role_should_fail_with_a_few_roles_no_permission() {
     });
 }
 
+#[test]
+fn grant_role_should_work_for_a_space() {
+    ExtBuilder::build().execute_with(|| {
+        let user = User::Space(SPACE1);
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role(None, None, Some(vec![user.clone()])));
+
+        // Check whether data was stored correctly
+        assert_eq!(Roles::role_ids_by_user_in_space(user.clone(), SPACE1), vec![ROLE1]);
+        assert!(Roles::space_role_holders_by_space_id(SPACE1, SPACE1));
+
+        assert_ok!(_revoke_role(None, None, Some(vec![user])));
+        assert!(!Roles::space_role_holders_by_space_id(SPACE1, SPACE1));
+    });
+}
+
+#[test]
+fn grant_role_should_count_roles_held_by_a_space() {
+    ExtBuilder::build().execute_with(|| {
+        let user = User::Space(SPACE1);
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_create_default_role()); // RoleId 2
+        assert_ok!(_grant_role(None, None, Some(vec![user.clone()])));
+        assert_ok!(_grant_role(None, Some(ROLE2), Some(vec![user.clone()])));
+        assert_eq!(Roles::held_roles_count_by_space_id(SPACE1), 2);
+        assert_eq!(Roles::items_to_clean_up(SPACE1), 2 + 2 + 2);
+
+        assert_ok!(_revoke_role(None, None, Some(vec![user])));
+        assert_eq!(Roles::held_roles_count_by_space_id(SPACE1), 1);
+    });
+}
+
+#[test]
+fn grant_role_should_fail_when_space_holder_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_noop!(
+            _grant_role(
+                None, // From ACCOUNT1
+                None, // RoleId 1
+                Some(vec![User::Space(SPACE2)])
+            ), "SpaceNotFound"
+        );
+    });
+}
+
 #[test]
 fn revoke_role_should_work() {
     ExtBuilder::build().execute_with(|| {
@@ -563,7 +612,7 @@ fn delete_role_should_fail_with_no_permission() {
 }
 
 #[test]
-fn delete_role_should_fail_with_too_many_users_for_delete_role() {
+fn delete_role_should_continue_in_next_blocks_with_too_many_users() {
     ExtBuilder::build().execute_with(|| {
         let mut users: Vec<User<AccountId>> = Vec::new();
         for account in 2..23 {
@@ -571,8 +620,40 @@ fn delete_role_should_fail_with_too_many_users_for_delete_role() {
         }
 
         assert_ok!(_create_default_role()); // RoleId 1
-        assert_ok!(_grant_role(None, None, Some(users))); // Grant RoleId 1 to ACCOUNT2-ACCOUNT20
-        assert_noop!(_delete_default_role(), Error::<Test>::TooManyUsersToDeleteRole);
+        assert_ok!(_grant_role(None, None, Some(users))); // Grant RoleId 1 to ACCOUNT2-ACCOUNT22
+        assert_ok!(_delete_default_role());
+
+        // Only 20 users can be processed per block in the mock
+        let role = Roles::role_by_id(ROLE1).unwrap();
+        assert!(role.disabled);
+        assert_eq!(Roles::users_count_by_role_id(ROLE1), 1);
+        assert_eq!(Roles::role_deletion_requested_by(ROLE1), Some(ACCOUNT1));
+        System::assert_last_event(crate::Event::<Test>::RoleDeletionProgressed(ROLE1, 1).into());
+
+        run_to_block(2);
+        assert!(Roles::role_by_id(ROLE1).is_none());
+        assert!(Roles::users_by_role_id(ROLE1).is_empty());
+        assert!(Roles::role_ids_by_space_id(SPACE1).is_empty());
+        assert!(Roles::role_deletion_requested_by(ROLE1).is_none());
+        System::assert_last_event(crate::Event::<Test>::RoleDeleted(ACCOUNT1, ROLE1).into());
+    });
+}
+
+#[test]
+fn role_being_deleted_should_not_be_updated_or_granted() {
+    ExtBuilder::build().execute_with(|| {
+        let mut users: Vec<User<AccountId>> = Vec::new();
+        for account in 2..23 {
+            users.push(User::Account(account));
+        }
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role(None, None, Some(users)));
+        assert_ok!(_delete_default_role());
+
+        assert_noop!(_update_default_role(), Error::<Test>::RoleIsBeingDeleted);
+        assert_noop!(_grant_default_role(), Error::<Test>::RoleIsBeingDeleted);
+        assert_noop!(_delete_default_role(), Error::<Test>::RoleIsBeingDeleted);
     });
 }
 
@@ -588,3 +669,176 @@ fn delete_role_should_fail_with_a_few_roles_no_permission() {
         );
     });
 }
+
+#[test]
+fn grant_role_temporarily_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        let user = User::Account(ACCOUNT2);
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role_temporarily(None, None, None, None)); // Grant RoleId 1 to ACCOUNT2
+
+        // Check whether data was stored correctly
+        assert_eq!(Roles::users_by_role_id(ROLE1), vec![user.clone()]);
+        assert_eq!(Roles::role_ids_by_user_in_space(user.clone(), SPACE1), vec![ROLE1]);
+        assert_eq!(Roles::role_grant_expires_at(ROLE1, user), Some(1 + TIME_TO_LIVE));
+    });
+}
+
+#[test]
+fn grant_role_temporarily_should_fail_with_zero_time_to_live() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_noop!(_grant_role_temporarily(None, None, None, Some(0)), Error::<Test>::ZeroTimeToLive);
+    });
+}
+
+#[test]
+fn grant_role_should_make_temporary_grant_permanent() {
+    ExtBuilder::build().execute_with(|| {
+        let user = User::Account(ACCOUNT2);
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role_temporarily(None, None, None, None));
+        assert_ok!(_grant_default_role());
+        assert!(Roles::role_grant_expires_at(ROLE1, user.clone()).is_none());
+
+        run_to_block(1 + TIME_TO_LIVE);
+        assert_eq!(Roles::role_ids_by_user_in_space(user, SPACE1), vec![ROLE1]);
+    });
+}
+
+#[test]
+fn expired_grant_should_give_no_permissions_before_it_is_removed() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1 with ManageRoles permission
+        assert_ok!(_grant_role_temporarily(None, None, None, None)); // Grant RoleId 1 to ACCOUNT2
+
+        assert_ok!(_create_role(Some(Origin::signed(ACCOUNT2)), None, None, None, None)); // RoleId 2
+
+        System::set_block_number(1 + TIME_TO_LIVE);
+        assert_noop!(
+            _create_role(Some(Origin::signed(ACCOUNT2)), None, None, None, None),
+            Error::<Test>::NoPermissionToManageRoles
+        );
+    });
+}
+
+#[test]
+fn expired_grants_should_be_removed_on_initialize() {
+    ExtBuilder::build().execute_with(|| {
+        let user = User::Account(ACCOUNT2);
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role_temporarily(None, None, None, None)); // Grant RoleId 1 to ACCOUNT2
+
+        run_to_block(TIME_TO_LIVE);
+        assert_eq!(Roles::users_by_role_id(ROLE1), vec![user.clone()]);
+
+        run_to_block(1 + TIME_TO_LIVE);
+        assert!(Roles::users_by_role_id(ROLE1).is_empty());
+        assert!(Roles::role_ids_by_user_in_space(user.clone(), SPACE1).is_empty());
+        assert!(Roles::role_grant_expires_at(ROLE1, user.clone()).is_none());
+        assert_eq!(Roles::users_count_by_role_id(ROLE1), 0);
+
+        System::assert_last_event(crate::Event::<Test>::RoleGrantsExpired(ROLE1, vec![user]).into());
+    });
+}
+
+#[test]
+fn expired_grants_over_the_limit_should_be_removed_in_next_block() {
+    ExtBuilder::build().execute_with(|| {
+        let users = vec![User::Account(ACCOUNT1), User::Account(ACCOUNT2), User::Account(ACCOUNT3)];
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role_temporarily(None, None, Some(users), None));
+
+        // Only two grants can be removed per block in the mock, as checking a block takes a unit of the limit.
+        run_to_block(1 + TIME_TO_LIVE);
+        assert_eq!(Roles::users_count_by_role_id(ROLE1), 1);
+
+        run_to_block(2 + TIME_TO_LIVE);
+        assert!(Roles::users_by_role_id(ROLE1).is_empty());
+    });
+}
+
+#[test]
+fn expired_role_should_be_deleted_on_initialize() {
+    ExtBuilder::build().execute_with(|| {
+        let user = User::Account(ACCOUNT2);
+
+        assert_ok!(_create_role(None, None, Some(Some(TIME_TO_LIVE)), None, None)); // RoleId 1
+        assert_ok!(_grant_default_role()); // Grant RoleId 1 to ACCOUNT2
+
+        run_to_block(1 + TIME_TO_LIVE);
+        assert!(Roles::role_by_id(ROLE1).is_none());
+        assert!(Roles::role_ids_by_space_id(SPACE1).is_empty());
+        assert!(Roles::role_ids_by_user_in_space(user, SPACE1).is_empty());
+
+        System::assert_last_event(crate::Event::<Test>::RoleExpired(ROLE1).into());
+    });
+}
+
+#[test]
+fn regranted_role_should_not_expire_at_previous_block() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_role_temporarily(None, None, None, None)); // Grant RoleId 1 to ACCOUNT2
+        assert_ok!(_grant_role_temporarily(None, None, None, Some(2 * TIME_TO_LIVE)));
+
+        let grant = (ROLE1, User::Account(ACCOUNT2));
+        assert!(!RoleGrantsExpiringAt::<Test>::contains_key(1 + TIME_TO_LIVE, grant.clone()));
+        assert!(RoleGrantsExpiringAt::<Test>::contains_key(1 + 2 * TIME_TO_LIVE, grant));
+
+        run_to_block(1 + TIME_TO_LIVE);
+        assert_eq!(Roles::users_by_role_id(ROLE1), vec![User::Account(ACCOUNT2)]);
+    });
+}
+
+#[test]
+fn schedule_expiring_roles_should_schedule_roles_created_before_it() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_role(None, None, Some(Some(TIME_TO_LIVE)), None, None)); // RoleId 1
+        assert_ok!(_create_default_role()); // RoleId 2
+
+        // As if the role was created before roles were scheduled to expire:
+        RolesExpiringAt::<Test>::remove(1 + TIME_TO_LIVE, ROLE1);
+        StorageVersion::new(2).put::<Roles>();
+
+        migrations::schedule_expiring_roles::<Test>();
+        assert!(RolesExpiringAt::<Test>::contains_key(1 + TIME_TO_LIVE, ROLE1));
+        assert!(RolesExpiringAt::<Test>::iter_key_prefix(1 + TIME_TO_LIVE).all(|role_id| role_id == ROLE1));
+
+        run_to_block(1 + TIME_TO_LIVE);
+        assert!(Roles::role_by_id(ROLE1).is_none());
+        assert!(Roles::role_by_id(ROLE2).is_some());
+    });
+}
+
+#[test]
+fn move_users_by_role_id_to_double_map_should_count_moved_users() {
+    ExtBuilder::build().execute_with(|| {
+        use frame_support::storage::{generator::StorageDoubleMap, unhashed};
+        use pallet_utils::{MoveVecMapToDoubleMap, MultiBlockMigration, VecMapMigrationCursors};
+
+        assert_ok!(_create_default_role()); // RoleId 1
+        assert_ok!(_grant_default_role()); // Granted to ACCOUNT2
+
+        // As if the users of the role were stored in a `Vec` before `UsersByRoleId` became a double map:
+        let old_users = vec![User::Account(ACCOUNT2), User::Account(ACCOUNT3), User::Space(SPACE1)];
+        unhashed::put(&UsersByRoleId::<Test>::storage_double_map_final_key1(ROLE1), &old_users);
+        StorageVersion::new(1).put::<Roles>();
+
+        migrations::move_users_by_role_id_to_double_map::<Test>();
+        <MoveVecMapToDoubleMap<
+            UsersByRoleId<Test>,
+            RoleId,
+            User<AccountId>,
+            migrations::CountUsersMovedToDoubleMap<Test>,
+        > as MultiBlockMigration<Test>>::migrate_step(&mut 100);
+
+        assert!(VecMapMigrationCursors::<Test>::iter().next().is_none());
+        assert!(old_users.iter().all(|user| UsersByRoleId::<Test>::contains_key(ROLE1, user)));
+        assert_eq!(UsersCountByRoleId::<Test>::get(ROLE1), 3);
+    });
+}
@@ -0,0 +1,147 @@
+//! Hand-written weights for pallet_roles
+//!
+//! These weights are estimated from the storage reads and writes of the extrinsics
+//! and are not benchmarked. Replace this file with the output of
+//! `./scripts/run-benchmark-on.sh pallet_roles ./pallets/roles/src` run on the reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_roles.
+pub trait WeightInfo {
+	fn create_role() -> Weight;
+	fn update_role() -> Weight;
+	fn delete_role(u: u32, ) -> Weight;
+	fn grant_role(u: u32, ) -> Weight;
+	fn revoke_role(u: u32, ) -> Weight;
+	fn grant_role_temporarily(u: u32, ) -> Weight;
+	fn remove_expired_grants_and_roles(n: u32, ) -> Weight;
+	fn continue_role_deletions(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_roles using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn create_role() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn update_role() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn delete_role(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn grant_role(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn revoke_role(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn grant_role_temporarily(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(u as Weight)))
+	}
+	fn remove_expired_grants_and_roles(n: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	fn continue_role_deletions(n: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_role() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn update_role() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn delete_role(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
+	}
+	fn grant_role(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn revoke_role(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn grant_role_temporarily(u: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(u as Weight)))
+	}
+	fn remove_expired_grants_and_roles(n: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
+	fn continue_role_deletions(n: u32, ) -> Weight {
+		(1_000_000 as Weight)
+			.saturating_add((500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+}
@@ -0,0 +1,357 @@
+//! # Scores Module
+//!
+//! This module updates the score of posts and spaces and the reputation of accounts
+//! when other accounts upvote, downvote, share, comment or follow them.
+//!
+//! The weight of every scoring action is set in the pallet's `Config`. A score diff of an action
+//! is its weight multiplied by `log_2` of the actor's reputation (plus one), so actions
+//! of accounts with a low reputation count for less.
+//!
+//! Every applied score diff is stored per actor, so it can be reverted exactly
+//! when a reaction or a follow is undone. Accounts cannot score their own posts and spaces.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::Get,
+};
+use sp_runtime::RuntimeDebug;
+
+use pallet_posts::{Pallet as Posts, Post, PostScores};
+use pallet_profile_follows::{BeforeAccountFollowed, BeforeAccountUnfollowed};
+use pallet_profiles::{Pallet as Profiles, SocialAccountById};
+use pallet_reactions::{ReactionKind, ReactionScores, ReactionTarget};
+use pallet_space_follows::{BeforeSpaceFollowed, BeforeSpaceUnfollowed};
+use pallet_spaces::{Pallet as Spaces, Space};
+use pallet_utils::{log_2, PostId, SpaceId};
+
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ScoringAction {
+    UpvotePost,
+    DownvotePost,
+    SharePost,
+    CreateComment,
+    UpvoteComment,
+    DownvoteComment,
+    ShareComment,
+    FollowSpace,
+    FollowAccount,
+}
+
+/// The diffs that an action of an account has applied to the score of a post or a space
+/// and to the reputation of its owner.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ScoreDiff {
+    pub score: i16,
+
+    /// The diff that was actually applied to the owner's reputation,
+    /// as a reputation cannot go below the minimum one.
+    pub reputation: i16,
+}
+
+/// The diffs that following a space has applied, and the owner of this space whose reputation
+/// was changed. The diffs are reverted on this account, even if the space is transferred since then.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SpaceScoreDiff<AccountId> {
+    pub score: i16,
+    pub reputation: i16,
+    pub credited_account: AccountId,
+}
+
+/// The minimum reputation of an account, the same that a new social account has.
+pub const MIN_REPUTATION: u32 = 1;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_posts::Config
+        + pallet_profile_follows::Config
+        + pallet_profiles::Config
+        + pallet_reactions::Config
+        + pallet_space_follows::Config
+        + pallet_spaces::Config
+    {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        // Weights of the related social account actions
+        #[pallet::constant]
+        type FollowSpaceActionWeight: Get<i16>;
+        #[pallet::constant]
+        type FollowAccountActionWeight: Get<i16>;
+
+        #[pallet::constant]
+        type SharePostActionWeight: Get<i16>;
+        #[pallet::constant]
+        type UpvotePostActionWeight: Get<i16>;
+        #[pallet::constant]
+        type DownvotePostActionWeight: Get<i16>;
+
+        #[pallet::constant]
+        type CreateCommentActionWeight: Get<i16>;
+        #[pallet::constant]
+        type ShareCommentActionWeight: Get<i16>;
+        #[pallet::constant]
+        type UpvoteCommentActionWeight: Get<i16>;
+        #[pallet::constant]
+        type DownvoteCommentActionWeight: Get<i16>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()
+        }
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn post_score_by_account)]
+    pub type PostScoreByAccount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (/* actor */ T::AccountId, /* subject */ PostId, ScoringAction),
+        ScoreDiff,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn space_score_by_account)]
+    pub type SpaceScoreByAccount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (/* actor */ T::AccountId, /* subject */ SpaceId, ScoringAction),
+        SpaceScoreDiff<T::AccountId>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn account_reputation_diff_by_account)]
+    pub type AccountReputationDiffByAccount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (/* actor */ T::AccountId, /* subject */ T::AccountId, ScoringAction),
+        i16,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        AccountReputationChanged(T::AccountId, ScoringAction, /* new reputation */ u32),
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {}
+}
+
+impl<T: Config> Pallet<T> {
+
+    pub fn weight_of_scoring_action(action: ScoringAction) -> i16 {
+        use ScoringAction::*;
+        match action {
+            UpvotePost => T::UpvotePostActionWeight::get(),
+            DownvotePost => T::DownvotePostActionWeight::get(),
+            SharePost => T::SharePostActionWeight::get(),
+            CreateComment => T::CreateCommentActionWeight::get(),
+            UpvoteComment => T::UpvoteCommentActionWeight::get(),
+            DownvoteComment => T::DownvoteCommentActionWeight::get(),
+            ShareComment => T::ShareCommentActionWeight::get(),
+            FollowSpace => T::FollowSpaceActionWeight::get(),
+            FollowAccount => T::FollowAccountActionWeight::get(),
+        }
+    }
+
+    pub fn score_diff_for_action(actor_reputation: u32, action: ScoringAction) -> i16 {
+        let multiplier = log_2(actor_reputation).unwrap_or_default().saturating_add(1);
+        Self::weight_of_scoring_action(action).saturating_mul(multiplier as i16)
+    }
+
+    fn reputation_of(account: T::AccountId) -> u32 {
+        Profiles::<T>::get_or_new_social_account(account).reputation
+    }
+
+    /// Change the reputation of an account and return the diff that was actually applied.
+    fn change_account_reputation(account: T::AccountId, action: ScoringAction, diff: i16) -> i16 {
+        let mut social_account = Profiles::<T>::get_or_new_social_account(account.clone());
+        let old_reputation = social_account.reputation;
+
+        social_account.change_reputation(diff);
+        social_account.reputation = social_account.reputation.max(MIN_REPUTATION);
+
+        let new_reputation = social_account.reputation;
+        <SocialAccountById<T>>::insert(account.clone(), social_account);
+
+        Self::deposit_event(Event::AccountReputationChanged(account, action, new_reputation));
+        (new_reputation as i64 - old_reputation as i64) as i16
+    }
+
+    /// Change the score of a post and, if it's a root post, the score of its space.
+    fn change_post_score(post: &Post<T>, diff: i16) {
+        let _ = Posts::<T>::mutate_post_by_id(post.id, |post| post.change_score(diff.into()));
+
+        if post.is_root_post() {
+            if let Some(space_id) = post.space_id {
+                let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| space.change_score(diff.into()));
+            }
+        }
+    }
+
+    /// Score a post only once per actor and action.
+    fn score_post(actor: T::AccountId, post_id: PostId, action: ScoringAction) {
+        let key = (actor.clone(), post_id, action);
+        if PostScoreByAccount::<T>::contains_key(&key) {
+            return;
+        }
+
+        if let Some(post) = Posts::<T>::post_by_id(post_id) {
+            if post.is_owner(&actor) {
+                return;
+            }
+
+            let score = Self::score_diff_for_action(Self::reputation_of(actor), action);
+            Self::change_post_score(&post, score);
+            let reputation = Self::change_account_reputation(post.owner, action, score);
+
+            PostScoreByAccount::<T>::insert(key, ScoreDiff { score, reputation });
+        }
+    }
+
+    fn revert_post_score(actor: T::AccountId, post_id: PostId, action: ScoringAction) {
+        if let Some(diff) = PostScoreByAccount::<T>::take((actor, post_id, action)) {
+            if let Some(post) = Posts::<T>::post_by_id(post_id) {
+                Self::change_post_score(&post, diff.score.saturating_neg());
+                Self::change_account_reputation(post.owner, action, diff.reputation.saturating_neg());
+            }
+        }
+    }
+
+    /// Only upvotes and downvotes of posts and comments are scored.
+    fn scoring_action_by_reaction(
+        target: &ReactionTarget<T::AccountId>,
+        kind: &ReactionKind,
+    ) -> Option<(PostId, ScoringAction)> {
+        if let ReactionTarget::Post(post_id) = target {
+            let is_comment = Posts::<T>::post_by_id(post_id)?.is_comment();
+
+            let action = match (kind, is_comment) {
+                (ReactionKind::Upvote, false) => ScoringAction::UpvotePost,
+                (ReactionKind::Downvote, false) => ScoringAction::DownvotePost,
+                (ReactionKind::Upvote, true) => ScoringAction::UpvoteComment,
+                (ReactionKind::Downvote, true) => ScoringAction::DownvoteComment,
+                (ReactionKind::Custom(_), _) => return None,
+            };
+
+            return Some((*post_id, action));
+        }
+
+        None
+    }
+}
+
+impl<T: Config> PostScores<T> for Pallet<T> {
+    fn score_post_on_new_share(account: T::AccountId, original_post_id: PostId) {
+        let action = match Posts::<T>::post_by_id(original_post_id) {
+            Some(post) if post.is_comment() => ScoringAction::ShareComment,
+            _ => ScoringAction::SharePost,
+        };
+        Self::score_post(account, original_post_id, action);
+    }
+
+    fn score_root_post_on_new_comment(account: T::AccountId, root_post_id: PostId) {
+        Self::score_post(account, root_post_id, ScoringAction::CreateComment);
+    }
+}
+
+impl<T: Config> ReactionScores<T> for Pallet<T> {
+    fn score_on_reaction(actor: T::AccountId, target: &ReactionTarget<T::AccountId>, kind: &ReactionKind) {
+        if let Some((post_id, action)) = Self::scoring_action_by_reaction(target, kind) {
+            Self::score_post(actor, post_id, action);
+        }
+    }
+
+    fn revert_score_on_reaction(actor: T::AccountId, target: &ReactionTarget<T::AccountId>, kind: &ReactionKind) {
+        if let Some((post_id, action)) = Self::scoring_action_by_reaction(target, kind) {
+            Self::revert_post_score(actor, post_id, action);
+        }
+    }
+}
+
+impl<T: Config> BeforeSpaceFollowed<T> for Pallet<T> {
+    fn before_space_followed(follower: T::AccountId, follower_reputation: u32, space: &mut Space<T>) -> DispatchResult {
+        let action = ScoringAction::FollowSpace;
+        let key = (follower.clone(), space.id, action);
+
+        // A space owner follows their space on its creation, which should not be scored.
+        if space.is_owner(&follower) || SpaceScoreByAccount::<T>::contains_key(&key) {
+            return Ok(());
+        }
+
+        let score = Self::score_diff_for_action(follower_reputation, action);
+        space.change_score(score.into());
+        let reputation = Self::change_account_reputation(space.owner.clone(), action, score);
+
+        SpaceScoreByAccount::<T>::insert(key, SpaceScoreDiff {
+            score,
+            reputation,
+            credited_account: space.owner.clone(),
+        });
+        Ok(())
+    }
+}
+
+impl<T: Config> BeforeSpaceUnfollowed<T> for Pallet<T> {
+    fn before_space_unfollowed(follower: T::AccountId, space: &mut Space<T>) -> DispatchResult {
+        let action = ScoringAction::FollowSpace;
+
+        if let Some(diff) = SpaceScoreByAccount::<T>::take((follower, space.id, action)) {
+            space.change_score(diff.score.saturating_neg().into());
+            Self::change_account_reputation(diff.credited_account, action, diff.reputation.saturating_neg());
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> BeforeAccountFollowed<T> for Pallet<T> {
+    fn before_account_followed(follower: T::AccountId, follower_reputation: u32, following: T::AccountId) -> DispatchResult {
+        let action = ScoringAction::FollowAccount;
+        let key = (follower, following.clone(), action);
+
+        if !AccountReputationDiffByAccount::<T>::contains_key(&key) {
+            let score = Self::score_diff_for_action(follower_reputation, action);
+            let reputation = Self::change_account_reputation(following, action, score);
+            AccountReputationDiffByAccount::<T>::insert(key, reputation);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Config> BeforeAccountUnfollowed<T> for Pallet<T> {
+    fn before_account_unfollowed(follower: T::AccountId, following: T::AccountId) -> DispatchResult {
+        let action = ScoringAction::FollowAccount;
+
+        if let Some(reputation) = AccountReputationDiffByAccount::<T>::take((follower, following.clone(), action)) {
+            Self::change_account_reputation(following, action, reputation.saturating_neg());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! Session keys pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use sp_std::{boxed::Box, vec};
+
+const SESSION_KEY_SEED: u32 = 0;
+
+fn system_scopes() -> Vec<SessionKeyScope> {
+    vec![SessionKeyScope::Pallet(b"System".to_vec())]
+}
+
+fn add_session_key<T: Config>(owner: T::AccountId, index: u32) -> Result<T::AccountId, &'static str> {
+    let key: T::AccountId = account("session_key", index, SESSION_KEY_SEED);
+    Pallet::<T>::add_key(
+        RawOrigin::Signed(owner.clone()).into(),
+        key.clone(),
+        T::MaxSessionKeyTimeToLive::get(),
+        system_scopes(),
+    )?;
+    Pallet::<T>::accept_key(RawOrigin::Signed(key.clone()).into(), owner)?;
+    Ok(key)
+}
+
+benchmarks! {
+    add_key {
+        let owner: T::AccountId = whitelisted_caller();
+        let key: T::AccountId = account("session_key", 0, SESSION_KEY_SEED);
+        let time_to_live = T::MaxSessionKeyTimeToLive::get();
+    }: _(RawOrigin::Signed(owner.clone()), key.clone(), time_to_live, system_scopes())
+    verify {
+        assert!(PendingSessionKeys::<T>::contains_key(&key, &owner));
+    }
+
+    accept_key {
+        let owner: T::AccountId = whitelisted_caller();
+        let key: T::AccountId = account("session_key", 0, SESSION_KEY_SEED);
+        Pallet::<T>::add_key(
+            RawOrigin::Signed(owner.clone()).into(),
+            key.clone(),
+            T::MaxSessionKeyTimeToLive::get(),
+            system_scopes(),
+        )?;
+    }: _(RawOrigin::Signed(key.clone()), owner.clone())
+    verify {
+        assert!(SessionKeyDetails::<T>::contains_key(&key));
+        assert_eq!(Pallet::<T>::session_keys_by_owner(&owner), vec![key]);
+    }
+
+    remove_key {
+        let owner: T::AccountId = whitelisted_caller();
+        let key = add_session_key::<T>(owner.clone(), 0)?;
+    }: _(RawOrigin::Signed(owner.clone()), key.clone())
+    verify {
+        assert!(!SessionKeyDetails::<T>::contains_key(&key));
+        assert!(Pallet::<T>::session_keys_by_owner(&owner).is_empty());
+    }
+
+    remove_keys {
+        let k in 1 .. T::MaxSessionKeysPerAccount::get().into();
+
+        let owner: T::AccountId = whitelisted_caller();
+        for i in 0 .. k {
+            add_session_key::<T>(owner.clone(), i)?;
+        }
+    }: _(RawOrigin::Signed(owner.clone()))
+    verify {
+        assert!(Pallet::<T>::session_keys_by_owner(&owner).is_empty());
+    }
+
+    proxy {
+        let owner: T::AccountId = whitelisted_caller();
+        let key = add_session_key::<T>(owner, 0)?;
+        let call: <T as Config>::Call = frame_system::Call::<T>::remark { remark: vec![] }.into();
+    }: _(RawOrigin::Signed(key), Box::new(call))
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::ExtBuilder::build(),
+        crate::mock::Test,
+    );
+}
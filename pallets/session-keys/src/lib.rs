@@ -0,0 +1,351 @@
+//! # Session Keys Module
+//!
+//! Pallet that lets an account register session keys, so that apps can act on its behalf
+//! without holding its main key or asking it to sign every action.
+//!
+//! An account proposes a session key with `add_key`, and the key starts working once it
+//! accepts this proposal with `accept_key`. So nobody can register someone else's key
+//! as their own session key and receive the calls made with it.
+//!
+//! A session key can only make the calls of pallets or single calls in its scope,
+//! and only until it expires. A call made with a session key is dispatched
+//! from the main account, so e.g. the `created.account` of a new post is the main account.
+//!
+//! Session keys are never removed implicitly: the main account revokes them explicitly,
+//! even the expired ones.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+use codec::{Decode, Encode};
+use frame_support::ensure;
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
+use sp_std::prelude::*;
+
+use pallet_utils::WhoAndWhen;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Calls that a session key is allowed to make on behalf of its owner.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum SessionKeyScope {
+    /// All calls of a pallet. \[pallet_name\]
+    Pallet(Vec<u8>),
+    /// A single call of a pallet. \[pallet_name, call_name\]
+    Call(Vec<u8>, Vec<u8>),
+}
+
+impl SessionKeyScope {
+    fn covers(&self, pallet_name: &[u8], call_name: &[u8]) -> bool {
+        match self {
+            Self::Pallet(pallet) => pallet.as_slice() == pallet_name,
+            Self::Call(pallet, call) => pallet.as_slice() == pallet_name && call.as_slice() == call_name,
+        }
+    }
+
+    fn has_empty_name(&self) -> bool {
+        match self {
+            Self::Pallet(pallet) => pallet.is_empty(),
+            Self::Call(pallet, call) => pallet.is_empty() || call.is_empty(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct SessionKey<T: Config> {
+    /// The owner of a session key is `created.account`.
+    pub created: WhoAndWhen<T>,
+    pub expires_at: T::BlockNumber,
+    pub scopes: Vec<SessionKeyScope>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{GetCallMetadata, OriginTrait},
+        weights::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Dispatchable, Saturating, Zero};
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_utils::Config {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// The overarching call type.
+        type Call: Parameter
+            + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + GetCallMetadata
+            + From<frame_system::Call<Self>>
+            + IsType<<Self as frame_system::Config>::Call>;
+
+        /// The maximum number of session keys that one account can have, including the expired ones.
+        #[pallet::constant]
+        type MaxSessionKeysPerAccount: Get<u16>;
+
+        /// The maximum number of scopes that one session key can have.
+        #[pallet::constant]
+        type MaxScopesPerSessionKey: Get<u16>;
+
+        /// The maximum number of blocks for which a session key can be added.
+        #[pallet::constant]
+        type MaxSessionKeyTimeToLive: Get<Self::BlockNumber>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::storage]
+    #[pallet::getter(fn session_key_details)]
+    pub type SessionKeyDetails<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, SessionKey<T>>;
+
+    /// Session keys proposed by owners, by (session key, owner), that the keys haven't accepted yet.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_session_key)]
+    pub type PendingSessionKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        SessionKey<T>,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn session_keys_by_owner)]
+    pub type SessionKeysByOwner<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// \[owner, session_key, expires_at\]
+        SessionKeyProposed(T::AccountId, T::AccountId, T::BlockNumber),
+        /// \[owner, session_key, expires_at\]
+        SessionKeyAdded(T::AccountId, T::AccountId, T::BlockNumber),
+        /// \[owner, session_key\]
+        SessionKeyRemoved(T::AccountId, T::AccountId),
+        /// \[owner\]
+        AllSessionKeysRemoved(T::AccountId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// An account cannot be a session key of itself.
+        CannotAddOwnAccountAsSessionKey,
+        /// This account is already a session key.
+        SessionKeyAlreadyAdded,
+        /// The owner already has the maximum number of session keys.
+        TooManySessionKeys,
+        /// A session key should have at least one scope.
+        NoScopesProvided,
+        /// A session key has more scopes than allowed.
+        TooManyScopes,
+        /// Pallet or call name in a scope is empty.
+        EmptyNameInScope,
+        /// Time to live of a session key should be greater than zero.
+        ZeroTimeToLive,
+        /// Time to live of a session key is greater than allowed.
+        TimeToLiveTooLong,
+        /// Session key not found by this account.
+        SessionKeyNotFound,
+        /// Only the owner of a session key can remove it.
+        NotASessionKeyOwner,
+        /// Session key has expired.
+        SessionKeyExpired,
+        /// This call is not in the scopes of a session key.
+        CallNotAllowedForSessionKey,
+        /// This account has not proposed such a session key.
+        SessionKeyNotProposed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Propose an account to be a session key of the origin. The key starts working
+        /// once it accepts this proposal with `accept_key`. A new proposal of the same key
+        /// by the same owner replaces the previous one.
+        #[pallet::weight(<T as Config>::WeightInfo::add_key())]
+        pub fn add_key(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            time_to_live: T::BlockNumber,
+            scopes: Vec<SessionKeyScope>,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(key != owner, Error::<T>::CannotAddOwnAccountAsSessionKey);
+            ensure!(!SessionKeyDetails::<T>::contains_key(&key), Error::<T>::SessionKeyAlreadyAdded);
+
+            ensure!(!scopes.is_empty(), Error::<T>::NoScopesProvided);
+            ensure!(scopes.len() <= T::MaxScopesPerSessionKey::get() as usize, Error::<T>::TooManyScopes);
+            ensure!(!scopes.iter().any(SessionKeyScope::has_empty_name), Error::<T>::EmptyNameInScope);
+
+            ensure!(!time_to_live.is_zero(), Error::<T>::ZeroTimeToLive);
+            ensure!(time_to_live <= T::MaxSessionKeyTimeToLive::get(), Error::<T>::TimeToLiveTooLong);
+
+            Self::ensure_can_add_key(&owner)?;
+
+            let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(time_to_live);
+            let session_key = SessionKey {
+                created: WhoAndWhen::<T>::new(owner.clone()),
+                expires_at,
+                scopes,
+            };
+
+            PendingSessionKeys::<T>::insert(&key, &owner, session_key);
+
+            Self::deposit_event(Event::SessionKeyProposed(owner, key, expires_at));
+            Ok(().into())
+        }
+
+        /// Remove a session key of the origin, or its proposal that the key hasn't accepted yet.
+        #[pallet::weight(<T as Config>::WeightInfo::remove_key())]
+        pub fn remove_key(origin: OriginFor<T>, key: T::AccountId) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+
+            if PendingSessionKeys::<T>::take(&key, &owner).is_none() {
+                let session_key = Self::require_session_key(&key)?;
+                ensure!(session_key.created.account == owner, Error::<T>::NotASessionKeyOwner);
+
+                SessionKeysByOwner::<T>::mutate(&owner, |keys| keys.retain(|k| *k != key));
+                SessionKeyDetails::<T>::remove(&key);
+            }
+
+            Self::deposit_event(Event::SessionKeyRemoved(owner, key));
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::remove_keys(T::MaxSessionKeysPerAccount::get().into()))]
+        pub fn remove_keys(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+
+            let keys = SessionKeysByOwner::<T>::take(&owner);
+            for key in keys.iter() {
+                SessionKeyDetails::<T>::remove(key);
+            }
+
+            Self::deposit_event(Event::AllSessionKeysRemoved(owner));
+            Ok(Some(<T as Config>::WeightInfo::remove_keys(keys.len() as u32)).into())
+        }
+
+        /// Dispatch a call from the owner of a session key (the signer of this extrinsic).
+        #[pallet::weight({
+            let dispatch_info = call.get_dispatch_info();
+            (
+                <T as Config>::WeightInfo::proxy().saturating_add(dispatch_info.weight),
+                dispatch_info.class,
+            )
+        })]
+        pub fn proxy(origin: OriginFor<T>, call: Box<<T as Config>::Call>) -> DispatchResultWithPostInfo {
+            let key = ensure_signed(origin)?;
+
+            let session_key = Self::require_session_key(&key)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < session_key.expires_at,
+                Error::<T>::SessionKeyExpired
+            );
+            ensure!(Self::is_call_allowed(&session_key.scopes, &call), Error::<T>::CallNotAllowedForSessionKey);
+
+            let mut origin: T::Origin = frame_system::RawOrigin::Signed(session_key.created.account).into();
+
+            // Also applies to the calls nested in this one, e.g. in a batch.
+            let scopes = session_key.scopes;
+            origin.add_filter(move |c: &<T as frame_system::Config>::Call| {
+                Self::is_call_allowed(&scopes, <T as Config>::Call::from_ref(c))
+            });
+
+            let call_info = call.get_dispatch_info();
+            let result = call.dispatch(origin);
+
+            // Refunds of the call are kept, as only the weight of proxying it is added.
+            let weight = <T as Config>::WeightInfo::proxy()
+                .saturating_add(extract_actual_weight(&result, &call_info));
+
+            result
+                .map(|_| Some(weight).into())
+                .map_err(|mut e| {
+                    e.post_info = Some(weight).into();
+                    e
+                })
+        }
+
+        /// Accept the proposal of `owner` to make the origin its session key.
+        #[pallet::weight(<T as Config>::WeightInfo::accept_key())]
+        pub fn accept_key(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResultWithPostInfo {
+            let key = ensure_signed(origin)?;
+
+            let session_key = Self::pending_session_key(&key, &owner).ok_or(Error::<T>::SessionKeyNotProposed)?;
+
+            ensure!(!SessionKeyDetails::<T>::contains_key(&key), Error::<T>::SessionKeyAlreadyAdded);
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < session_key.expires_at,
+                Error::<T>::SessionKeyExpired
+            );
+            Self::ensure_can_add_key(&owner)?;
+
+            let expires_at = session_key.expires_at;
+
+            PendingSessionKeys::<T>::remove(&key, &owner);
+            SessionKeysByOwner::<T>::mutate(&owner, |keys| keys.push(key.clone()));
+            SessionKeyDetails::<T>::insert(&key, session_key);
+
+            Self::deposit_event(Event::SessionKeyAdded(owner, key, expires_at));
+            Ok(().into())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn require_session_key(key: &T::AccountId) -> Result<SessionKey<T>, DispatchError> {
+        Ok(Self::session_key_details(key).ok_or(Error::<T>::SessionKeyNotFound)?)
+    }
+
+    /// The owner on whose behalf a session key can make a given call now, if it can.
+    pub fn owner_for_call(key: &T::AccountId, call: &<T as Config>::Call) -> Option<T::AccountId> {
+        Self::session_key_details(key)
+            .filter(|session_key| <frame_system::Pallet<T>>::block_number() < session_key.expires_at)
+            .filter(|session_key| Self::is_call_allowed(&session_key.scopes, call))
+            .map(|session_key| session_key.created.account)
+    }
+
+    fn ensure_can_add_key(owner: &T::AccountId) -> DispatchResult {
+        let keys_count = Self::session_keys_by_owner(owner).len();
+        ensure!(keys_count < T::MaxSessionKeysPerAccount::get() as usize, Error::<T>::TooManySessionKeys);
+        Ok(())
+    }
+
+    /// Calls of this pallet are never allowed, so a session key cannot manage session keys.
+    fn is_call_allowed(scopes: &[SessionKeyScope], call: &<T as Config>::Call) -> bool {
+        use frame_support::traits::{GetCallMetadata, PalletInfoAccess};
+
+        let metadata = call.get_call_metadata();
+        let pallet_name = metadata.pallet_name.as_bytes();
+        let call_name = metadata.function_name.as_bytes();
+
+        pallet_name != <Self as PalletInfoAccess>::name().as_bytes()
+            && scopes.iter().any(|scope| scope.covers(pallet_name, call_name))
+    }
+}
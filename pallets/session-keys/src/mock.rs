@@ -0,0 +1,220 @@
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup}, testing::Header,
+};
+
+use crate as session_keys;
+use crate::SessionKeyScope;
+
+use frame_support::{
+    parameter_types,
+    dispatch::DispatchResultWithPostInfo,
+    traits::Everything,
+};
+use pallet_utils::{DEFAULT_MIN_HANDLE_LEN, DEFAULT_MAX_HANDLE_LEN};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+        Utils: pallet_utils::{Pallet, Storage, Event<T>, Config<T>},
+        SessionKeys: session_keys::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = u64;
+    type DustRemoval = ();
+    type Event = Event;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+}
+
+parameter_types! {
+    pub const MinHandleLen: u32 = DEFAULT_MIN_HANDLE_LEN;
+    pub const MaxHandleLen: u32 = DEFAULT_MAX_HANDLE_LEN;
+}
+
+impl pallet_utils::Config for Test {
+    type Event = Event;
+    type Currency = Balances;
+    type MinHandleLen = MinHandleLen;
+    type MaxHandleLen = MaxHandleLen;
+    type PremiumHandleLen = ();
+    type HandleRegistrationPeriod = ();
+    type HandleGracePeriod = ();
+    type MaxHandlesToReleasePerBlock = ();
+    type MaxRawContentLen = ();
+    type StorageDepositPerItem = ();
+    type StorageDepositPerByte = ();
+    type MaxItemsToMigratePerBlock = ();
+    type MultiBlockMigrations = ();
+}
+
+parameter_types! {
+    pub const MaxSessionKeysPerAccount: u16 = 2;
+    pub const MaxScopesPerSessionKey: u16 = 2;
+    pub const MaxSessionKeyTimeToLive: BlockNumber = 100;
+}
+
+impl session_keys::Config for Test {
+    type Event = Event;
+    type Call = Call;
+    type MaxSessionKeysPerAccount = MaxSessionKeysPerAccount;
+    type MaxScopesPerSessionKey = MaxScopesPerSessionKey;
+    type MaxSessionKeyTimeToLive = MaxSessionKeyTimeToLive;
+    type WeightInfo = ();
+}
+
+pub(crate) type AccountId = u64;
+pub(crate) type BlockNumber = u64;
+
+pub(crate) const ACCOUNT1: AccountId = 1;
+pub(crate) const ACCOUNT2: AccountId = 2;
+pub(crate) const SESSION_KEY1: AccountId = 11;
+pub(crate) const SESSION_KEY2: AccountId = 12;
+pub(crate) const SESSION_KEY3: AccountId = 13;
+
+pub(crate) const TIME_TO_LIVE: BlockNumber = 10;
+
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+    pub(crate) fn build() -> TestExternalities {
+        let storage = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| System::set_block_number(1));
+
+        ext
+    }
+
+    pub(crate) fn build_with_session_key() -> TestExternalities {
+        let mut ext = Self::build();
+        ext.execute_with(|| {
+            _add_default_key().unwrap();
+        });
+        ext
+    }
+}
+
+pub(crate) fn remark_call() -> Call {
+    Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+pub(crate) fn remark_scope() -> SessionKeyScope {
+    SessionKeyScope::Call(b"System".to_vec(), b"remark".to_vec())
+}
+
+pub(crate) fn _add_default_key() -> DispatchResultWithPostInfo {
+    _add_key(None, None, None, None)
+}
+
+/// Propose a session key and accept it by that key.
+pub(crate) fn _add_key(
+    owner: Option<AccountId>,
+    key: Option<AccountId>,
+    time_to_live: Option<BlockNumber>,
+    scopes: Option<Vec<SessionKeyScope>>,
+) -> DispatchResultWithPostInfo {
+    let owner = owner.unwrap_or(ACCOUNT1);
+    let key = key.unwrap_or(SESSION_KEY1);
+
+    _propose_key(Some(owner), Some(key), time_to_live, scopes)?;
+    _accept_key(Some(key), Some(owner))
+}
+
+pub(crate) fn _propose_key(
+    owner: Option<AccountId>,
+    key: Option<AccountId>,
+    time_to_live: Option<BlockNumber>,
+    scopes: Option<Vec<SessionKeyScope>>,
+) -> DispatchResultWithPostInfo {
+    SessionKeys::add_key(
+        Origin::signed(owner.unwrap_or(ACCOUNT1)),
+        key.unwrap_or(SESSION_KEY1),
+        time_to_live.unwrap_or(TIME_TO_LIVE),
+        scopes.unwrap_or_else(|| vec![remark_scope()]),
+    )
+}
+
+pub(crate) fn _accept_key(key: Option<AccountId>, owner: Option<AccountId>) -> DispatchResultWithPostInfo {
+    SessionKeys::accept_key(
+        Origin::signed(key.unwrap_or(SESSION_KEY1)),
+        owner.unwrap_or(ACCOUNT1),
+    )
+}
+
+pub(crate) fn _remove_key(origin: Option<Origin>, key: Option<AccountId>) -> DispatchResultWithPostInfo {
+    SessionKeys::remove_key(
+        origin.unwrap_or_else(|| Origin::signed(ACCOUNT1)),
+        key.unwrap_or(SESSION_KEY1),
+    )
+}
+
+pub(crate) fn _proxy(origin: Option<Origin>, call: Option<Call>) -> DispatchResultWithPostInfo {
+    SessionKeys::proxy(
+        origin.unwrap_or_else(|| Origin::signed(SESSION_KEY1)),
+        Box::new(call.unwrap_or_else(remark_call)),
+    )
+}
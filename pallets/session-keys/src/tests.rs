@@ -0,0 +1,255 @@
+use crate::{Config, Error, Event, SessionKeyScope, WeightInfo, mock::*};
+use frame_support::{assert_ok, assert_noop, weights::GetDispatchInfo};
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+// Test `fn add_key(..)`
+
+#[test]
+fn add_key_should_propose_key() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_propose_key(None, None, None, None));
+
+        let session_key = SessionKeys::pending_session_key(SESSION_KEY1, ACCOUNT1).unwrap();
+        assert_eq!(session_key.created.account, ACCOUNT1);
+        assert_eq!(session_key.expires_at, 1 + TIME_TO_LIVE);
+        assert_eq!(session_key.scopes, vec![remark_scope()]);
+
+        // The key doesn't work until it accepts the proposal:
+        assert!(SessionKeys::session_key_details(SESSION_KEY1).is_none());
+        assert!(SessionKeys::session_keys_by_owner(ACCOUNT1).is_empty());
+        assert_noop!(_proxy(None, None), Error::<Test>::SessionKeyNotFound);
+
+        System::assert_last_event(Event::<Test>::SessionKeyProposed(ACCOUNT1, SESSION_KEY1, 1 + TIME_TO_LIVE).into());
+    });
+}
+
+#[test]
+fn add_key_should_fail_when_key_is_owner_account() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            _add_key(None, Some(ACCOUNT1), None, None),
+            Error::<Test>::CannotAddOwnAccountAsSessionKey
+        );
+    });
+}
+
+#[test]
+fn add_key_should_fail_when_key_is_already_added() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        assert_noop!(
+            _add_key(Some(ACCOUNT2), None, None, None),
+            Error::<Test>::SessionKeyAlreadyAdded
+        );
+    });
+}
+
+#[test]
+fn add_key_should_fail_when_owner_has_too_many_keys() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        assert_ok!(_add_key(None, Some(SESSION_KEY2), None, None));
+        assert_noop!(
+            _add_key(None, Some(SESSION_KEY3), None, None),
+            Error::<Test>::TooManySessionKeys
+        );
+    });
+}
+
+#[test]
+fn add_key_should_fail_when_scopes_are_invalid() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_add_key(None, None, None, Some(vec![])), Error::<Test>::NoScopesProvided);
+        assert_noop!(
+            _add_key(None, None, None, Some(vec![remark_scope(); 3])),
+            Error::<Test>::TooManyScopes
+        );
+        assert_noop!(
+            _add_key(None, None, None, Some(vec![SessionKeyScope::Call(b"System".to_vec(), vec![])])),
+            Error::<Test>::EmptyNameInScope
+        );
+    });
+}
+
+#[test]
+fn add_key_should_fail_when_time_to_live_is_invalid() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_add_key(None, None, Some(0), None), Error::<Test>::ZeroTimeToLive);
+        assert_noop!(
+            _add_key(None, None, Some(MaxSessionKeyTimeToLive::get() + 1), None),
+            Error::<Test>::TimeToLiveTooLong
+        );
+    });
+}
+
+// Test `fn accept_key(..)`
+
+#[test]
+fn accept_key_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_propose_key(None, None, None, None));
+        assert_ok!(_accept_key(None, None));
+
+        let session_key = SessionKeys::session_key_details(SESSION_KEY1).unwrap();
+        assert_eq!(session_key.created.account, ACCOUNT1);
+        assert_eq!(session_key.expires_at, 1 + TIME_TO_LIVE);
+        assert_eq!(session_key.scopes, vec![remark_scope()]);
+
+        assert_eq!(SessionKeys::session_keys_by_owner(ACCOUNT1), vec![SESSION_KEY1]);
+        assert!(SessionKeys::pending_session_key(SESSION_KEY1, ACCOUNT1).is_none());
+        System::assert_last_event(Event::<Test>::SessionKeyAdded(ACCOUNT1, SESSION_KEY1, 1 + TIME_TO_LIVE).into());
+    });
+}
+
+#[test]
+fn accept_key_should_fail_when_key_was_not_proposed_by_owner() {
+    ExtBuilder::build().execute_with(|| {
+        // Another account proposes the key first, trying to receive the calls made with it:
+        assert_ok!(_propose_key(Some(ACCOUNT2), None, None, None));
+        assert_noop!(_accept_key(None, None), Error::<Test>::SessionKeyNotProposed);
+
+        // This doesn't stop the real owner from adding its key:
+        assert_ok!(_propose_key(None, None, None, None));
+        assert_ok!(_accept_key(None, None));
+        assert_eq!(SessionKeys::session_key_details(SESSION_KEY1).unwrap().created.account, ACCOUNT1);
+        assert!(SessionKeys::session_keys_by_owner(ACCOUNT2).is_empty());
+    });
+}
+
+#[test]
+fn accept_key_should_fail_when_key_is_already_added() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_propose_key(Some(ACCOUNT2), None, None, None));
+        assert_ok!(_add_default_key());
+        assert_noop!(_accept_key(None, Some(ACCOUNT2)), Error::<Test>::SessionKeyAlreadyAdded);
+    });
+}
+
+#[test]
+fn accept_key_should_fail_when_proposal_has_expired() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_propose_key(None, None, None, None));
+        System::set_block_number(1 + TIME_TO_LIVE);
+        assert_noop!(_accept_key(None, None), Error::<Test>::SessionKeyExpired);
+    });
+}
+
+// Test `fn remove_key(..)`
+
+#[test]
+fn remove_key_should_work() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        assert_ok!(_remove_key(None, None));
+
+        assert!(SessionKeys::session_key_details(SESSION_KEY1).is_none());
+        assert!(SessionKeys::session_keys_by_owner(ACCOUNT1).is_empty());
+        System::assert_last_event(Event::<Test>::SessionKeyRemoved(ACCOUNT1, SESSION_KEY1).into());
+    });
+}
+
+#[test]
+fn remove_key_should_remove_proposed_key() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_propose_key(None, None, None, None));
+        assert_ok!(_remove_key(None, None));
+
+        assert!(SessionKeys::pending_session_key(SESSION_KEY1, ACCOUNT1).is_none());
+        assert_noop!(_accept_key(None, None), Error::<Test>::SessionKeyNotProposed);
+    });
+}
+
+#[test]
+fn remove_key_should_fail_when_origin_is_not_owner() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        assert_noop!(_remove_key(Some(Origin::signed(ACCOUNT2)), None), Error::<Test>::NotASessionKeyOwner);
+        assert_noop!(_remove_key(Some(Origin::signed(SESSION_KEY1)), None), Error::<Test>::NotASessionKeyOwner);
+    });
+}
+
+#[test]
+fn remove_key_should_fail_when_key_not_found() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_remove_key(None, None), Error::<Test>::SessionKeyNotFound);
+    });
+}
+
+// Test `fn remove_keys(..)`
+
+#[test]
+fn remove_keys_should_remove_all_keys_of_owner() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        assert_ok!(_add_key(None, Some(SESSION_KEY2), None, None));
+        assert_ok!(_add_key(Some(ACCOUNT2), Some(SESSION_KEY3), None, None));
+
+        assert_ok!(SessionKeys::remove_keys(Origin::signed(ACCOUNT1)));
+
+        assert!(SessionKeys::session_key_details(SESSION_KEY1).is_none());
+        assert!(SessionKeys::session_key_details(SESSION_KEY2).is_none());
+        assert!(SessionKeys::session_keys_by_owner(ACCOUNT1).is_empty());
+
+        assert!(SessionKeys::session_key_details(SESSION_KEY3).is_some());
+        System::assert_last_event(Event::<Test>::AllSessionKeysRemoved(ACCOUNT1).into());
+    });
+}
+
+// Test `fn proxy(..)`
+
+#[test]
+fn proxy_should_dispatch_call_from_owner() {
+    ExtBuilder::build().execute_with(|| {
+        let scopes = vec![SessionKeyScope::Pallet(b"System".to_vec())];
+        assert_ok!(_add_key(None, None, None, Some(scopes)));
+
+        let remark = b"liked".to_vec();
+        let call = Call::System(frame_system::Call::remark_with_event { remark: remark.clone() });
+        assert_ok!(_proxy(None, Some(call)));
+
+        let remark_hash: H256 = BlakeTwo256::hash(&remark);
+        System::assert_last_event(frame_system::Event::<Test>::Remarked(ACCOUNT1, remark_hash).into());
+    });
+}
+
+#[test]
+fn proxy_should_add_actual_weight_of_call_to_its_own_weight() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        let call_weight = remark_call().get_dispatch_info().weight;
+        let post_info = _proxy(None, None).unwrap();
+
+        assert_eq!(post_info.actual_weight, Some(<Test as Config>::WeightInfo::proxy() + call_weight));
+    });
+}
+
+#[test]
+fn proxy_should_fail_when_call_is_out_of_scope() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        let call = Call::System(frame_system::Call::remark_with_event { remark: vec![] });
+        assert_noop!(_proxy(None, Some(call)), Error::<Test>::CallNotAllowedForSessionKey);
+    });
+}
+
+#[test]
+fn proxy_should_fail_for_calls_of_session_keys_pallet() {
+    ExtBuilder::build().execute_with(|| {
+        let scopes = vec![SessionKeyScope::Pallet(b"SessionKeys".to_vec())];
+        assert_ok!(_add_key(None, None, None, Some(scopes)));
+
+        let call = Call::SessionKeys(crate::Call::remove_keys {});
+        assert_noop!(_proxy(None, Some(call)), Error::<Test>::CallNotAllowedForSessionKey);
+    });
+}
+
+#[test]
+fn proxy_should_fail_when_key_has_expired() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        System::set_block_number(1 + TIME_TO_LIVE);
+        assert_noop!(_proxy(None, None), Error::<Test>::SessionKeyExpired);
+    });
+}
+
+#[test]
+fn proxy_should_fail_when_key_was_removed() {
+    ExtBuilder::build_with_session_key().execute_with(|| {
+        assert_ok!(_proxy(None, None));
+        assert_ok!(_remove_key(None, None));
+        assert_noop!(_proxy(None, None), Error::<Test>::SessionKeyNotFound);
+    });
+}
@@ -12,5 +12,7 @@ sp_api::decl_runtime_apis! {
         fn get_space_ids_followed_by_account(account: AccountId) -> Vec<SpaceId>;
 
         fn filter_followed_space_ids(account: AccountId, space_ids: Vec<SpaceId>) -> Vec<SpaceId>;
+
+        fn get_space_followers_page(space_id: SpaceId, cursor: Option<AccountId>, limit: u16) -> Vec<AccountId>;
     }
 }
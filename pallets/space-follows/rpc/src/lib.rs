@@ -25,6 +25,15 @@ pub trait SpaceFollowsApi<BlockHash, AccountId> {
         account: AccountId,
         space_ids: Vec<SpaceId>,
     ) -> Result<Vec<SpaceId>>;
+
+    #[rpc(name = "spaceFollows_getSpaceFollowersPage")]
+    fn get_space_followers_page(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+        cursor: Option<AccountId>,
+        limit: u16,
+    ) -> Result<Vec<AccountId>>;
 }
 
 pub struct SpaceFollows<C, M> {
@@ -73,4 +82,18 @@ where
         let runtime_api_result = api.filter_followed_space_ids(&at, account, space_ids);
         runtime_api_result.map_err(map_rpc_error)
     }
+
+    fn get_space_followers_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        space_id: SpaceId,
+        cursor: Option<AccountId>,
+        limit: u16,
+    ) -> Result<Vec<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_space_followers_page(&at, space_id, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
 }
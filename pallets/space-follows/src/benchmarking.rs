@@ -0,0 +1,37 @@
+//! Space follows pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use pallet_utils::mock_functions::valid_content_ipfs;
+
+fn create_space<T: Config>() -> Result<SpaceId, &'static str> {
+    let space_owner: T::AccountId = account("space_owner", 0, 0);
+
+    let space_id = Spaces::<T>::next_space_id();
+    Spaces::<T>::create_space(RawOrigin::Signed(space_owner).into(), None, None, valid_content_ipfs(), None)?;
+
+    Ok(space_id)
+}
+
+benchmarks! {
+    follow_space {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>()?;
+    }: _(RawOrigin::Signed(caller.clone()), space_id)
+    verify {
+        assert!(Pallet::<T>::space_followed_by_account((caller, space_id)));
+    }
+
+    unfollow_space {
+        let caller: T::AccountId = whitelisted_caller();
+        let space_id = create_space::<T>()?;
+        Pallet::<T>::follow_space(RawOrigin::Signed(caller.clone()).into(), space_id)?;
+    }: _(RawOrigin::Signed(caller.clone()), space_id)
+    verify {
+        assert!(!Pallet::<T>::space_followed_by_account((caller, space_id)));
+    }
+}
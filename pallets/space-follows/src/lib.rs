@@ -1,39 +1,93 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure,
-    dispatch::DispatchResult,
-    traits::Get
+    ensure,
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
 };
 use sp_std::prelude::*;
-use frame_system::{self as system, ensure_signed};
+use frame_system::ensure_signed;
 
 use df_traits::{
-    SpaceFollowsProvider,
+    BeforeSpaceDeleted, SpaceFollowsProvider,
     moderation::IsAccountBlocked,
 };
-use pallet_profiles::{Module as Profiles, SocialAccountById};
-use pallet_spaces::{BeforeSpaceCreated, Module as Spaces, Space, SpaceById};
+use pallet_profiles::{Pallet as Profiles, SocialAccountById};
+use pallet_spaces::{BeforeSpaceCreated, Pallet as Spaces, Space, SpaceById};
 use pallet_utils::{Error as UtilsError, SpaceId, remove_from_vec};
 
+pub mod migrations;
 pub mod rpc;
+pub mod weights;
+pub use weights::WeightInfo;
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_spaces::Config
-    + pallet_profiles::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
-    type BeforeSpaceFollowed: BeforeSpaceFollowed<Self>;
+pub use pallet::*;
 
-    type BeforeSpaceUnfollowed: BeforeSpaceUnfollowed<Self>;
-}
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_spaces::Config
+        + pallet_profiles::Config
+    {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+
+        type BeforeSpaceFollowed: BeforeSpaceFollowed<Self>;
+
+        type BeforeSpaceUnfollowed: BeforeSpaceUnfollowed<Self>;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()
+        }
+    }
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    /// Whether an account follows a given space, by (space id, follower account).
+    #[pallet::storage]
+    pub type SpaceFollowers<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn space_followed_by_account)]
+    pub type SpaceFollowedByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::AccountId, SpaceId), bool, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn spaces_followed_by_account)]
+    pub type SpacesFollowedByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<SpaceId>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        SpaceFollowed(/* follower */ T::AccountId, /* following */ SpaceId),
+        SpaceUnfollowed(/* follower */ T::AccountId, /* unfollowing */ SpaceId),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
         /// Social account was not found by id.
         SocialAccountNotFound,
         /// Account is already a space follower.
@@ -43,70 +97,50 @@ decl_error! {
         /// Not allowed to follow a hidden space.
         CannotFollowHiddenSpace,
     }
-}
-
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as SpaceFollowsModule {
-        pub SpaceFollowers get(fn space_followers):
-            map hasher(twox_64_concat) SpaceId => Vec<T::AccountId>;
-
-        pub SpaceFollowedByAccount get(fn space_followed_by_account):
-            map hasher(blake2_128_concat) (T::AccountId, SpaceId) => bool;
-
-        pub SpacesFollowedByAccount get(fn spaces_followed_by_account):
-            map hasher(blake2_128_concat) T::AccountId => Vec<SpaceId>;
-    }
-}
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId,
-    {
-        SpaceFollowed(/* follower */ AccountId, /* following */ SpaceId),
-        SpaceUnfollowed(/* follower */ AccountId, /* unfollowing */ SpaceId),
-    }
-);
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::weight(<T as Config>::WeightInfo::follow_space())]
+        pub fn follow_space(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResultWithPostInfo {
+            let follower = ensure_signed(origin)?;
 
-// The pallet's dispatchable functions.
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
-    // Initializing errors
-    type Error = Error<T>;
+            ensure!(!Self::space_followed_by_account((follower.clone(), space_id)), Error::<T>::AlreadySpaceFollower);
 
-    // Initializing events
-    fn deposit_event() = default;
+            let space = &mut Spaces::require_space(space_id)?;
+            ensure!(!space.hidden, Error::<T>::CannotFollowHiddenSpace);
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 5)]
-    pub fn follow_space(origin, space_id: SpaceId) -> DispatchResult {
-      let follower = ensure_signed(origin)?;
+            ensure!(T::IsAccountBlocked::is_allowed_account(follower.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
 
-      ensure!(!Self::space_followed_by_account((follower.clone(), space_id)), Error::<T>::AlreadySpaceFollower);
+            Self::add_space_follower(follower, space)?;
+            <SpaceById<T>>::insert(space_id, space);
 
-      let space = &mut Spaces::require_space(space_id)?;
-      ensure!(!space.hidden, Error::<T>::CannotFollowHiddenSpace);
+            Ok(().into())
+        }
 
-      ensure!(T::IsAccountBlocked::is_allowed_account(follower.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+        #[pallet::weight(<T as Config>::WeightInfo::unfollow_space())]
+        pub fn unfollow_space(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResultWithPostInfo {
+            let follower = ensure_signed(origin)?;
 
-      Self::add_space_follower(follower, space)?;
-      <SpaceById<T>>::insert(space_id, space);
+            ensure!(Self::space_followed_by_account((follower.clone(), space_id)), Error::<T>::NotSpaceFollower);
 
-      Ok(())
+            Self::unfollow_space_by_account(follower, space_id)?;
+            Ok(().into())
+        }
     }
+}
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(5, 5)]
-    pub fn unfollow_space(origin, space_id: SpaceId) -> DispatchResult {
-      let follower = ensure_signed(origin)?;
-
-      ensure!(Self::space_followed_by_account((follower.clone(), space_id)), Error::<T>::NotSpaceFollower);
+impl<T: Config> Pallet<T> {
+    /// Get the accounts that follow a given space, sorted by account id.
+    pub fn space_followers(space_id: SpaceId) -> Vec<T::AccountId> {
+        let mut followers: Vec<T::AccountId> = SpaceFollowers::<T>::iter_prefix(space_id)
+            .map(|(follower, _)| follower)
+            .collect();
 
-      Self::unfollow_space_by_account(follower, space_id)
+        followers.sort();
+        followers
     }
-  }
-}
 
-impl<T: Config> Module<T> {
-    fn add_space_follower(follower: T::AccountId, space: &mut Space<T>) -> DispatchResult {
+    pub fn add_space_follower(follower: T::AccountId, space: &mut Space<T>) -> DispatchResult {
         space.inc_followers();
 
         let mut social_account = Profiles::get_or_new_social_account(follower.clone());
@@ -116,12 +150,12 @@ impl<T: Config> Module<T> {
             follower.clone(), social_account.reputation, space)?;
 
         let space_id = space.id;
-        <SpaceFollowers<T>>::mutate(space_id, |followers| followers.push(follower.clone()));
-        <SpaceFollowedByAccount<T>>::insert((follower.clone(), space_id), true);
-        <SpacesFollowedByAccount<T>>::mutate(follower.clone(), |space_ids| space_ids.push(space_id));
+        SpaceFollowers::<T>::insert(space_id, follower.clone(), true);
+        SpaceFollowedByAccount::<T>::insert((follower.clone(), space_id), true);
+        SpacesFollowedByAccount::<T>::mutate(follower.clone(), |space_ids| space_ids.push(space_id));
         <SocialAccountById<T>>::insert(follower.clone(), social_account);
 
-        Self::deposit_event(RawEvent::SpaceFollowed(follower, space_id));
+        Self::deposit_event(Event::SpaceFollowed(follower, space_id));
 
         Ok(())
     }
@@ -135,29 +169,47 @@ impl<T: Config> Module<T> {
 
         T::BeforeSpaceUnfollowed::before_space_unfollowed(follower.clone(), space)?;
 
-        <SpacesFollowedByAccount<T>>::mutate(follower.clone(), |space_ids| remove_from_vec(space_ids, space_id));
-        <SpaceFollowers<T>>::mutate(space_id, |account_ids| remove_from_vec(account_ids, follower.clone()));
-        <SpaceFollowedByAccount<T>>::remove((follower.clone(), space_id));
+        SpacesFollowedByAccount::<T>::mutate(follower.clone(), |space_ids| remove_from_vec(space_ids, space_id));
+        SpaceFollowers::<T>::remove(space_id, follower.clone());
+        SpaceFollowedByAccount::<T>::remove((follower.clone(), space_id));
         <SocialAccountById<T>>::insert(follower.clone(), social_account);
         <SpaceById<T>>::insert(space_id, space);
 
-        Self::deposit_event(RawEvent::SpaceUnfollowed(follower, space_id));
+        Self::deposit_event(Event::SpaceUnfollowed(follower, space_id));
         Ok(())
     }
 }
 
-impl<T: Config> SpaceFollowsProvider for Module<T> {
+impl<T: Config> SpaceFollowsProvider for Pallet<T> {
     type AccountId = T::AccountId;
 
     fn is_space_follower(account: Self::AccountId, space_id: SpaceId) -> bool {
-        Module::<T>::space_followed_by_account((account, space_id))
+        Pallet::<T>::space_followed_by_account((account, space_id))
     }
 }
 
-impl<T: Config> BeforeSpaceCreated<T> for Module<T> {
+impl<T: Config> BeforeSpaceCreated<T> for Pallet<T> {
     fn before_space_created(creator: T::AccountId, space: &mut Space<T>) -> DispatchResult {
         // Make a space creator the first follower of this space:
-        Module::<T>::add_space_follower(creator, space)
+        Pallet::<T>::add_space_follower(creator, space)
+    }
+}
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+    fn items_to_clean_up(space_id: SpaceId) -> u32 {
+        Spaces::<T>::space_by_id(space_id).map_or(0, |space| space.followers_count)
+    }
+
+    fn before_space_deleted(space_id: SpaceId) {
+        for (follower, _) in SpaceFollowers::<T>::drain_prefix(space_id) {
+            SpaceFollowedByAccount::<T>::remove((follower.clone(), space_id));
+            SpacesFollowedByAccount::<T>::mutate(follower.clone(), |space_ids| remove_from_vec(space_ids, space_id));
+            <SocialAccountById<T>>::mutate(follower, |social_account_opt| {
+                if let Some(social_account) = social_account_opt {
+                    social_account.dec_following_spaces();
+                }
+            });
+        }
     }
 }
 
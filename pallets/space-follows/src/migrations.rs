@@ -0,0 +1,18 @@
+use frame_support::traits::{Get, GetStorageVersion, StorageVersion};
+use pallet_utils::start_moving_vec_map_to_double_map;
+
+use super::*;
+
+/// Storage version 2 moves `SpaceFollowers` from `Vec`s of accounts to a double map.
+/// The accounts are moved over several blocks by `pallet_utils::MoveVecMapToDoubleMap`.
+pub fn move_space_followers_to_double_map<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let weight = start_moving_vec_map_to_double_map::<T, SpaceFollowers<T>, SpaceId, T::AccountId>();
+
+    StorageVersion::new(2).put::<Pallet<T>>();
+
+    weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+}
@@ -1,10 +1,10 @@
 use sp_std::prelude::*;
 
-use pallet_utils::SpaceId;
+use pallet_utils::{paginate_double_map_keys, SpaceId};
 
-use crate::{Module, Config};
+use crate::{Pallet, Config, SpaceFollowers};
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
     pub fn get_space_ids_followed_by_account(account: T::AccountId) -> Vec<SpaceId> {
         Self::spaces_followed_by_account(account)
     }
@@ -14,4 +14,9 @@ impl<T: Config> Module<T> {
             .filter(|space_id| Self::space_followed_by_account((&account, space_id)))
             .cloned().collect()
     }
+
+    /// Get up to `limit` followers of a given space, starting after the `cursor` account.
+    pub fn get_space_followers_page(space_id: SpaceId, cursor: Option<T::AccountId>, limit: u16) -> Vec<T::AccountId> {
+        paginate_double_map_keys::<SpaceFollowers<T>, _, _, _>(&space_id, cursor, limit.into())
+    }
 }
\ No newline at end of file
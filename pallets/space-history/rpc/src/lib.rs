@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use codec::Codec;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+
+use pallet_space_history::rpc::FlatSpaceHistoryRecord;
+use pallet_utils::{SpaceId, rpc::map_rpc_error};
+pub use space_history_runtime_api::SpaceHistoryApi as SpaceHistoryRuntimeApi;
+
+#[rpc]
+pub trait SpaceHistoryApi<BlockHash, AccountId, BlockNumber> {
+    #[rpc(name = "spaceHistory_getSpaceHistory")]
+    fn get_space_history(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatSpaceHistoryRecord<AccountId, BlockNumber>>>;
+}
+
+pub struct SpaceHistory<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> SpaceHistory<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, BlockNumber> SpaceHistoryApi<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for SpaceHistory<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    BlockNumber: Codec,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: SpaceHistoryRuntimeApi<Block, AccountId, BlockNumber>,
+{
+    fn get_space_history(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16,
+    ) -> Result<Vec<FlatSpaceHistoryRecord<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_space_history(&at, space_id, offset, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+}
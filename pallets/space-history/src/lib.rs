@@ -2,14 +2,14 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use frame_support::{decl_module, decl_storage};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::Vec;
-use frame_system::{self as system};
 
 use pallet_utils::{SpaceId, WhoAndWhen};
 use pallet_spaces::{Space, SpaceUpdate, AfterSpaceUpdated};
 
+pub mod rpc;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct SpaceHistoryRecord<T: Config> {
@@ -17,23 +17,49 @@ pub struct SpaceHistoryRecord<T: Config> {
     pub old_data: SpaceUpdate,
 }
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_spaces::Config
-    + pallet_utils::Config
-{}
-
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as SpaceHistoryModule {
-        pub EditHistory get(fn edit_history):
-            map hasher(twox_64_concat) SpaceId => Vec<SpaceHistoryRecord<T>>;
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_spaces::Config
+        + pallet_utils::Config
+    {
+        /// The maximum number of edit history records stored per space.
+        /// The oldest records are pruned when this limit is exceeded.
+        #[pallet::constant]
+        type MaxHistoryRecords: Get<u32>;
     }
-}
 
-// The pallet's dispatchable functions.
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {}
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<EditHistory<T>, Vec<SpaceHistoryRecord<T>>>()
+        }
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn edit_history)]
+    pub type EditHistory<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, Vec<SpaceHistoryRecord<T>>, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {}
 }
 
 impl<T: Config> SpaceHistoryRecord<T> {
@@ -45,9 +71,15 @@ impl<T: Config> SpaceHistoryRecord<T> {
     }
 }
 
-impl<T: Config> AfterSpaceUpdated<T> for Module<T> {
+impl<T: Config> AfterSpaceUpdated<T> for Pallet<T> {
     fn after_space_updated(sender: T::AccountId, space: &Space<T>, old_data: SpaceUpdate) {
-        <EditHistory<T>>::mutate(space.id, |ids|
-            ids.push(SpaceHistoryRecord::<T>::new(sender, old_data)));
+        EditHistory::<T>::mutate(space.id, |records| {
+            records.push(SpaceHistoryRecord::<T>::new(sender, old_data));
+
+            let max_records = T::MaxHistoryRecords::get() as usize;
+            if records.len() > max_records {
+                records.drain(..records.len() - max_records);
+            }
+        });
     }
 }
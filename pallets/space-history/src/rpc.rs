@@ -0,0 +1,76 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+use pallet_permissions::SpacePermissions;
+use pallet_utils::{SpaceId, rpc::{FlatContent, FlatEditedWhoAndWhen, ShouldSkip}};
+
+use crate::{Pallet, SpaceHistoryRecord, Config};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatSpaceHistoryRecord<AccountId, BlockNumber> {
+    #[cfg_attr(feature = "std", serde(flatten))]
+    pub edited: FlatEditedWhoAndWhen<AccountId, BlockNumber>,
+
+    /// `Some(None)` means that the space had no parent before this edit.
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_parent_id: Option<Option<SpaceId>>,
+
+    /// `Some(None)` means that the space had no handle before this edit.
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip", serialize_with = "bytes_to_string"))]
+    pub old_handle: Option<Option<Vec<u8>>>,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_content: Option<FlatContent>,
+
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_hidden: Option<bool>,
+
+    /// `Some(None)` means that the space had no permissions overridden before this edit.
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub old_permissions: Option<Option<SpacePermissions>>,
+}
+
+#[cfg(feature = "std")]
+fn bytes_to_string<S>(field: &Option<Option<Vec<u8>>>, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+    match field.clone().flatten() {
+        // If Bytes slice is invalid, then empty string will be returned
+        Some(bytes) => serializer.serialize_str(std::str::from_utf8(&bytes).unwrap_or_default()),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl<T: Config> From<SpaceHistoryRecord<T>> for FlatSpaceHistoryRecord<T::AccountId, T::BlockNumber> {
+    fn from(from: SpaceHistoryRecord<T>) -> Self {
+        let SpaceHistoryRecord { edited, old_data } = from;
+
+        Self {
+            edited: edited.into(),
+            old_parent_id: old_data.parent_id,
+            old_handle: old_data.handle,
+            old_content: old_data.content.map(|content| content.into()),
+            old_hidden: old_data.hidden,
+            old_permissions: old_data.permissions,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns edit history records of a space starting from the most recent one.
+    pub fn get_space_history(
+        space_id: SpaceId,
+        offset: u64,
+        limit: u16,
+    ) -> Vec<FlatSpaceHistoryRecord<T::AccountId, T::BlockNumber>> {
+        Self::edit_history(space_id)
+            .into_iter()
+            .rev()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|record| record.into())
+            .collect()
+    }
+}
@@ -0,0 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use pallet_space_ownership::rpc::FlatPendingOwnershipTransfer;
+use pallet_utils::SpaceId;
+
+sp_api::decl_runtime_apis! {
+    pub trait SpaceOwnershipApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec
+    {
+        fn get_incoming_ownership_transfers(
+            account: AccountId,
+            cursor: Option<SpaceId>,
+            limit: u16
+        ) -> Vec<FlatPendingOwnershipTransfer<AccountId, BlockNumber>>;
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use codec::Codec;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+
+use pallet_space_ownership::rpc::FlatPendingOwnershipTransfer;
+use pallet_utils::{SpaceId, rpc::map_rpc_error};
+pub use space_ownership_runtime_api::SpaceOwnershipApi as SpaceOwnershipRuntimeApi;
+
+#[rpc]
+pub trait SpaceOwnershipApi<BlockHash, AccountId, BlockNumber> {
+    #[rpc(name = "spaceOwnership_getIncomingOwnershipTransfers")]
+    fn get_incoming_ownership_transfers(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        cursor: Option<SpaceId>,
+        limit: u16,
+    ) -> Result<Vec<FlatPendingOwnershipTransfer<AccountId, BlockNumber>>>;
+}
+
+pub struct SpaceOwnership<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> SpaceOwnership<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, BlockNumber> SpaceOwnershipApi<<Block as BlockT>::Hash, AccountId, BlockNumber>
+    for SpaceOwnership<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    BlockNumber: Codec,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: SpaceOwnershipRuntimeApi<Block, AccountId, BlockNumber>,
+{
+    fn get_incoming_ownership_transfers(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        cursor: Option<SpaceId>,
+        limit: u16,
+    ) -> Result<Vec<FlatPendingOwnershipTransfer<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_incoming_ownership_transfers(&at, account, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+}
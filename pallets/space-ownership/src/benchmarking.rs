@@ -0,0 +1,75 @@
+//! Space ownership pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{sp_runtime::traits::Bounded, traits::Currency};
+use pallet_utils::mock_functions::valid_content_ipfs;
+
+fn space_owner<T: Config>() -> T::AccountId {
+    let owner: T::AccountId = account("space_owner", 0, 0);
+    <T as pallet_spaces::Config>::Currency::make_free_balance_be(&owner, Bounded::max_value());
+    owner
+}
+
+fn new_space_owner<T: Config>() -> T::AccountId {
+    let new_owner: T::AccountId = whitelisted_caller();
+    let minimum_balance = <T as pallet_spaces::Config>::Currency::minimum_balance();
+    <T as pallet_spaces::Config>::Currency::make_free_balance_be(&new_owner, minimum_balance);
+    new_owner
+}
+
+/// Create a space with a handle, so that the handle deposit is moved on ownership transfer.
+fn create_space<T: Config>(owner: T::AccountId) -> Result<SpaceId, &'static str> {
+    let space_id = Spaces::<T>::next_space_id();
+    Spaces::<T>::create_space(
+        RawOrigin::Signed(owner).into(),
+        None,
+        Some(b"space_handle".to_vec()),
+        valid_content_ipfs(),
+        None
+    )?;
+
+    Ok(space_id)
+}
+
+fn create_pending_transfer<T: Config>() -> Result<(T::AccountId, T::AccountId, SpaceId), &'static str> {
+    let owner = space_owner::<T>();
+    let new_owner = new_space_owner::<T>();
+    let space_id = create_space::<T>(owner.clone())?;
+
+    // Move roles and follow of the owner, so that accepting the transfer does the most work.
+    Pallet::<T>::transfer_space_ownership(RawOrigin::Signed(owner.clone()).into(), space_id, new_owner.clone(), true)?;
+
+    Ok((owner, new_owner, space_id))
+}
+
+benchmarks! {
+    transfer_space_ownership {
+        let owner = space_owner::<T>();
+        let new_owner = new_space_owner::<T>();
+        let space_id = create_space::<T>(owner.clone())?;
+    }: _(RawOrigin::Signed(owner), space_id, new_owner.clone(), true)
+    verify {
+        assert_eq!(Pallet::<T>::pending_space_owner(space_id), Some(new_owner));
+    }
+
+    accept_pending_ownership {
+        let (_, new_owner, space_id) = create_pending_transfer::<T>()?;
+    }: _(RawOrigin::Signed(new_owner.clone()), space_id)
+    verify {
+        let space = Spaces::<T>::space_by_id(space_id).ok_or("Space was not found")?;
+        assert_eq!(space.owner, new_owner);
+        assert!(Pallet::<T>::pending_space_owner(space_id).is_none());
+    }
+
+    reject_pending_ownership {
+        let (_, new_owner, space_id) = create_pending_transfer::<T>()?;
+    }: _(RawOrigin::Signed(new_owner), space_id)
+    verify {
+        assert!(Pallet::<T>::pending_space_owner(space_id).is_none());
+    }
+}
@@ -1,129 +1,321 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage,
     ensure,
-    dispatch::DispatchResult,
-    traits::Get
+    dispatch::DispatchResultWithPostInfo,
 };
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
-use frame_system::{self as system, ensure_signed};
-
-use df_traits::moderation::IsAccountBlocked;
-use pallet_spaces::{Module as Spaces, SpaceById, SpaceIdsByOwner};
-use pallet_utils::{Error as UtilsError, SpaceId, remove_from_vec};
-
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_spaces::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
-}
+use frame_system::ensure_signed;
 
-decl_error! {
-  pub enum Error for Module<T: Config> {
-    /// The current space owner cannot transfer ownership to themself.
-    CannotTranferToCurrentOwner,
-    /// Account is already an owner of a space.
-    AlreadyASpaceOwner,
-    /// There is no pending ownership transfer for a given space.
-    NoPendingTransferOnSpace,
-    /// Account is not allowed to accept ownership transfer.
-    NotAllowedToAcceptOwnershipTransfer,
-    /// Account is not allowed to reject ownership transfer.
-    NotAllowedToRejectOwnershipTransfer,
-  }
-}
+use df_traits::{BeforeSpaceDeleted, moderation::IsAccountBlocked};
+use pallet_roles::Pallet as Roles;
+use pallet_space_follows::Pallet as SpaceFollows;
+use pallet_spaces::{Pallet as Spaces, SpaceById, SpaceIdsByOwner, SpaceOwnersAction};
+use pallet_utils::{Pallet as Utils, Error as UtilsError, SpaceId, StorageItem, User};
 
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as SpaceOwnershipModule {
-        pub PendingSpaceOwner get(fn pending_space_owner):
-            map hasher(twox_64_concat) SpaceId => Option<T::AccountId>;
-    }
+pub mod migrations;
+pub mod rpc;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// A space ownership transfer that waits to be accepted or rejected by a new owner.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PendingOwnershipTransfer<T: Config> {
+    pub new_owner: T::AccountId,
+    /// The block at which this transfer expires if it was not accepted or rejected before.
+    pub expires_at: T::BlockNumber,
+    /// Whether the roles and the follow of the current owner should be moved
+    /// to the new owner when the transfer is accepted.
+    pub transfer_roles_and_follow: bool,
 }
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId,
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{One, Saturating};
+
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_spaces::Config
+        + pallet_space_follows::Config
+        + pallet_roles::Config
     {
-        SpaceOwnershipTransferCreated(/* current owner */ AccountId, SpaceId, /* new owner */ AccountId),
-        SpaceOwnershipTransferAccepted(AccountId, SpaceId),
-        SpaceOwnershipTransferRejected(AccountId, SpaceId),
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// The number of blocks after which a pending ownership transfer expires.
+        #[pallet::constant]
+        type TransferExpirationPeriod: Get<Self::BlockNumber>;
+
+        /// The maximum number of pending transfers that can expire in one block, including
+        /// one unit for each block whose expirations are checked. Transfers that don't fit
+        /// into this limit expire in the next blocks.
+        #[pallet::constant]
+        type MaxExpiredTransfersToProcessPerBlock: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
-);
 
-// The pallet's dispatchable functions.
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
 
-    // Initializing errors
-    type Error = Error<T>;
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::expire_pending_transfers(now)
+        }
 
-    // Initializing events
-    fn deposit_event() = default;
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<PendingSpaceOwner<T>, PendingOwnershipTransfer<T>>()
+        }
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_ownership_transfer)]
+    pub type PendingSpaceOwner<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, PendingOwnershipTransfer<T>, OptionQuery>;
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 1)]
-    pub fn transfer_space_ownership(origin, space_id: SpaceId, transfer_to: T::AccountId) -> DispatchResult {
-      let who = ensure_signed(origin)?;
+    /// Ids of spaces with a pending ownership transfer to a given account.
+    #[pallet::storage]
+    pub type PendingTransfersByNewOwner<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, SpaceId, bool, ValueQuery>;
 
-      let space = Spaces::<T>::require_space(space_id)?;
-      space.ensure_space_owner(who.clone())?;
+    /// Ids of spaces with a pending ownership transfer that expires at a given block.
+    #[pallet::storage]
+    pub type PendingTransfersByExpiration<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, SpaceId, bool, ValueQuery>;
 
-      ensure!(who != transfer_to, Error::<T>::CannotTranferToCurrentOwner);
-      ensure!(T::IsAccountBlocked::is_allowed_account(transfer_to.clone(), space_id), UtilsError::<T>::AccountIsBlocked);
+    /// The earliest block whose pending transfers may have not expired yet.
+    #[pallet::storage]
+    pub type NextTransferExpirationBlock<T: Config> = StorageValue<_, T::BlockNumber>;
 
-      <PendingSpaceOwner<T>>::insert(space_id, transfer_to.clone());
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        SpaceOwnershipTransferCreated(/* current owner */ T::AccountId, SpaceId, /* new owner */ T::AccountId),
+        SpaceOwnershipTransferAccepted(T::AccountId, SpaceId),
+        SpaceOwnershipTransferRejected(T::AccountId, SpaceId),
+        /// A pending ownership transfer was not accepted in time. \[space_id, new_owner\]
+        SpaceOwnershipTransferExpired(SpaceId, T::AccountId),
+    }
 
-      Self::deposit_event(RawEvent::SpaceOwnershipTransferCreated(who, space_id, transfer_to));
-      Ok(())
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The current space owner cannot transfer ownership to themself.
+        CannotTranferToCurrentOwner,
+        /// Account is already the main owner of a space.
+        AlreadyASpaceOwner,
+        /// There is no pending ownership transfer for a given space.
+        NoPendingTransferOnSpace,
+        /// Account is not allowed to accept ownership transfer.
+        NotAllowedToAcceptOwnershipTransfer,
+        /// Account is not allowed to reject ownership transfer.
+        NotAllowedToRejectOwnershipTransfer,
     }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 2)]
-    pub fn accept_pending_ownership(origin, space_id: SpaceId) -> DispatchResult {
-      let new_owner = ensure_signed(origin)?;
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Propose to transfer the ownership of a space to another account.
+        /// The transfer expires after `TransferExpirationPeriod` blocks if it is not accepted.
+        /// A new transfer of the same space replaces the previous pending one.
+        ///
+        /// If `transfer_roles_and_follow` is `true`, the roles of the current owner in this space
+        /// and their follow of this space are moved to the new owner on acceptance.
+        ///
+        /// If the space has an owners threshold greater than one, the transfer should be
+        /// approved by that many owners (see `SpaceOwnersAction::TransferOwnership`).
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_space_ownership())]
+        #[transactional]
+        pub fn transfer_space_ownership(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            transfer_to: T::AccountId,
+            transfer_roles_and_follow: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let space = Spaces::<T>::require_space(space_id)?;
+            space.ensure_space_owner(who.clone())?;
+
+            ensure!(who != transfer_to && space.owner != transfer_to, Error::<T>::CannotTranferToCurrentOwner);
+            ensure!(T::IsAccountBlocked::is_allowed_account(transfer_to.clone(), space_id), UtilsError::<T>::AccountIsBlocked);
+
+            Spaces::<T>::ensure_space_owners_approved(&space, &who, &SpaceOwnersAction::TransferOwnership(transfer_to.clone()))?;
+
+            Self::remove_pending_transfer(space_id);
+
+            let expires_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::TransferExpirationPeriod::get());
+
+            Self::insert_pending_transfer(space_id, PendingOwnershipTransfer {
+                new_owner: transfer_to.clone(),
+                expires_at,
+                transfer_roles_and_follow,
+            });
+
+            Self::deposit_event(Event::SpaceOwnershipTransferCreated(who, space_id, transfer_to));
+            Ok(().into())
+        }
 
-      let mut space = Spaces::require_space(space_id)?;
-      ensure!(!space.is_owner(&new_owner), Error::<T>::AlreadyASpaceOwner);
+        #[pallet::weight(<T as Config>::WeightInfo::accept_pending_ownership())]
+        #[transactional]
+        pub fn accept_pending_ownership(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResultWithPostInfo {
+            let new_owner = ensure_signed(origin)?;
 
-      let transfer_to = Self::pending_space_owner(space_id).ok_or(Error::<T>::NoPendingTransferOnSpace)?;
-      ensure!(new_owner == transfer_to, Error::<T>::NotAllowedToAcceptOwnershipTransfer);
+            let mut space = Spaces::require_space(space_id)?;
+            // A co-owner can become the main owner of a space.
+            ensure!(space.owner != new_owner, Error::<T>::AlreadyASpaceOwner);
 
-      // Here we know that the origin is eligible to become a new owner of this space.
-      <PendingSpaceOwner<T>>::remove(space_id);
+            let transfer = Self::pending_ownership_transfer(space_id).ok_or(Error::<T>::NoPendingTransferOnSpace)?;
+            // An expired transfer may still wait for its removal if too many transfers expired at once.
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < transfer.expires_at,
+                Error::<T>::NoPendingTransferOnSpace
+            );
+            ensure!(new_owner == transfer.new_owner, Error::<T>::NotAllowedToAcceptOwnershipTransfer);
 
-      Spaces::maybe_transfer_handle_deposit_to_new_space_owner(&space, &new_owner)?;
+            // Here we know that the origin is eligible to become a new owner of this space.
+            Self::remove_pending_transfer(space_id);
 
-      let old_owner = space.owner;
-      space.owner = new_owner.clone();
-      <SpaceById<T>>::insert(space_id, space);
+            Spaces::maybe_transfer_handle_deposit_to_new_space_owner(&space, &new_owner)?;
+            Utils::<T>::move_storage_deposit(StorageItem::Space(space_id), &new_owner)?;
+            Spaces::remove_space_co_owner(&space, &new_owner);
 
-      // Remove space id from the list of spaces by old owner
-      <SpaceIdsByOwner<T>>::mutate(old_owner, |space_ids| remove_from_vec(space_ids, space_id));
+            let old_owner = space.owner.clone();
+            space.owner = new_owner.clone();
 
-      // Add space id to the list of spaces by new owner
-      <SpaceIdsByOwner<T>>::mutate(new_owner.clone(), |ids| ids.push(space_id));
+            // Make a new owner a follower of this space, the same as a space creator is.
+            if !SpaceFollows::<T>::space_followed_by_account((new_owner.clone(), space_id)) {
+                SpaceFollows::<T>::add_space_follower(new_owner.clone(), &mut space)?;
+            }
 
-      // TODO add a new owner as a space follower? See T::BeforeSpaceCreated::before_space_created(new_owner.clone(), space)?;
+            <SpaceById<T>>::insert(space_id, space);
 
-      Self::deposit_event(RawEvent::SpaceOwnershipTransferAccepted(new_owner, space_id));
-      Ok(())
+            // Remove space id from the list of spaces by old owner
+            <SpaceIdsByOwner<T>>::remove(old_owner.clone(), space_id);
+
+            // Add space id to the list of spaces by new owner
+            <SpaceIdsByOwner<T>>::insert(new_owner.clone(), space_id, true);
+
+            if transfer.transfer_roles_and_follow {
+                Roles::<T>::move_user_roles_in_space(
+                    space_id,
+                    &User::Account(old_owner.clone()),
+                    &User::Account(new_owner.clone()),
+                );
+
+                if SpaceFollows::<T>::space_followed_by_account((old_owner.clone(), space_id)) {
+                    SpaceFollows::<T>::unfollow_space_by_account(old_owner, space_id)?;
+                }
+            }
+
+            Self::deposit_event(Event::SpaceOwnershipTransferAccepted(new_owner, space_id));
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::reject_pending_ownership())]
+        pub fn reject_pending_ownership(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let space = Spaces::<T>::require_space(space_id)?;
+            let transfer = Self::pending_ownership_transfer(space_id).ok_or(Error::<T>::NoPendingTransferOnSpace)?;
+            ensure!(who == transfer.new_owner || who == space.owner, Error::<T>::NotAllowedToRejectOwnershipTransfer);
+
+            Self::remove_pending_transfer(space_id);
+
+            Self::deposit_event(Event::SpaceOwnershipTransferRejected(who, space_id));
+            Ok(().into())
+        }
     }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 1)]
-    pub fn reject_pending_ownership(origin, space_id: SpaceId) -> DispatchResult {
-      let who = ensure_signed(origin)?;
+    impl<T: Config> Pallet<T> {
+        /// Get an account to which the ownership of a given space is being transferred, if any.
+        pub fn pending_space_owner(space_id: SpaceId) -> Option<T::AccountId> {
+            Self::pending_ownership_transfer(space_id).map(|transfer| transfer.new_owner)
+        }
+
+        fn insert_pending_transfer(space_id: SpaceId, transfer: PendingOwnershipTransfer<T>) {
+            PendingTransfersByNewOwner::<T>::insert(&transfer.new_owner, space_id, true);
+            PendingTransfersByExpiration::<T>::insert(transfer.expires_at, space_id, true);
+            PendingSpaceOwner::<T>::insert(space_id, transfer);
+        }
+
+        fn remove_pending_transfer(space_id: SpaceId) -> Option<PendingOwnershipTransfer<T>> {
+            let transfer = PendingSpaceOwner::<T>::take(space_id)?;
+            PendingTransfersByNewOwner::<T>::remove(&transfer.new_owner, space_id);
+            PendingTransfersByExpiration::<T>::remove(transfer.expires_at, space_id);
+            Some(transfer)
+        }
 
-      let space = Spaces::<T>::require_space(space_id)?;
-      let transfer_to = Self::pending_space_owner(space_id).ok_or(Error::<T>::NoPendingTransferOnSpace)?;
-      ensure!(who == transfer_to || who == space.owner, Error::<T>::NotAllowedToRejectOwnershipTransfer);
+        /// Remove the pending transfers that expired by the block `now`, at most
+        /// `MaxExpiredTransfersToProcessPerBlock` of them. The rest are removed in the next blocks.
+        fn expire_pending_transfers(now: T::BlockNumber) -> Weight {
+            let mut budget = T::MaxExpiredTransfersToProcessPerBlock::get() as usize;
+            let mut block = NextTransferExpirationBlock::<T>::get().unwrap_or(now);
 
-      <PendingSpaceOwner<T>>::remove(space_id);
+            let mut blocks_checked: u64 = 0;
+            let mut expired: u64 = 0;
+
+            while block <= now && budget > 0 {
+                budget -= 1;
+                blocks_checked += 1;
+
+                let space_ids: Vec<SpaceId> =
+                    PendingTransfersByExpiration::<T>::iter_key_prefix(block).take(budget).collect();
+                budget = budget.saturating_sub(space_ids.len());
+
+                for space_id in space_ids {
+                    if let Some(transfer) = Self::remove_pending_transfer(space_id) {
+                        Self::deposit_event(Event::SpaceOwnershipTransferExpired(space_id, transfer.new_owner));
+                    }
+                    // In case the index had no transfer behind it:
+                    PendingTransfersByExpiration::<T>::remove(block, space_id);
+                    expired += 1;
+                }
+
+                if PendingTransfersByExpiration::<T>::iter_key_prefix(block).next().is_some() {
+                    break;
+                }
+                block = block.saturating_add(One::one());
+            }
+
+            NextTransferExpirationBlock::<T>::put(block);
+
+            T::DbWeight::get().reads_writes(
+                blocks_checked.saturating_add(expired.saturating_mul(2)).saturating_add(1),
+                expired.saturating_mul(3).saturating_add(1),
+            )
+        }
+    }
+}
+
+impl<T: Config> BeforeSpaceDeleted for Pallet<T> {
+    fn items_to_clean_up(_space_id: SpaceId) -> u32 {
+        // At most one pending transfer, which is included into the base weight of a space deletion.
+        0
+    }
 
-      Self::deposit_event(RawEvent::SpaceOwnershipTransferRejected(who, space_id));
-      Ok(())
+    fn before_space_deleted(space_id: SpaceId) {
+        Self::remove_pending_transfer(space_id);
     }
-  }
 }
@@ -0,0 +1,35 @@
+use frame_support::{traits::{Get, GetStorageVersion, StorageVersion}, weights::Weight};
+use sp_runtime::traits::Saturating;
+
+use super::*;
+
+/// Turn accounts in `PendingSpaceOwner` into `PendingOwnershipTransfer`s that expire
+/// in `TransferExpirationPeriod` blocks from now and do not move roles and follow.
+/// This is storage version 2 of the pallet.
+pub fn add_expiration_to_pending_transfers<T: Config>() -> Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let expires_at = <frame_system::Pallet<T>>::block_number()
+        .saturating_add(T::TransferExpirationPeriod::get());
+
+    let mut transfers_migrated: u64 = 0;
+
+    PendingSpaceOwner::<T>::translate::<T::AccountId, _>(|space_id, new_owner| {
+        transfers_migrated += 1;
+
+        PendingTransfersByNewOwner::<T>::insert(&new_owner, space_id, true);
+        PendingTransfersByExpiration::<T>::insert(expires_at, space_id, true);
+
+        Some(PendingOwnershipTransfer {
+            new_owner,
+            expires_at,
+            transfer_roles_and_follow: false,
+        })
+    });
+
+    StorageVersion::new(2).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(transfers_migrated + 2, transfers_migrated * 3 + 1)
+}
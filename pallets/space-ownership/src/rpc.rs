@@ -0,0 +1,46 @@
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+use pallet_spaces::Pallet as Spaces;
+use pallet_utils::{paginate_double_map_keys, SpaceId};
+
+use crate::{Pallet, PendingTransfersByNewOwner, Config};
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatPendingOwnershipTransfer<AccountId, BlockNumber> {
+    pub space_id: SpaceId,
+    pub current_owner: AccountId,
+    pub new_owner: AccountId,
+    pub expires_at: BlockNumber,
+    pub transfer_roles_and_follow: bool,
+}
+
+impl<T: Config> Pallet<T> {
+    /// Get up to `limit` pending ownership transfers of spaces to a given account,
+    /// starting after the `cursor` space id.
+    pub fn get_incoming_ownership_transfers(
+        account: T::AccountId,
+        cursor: Option<SpaceId>,
+        limit: u16,
+    ) -> Vec<FlatPendingOwnershipTransfer<T::AccountId, T::BlockNumber>> {
+        paginate_double_map_keys::<PendingTransfersByNewOwner<T>, _, _, _>(&account, cursor, limit.into())
+            .into_iter()
+            .filter_map(|space_id| {
+                let transfer = Self::pending_ownership_transfer(space_id)?;
+                let space = Spaces::<T>::space_by_id(space_id)?;
+
+                Some(FlatPendingOwnershipTransfer {
+                    space_id,
+                    current_owner: space.owner,
+                    new_owner: transfer.new_owner,
+                    expires_at: transfer.expires_at,
+                    transfer_roles_and_follow: transfer.transfer_roles_and_follow,
+                })
+            })
+            .collect()
+    }
+}
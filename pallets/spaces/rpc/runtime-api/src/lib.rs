@@ -25,8 +25,14 @@ sp_api::decl_runtime_apis! {
 
         fn get_unlisted_space_ids_by_owner(owner: AccountId) -> Vec<SpaceId>;
 
+        fn get_space_ids_by_owner_page(owner: AccountId, cursor: Option<SpaceId>, limit: u16) -> Vec<SpaceId>;
+
         fn get_space_by_handle(handle: Vec<u8>) -> Option<FlatSpace<AccountId, BlockNumber>>;
 
         fn get_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId>;
+
+        fn get_subspace_ids(space_id: SpaceId) -> Vec<SpaceId>;
+
+        fn get_space_ancestor_ids(space_id: SpaceId) -> Vec<SpaceId>;
     }
 }
@@ -71,6 +71,29 @@ pub trait SpacesApi<BlockHash, AccountId, BlockNumber> {
         owner: AccountId,
     ) -> Result<Vec<SpaceId>>;
 
+    #[rpc(name = "spaces_getSpaceIdsByOwnerPage")]
+    fn get_space_ids_by_owner_page(
+        &self,
+        at: Option<BlockHash>,
+        owner: AccountId,
+        cursor: Option<SpaceId>,
+        limit: u16,
+    ) -> Result<Vec<SpaceId>>;
+
+    #[rpc(name = "spaces_getSubspaceIds")]
+    fn get_subspace_ids(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+    ) -> Result<Vec<SpaceId>>;
+
+    #[rpc(name = "spaces_getSpaceAncestorIds")]
+    fn get_space_ancestor_ids(
+        &self,
+        at: Option<BlockHash>,
+        space_id: SpaceId,
+    ) -> Result<Vec<SpaceId>>;
+
     #[rpc(name = "spaces_nextSpaceId")]
     fn get_next_space_id(&self, at: Option<BlockHash>) -> Result<SpaceId>;
 }
@@ -185,6 +208,36 @@ where
         runtime_api_result.map_err(map_rpc_error)
     }
 
+    fn get_space_ids_by_owner_page(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        owner: AccountId,
+        cursor: Option<SpaceId>,
+        limit: u16,
+    ) -> Result<Vec<SpaceId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_space_ids_by_owner_page(&at, owner, cursor, limit);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_subspace_ids(&self, at: Option<<Block as BlockT>::Hash>, space_id: SpaceId) -> Result<Vec<u64>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_subspace_ids(&at, space_id);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
+    fn get_space_ancestor_ids(&self, at: Option<<Block as BlockT>::Hash>, space_id: SpaceId) -> Result<Vec<u64>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.get_space_ancestor_ids(&at, space_id);
+        runtime_api_result.map_err(map_rpc_error)
+    }
+
     fn get_next_space_id(&self, at: Option<<Block as BlockT>::Hash>) -> Result<u64> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
@@ -0,0 +1,170 @@
+//! Spaces pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Pallet;
+use frame_system::RawOrigin;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Currency;
+use sp_runtime::traits::Bounded;
+use pallet_utils::mock_functions::{updated_content_ipfs, valid_content_ipfs};
+
+fn space_handle() -> Vec<u8> {
+    b"space_handle".to_vec()
+}
+
+fn caller_with_balance<T: Config>() -> T::AccountId {
+    let caller: T::AccountId = whitelisted_caller();
+    <T as Config>::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+    caller
+}
+
+fn create_space_with_handle<T: Config>(owner: T::AccountId) -> Result<SpaceId, &'static str> {
+    let space_id = Pallet::<T>::next_space_id();
+    Pallet::<T>::create_space(
+        RawOrigin::Signed(owner).into(),
+        None,
+        Some(space_handle()),
+        valid_content_ipfs(),
+        None
+    )?;
+
+    Ok(space_id)
+}
+
+fn create_subspaces<T: Config>(owner: T::AccountId, parent_id: SpaceId, count: u32) -> Result<(), &'static str> {
+    for _ in 0 .. count {
+        Pallet::<T>::create_space(
+            RawOrigin::Signed(owner.clone()).into(),
+            Some(parent_id),
+            None,
+            valid_content_ipfs(),
+            None
+        )?;
+    }
+
+    Ok(())
+}
+
+benchmarks! {
+    create_space {
+        let caller = caller_with_balance::<T>();
+        let space_id = Pallet::<T>::next_space_id();
+    }: _(RawOrigin::Signed(caller.clone()), None, Some(space_handle()), valid_content_ipfs(), None)
+    verify {
+        let space = Pallet::<T>::space_by_id(space_id).ok_or("Space was not created")?;
+        assert_eq!(space.owner, caller);
+        assert_eq!(Pallet::<T>::space_id_by_handle(space_handle()), Some(space_id));
+    }
+
+    update_space {
+        let s in 0 .. T::MaxSubspacesToMove::get();
+
+        let caller = caller_with_balance::<T>();
+        let space_id = create_space_with_handle::<T>(caller.clone())?;
+        create_subspaces::<T>(caller.clone(), space_id, s)?;
+
+        let new_parent_id = Pallet::<T>::next_space_id();
+        Pallet::<T>::create_space(RawOrigin::Signed(caller.clone()).into(), None, None, valid_content_ipfs(), None)?;
+
+        let update = SpaceUpdate {
+            parent_id: Some(Some(new_parent_id)),
+            handle: Some(Some(b"new_space_handle".to_vec())),
+            content: Some(updated_content_ipfs()),
+            hidden: Some(true),
+            permissions: None,
+        };
+    }: _(RawOrigin::Signed(caller), space_id, update)
+    verify {
+        let space = Pallet::<T>::space_by_id(space_id).ok_or("Space was not found")?;
+        assert_eq!(space.parent_id, Some(new_parent_id));
+        assert_eq!(space.content, updated_content_ipfs());
+        assert!(space.hidden);
+        assert_eq!(Pallet::<T>::space_id_by_handle(space_handle()), None);
+    }
+
+    delete_space {
+        let i in 0 .. 100;
+
+        let caller = caller_with_balance::<T>();
+        let space_id = create_space_with_handle::<T>(caller.clone())?;
+        create_subspaces::<T>(caller.clone(), space_id, i)?;
+
+        let max_items = i.saturating_add(T::BeforeSpaceDeleted::items_to_clean_up(space_id));
+    }: _(RawOrigin::Signed(caller), space_id, max_items)
+    verify {
+        assert!(Pallet::<T>::space_by_id(space_id).is_none());
+        assert_eq!(Pallet::<T>::space_id_by_handle(space_handle()), None);
+    }
+
+    update_settings {
+        let new_settings = SpacesSettings {
+            handles_enabled: !Pallet::<T>::settings().handles_enabled,
+        };
+    }: _(RawOrigin::Root, new_settings.clone())
+    verify {
+        assert_eq!(Pallet::<T>::settings(), new_settings);
+    }
+
+    force_unreserve_handle {
+        let caller = caller_with_balance::<T>();
+        let space_id = create_space_with_handle::<T>(caller)?;
+    }: _(RawOrigin::Root, space_handle())
+    verify {
+        assert_eq!(Pallet::<T>::space_id_by_handle(space_handle()), None);
+        let space = Pallet::<T>::space_by_id(space_id).ok_or("Space was not found")?;
+        assert!(space.handle.is_none());
+    }
+
+    update_space_owners {
+        let n in 1 .. T::MaxSpaceOwners::get() as u32 - 1;
+
+        let caller = caller_with_balance::<T>();
+        let space_id = create_space_with_handle::<T>(caller.clone())?;
+
+        let update = SpaceOwnersUpdate {
+            add_owners: (0..n).map(|i| account("co_owner", i, 0)).collect(),
+            remove_owners: vec![],
+            threshold: Some(2),
+        };
+    }: _(RawOrigin::Signed(caller), space_id, update)
+    verify {
+        assert_eq!(SpaceCoOwners::<T>::iter_prefix(space_id).count(), n as usize);
+        assert_eq!(Pallet::<T>::space_owners_threshold(space_id), 2);
+    }
+
+    approve_space_owners_action {
+        let caller = caller_with_balance::<T>();
+        let space_id = create_space_with_handle::<T>(caller.clone())?;
+        let action = SpaceOwnersAction::UpdateHandle(None);
+    }: _(RawOrigin::Signed(caller.clone()), space_id, action.clone())
+    verify {
+        let action_hash = T::Hashing::hash_of(&action);
+        assert_eq!(Pallet::<T>::space_owners_action_approvals(space_id, action_hash), vec![caller]);
+    }
+
+    revoke_space_owners_action_approval {
+        let caller = caller_with_balance::<T>();
+        let space_id = create_space_with_handle::<T>(caller.clone())?;
+        let action = SpaceOwnersAction::UpdateHandle(None);
+        Pallet::<T>::approve_space_owners_action(RawOrigin::Signed(caller.clone()).into(), space_id, action.clone())?;
+    }: _(RawOrigin::Signed(caller), space_id, action.clone())
+    verify {
+        let action_hash = T::Hashing::hash_of(&action);
+        assert!(Pallet::<T>::space_owners_action_approvals(space_id, action_hash).is_empty());
+    }
+
+    renew_space_handle {
+        let caller = caller_with_balance::<T>();
+        let space_id = create_space_with_handle::<T>(caller.clone())?;
+        let expires_at = Pallet::<T>::handle_registration_by_space_id(space_id)
+            .and_then(|registration| registration.expires_at)
+            .ok_or("Space handle does not expire")?;
+    }: _(RawOrigin::Signed(caller), space_id)
+    verify {
+        let registration = Pallet::<T>::handle_registration_by_space_id(space_id)
+            .ok_or("Space handle registration was not found")?;
+        assert!(registration.expires_at > Some(expires_at));
+    }
+}
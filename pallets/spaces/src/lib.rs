@@ -2,6 +2,21 @@
 //!
 //! Spaces are the primary components of Subsocial. This module allows you to create a Space
 //! and customize it by updating its' owner(s), content, unique handle, and permissions.
+//! A space can also be deleted by its owner, which frees its handle.
+//!
+//! A space handle is registered for `HandleRegistrationPeriod` blocks (if it's set in the utils
+//! pallet) and can be renewed by the space owner. The handle is released automatically
+//! when its grace period is over. Handles shorter than `PremiumHandleLen` need a higher deposit.
+//!
+//! Besides its main owner, a space can have co-owners. Once a space has an owners threshold
+//! greater than one, sensitive actions, such as changing a handle, a parent or a visibility,
+//! overriding permissions, transferring ownership, changing the owners or deleting a space,
+//! need the approval of that many owners
+//! (see `SpaceOwnersAction`).
+//!
+//! A space can be nested into another space by setting its `parent_id`. Subspaces inherit
+//! permissions that they don't override from their ancestors, and the nesting depth is limited
+//! by `MaxSubspaceDepth`.
 //!
 //! To understand how Spaces fit into the Subsocial ecosystem, you can think of how
 //! folders and files work in a file system. Spaces are similar to folders, that can contain Posts,
@@ -24,24 +39,34 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure,
+    ensure,
     dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
     traits::{Get, Currency, ExistenceRequirement, ReservableCurrency},
     weights::Pays,
 };
-use sp_runtime::{RuntimeDebug, traits::Zero};
+use sp_runtime::{RuntimeDebug, traits::{Hash, One, Saturating, Zero}};
 use sp_std::prelude::*;
-use frame_system::{self as system, ensure_signed, ensure_root};
+use frame_system::{ensure_signed, ensure_root};
 
 use df_traits::{
-    SpaceForRoles, SpaceForRolesProvider, PermissionChecker, SpaceFollowsProvider,
+    SpaceForRoles, SpaceForRolesProvider, PermissionChecker, SpaceFollowsProvider, BeforeSpaceDeleted,
+    HandleOwnerProvider,
     moderation::{IsAccountBlocked, IsContentBlocked},
 };
-use pallet_permissions::{Module as Permissions, SpacePermission, SpacePermissions, SpacePermissionsContext};
-use pallet_utils::{Module as Utils, Error as UtilsError, SpaceId, WhoAndWhen, Content};
+use pallet_permissions::{Pallet as Permissions, SpacePermission, SpacePermissions, SpacePermissionsContext};
+use pallet_utils::{
+    Pallet as Utils, Error as UtilsError, SpaceId, User, WhoAndWhen, Content, HandleRegistration, StorageItem, remove_from_vec,
+};
+
+pub use pallet::*;
 
 pub mod rpc;
 pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 /// Information about a space's owner, its' content, visibility and custom permissions.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -97,6 +122,28 @@ pub struct SpaceUpdate {
     pub permissions: Option<Option<SpacePermissions>>,
 }
 
+/// Changes to the co-owners of a space and to the number of owners that need to approve
+/// sensitive actions on this space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct SpaceOwnersUpdate<AccountId> {
+    pub add_owners: Vec<AccountId>,
+    pub remove_owners: Vec<AccountId>,
+    pub threshold: Option<u16>,
+}
+
+/// An action on a space that needs to be approved by at least `SpaceOwnersThreshold` owners.
+/// An owner who submits such an action counts as one of its approvers.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum SpaceOwnersAction<AccountId> {
+    UpdateHandle(Option<Vec<u8>>),
+    UpdateParent(Option<SpaceId>),
+    UpdateHidden(bool),
+    OverridePermissions(Option<SpacePermissions>),
+    TransferOwnership(AccountId),
+    UpdateOwners(SpaceOwnersUpdate<AccountId>),
+    DeleteSpace,
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct SpacesSettings {
     pub handles_enabled: bool
@@ -111,321 +158,761 @@ impl Default for SpacesSettings {
 }
 
 type BalanceOf<T> =
-  <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance;
+  <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-/// The pallet's configuration trait.
-pub trait Config: system::Config
-    + pallet_utils::Config
-    + pallet_permissions::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+pub const FIRST_SPACE_ID: u64 = 1;
+pub const RESERVED_SPACE_COUNT: u64 = 1000;
 
-    type Currency: ReservableCurrency<Self::AccountId>;
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
 
-    type Roles: PermissionChecker<AccountId=Self::AccountId>;
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
-    type SpaceFollows: SpaceFollowsProvider<AccountId=Self::AccountId>;
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config
+        + pallet_utils::Config
+        + pallet_permissions::Config
+    {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-    type BeforeSpaceCreated: BeforeSpaceCreated<Self>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
 
-    type AfterSpaceUpdated: AfterSpaceUpdated<Self>;
+        type Currency: ReservableCurrency<Self::AccountId>;
 
-    type IsAccountBlocked: IsAccountBlocked<Self::AccountId>;
+        type Roles: PermissionChecker<AccountId=Self::AccountId>;
 
-    type IsContentBlocked: IsContentBlocked;
+        type SpaceFollows: SpaceFollowsProvider<AccountId=Self::AccountId>;
 
-    type HandleDeposit: Get<BalanceOf<Self>>;
-}
+        type BeforeSpaceCreated: BeforeSpaceCreated<Self>;
 
-decl_error! {
-  pub enum Error for Module<T: Config> {
-    /// Space was not found by id.
-    SpaceNotFound,
-    /// Space handle is not unique.
-    SpaceHandleIsNotUnique,
-    /// Handles are disabled in `PalletSettings`.
-    HandlesAreDisabled,
-    /// Nothing to update in this space.
-    NoUpdatesForSpace,
-    /// Only space owners can manage this space.
-    NotASpaceOwner,
-    /// User has no permission to update this space.
-    NoPermissionToUpdateSpace,
-    /// User has no permission to create subspaces within this space.
-    NoPermissionToCreateSubspaces,
-    /// Space is at root level, no `parent_id` specified.
-    SpaceIsAtRoot,
-    /// New spaces' settings don't differ from the old ones.
-    NoUpdatesForSpacesSettings,
-  }
-}
+        type AfterSpaceUpdated: AfterSpaceUpdated<Self>;
 
-pub const FIRST_SPACE_ID: u64 = 1;
-pub const RESERVED_SPACE_COUNT: u64 = 1000;
+        type BeforeSpaceDeleted: BeforeSpaceDeleted;
 
-// This pallet's storage items.
-decl_storage! {
-    trait Store for Module<T: Config> as SpacesModule {
-
-        /// The next space id.
-        pub NextSpaceId get(fn next_space_id): SpaceId = RESERVED_SPACE_COUNT + 1;
-
-        /// Get the details of a space by its' id.
-        pub SpaceById get(fn space_by_id) build(|config: &GenesisConfig<T>| {
-          let mut spaces: Vec<(SpaceId, Space<T>)> = Vec::new();
-          let endowed_account = config.endowed_account.clone();
-          for id in FIRST_SPACE_ID..=RESERVED_SPACE_COUNT {
-            spaces.push((id, Space::<T>::new(id, None, endowed_account.clone(), Content::None, None, None)));
-          }
-          spaces
-        }):
-            map hasher(twox_64_concat) SpaceId => Option<Space<T>>;
-
-        /// Find a given space id by its' unique handle.
-        /// If a handle is not registered, nothing will be returned (`None`).
-        pub SpaceIdByHandle get(fn space_id_by_handle):
-            map hasher(blake2_128_concat) Vec<u8> => Option<SpaceId>;
-
-        /// Find the ids of all spaces owned, by a given account.
-        pub SpaceIdsByOwner get(fn space_ids_by_owner):
-            map hasher(twox_64_concat) T::AccountId => Vec<SpaceId>;
-
-        pub PalletSettings get(fn settings): SpacesSettings;
-
-        /// True if `SpaceIdByHandle` storage is already fixed.
-        // TODO delete this storage and corresponding migration, after the migration executed and the storage value is `true`.
-        pub SpaceIdByHandleStorageFixed: bool = false;
-    }
-    add_extra_genesis {
-      config(endowed_account): T::AccountId;
-      build(|_: &Self| {
-        SpaceIdByHandleStorageFixed::put(true);
-      })
-    }
-}
+        type IsAccountBlocked: IsAccountBlocked<Self::AccountId>;
 
-decl_event!(
-    pub enum Event<T> where
-        <T as system::Config>::AccountId,
-    {
-        SpaceCreated(AccountId, SpaceId),
-        SpaceUpdated(AccountId, SpaceId),
-        SpaceDeleted(AccountId, SpaceId),
+        type IsContentBlocked: IsContentBlocked;
+
+        /// Profile handles, which share one namespace with space handles.
+        type ProfileHandles: HandleOwnerProvider<Self::AccountId>;
+
+        #[pallet::constant]
+        type HandleDeposit: Get<BalanceOf<Self>>;
+
+        /// The maximum number of ancestors that a subspace can have.
+        #[pallet::constant]
+        type MaxSubspaceDepth: Get<u32>;
+
+        /// The maximum number of subspaces that can be moved together with their space
+        /// when its parent is updated.
+        #[pallet::constant]
+        type MaxSubspacesToMove: Get<u32>;
+
+        /// The maximum number of owners of a space, including its main owner.
+        #[pallet::constant]
+        type MaxSpaceOwners: Get<u16>;
+
+        /// The maximum number of actions on a space that can wait for approvals of its owners.
+        #[pallet::constant]
+        type MaxPendingSpaceOwnersActions: Get<u32>;
     }
-);
 
-// The pallet's dispatchable functions.
-decl_module! {
-  pub struct Module<T: Config> for enum Call where origin: T::Origin {
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
 
-    const HandleDeposit: BalanceOf<T> = T::HandleDeposit::get();
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::release_expired_handles(now)
+        }
 
-    // Initializing errors
-    type Error = Error<T>;
+        fn on_runtime_upgrade() -> Weight {
+            let mut final_weight = Zero::zero();
 
-    // Initializing events
-    fn deposit_event() = default;
+            if !SpaceIdByHandleStorageFixed::<T>::get() {
+                final_weight = migrations::fix_corrupted_handles_storage::<T>();
+            }
 
-    fn on_runtime_upgrade() -> frame_support::weights::Weight {
-      let mut final_weight = Zero::zero();
+            if !SpaceIdsByParentIdStorageBuilt::<T>::get() {
+                final_weight = final_weight.saturating_add(migrations::build_space_ids_by_parent_id_storage::<T>());
+            }
 
-      if !SpaceIdByHandleStorageFixed::get() {
-        final_weight = migrations::fix_corrupted_handles_storage::<T>();
-      }
+            final_weight
+        }
 
-      final_weight
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            pallet_utils::ensure_storage_version::<Self>()?;
+            pallet_utils::ensure_map_decodes::<SpaceById<T>, Space<T>>()
+        }
     }
 
-    #[weight = 500_000 + T::DbWeight::get().reads_writes(5, 4)]
-    pub fn create_space(
-      origin,
-      parent_id_opt: Option<SpaceId>,
-      handle_opt: Option<Vec<u8>>,
-      content: Content,
-      permissions_opt: Option<SpacePermissions>
-    ) -> DispatchResult {
-      let owner = ensure_signed(origin)?;
+    #[pallet::type_value]
+    pub fn DefaultForNextSpaceId() -> SpaceId {
+        RESERVED_SPACE_COUNT + 1
+    }
 
-      Utils::<T>::is_valid_content(content.clone())?;
+    /// The next space id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_space_id)]
+    pub type NextSpaceId<T: Config> = StorageValue<_, SpaceId, ValueQuery, DefaultForNextSpaceId>;
+
+    /// Get the details of a space by its' id.
+    #[pallet::storage]
+    #[pallet::getter(fn space_by_id)]
+    pub type SpaceById<T: Config> = StorageMap<_, Twox64Concat, SpaceId, Space<T>, OptionQuery>;
+
+    /// Find a given space id by its' unique handle.
+    /// If a handle is not registered, nothing will be returned (`None`).
+    #[pallet::storage]
+    #[pallet::getter(fn space_id_by_handle)]
+    pub type SpaceIdByHandle<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, SpaceId, OptionQuery>;
+
+    /// The deposit and the expiration of a handle of a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn handle_registration_by_space_id)]
+    pub type HandleRegistrationBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, HandleRegistration<T::BlockNumber, BalanceOf<T>>, OptionQuery>;
+
+    /// Ids of spaces whose handles are released at a given block, unless they are renewed.
+    #[pallet::storage]
+    pub type SpaceHandlesByReleaseBlock<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, SpaceId, bool, ValueQuery>;
+
+    /// The earliest block whose space handles may have not been released yet.
+    #[pallet::storage]
+    pub type NextHandleReleaseBlock<T: Config> = StorageValue<_, T::BlockNumber>;
+
+    /// Whether a space is owned by a given account, by (owner account, space id).
+    #[pallet::storage]
+    pub type SpaceIdsByOwner<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, SpaceId, bool, ValueQuery>;
+
+    /// Whether a given account is a co-owner of a space, by (space id, co-owner account).
+    /// The main owner of a space is stored in `Space.owner` and is not a co-owner.
+    #[pallet::storage]
+    pub type SpaceCoOwners<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultForSpaceOwnersThreshold() -> u16 {
+        1
+    }
 
-      if handle_opt.is_some() {
-        Self::ensure_handles_enabled()?;
-      }
+    /// The number of owners that need to approve a `SpaceOwnersAction` on a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn space_owners_threshold)]
+    pub type SpaceOwnersThreshold<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, u16, ValueQuery, DefaultForSpaceOwnersThreshold>;
+
+    /// The owners who approved an action on a space, by (space id, hash of `SpaceOwnersAction`).
+    #[pallet::storage]
+    #[pallet::getter(fn space_owners_action_approvals)]
+    pub type SpaceOwnersActionApprovals<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Identity, T::Hash, Vec<T::AccountId>, ValueQuery>;
+
+    /// The number of actions on a given space that have approvals in `SpaceOwnersActionApprovals`.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_space_owners_actions_count)]
+    pub type PendingSpaceOwnersActionsCount<T: Config> = StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    /// Whether a space is a direct subspace of a given space, by (parent space id, subspace id).
+    #[pallet::storage]
+    pub type SpaceIdsByParentId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, SpaceId, bool, ValueQuery>;
+
+    /// The number of direct subspaces of a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn subspaces_count)]
+    pub type SubspacesCountByParentId<T: Config> = StorageMap<_, Twox64Concat, SpaceId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn settings)]
+    pub type PalletSettings<T: Config> = StorageValue<_, SpacesSettings, ValueQuery>;
+
+    /// True if `SpaceIdByHandle` storage is already fixed.
+    // TODO delete this storage and corresponding migration, after the migration executed and the storage value is `true`.
+    #[pallet::storage]
+    pub type SpaceIdByHandleStorageFixed<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// True if `SpaceIdsByParentId` storage is already built from existing spaces.
+    // TODO delete this storage and corresponding migration, after the migration executed and the storage value is `true`.
+    #[pallet::storage]
+    pub type SpaceIdsByParentIdStorageBuilt<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub endowed_account: T::AccountId,
+    }
 
-      // TODO: add tests for this case
-      if let Some(parent_id) = parent_id_opt {
-        let parent_space = Self::require_space(parent_id)?;
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                endowed_account: Default::default(),
+            }
+        }
+    }
 
-        ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), parent_id), UtilsError::<T>::AccountIsBlocked);
-        ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), parent_id), UtilsError::<T>::ContentIsBlocked);
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for id in FIRST_SPACE_ID..=RESERVED_SPACE_COUNT {
+                let space = Space::<T>::new(id, None, self.endowed_account.clone(), Content::None, None, None);
+                SpaceById::<T>::insert(id, space);
+            }
 
-        Self::ensure_account_has_space_permission(
-          owner.clone(),
-          &parent_space,
-          SpacePermission::CreateSubspaces,
-          Error::<T>::NoPermissionToCreateSubspaces.into()
-        )?;
-      }
+            SpaceIdByHandleStorageFixed::<T>::put(true);
+            SpaceIdsByParentIdStorageBuilt::<T>::put(true);
+        }
+    }
 
-      let permissions = permissions_opt.map(|perms| {
-        Permissions::<T>::override_permissions(perms)
-      });
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        SpaceCreated(T::AccountId, SpaceId),
+        SpaceUpdated(T::AccountId, SpaceId),
+        SpaceDeleted(T::AccountId, SpaceId),
+        SpaceOwnersUpdated(T::AccountId, SpaceId),
+        SpaceHandleRenewed(T::AccountId, SpaceId),
+        /// A space handle was not renewed in time and was released. \[space_id, handle\]
+        SpaceHandleReleased(SpaceId, Vec<u8>),
+        /// An owner approved an action on a space. \[owner, space_id, action_hash\]
+        SpaceOwnersActionApproved(T::AccountId, SpaceId, T::Hash),
+        /// An owner revoked their approval of an action on a space. \[owner, space_id, action_hash\]
+        SpaceOwnersActionApprovalRevoked(T::AccountId, SpaceId, T::Hash),
+    }
 
-      let space_id = Self::next_space_id();
-      let new_space = &mut Space::new(space_id, parent_id_opt, owner.clone(), content, handle_opt.clone(), permissions);
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Space was not found by id.
+        SpaceNotFound,
+        /// Space handle is not unique: it's already reserved by another space or profile.
+        SpaceHandleIsNotUnique,
+        /// Handles are disabled in `PalletSettings`.
+        HandlesAreDisabled,
+        /// Nothing to update in this space.
+        NoUpdatesForSpace,
+        /// Only space owners can manage this space.
+        NotASpaceOwner,
+        /// User has no permission to update this space.
+        NoPermissionToUpdateSpace,
+        /// User has no permission to create subspaces within this space.
+        NoPermissionToCreateSubspaces,
+        /// A subspace owner is not allowed to delete their own subspaces within this space.
+        NoPermissionToDeleteOwnSubspaces,
+        /// User has no permission to delete any subspaces within this space.
+        NoPermissionToDeleteAnySubspace,
+        /// Space is at root level, no `parent_id` specified.
+        SpaceIsAtRoot,
+        /// Cannot make a space a subspace of itself or of its own subspaces.
+        SpaceParentCycle,
+        /// Subspaces cannot be nested deeper than `MaxSubspaceDepth`.
+        MaxSubspaceDepthReached,
+        /// Space has more than `MaxSubspacesToMove` subspaces to be moved to another parent.
+        TooManySubspacesToMove,
+        /// User has no permission to override permissions of subspaces within this space.
+        NoPermissionToOverrideSubspacePermissions,
+        /// New spaces' settings don't differ from the old ones.
+        NoUpdatesForSpacesSettings,
+        /// Nothing to update in the owners of this space.
+        NoUpdatesForSpaceOwners,
+        /// Account is already an owner of this space.
+        AlreadyASpaceOwner,
+        /// Account is not a co-owner of this space.
+        NotASpaceCoOwner,
+        /// The main owner of a space cannot be removed, only replaced by an ownership transfer.
+        CannotRemoveMainSpaceOwner,
+        /// A space cannot have more than `MaxSpaceOwners` owners.
+        TooManySpaceOwners,
+        /// Owners threshold should be at least one and not greater than the number of owners.
+        InvalidSpaceOwnersThreshold,
+        /// Not enough owners approved this action on the space.
+        NotEnoughSpaceOwnersApprovals,
+        /// This owner already approved this action on the space.
+        SpaceOwnersActionAlreadyApproved,
+        /// This owner did not approve this action on the space.
+        SpaceOwnersActionNotApproved,
+        /// A space cannot have more than `MaxPendingSpaceOwnersActions` actions waiting for approvals.
+        TooManyPendingSpaceOwnersActions,
+        /// Space has no handle.
+        SpaceHasNoHandle,
+        /// Space has more posts, followers, subspaces, roles, reactions and pending owners' actions
+        /// than allowed to delete with it.
+        TooManyItemsToDeleteWithSpace,
+    }
 
-      if let Some(handle) = handle_opt {
-        new_space.reserve_handle(handle)?;
-      }
+    // The pallet's dispatchable functions.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a space. A deposit for `Raw` content and a handle deposit
+        /// are reserved from the space owner.
+        #[pallet::weight(<T as Config>::WeightInfo::create_space())]
+        #[transactional]
+        pub fn create_space(
+            origin: OriginFor<T>,
+            parent_id_opt: Option<SpaceId>,
+            handle_opt: Option<Vec<u8>>,
+            content: Content,
+            permissions_opt: Option<SpacePermissions>
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+
+            Utils::<T>::is_valid_content(content.clone())?;
+
+            if handle_opt.is_some() {
+                Self::ensure_handles_enabled()?;
+            }
 
-      // FIXME: What's about handle reservation if this fails?
-      T::BeforeSpaceCreated::before_space_created(owner.clone(), new_space)?;
+            // TODO: add tests for this case
+            if let Some(parent_id) = parent_id_opt {
+                let parent_space = Self::require_space(parent_id)?;
 
-      <SpaceById<T>>::insert(space_id, new_space);
-      <SpaceIdsByOwner<T>>::mutate(owner.clone(), |ids| ids.push(space_id));
-      NextSpaceId::mutate(|n| { *n += 1; });
+                ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), parent_id), UtilsError::<T>::AccountIsBlocked);
+                ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), parent_id), UtilsError::<T>::ContentIsBlocked);
 
-      Self::deposit_event(RawEvent::SpaceCreated(owner, space_id));
-      Ok(())
-    }
+                Self::ensure_account_has_space_permission(
+                    owner.clone(),
+                    &parent_space,
+                    SpacePermission::CreateSubspaces,
+                    Error::<T>::NoPermissionToCreateSubspaces.into()
+                )?;
 
-    #[weight = 500_000 + T::DbWeight::get().reads_writes(3, 3)]
-    pub fn update_space(origin, space_id: SpaceId, update: SpaceUpdate) -> DispatchResult {
-      let owner = ensure_signed(origin)?;
+                if permissions_opt.is_some() {
+                    Self::ensure_account_can_override_subspace_permissions(owner.clone(), &parent_space)?;
+                }
 
-      let has_updates =
-        update.parent_id.is_some() ||
-        update.handle.is_some() ||
-        update.content.is_some() ||
-        update.hidden.is_some() ||
-        update.permissions.is_some();
+                Self::ensure_subspace_depth_allowed(parent_id)?;
+            }
+
+            let permissions = permissions_opt.map(|perms| {
+                Permissions::<T>::override_permissions(perms)
+            });
 
-      ensure!(has_updates, Error::<T>::NoUpdatesForSpace);
+            let space_id = Self::next_space_id();
+            let new_space = &mut Space::new(space_id, parent_id_opt, owner.clone(), content, handle_opt.clone(), permissions);
+
+            if let Some(handle) = handle_opt {
+                new_space.reserve_handle(handle)?;
+            }
+            Utils::<T>::reserve_storage_deposit(&owner, StorageItem::Space(space_id), new_space)?;
+
+            T::BeforeSpaceCreated::before_space_created(owner.clone(), new_space)?;
+
+            SpaceById::<T>::insert(space_id, new_space);
+            SpaceIdsByOwner::<T>::insert(owner.clone(), space_id, true);
+            if let Some(parent_id) = parent_id_opt {
+                Self::add_subspace(parent_id, space_id);
+            }
+            NextSpaceId::<T>::mutate(|n| { *n += 1; });
 
-      let mut space = Self::require_space(space_id)?;
+            Self::deposit_event(Event::SpaceCreated(owner, space_id));
+            Ok(().into())
+        }
 
-      ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
+        /// Update a space. If the space has an owners threshold greater than one,
+        /// a change of its handle, parent, visibility or permissions should be approved by that many owners.
+        ///
+        /// A space can be moved to another parent together with at most `MaxSubspacesToMove` subspaces.
+        #[pallet::weight(<T as Config>::WeightInfo::update_space(T::MaxSubspacesToMove::get()))]
+        #[transactional]
+        pub fn update_space(origin: OriginFor<T>, space_id: SpaceId, update: SpaceUpdate) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
 
-      Self::ensure_account_has_space_permission(
-        owner.clone(),
-        &space,
-        SpacePermission::UpdateSpace,
-        Error::<T>::NoPermissionToUpdateSpace.into()
-      )?;
+            let has_updates =
+                update.parent_id.is_some() ||
+                update.handle.is_some() ||
+                update.content.is_some() ||
+                update.hidden.is_some() ||
+                update.permissions.is_some();
 
-      let mut is_update_applied = false;
-      let mut old_data = SpaceUpdate::default();
+            ensure!(has_updates, Error::<T>::NoUpdatesForSpace);
 
-      // TODO: add tests for this case
-      if let Some(parent_id_opt) = update.parent_id {
-        if parent_id_opt != space.parent_id {
+            let mut space = Self::require_space(space_id)?;
 
-          if let Some(parent_id) = parent_id_opt {
-            let parent_space = Self::require_space(parent_id)?;
+            ensure!(T::IsAccountBlocked::is_allowed_account(owner.clone(), space.id), UtilsError::<T>::AccountIsBlocked);
 
             Self::ensure_account_has_space_permission(
-              owner.clone(),
-              &parent_space,
-              SpacePermission::CreateSubspaces,
-              Error::<T>::NoPermissionToCreateSubspaces.into()
+                owner.clone(),
+                &space,
+                SpacePermission::UpdateSpace,
+                Error::<T>::NoPermissionToUpdateSpace.into()
             )?;
-          }
 
-          old_data.parent_id = Some(space.parent_id);
-          space.parent_id = parent_id_opt;
-          is_update_applied = true;
-        }
-      }
+            let mut is_update_applied = false;
+            let mut old_data = SpaceUpdate::default();
+            let mut subspaces_checked: u32 = 0;
+
+            if let Some(parent_id_opt) = update.parent_id {
+                if parent_id_opt != space.parent_id {
+
+                    if let Some(parent_id) = parent_id_opt {
+                        let parent_space = Self::require_space(parent_id)?;
+
+                        ensure!(!Self::is_parent_cycle(space_id, parent_id), Error::<T>::SpaceParentCycle);
+
+                        Self::ensure_account_has_space_permission(
+                            owner.clone(),
+                            &parent_space,
+                            SpacePermission::CreateSubspaces,
+                            Error::<T>::NoPermissionToCreateSubspaces.into()
+                        )?;
+
+                        let max_height = Self::max_subspaces_height_under(parent_id)?;
+                        subspaces_checked = Self::ensure_subspaces_height_allowed(space_id, max_height)?;
+                    }
+
+                    Self::ensure_space_owners_approved(&space, &owner, &SpaceOwnersAction::UpdateParent(parent_id_opt))?;
+
+                    old_data.parent_id = Some(space.parent_id);
+                    space.parent_id = parent_id_opt;
+                    is_update_applied = true;
+                }
+            }
 
-      if let Some(content) = update.content {
-        if content != space.content {
-          Utils::<T>::is_valid_content(content.clone())?;
+            if let Some(content) = update.content {
+                if content != space.content {
+                    Utils::<T>::is_valid_content(content.clone())?;
 
-          ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), space.id), UtilsError::<T>::ContentIsBlocked);
-          if let Some(parent_id) = space.parent_id {
-            ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), parent_id), UtilsError::<T>::ContentIsBlocked);
-          }
+                    ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), space.id), UtilsError::<T>::ContentIsBlocked);
+                    if let Some(parent_id) = space.parent_id {
+                        ensure!(T::IsContentBlocked::is_allowed_content(content.clone(), parent_id), UtilsError::<T>::ContentIsBlocked);
+                    }
 
-          old_data.content = Some(space.content);
-          space.content = content;
-          is_update_applied = true;
+                    old_data.content = Some(space.content);
+                    space.content = content;
+                    is_update_applied = true;
+                }
+            }
+
+            if let Some(hidden) = update.hidden {
+                if hidden != space.hidden {
+                    Self::ensure_space_owners_approved(&space, &owner, &SpaceOwnersAction::UpdateHidden(hidden))?;
+
+                    old_data.hidden = Some(space.hidden);
+                    space.hidden = hidden;
+                    is_update_applied = true;
+                }
+            }
+
+            if let Some(overrides_opt) = update.permissions {
+                if space.permissions != overrides_opt {
+                    if let Some(parent_id) = space.parent_id {
+                        let parent_space = Self::require_space(parent_id)?;
+                        Self::ensure_account_can_override_subspace_permissions(owner.clone(), &parent_space)?;
+                    }
+
+                    Self::ensure_space_owners_approved(
+                        &space,
+                        &owner,
+                        &SpaceOwnersAction::OverridePermissions(overrides_opt.clone())
+                    )?;
+
+                    old_data.permissions = Some(space.permissions);
+
+                    if let Some(overrides) = overrides_opt.clone() {
+                        space.permissions = Some(Permissions::<T>::override_permissions(overrides));
+                    } else {
+                        space.permissions = overrides_opt;
+                    }
+
+                    is_update_applied = true;
+                }
+            }
+
+            if let Some(new_handle_opt) = update.handle.clone() {
+                if new_handle_opt != space.handle {
+                    Self::ensure_space_owners_approved(&space, &owner, &SpaceOwnersAction::UpdateHandle(new_handle_opt))?;
+                }
+            }
+
+            let is_handle_updated = Self::update_handle(&space, update.handle.clone())?;
+            if is_handle_updated {
+                    old_data.handle = Some(space.handle);
+                    space.handle = update.handle.unwrap();
+                    is_update_applied = true
+                }
+
+            // Update this space only if at least one field should be updated:
+            if is_update_applied {
+                space.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
+
+                if let Some(old_parent_id_opt) = old_data.parent_id {
+                    if let Some(old_parent_id) = old_parent_id_opt {
+                        Self::remove_subspace(old_parent_id, space_id);
+                    }
+                    if let Some(new_parent_id) = space.parent_id {
+                        Self::add_subspace(new_parent_id, space_id);
+                    }
+                }
+
+                // A storage deposit stays with the space owner, even if an editor is another account.
+                Utils::<T>::update_storage_deposit(StorageItem::Space(space_id), &space)?;
+
+                SpaceById::<T>::insert(space_id, space.clone());
+                T::AfterSpaceUpdated::after_space_updated(owner.clone(), &space, old_data);
+
+                Self::deposit_event(Event::SpaceUpdated(owner, space_id));
+            }
+            Ok(Some(<T as Config>::WeightInfo::update_space(subspaces_checked)).into())
         }
-      }
 
-      if let Some(hidden) = update.hidden {
-        if hidden != space.hidden {
-          old_data.hidden = Some(space.hidden);
-          space.hidden = hidden;
-          is_update_applied = true;
+        /// Delete a space, unreserve its handle and return the handle and storage deposits to the space owner.
+        /// Posts of this space are moved out of it (their `space_id` becomes `None`),
+        /// while roles, followers and a pending ownership transfer of this space are removed.
+        /// Subspaces of this space are moved to the root level.
+        ///
+        /// `max_items` is the maximum number of posts, followers, subspaces, roles, role grants, reactions
+        /// and pending owners' actions that can be removed or updated together with this space. The deletion fails
+        /// if the space has more of them, so that its weight stays bounded.
+        ///
+        /// If the space has an owners threshold greater than one, its deletion by one of its owners
+        /// should be approved by that many owners.
+        #[pallet::weight(<T as Config>::WeightInfo::delete_space(*max_items))]
+        #[transactional]
+        pub fn delete_space(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            max_items: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+
+            let subspaces_count = Self::subspaces_count(space_id);
+            let items_count = T::BeforeSpaceDeleted::items_to_clean_up(space_id)
+                .saturating_add(subspaces_count)
+                .saturating_add(Self::pending_space_owners_actions_count(space_id));
+            ensure!(items_count <= max_items, Error::<T>::TooManyItemsToDeleteWithSpace);
+
+            if let Some(parent_id) = space.parent_id {
+                let parent_space = Self::require_space(parent_id)?;
+
+                ensure!(T::IsAccountBlocked::is_allowed_account(who.clone(), parent_id), UtilsError::<T>::AccountIsBlocked);
+
+                let (permission_to_check, permission_error) = if space.is_owner(&who) {
+                    (SpacePermission::DeleteOwnSubspaces, Error::<T>::NoPermissionToDeleteOwnSubspaces)
+                } else {
+                    (SpacePermission::DeleteAnySubspace, Error::<T>::NoPermissionToDeleteAnySubspace)
+                };
+
+                Self::ensure_account_has_space_permission(
+                    who.clone(),
+                    &parent_space,
+                    permission_to_check,
+                    permission_error.into()
+                )?;
+            } else {
+                space.ensure_space_owner(who.clone())?;
+            }
+
+            // A moderator of a parent space deletes a subspace on behalf of that parent space.
+            if space.is_owner(&who) {
+                Self::ensure_space_owners_approved(&space, &who, &SpaceOwnersAction::DeleteSpace)?;
+            }
+
+            if let Some(handle) = space.handle.clone() {
+                space.unreserve_handle(handle)?;
+            }
+            Utils::<T>::release_storage_deposit(StorageItem::Space(space_id));
+
+            T::BeforeSpaceDeleted::before_space_deleted(space_id);
+
+            for (subspace_id, _) in SpaceIdsByParentId::<T>::drain_prefix(space_id) {
+                SpaceById::<T>::mutate(subspace_id, |subspace_opt| {
+                    if let Some(subspace) = subspace_opt {
+                        subspace.parent_id = None;
+                    }
+                });
+            }
+            SubspacesCountByParentId::<T>::remove(space_id);
+            if let Some(parent_id) = space.parent_id {
+                Self::remove_subspace(parent_id, space_id);
+            }
+
+            SpaceIdsByOwner::<T>::remove(space.owner.clone(), space_id);
+            for (co_owner, _) in SpaceCoOwners::<T>::drain_prefix(space_id) {
+                SpaceIdsByOwner::<T>::remove(co_owner, space_id);
+            }
+            SpaceOwnersThreshold::<T>::remove(space_id);
+            let pending_actions_count = PendingSpaceOwnersActionsCount::<T>::take(space_id);
+            SpaceOwnersActionApprovals::<T>::remove_prefix(space_id, Some(pending_actions_count));
+            SpaceById::<T>::remove(space_id);
+
+            Self::deposit_event(Event::SpaceDeleted(who, space_id));
+            Ok(Some(<T as Config>::WeightInfo::delete_space(items_count)).into())
         }
-      }
 
-      if let Some(overrides_opt) = update.permissions {
-        if space.permissions != overrides_opt {
-          old_data.permissions = Some(space.permissions);
+        #[pallet::weight(<T as Config>::WeightInfo::update_settings())]
+        pub fn update_settings(origin: OriginFor<T>, new_settings: SpacesSettings) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let space_settings = Self::settings();
+            ensure!(space_settings != new_settings, Error::<T>::NoUpdatesForSpacesSettings);
 
-          if let Some(overrides) = overrides_opt.clone() {
-            space.permissions = Some(Permissions::<T>::override_permissions(overrides));
-          } else {
-            space.permissions = overrides_opt;
-          }
+            PalletSettings::<T>::mutate(|settings| *settings = new_settings);
 
-          is_update_applied = true;
+            Ok(().into())
         }
-      }
 
-      let is_handle_updated = Self::update_handle(&space, update.handle.clone())?;
-      if is_handle_updated {
-          old_data.handle = Some(space.handle);
-          space.handle = update.handle.unwrap();
-          is_update_applied = true
+        #[pallet::weight(<T as Config>::WeightInfo::force_unreserve_handle())]
+        pub fn force_unreserve_handle(origin: OriginFor<T>, handle: Vec<u8>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let lowercased_handle = Utils::<T>::lowercase_handle(handle);
+
+            if let Some(space_id) = Self::space_id_by_handle(&lowercased_handle) {
+                if let Ok(mut space) = Self::require_space(space_id) {
+                    space.unreserve_handle(lowercased_handle)?;
+
+                    space.handle = None;
+                    SpaceById::<T>::insert(space_id, space);
+                } else {
+                    SpaceIdByHandle::<T>::remove(&lowercased_handle);
+                }
+            }
+
+            Ok(Pays::No.into())
         }
 
-      // Update this space only if at least one field should be updated:
-      if is_update_applied {
-        space.updated = Some(WhoAndWhen::<T>::new(owner.clone()));
+        /// Extend the registration of a space handle for one more `HandleRegistrationPeriod`.
+        /// An expired handle can be renewed until its grace period is over.
+        #[pallet::weight(<T as Config>::WeightInfo::renew_space_handle())]
+        pub fn renew_space_handle(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-        <SpaceById<T>>::insert(space_id, space.clone());
-        T::AfterSpaceUpdated::after_space_updated(owner.clone(), &space, old_data);
+            let space = Self::require_space(space_id)?;
+            space.ensure_space_owner(who.clone())?;
+            ensure!(space.handle.is_some(), Error::<T>::SpaceHasNoHandle);
 
-        Self::deposit_event(RawEvent::SpaceUpdated(owner, space_id));
-      }
-      Ok(())
-    }
+            let mut registration = Self::remove_handle_registration(space_id)
+                .ok_or(UtilsError::<T>::HandleDoesNotExpire)?;
+            Utils::<T>::renew_handle_registration(&mut registration)?;
+            Self::insert_handle_registration(space_id, registration);
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(1, 1)]
-    pub fn update_settings(origin, new_settings: SpacesSettings) -> DispatchResult {
-      ensure_root(origin)?;
+            Self::deposit_event(Event::SpaceHandleRenewed(who, space_id));
+            Ok(().into())
+        }
 
-      let space_settings = Self::settings();
-      ensure!(space_settings != new_settings, Error::<T>::NoUpdatesForSpacesSettings);
+        /// Add or remove co-owners of a space and change the number of owners
+        /// that need to approve sensitive actions on this space.
+        /// The main owner of a space cannot be removed this way.
+        #[pallet::weight(<T as Config>::WeightInfo::update_space_owners(
+            update.add_owners.len() as u32 + update.remove_owners.len() as u32
+        ))]
+        #[transactional]
+        pub fn update_space_owners(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            update: SpaceOwnersUpdate<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let has_updates =
+                !update.add_owners.is_empty() ||
+                !update.remove_owners.is_empty() ||
+                update.threshold.is_some();
+
+            ensure!(has_updates, Error::<T>::NoUpdatesForSpaceOwners);
+
+            let space = Self::require_space(space_id)?;
+            space.ensure_space_owner(who.clone())?;
+
+            Self::ensure_space_owners_approved(&space, &who, &SpaceOwnersAction::UpdateOwners(update.clone()))?;
+
+            for co_owner in update.remove_owners {
+                ensure!(co_owner != space.owner, Error::<T>::CannotRemoveMainSpaceOwner);
+                ensure!(SpaceCoOwners::<T>::contains_key(space_id, &co_owner), Error::<T>::NotASpaceCoOwner);
+
+                SpaceCoOwners::<T>::remove(space_id, &co_owner);
+                SpaceIdsByOwner::<T>::remove(co_owner, space_id);
+            }
 
-      PalletSettings::mutate(|settings| *settings = new_settings);
+            for co_owner in update.add_owners {
+                ensure!(!space.is_owner(&co_owner), Error::<T>::AlreadyASpaceOwner);
 
-      Ok(())
-    }
+                SpaceCoOwners::<T>::insert(space_id, &co_owner, true);
+                SpaceIdsByOwner::<T>::insert(co_owner, space_id, true);
+            }
 
-    #[weight = 10_000 + T::DbWeight::get().reads_writes(2, 2)]
-    pub fn force_unreserve_handle(origin, handle: Vec<u8>) -> DispatchResultWithPostInfo {
-      ensure_root(origin)?;
+            let owners_count = Self::space_owners(&space).len();
+            ensure!(owners_count <= T::MaxSpaceOwners::get() as usize, Error::<T>::TooManySpaceOwners);
 
-      let lowercased_handle = handle.to_ascii_lowercase();
+            let threshold = update.threshold.unwrap_or_else(|| Self::space_owners_threshold(space_id));
+            ensure!(threshold >= 1 && threshold as usize <= owners_count, Error::<T>::InvalidSpaceOwnersThreshold);
+            SpaceOwnersThreshold::<T>::insert(space_id, threshold);
 
-      if let Some(space_id) = Self::space_id_by_handle(&lowercased_handle) {
-        if let Ok(mut space) = Self::require_space(space_id) {
-          space.unreserve_handle(lowercased_handle)?;
+            Self::deposit_event(Event::SpaceOwnersUpdated(who, space_id));
+            Ok(().into())
+        }
 
-          space.handle = None;
-          SpaceById::<T>::insert(space_id, space);
-        } else {
-          SpaceIdByHandle::remove(&lowercased_handle);
+        /// Approve an action on a space in advance, so that it can be executed by another owner
+        /// once enough owners approved it. At most `MaxPendingSpaceOwnersActions` actions
+        /// of a space can wait for approvals at a time.
+        #[pallet::weight(<T as Config>::WeightInfo::approve_space_owners_action())]
+        pub fn approve_space_owners_action(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            action: SpaceOwnersAction<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+            space.ensure_space_owner(who.clone())?;
+
+            let action_hash = T::Hashing::hash_of(&action);
+            let mut approvals = Self::space_owners_action_approvals(space_id, action_hash);
+            ensure!(!approvals.contains(&who), Error::<T>::SpaceOwnersActionAlreadyApproved);
+
+            if approvals.is_empty() {
+                let pending_actions_count = Self::pending_space_owners_actions_count(space_id);
+                ensure!(
+                    pending_actions_count < T::MaxPendingSpaceOwnersActions::get(),
+                    Error::<T>::TooManyPendingSpaceOwnersActions
+                );
+                PendingSpaceOwnersActionsCount::<T>::insert(space_id, pending_actions_count.saturating_add(1));
+            }
+
+            approvals.push(who.clone());
+            SpaceOwnersActionApprovals::<T>::insert(space_id, action_hash, approvals);
+
+            Self::deposit_event(Event::SpaceOwnersActionApproved(who, space_id, action_hash));
+            Ok(().into())
         }
-      }
 
-      Ok(Pays::No.into())
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_space_owners_action_approval())]
+        pub fn revoke_space_owners_action_approval(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            action: SpaceOwnersAction<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_space_exists(space_id)?;
+
+            let action_hash = T::Hashing::hash_of(&action);
+            let mut approvals = Self::space_owners_action_approvals(space_id, action_hash);
+            ensure!(approvals.contains(&who), Error::<T>::SpaceOwnersActionNotApproved);
+
+            remove_from_vec(&mut approvals, who.clone());
+            if approvals.is_empty() {
+                Self::remove_space_owners_action_approvals(space_id, action_hash);
+            } else {
+                SpaceOwnersActionApprovals::<T>::insert(space_id, action_hash, approvals);
+            }
+
+            Self::deposit_event(Event::SpaceOwnersActionApprovalRevoked(who, space_id, action_hash));
+            Ok(().into())
+        }
     }
-  }
 }
 
 impl<T: Config> Space<T> {
@@ -454,8 +941,9 @@ impl<T: Config> Space<T> {
         }
     }
 
+    /// Whether a given account is the main owner or one of the co-owners of this space.
     pub fn is_owner(&self, account: &T::AccountId) -> bool {
-        self.owner == *account
+        self.owner == *account || SpaceCoOwners::<T>::contains_key(self.id, account)
     }
 
     pub fn is_follower(&self, account: &T::AccountId) -> bool {
@@ -491,6 +979,10 @@ impl<T: Config> Space<T> {
         self.followers_count = self.followers_count.saturating_sub(1);
     }
 
+    pub fn change_score(&mut self, diff: i32) {
+        self.score = self.score.saturating_add(diff);
+    }
+
     pub fn try_get_parent(&self) -> Result<SpaceId, DispatchError> {
         self.parent_id.ok_or_else(|| Error::<T>::SpaceIsAtRoot.into())
     }
@@ -503,13 +995,18 @@ impl<T: Config> Space<T> {
         !self.is_public()
     }
 
+    /// Register a handle for this space and reserve a deposit, that depends on
+    /// the handle length, from the space owner.
     pub fn reserve_handle(
       &self,
       handle: Vec<u8>
     ) -> DispatchResult {
-      let handle_in_lowercase = Module::<T>::lowercase_and_ensure_unique_handle(handle)?;
-      Module::<T>::reserve_handle_deposit(&self.owner)?;
-      SpaceIdByHandle::insert(handle_in_lowercase, self.id);
+      // A deposit depends on the length of the handle itself, not of its key.
+      let deposit = Utils::<T>::handle_deposit(T::HandleDeposit::get(), &handle);
+      let handle_in_lowercase = Pallet::<T>::lowercase_and_ensure_unique_handle(handle)?;
+      Pallet::<T>::reserve_handle_deposit(&self.owner, deposit)?;
+      SpaceIdByHandle::<T>::insert(handle_in_lowercase, self.id);
+      Pallet::<T>::insert_handle_registration(self.id, Utils::<T>::new_handle_registration(deposit));
       Ok(())
     }
 
@@ -518,18 +1015,84 @@ impl<T: Config> Space<T> {
       handle: Vec<u8>
     ) -> DispatchResult {
       let handle_in_lowercase = Utils::<T>::lowercase_handle(handle);
-      Module::<T>::unreserve_handle_deposit(&self.owner);
-      SpaceIdByHandle::remove(handle_in_lowercase);
+      if let Some(registration) = Pallet::<T>::remove_handle_registration(self.id) {
+          Pallet::<T>::unreserve_handle_deposit(&self.owner, registration.deposit);
+      }
+      SpaceIdByHandle::<T>::remove(handle_in_lowercase);
       Ok(())
     }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
+
+    /// Get the ids of all spaces owned by a given account, sorted by id.
+    pub fn space_ids_by_owner(owner: T::AccountId) -> Vec<SpaceId> {
+        let mut space_ids: Vec<SpaceId> = SpaceIdsByOwner::<T>::iter_prefix(owner)
+            .map(|(space_id, _)| space_id)
+            .collect();
+
+        space_ids.sort_unstable();
+        space_ids
+    }
+
+    /// Get all owners of a space: its main owner first, followed by co-owners.
+    pub fn space_owners(space: &Space<T>) -> Vec<T::AccountId> {
+        let mut owners = vec![space.owner.clone()];
+        owners.extend(SpaceCoOwners::<T>::iter_key_prefix(space.id));
+        owners
+    }
+
+    /// Ensure that an action on a space is approved by at least `SpaceOwnersThreshold` owners
+    /// and clear its approvals. The account that executes this action counts as one of
+    /// the approvers if it's an owner of this space. Approvals of accounts that are no longer
+    /// owners of this space are ignored.
+    pub fn ensure_space_owners_approved(
+        space: &Space<T>,
+        who: &T::AccountId,
+        action: &SpaceOwnersAction<T::AccountId>,
+    ) -> DispatchResult {
+        let threshold = Self::space_owners_threshold(space.id);
+        if threshold <= 1 {
+            return Ok(());
+        }
+
+        let action_hash = T::Hashing::hash_of(action);
+        let approvals = Self::space_owners_action_approvals(space.id, action_hash);
+
+        let executor_approval = space.is_owner(who) && !approvals.contains(who);
+        let approvals_count = approvals.iter()
+            .filter(|approver| space.is_owner(approver))
+            .count()
+            .saturating_add(executor_approval as usize);
+
+        ensure!(approvals_count >= threshold as usize, Error::<T>::NotEnoughSpaceOwnersApprovals);
+
+        Self::remove_space_owners_action_approvals(space.id, action_hash);
+        Ok(())
+    }
+
+    fn remove_space_owners_action_approvals(space_id: SpaceId, action_hash: T::Hash) {
+        if SpaceOwnersActionApprovals::<T>::contains_key(space_id, action_hash) {
+            SpaceOwnersActionApprovals::<T>::remove(space_id, action_hash);
+            PendingSpaceOwnersActionsCount::<T>::mutate(space_id, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    /// Remove a co-owner of a space, e.g. when they became its main owner.
+    /// The owners threshold is lowered if it exceeds the new number of owners.
+    pub fn remove_space_co_owner(space: &Space<T>, co_owner: &T::AccountId) {
+        if SpaceCoOwners::<T>::take(space.id, co_owner) {
+            let owners_count = Self::space_owners(space).len() as u16;
+            SpaceOwnersThreshold::<T>::mutate(space.id, |threshold| {
+                *threshold = (*threshold).min(owners_count).max(1)
+            });
+        }
+    }
 
     /// Check that there is a `Space` with such `space_id` in the storage
     /// or return`SpaceNotFound` error.
     pub fn ensure_space_exists(space_id: SpaceId) -> DispatchResult {
-        ensure!(<SpaceById<T>>::contains_key(space_id), Error::<T>::SpaceNotFound);
+        ensure!(SpaceById::<T>::contains_key(space_id), Error::<T>::SpaceNotFound);
         Ok(())
     }
 
@@ -543,6 +1106,18 @@ impl<T: Config> Module<T> {
         space: &Space<T>,
         permission: SpacePermission,
         error: DispatchError,
+    ) -> DispatchResult {
+        Self::ensure_account_has_post_permission(account, space, None, permission, error)
+    }
+
+    /// Same as `ensure_account_has_space_permission`, but the permission overrides of a post
+    /// (if any) take precedence over the permissions of its space.
+    pub fn ensure_account_has_post_permission(
+        account: T::AccountId,
+        space: &Space<T>,
+        post_perms: Option<SpacePermissions>,
+        permission: SpacePermission,
+        error: DispatchError,
     ) -> DispatchResult {
         let is_owner = space.is_owner(&account);
         let is_follower = space.is_follower(&account);
@@ -551,7 +1126,8 @@ impl<T: Config> Module<T> {
             space_id: space.id,
             is_space_owner: is_owner,
             is_space_follower: is_follower,
-            space_perms: space.permissions.clone(),
+            space_perms: Self::get_inherited_space_permissions(space),
+            post_perms,
         };
 
         T::Roles::ensure_account_has_space_permission(
@@ -567,19 +1143,122 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    fn ensure_account_can_override_subspace_permissions(
+        account: T::AccountId,
+        parent_space: &Space<T>,
+    ) -> DispatchResult {
+        Self::ensure_account_has_space_permission(
+            account,
+            parent_space,
+            SpacePermission::OverrideSubspacePermissions,
+            Error::<T>::NoPermissionToOverrideSubspacePermissions.into()
+        )
+    }
+
     pub fn try_move_space_to_root(space_id: SpaceId) -> DispatchResult {
         let mut space = Self::require_space(space_id)?;
-        space.parent_id = None;
+
+        if let Some(parent_id) = space.parent_id.take() {
+            Self::remove_subspace(parent_id, space_id);
+        }
 
         SpaceById::<T>::insert(space_id, space);
         Ok(())
     }
 
+    pub(crate) fn add_subspace(parent_id: SpaceId, space_id: SpaceId) {
+        SpaceIdsByParentId::<T>::insert(parent_id, space_id, true);
+        SubspacesCountByParentId::<T>::mutate(parent_id, |count| *count = count.saturating_add(1));
+    }
+
+    fn remove_subspace(parent_id: SpaceId, space_id: SpaceId) {
+        if SpaceIdsByParentId::<T>::take(parent_id, space_id) {
+            SubspacesCountByParentId::<T>::mutate(parent_id, |count| *count = count.saturating_sub(1));
+        }
+    }
+
+    /// Get the ids of all ancestors of a given space, starting from its direct parent
+    /// and ending with a root space.
+    pub fn get_space_ancestor_ids(space_id: SpaceId) -> Vec<SpaceId> {
+        let mut ancestor_ids = Vec::new();
+        let mut parent_id_opt = Self::space_by_id(space_id).and_then(|space| space.parent_id);
+
+        while let Some(parent_id) = parent_id_opt {
+            // Protects from an infinite loop if the storage already contains a cycle.
+            if parent_id == space_id || ancestor_ids.contains(&parent_id) {
+                break;
+            }
+
+            ancestor_ids.push(parent_id);
+            parent_id_opt = Self::space_by_id(parent_id).and_then(|space| space.parent_id);
+        }
+
+        ancestor_ids
+    }
+
+    /// Check whether making `parent_id` a parent of `space_id` would create a cycle.
+    pub fn is_parent_cycle(space_id: SpaceId, parent_id: SpaceId) -> bool {
+        parent_id == space_id || Self::get_space_ancestor_ids(parent_id).contains(&space_id)
+    }
+
+    /// Get the number of levels of subspaces that a space nested into a space with `parent_id` can have.
+    fn max_subspaces_height_under(parent_id: SpaceId) -> Result<u32, DispatchError> {
+        let parent_depth = Self::get_space_ancestor_ids(parent_id).len() as u32;
+
+        T::MaxSubspaceDepth::get()
+            .checked_sub(parent_depth.saturating_add(1))
+            .ok_or_else(|| Error::<T>::MaxSubspaceDepthReached.into())
+    }
+
+    /// Ensure that a space can be nested into a space with `parent_id`.
+    fn ensure_subspace_depth_allowed(parent_id: SpaceId) -> DispatchResult {
+        Self::max_subspaces_height_under(parent_id).map(|_| ())
+    }
+
+    /// Ensure that a given space has at most `max_height` levels of subspaces below it.
+    /// Subspaces are checked level by level, and at most `MaxSubspacesToMove` of them.
+    /// Returns the number of checked subspaces.
+    fn ensure_subspaces_height_allowed(space_id: SpaceId, max_height: u32) -> Result<u32, DispatchError> {
+        let mut parent_ids = vec![space_id];
+        let mut height: u32 = 0;
+        let mut checked: u32 = 0;
+
+        loop {
+            // Subspaces of a level are counted before they are read, so that a read is always bounded.
+            let level_len = parent_ids.iter()
+                .fold(0u32, |len, parent_id| len.saturating_add(Self::subspaces_count(parent_id)));
+            if level_len == 0 {
+                break;
+            }
+
+            height = height.saturating_add(1);
+            ensure!(height <= max_height, Error::<T>::MaxSubspaceDepthReached);
+
+            checked = checked.saturating_add(level_len);
+            ensure!(checked <= T::MaxSubspacesToMove::get(), Error::<T>::TooManySubspacesToMove);
+
+            parent_ids = parent_ids.into_iter().flat_map(SpaceIdsByParentId::<T>::iter_key_prefix).collect();
+        }
+
+        Ok(checked)
+    }
+
+    /// Get permissions of a space, where permission sets that are not overridden by this space
+    /// are taken from the closest ancestor that overrides them.
+    pub fn get_inherited_space_permissions(space: &Space<T>) -> Option<SpacePermissions> {
+        Self::get_space_ancestor_ids(space.id)
+            .into_iter()
+            .filter_map(|ancestor_id| Self::space_by_id(ancestor_id).and_then(|ancestor| ancestor.permissions))
+            .fold(space.permissions.clone(), |perms_opt, ancestor_perms| {
+                Some(perms_opt.map_or(ancestor_perms.clone(), |perms| perms.inherit(ancestor_perms)))
+            })
+    }
+
     pub fn mutate_space_by_id<F: FnOnce(&mut Space<T>)> (
         space_id: SpaceId,
         f: F
     ) -> Result<Space<T>, DispatchError> {
-        <SpaceById<T>>::mutate(space_id, |space_opt| {
+        SpaceById::<T>::mutate(space_id, |space_opt| {
             if let Some(ref mut space) = space_opt.clone() {
                 f(space);
                 *space_opt = Some(space.clone());
@@ -591,22 +1270,24 @@ impl<T: Config> Module<T> {
         })
     }
 
-    /// Lowercase a handle and ensure that it's unique, i.e. no space reserved this handle yet.
+    /// Lowercase a handle and ensure that it's unique,
+    /// i.e. no space or profile reserved this handle yet.
     fn lowercase_and_ensure_unique_handle(handle: Vec<u8>) -> Result<Vec<u8>, DispatchError> {
         let handle_in_lowercase = Utils::<T>::lowercase_and_validate_a_handle(handle)?;
 
-        // Check if a handle is unique across all spaces' handles:
+        // Check if a handle is unique across all spaces' and profiles' handles:
         ensure!(Self::space_id_by_handle(handle_in_lowercase.clone()).is_none(), Error::<T>::SpaceHandleIsNotUnique);
+        ensure!(T::ProfileHandles::handle_owner(&handle_in_lowercase).is_none(), Error::<T>::SpaceHandleIsNotUnique);
 
         Ok(handle_in_lowercase)
     }
 
-    pub fn reserve_handle_deposit(space_owner: &T::AccountId) -> DispatchResult {
-        <T as Config>::Currency::reserve(space_owner, T::HandleDeposit::get())
+    pub fn reserve_handle_deposit(space_owner: &T::AccountId, deposit: BalanceOf<T>) -> DispatchResult {
+        <T as Config>::Currency::reserve(space_owner, deposit)
     }
 
-    pub fn unreserve_handle_deposit(space_owner: &T::AccountId) -> BalanceOf<T> {
-        <T as Config>::Currency::unreserve(space_owner, T::HandleDeposit::get())
+    pub fn unreserve_handle_deposit(space_owner: &T::AccountId, deposit: BalanceOf<T>) -> BalanceOf<T> {
+        <T as Config>::Currency::unreserve(space_owner, deposit)
     }
 
     /// This function will be performed only if a space has a handle.
@@ -614,20 +1295,89 @@ impl<T: Config> Module<T> {
     /// then transfer deposit amount to a new owner
     /// and reserve this amount from a new owner.
     pub fn maybe_transfer_handle_deposit_to_new_space_owner(space: &Space<T>, new_owner: &T::AccountId) -> DispatchResult {
-        if space.handle.is_some() {
+        if let Some(registration) = Self::handle_registration_by_space_id(space.id) {
             let old_owner = &space.owner;
-            Self::unreserve_handle_deposit(old_owner);
+            Self::unreserve_handle_deposit(old_owner, registration.deposit);
             <T as Config>::Currency::transfer(
                 old_owner,
                 new_owner,
-                T::HandleDeposit::get(),
+                registration.deposit,
                 ExistenceRequirement::KeepAlive
             )?;
-            Self::reserve_handle_deposit(new_owner)?;
+            Self::reserve_handle_deposit(new_owner, registration.deposit)?;
         }
         Ok(())
     }
 
+    fn insert_handle_registration(space_id: SpaceId, registration: HandleRegistration<T::BlockNumber, BalanceOf<T>>) {
+        if let Some(release_block) = Utils::<T>::handle_release_block(&registration) {
+            SpaceHandlesByReleaseBlock::<T>::insert(release_block, space_id, true);
+        }
+        HandleRegistrationBySpaceId::<T>::insert(space_id, registration);
+    }
+
+    fn remove_handle_registration(space_id: SpaceId) -> Option<HandleRegistration<T::BlockNumber, BalanceOf<T>>> {
+        let registration = HandleRegistrationBySpaceId::<T>::take(space_id)?;
+        if let Some(release_block) = Utils::<T>::handle_release_block(&registration) {
+            SpaceHandlesByReleaseBlock::<T>::remove(release_block, space_id);
+        }
+        Some(registration)
+    }
+
+    /// Release the handles of spaces whose grace period is over by the block `now`
+    /// and return their handle deposits to the space owners. At most `MaxHandlesToReleasePerBlock`
+    /// handles are released, the rest are released in the next blocks.
+    fn release_expired_handles(now: T::BlockNumber) -> Weight {
+        let mut budget = T::MaxHandlesToReleasePerBlock::get() as usize;
+        let mut block = NextHandleReleaseBlock::<T>::get().unwrap_or(now);
+
+        let mut blocks_checked: u64 = 0;
+        let mut released: u64 = 0;
+
+        while block <= now && budget > 0 {
+            budget -= 1;
+            blocks_checked += 1;
+
+            let space_ids: Vec<SpaceId> = SpaceHandlesByReleaseBlock::<T>::iter_key_prefix(block).take(budget).collect();
+            budget = budget.saturating_sub(space_ids.len());
+
+            for space_id in space_ids {
+                SpaceHandlesByReleaseBlock::<T>::remove(block, space_id);
+                Self::release_expired_handle(space_id);
+                released += 1;
+            }
+
+            if SpaceHandlesByReleaseBlock::<T>::iter_key_prefix(block).next().is_some() {
+                break;
+            }
+            block = block.saturating_add(One::one());
+        }
+
+        NextHandleReleaseBlock::<T>::put(block);
+
+        T::DbWeight::get().reads_writes(
+            blocks_checked.saturating_add(released.saturating_mul(3)).saturating_add(1),
+            released.saturating_mul(5).saturating_add(1),
+        )
+    }
+
+    fn release_expired_handle(space_id: SpaceId) {
+        if let Some(mut space) = Self::space_by_id(space_id) {
+            if let Some(handle) = space.handle.take() {
+                let registration = HandleRegistrationBySpaceId::<T>::take(space_id);
+                if let Some(deposit) = registration.map(|registration| registration.deposit) {
+                    Self::unreserve_handle_deposit(&space.owner, deposit);
+                }
+                SpaceIdByHandle::<T>::remove(Utils::<T>::lowercase_handle(handle.clone()));
+                // A space only shrinks here, so its storage deposit can only be partially returned.
+                let _ = Utils::<T>::update_storage_deposit(StorageItem::Space(space_id), &space);
+                SpaceById::<T>::insert(space_id, space);
+
+                Self::deposit_event(Event::SpaceHandleReleased(space_id, handle));
+            }
+        }
+    }
+
     fn update_handle(
         space: &Space<T>,
         maybe_new_handle: Option<Option<Vec<u8>>>,
@@ -647,12 +1397,12 @@ impl<T: Config> Module<T> {
                         // Change the current handle to a new one
 
                         // Validate data first
-                        let old_handle_lc = Utils::<T>::lowercase_handle(old_handle);
-                        let new_handle_lc = Self::lowercase_and_ensure_unique_handle(new_handle)?;
+                        Self::lowercase_and_ensure_unique_handle(new_handle.clone())?;
 
-                        // Update storage once data is valid
-                        SpaceIdByHandle::remove(old_handle_lc);
-                        SpaceIdByHandle::insert(new_handle_lc, space.id);
+                        // A deposit depends on the handle length, so the new handle
+                        // is registered from scratch once data is valid.
+                        space.unreserve_handle(old_handle)?;
+                        space.reserve_handle(new_handle)?;
                         is_handle_updated = true;
                     }
                 } else {
@@ -670,19 +1420,26 @@ impl<T: Config> Module<T> {
     }
 }
 
-impl<T: Config> SpaceForRolesProvider for Module<T> {
+impl<T: Config> SpaceForRolesProvider for Pallet<T> {
     type AccountId = T::AccountId;
 
     fn get_space(id: SpaceId) -> Result<SpaceForRoles<Self::AccountId>, DispatchError> {
-        let space = Module::<T>::require_space(id)?;
+        let space = Pallet::<T>::require_space(id)?;
+        let permissions = Pallet::<T>::get_inherited_space_permissions(&space);
 
         Ok(SpaceForRoles {
-            owner: space.owner,
-            permissions: space.permissions,
+            owners: Pallet::<T>::space_owners(&space),
+            permissions,
         })
     }
 }
 
+impl<T: Config> HandleOwnerProvider<T::AccountId> for Pallet<T> {
+    fn handle_owner(handle: &[u8]) -> Option<User<T::AccountId>> {
+        Self::space_id_by_handle(handle.to_vec()).map(User::Space)
+    }
+}
+
 pub trait BeforeSpaceCreated<T: Config> {
     fn before_space_created(follower: T::AccountId, space: &mut Space<T>) -> DispatchResult;
 }
@@ -1,7 +1,7 @@
-use frame_support::storage::IterableStorageMap;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
 use sp_runtime::traits::Saturating;
 
-use pallet_utils as Utils;
+use pallet_utils::{self as Utils, start_moving_vec_map_to_double_map};
 
 use super::*;
 
@@ -10,12 +10,12 @@ pub fn fix_corrupted_handles_storage<T: Config>() -> frame_support::weights::Wei
     let mut should_remove;
     let mut removed = 0;
 
-    for (handle, space_id) in SpaceIdByHandle::iter() {
+    for (handle, space_id) in SpaceIdByHandle::<T>::iter() {
         handles_iterated += 1;
         should_remove = false;
 
-        if let Some(space) = Module::<T>::space_by_id(&space_id) {
-            let space_handle_lc = space.handle.map(Utils::Module::<T>::lowercase_handle);
+        if let Some(space) = Pallet::<T>::space_by_id(&space_id) {
+            let space_handle_lc = space.handle.map(Utils::Pallet::<T>::lowercase_handle);
 
             if space_handle_lc.is_none() || space_handle_lc.as_ref() != Some(&handle) {
                 should_remove = true;
@@ -25,15 +25,132 @@ pub fn fix_corrupted_handles_storage<T: Config>() -> frame_support::weights::Wei
         }
 
         if should_remove {
-            SpaceIdByHandle::remove(handle);
+            SpaceIdByHandle::<T>::remove(handle);
             removed += 1;
         }
     }
 
-    SpaceIdByHandleStorageFixed::put(true);
+    SpaceIdByHandleStorageFixed::<T>::put(true);
 
     T::DbWeight::get().reads_writes(
         handles_iterated.saturating_mul(2),
         removed + 1,
     )
 }
+
+pub fn build_space_ids_by_parent_id_storage<T: Config>() -> frame_support::weights::Weight {
+    let mut spaces_iterated: u64 = 0;
+    let mut writes: u64 = 0;
+
+    for (space_id, space) in SpaceById::<T>::iter() {
+        spaces_iterated += 1;
+
+        if let Some(parent_id) = space.parent_id {
+            if Pallet::<T>::is_parent_cycle(space_id, parent_id) {
+                // Spaces that form a cycle can't be placed into a tree, so move them to root.
+                let mut space = space;
+                space.parent_id = None;
+                SpaceById::<T>::insert(space_id, space);
+            } else {
+                Pallet::<T>::add_subspace(parent_id, space_id);
+            }
+            writes += 1;
+        }
+    }
+
+    SpaceIdsByParentIdStorageBuilt::<T>::put(true);
+
+    T::DbWeight::get().reads_writes(
+        spaces_iterated.saturating_mul(2).saturating_add(writes),
+        writes.saturating_mul(2) + 1,
+    )
+}
+
+/// Storage version 2 moves `SpaceIdsByOwner` from `Vec`s of space ids to a double map.
+/// The ids are moved over several blocks by `pallet_utils::MoveVecMapToDoubleMap`.
+pub fn move_space_ids_by_owner_to_double_map<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(2) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let weight = start_moving_vec_map_to_double_map::<T, SpaceIdsByOwner<T>, T::AccountId, SpaceId>();
+
+    StorageVersion::new(2).put::<Pallet<T>>();
+
+    weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+}
+
+/// Storage version 3 registers the existing space handles with the flat `HandleDeposit`
+/// they were reserved for. These handles never expire.
+pub fn add_registrations_to_space_handles<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(3) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut handles_iterated: u64 = 0;
+    let mut handles_registered: u64 = 0;
+
+    for (_, space_id) in SpaceIdByHandle::<T>::iter() {
+        handles_iterated += 1;
+
+        if !HandleRegistrationBySpaceId::<T>::contains_key(space_id) {
+            HandleRegistrationBySpaceId::<T>::insert(space_id, HandleRegistration {
+                deposit: T::HandleDeposit::get(),
+                expires_at: None,
+            });
+            handles_registered += 1;
+        }
+    }
+
+    StorageVersion::new(3).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(handles_iterated.saturating_mul(2) + 1, handles_registered + 1)
+}
+
+/// Storage version 4 stores space handles under their skeletons (see `Utils::lowercase_handle`),
+/// which ASCII handles didn't have before.
+///
+/// If handles of several spaces get the same key, the handle stays with the oldest space,
+/// while the other spaces lose their handles and get their handle deposits back.
+pub fn rekey_space_handles_by_skeletons<T: Config>() -> frame_support::weights::Weight {
+    if Pallet::<T>::on_chain_storage_version() >= StorageVersion::new(4) {
+        return T::DbWeight::get().reads(1);
+    }
+
+    let mut space_ids: Vec<SpaceId> = SpaceIdByHandle::<T>::drain().map(|(_, space_id)| space_id).collect();
+    space_ids.sort_unstable();
+
+    let mut reads: u64 = space_ids.len() as u64;
+    let mut writes: u64 = space_ids.len() as u64;
+
+    for space_id in space_ids {
+        reads += 1;
+        let mut space = match Pallet::<T>::space_by_id(space_id) {
+            Some(space) => space,
+            None => continue,
+        };
+        let handle = match space.handle.clone() {
+            Some(handle) => handle,
+            None => continue,
+        };
+
+        let handle_key = Utils::Pallet::<T>::lowercase_handle(handle);
+        reads += 1;
+        if SpaceIdByHandle::<T>::contains_key(&handle_key) {
+            reads += 1;
+            if let Some(registration) = Pallet::<T>::remove_handle_registration(space_id) {
+                Pallet::<T>::unreserve_handle_deposit(&space.owner, registration.deposit);
+                writes += 2;
+            }
+            space.handle = None;
+            SpaceById::<T>::insert(space_id, space);
+        } else {
+            SpaceIdByHandle::<T>::insert(handle_key, space_id);
+        }
+        writes += 1;
+    }
+
+    StorageVersion::new(4).put::<Pallet<T>>();
+
+    T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+}
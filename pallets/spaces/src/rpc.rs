@@ -3,9 +3,9 @@ use codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 use sp_std::prelude::*;
 
-use pallet_utils::{bool_to_option, SpaceId, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}};
+use pallet_utils::{Pallet as Utils, bool_to_option, paginate_double_map_keys, SpaceId, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}};
 
-use crate::{Module, Space, Config, FIRST_SPACE_ID};
+use crate::{Pallet, Space, SpaceIdsByOwner, SpaceIdsByParentId, Config, FIRST_SPACE_ID};
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
@@ -69,7 +69,7 @@ impl<T: Config> From<Space<T>> for FlatSpace<T::AccountId, T::BlockNumber> {
     }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
     pub fn get_spaces_by_ids(space_ids: Vec<SpaceId>) -> Vec<FlatSpace<T::AccountId, T::BlockNumber>> {
         space_ids.iter()
             .filter_map(|id| Self::require_space(*id).ok())
@@ -110,11 +110,11 @@ impl<T: Config> Module<T> {
     }
 
     pub fn get_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId> {
-        Self::space_id_by_handle(handle)
+        Self::space_id_by_handle(Utils::<T>::lowercase_handle(handle))
     }
 
     pub fn get_space_by_handle(handle: Vec<u8>) -> Option<FlatSpace<T::AccountId, T::BlockNumber>> {
-        Self::space_id_by_handle(handle)
+        Self::get_space_id_by_handle(handle)
             .and_then(|space_id| Self::require_space(space_id).ok())
             .map(|space| space.into())
     }
@@ -136,6 +136,17 @@ impl<T: Config> Module<T> {
         Self::get_space_ids_by_owner(owner, |space| space.hidden)
     }
 
+    /// Get up to `limit` space ids owned by a given account, starting after the `cursor` space id.
+    pub fn get_space_ids_by_owner_page(owner: T::AccountId, cursor: Option<SpaceId>, limit: u16) -> Vec<SpaceId> {
+        paginate_double_map_keys::<SpaceIdsByOwner<T>, _, _, _>(&owner, cursor, limit.into())
+    }
+
+    pub fn get_subspace_ids(space_id: SpaceId) -> Vec<SpaceId> {
+        let mut subspace_ids: Vec<SpaceId> = SpaceIdsByParentId::<T>::iter_key_prefix(space_id).collect();
+        subspace_ids.sort_unstable();
+        subspace_ids
+    }
+
     pub fn get_next_space_id() -> SpaceId {
         Self::next_space_id()
     }
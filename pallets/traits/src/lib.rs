@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
+use sp_std::vec::Vec;
 
 use pallet_permissions::{
   SpacePermission,
@@ -13,7 +14,8 @@ pub mod moderation;
 
 /// Minimal set of fields from Space struct that are required by roles pallet.
 pub struct SpaceForRoles<AccountId> {
-  pub owner: AccountId,
+  /// The main owner of a space, followed by its co-owners.
+  pub owners: Vec<AccountId>,
   pub permissions: Option<SpacePermissions>,
 }
 
@@ -29,6 +31,31 @@ pub trait SpaceFollowsProvider {
   fn is_space_follower(account: Self::AccountId, space_id: SpaceId) -> bool;
 }
 
+/// Space and profile handles share one namespace, so a handle belongs either to a space
+/// or to an account (its profile).
+pub trait HandleOwnerProvider<AccountId> {
+  /// Find a space or an account that reserved a given lower-cased handle.
+  fn handle_owner(handle: &[u8]) -> Option<User<AccountId>>;
+}
+
+impl<AccountId> HandleOwnerProvider<AccountId> for () {
+  fn handle_owner(_handle: &[u8]) -> Option<User<AccountId>> {
+    None
+  }
+}
+
+/// Reputation of an account, as tracked by its social account.
+pub trait ReputationProvider<AccountId> {
+  /// Returns zero if an account has no social account yet.
+  fn reputation(account: &AccountId) -> u32;
+}
+
+impl<AccountId> ReputationProvider<AccountId> for () {
+  fn reputation(_account: &AccountId) -> u32 {
+    0
+  }
+}
+
 pub trait PermissionChecker {
   type AccountId;
 
@@ -54,3 +81,26 @@ pub trait PermissionChecker {
     )
   }
 }
+
+/// Hook that is called before a space is deleted, so that other pallets can clean up
+/// everything they store about this space.
+pub trait BeforeSpaceDeleted {
+  /// The number of items that `before_space_deleted` will remove or update for a given space.
+  /// It is used to bound the weight of a space deletion.
+  fn items_to_clean_up(space_id: SpaceId) -> u32;
+
+  fn before_space_deleted(space_id: SpaceId);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl BeforeSpaceDeleted for Tuple {
+  fn items_to_clean_up(space_id: SpaceId) -> u32 {
+    let mut items: u32 = 0;
+    for_tuples!( #( items = items.saturating_add(Tuple::items_to_clean_up(space_id)); )* );
+    items
+  }
+
+  fn before_space_deleted(space_id: SpaceId) {
+    for_tuples!( #( Tuple::before_space_deleted(space_id); )* );
+  }
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use pallet_utils::rpc::FlatStorageDepositQuote;
+
+sp_api::decl_runtime_apis! {
+    pub trait UtilsApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec
+    {
+        /// Quote a storage deposit for a SCALE-encoded runtime call, if it was dispatched by `account`.
+        fn quote_storage_deposit(account: AccountId, call: Vec<u8>) -> Option<FlatStorageDepositQuote<Balance>>;
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use codec::Codec;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use jsonrpc_core::Result;
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+
+use pallet_utils::rpc::{FlatStorageDepositQuote, map_rpc_error};
+pub use utils_runtime_api::UtilsApi as UtilsRuntimeApi;
+
+#[rpc]
+pub trait UtilsApi<BlockHash, AccountId, Balance> {
+    #[rpc(name = "utils_quoteStorageDeposit")]
+    fn quote_storage_deposit(
+        &self,
+        at: Option<BlockHash>,
+        account: AccountId,
+        call: Bytes,
+    ) -> Result<Option<FlatStorageDepositQuote<Balance>>>;
+}
+
+pub struct Utils<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> Utils<C, M> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance> UtilsApi<<Block as BlockT>::Hash, AccountId, Balance>
+    for Utils<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    Balance: Codec,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: UtilsRuntimeApi<Block, AccountId, Balance>,
+{
+    fn quote_storage_deposit(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+        account: AccountId,
+        call: Bytes,
+    ) -> Result<Option<FlatStorageDepositQuote<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        let runtime_api_result = api.quote_storage_deposit(&at, account, call.to_vec());
+        runtime_api_result.map_err(map_rpc_error)
+    }
+}
@@ -0,0 +1,150 @@
+//! Validation of IPFS content identifiers (CIDs).
+//!
+//! See the [CID specification](https://github.com/multiformats/cid) for details.
+
+use sp_std::prelude::*;
+
+/// Multicodecs of content that a CID v1 can point to.
+pub const ALLOWED_CID_CODECS: [u64; 4] = [
+    0x55,   // raw
+    0x70,   // dag-pb
+    0x71,   // dag-cbor
+    0x0129, // dag-json
+];
+
+/// Multihash functions, with their digest lengths in bytes, that a CID can be hashed with.
+pub const ALLOWED_CID_HASHES: [(u64, usize); 3] = [
+    (0x12, 32),   // sha2-256
+    (0x1e, 32),   // blake3
+    (0xb220, 32), // blake2b-256
+];
+
+const SHA2_256: u64 = 0x12;
+
+const BASE58_BTC_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum CidError {
+    /// A CID cannot be decoded or its structure is invalid.
+    Malformed,
+    /// A CID points to content of a codec that is not in `ALLOWED_CID_CODECS`.
+    CodecNotAllowed,
+    /// A CID is hashed with a function that is not in `ALLOWED_CID_HASHES`.
+    HashNotAllowed,
+}
+
+/// Check that a string is a CID v0 or a CID v1 with an allowed codec and hash function.
+///
+/// A CID v0 is a base58btc-encoded sha2-256 multihash, e.g. `QmRAQB6Ya...`.
+/// A CID v1 is multibase-encoded, where base32 (`b`), base58btc (`z`)
+/// and base16 (`f`) encodings are supported, e.g. `bafyrei...`.
+pub fn validate_cid(cid: &[u8]) -> Result<(), CidError> {
+    if cid.len() == 46 && cid.starts_with(b"Qm") {
+        let multihash = decode_base58_btc(cid).ok_or(CidError::Malformed)?;
+        let (hash_code, _) = validate_multihash(&multihash)?;
+        return if hash_code == SHA2_256 { Ok(()) } else { Err(CidError::Malformed) };
+    }
+
+    let (multibase, encoded) = cid.split_first().ok_or(CidError::Malformed)?;
+    let decoded = match multibase {
+        b'b' => decode_base32(encoded),
+        b'B' => decode_base32(&encoded.to_ascii_lowercase()),
+        b'z' => decode_base58_btc(encoded),
+        b'f' => decode_base16(encoded),
+        _ => None,
+    }.ok_or(CidError::Malformed)?;
+
+    let mut input = decoded.as_slice();
+    ensure_cid(read_varint(&mut input) == Some(1))?;
+
+    let codec = read_varint(&mut input).ok_or(CidError::Malformed)?;
+    if !ALLOWED_CID_CODECS.contains(&codec) {
+        return Err(CidError::CodecNotAllowed);
+    }
+
+    validate_multihash(input).map(|_| ())
+}
+
+/// Check that bytes are a multihash of an allowed hash function
+/// and return the hash function code with the digest length.
+fn validate_multihash(mut input: &[u8]) -> Result<(u64, usize), CidError> {
+    let hash_code = read_varint(&mut input).ok_or(CidError::Malformed)?;
+    let digest_len = read_varint(&mut input).ok_or(CidError::Malformed)? as usize;
+    ensure_cid(input.len() == digest_len)?;
+
+    if !ALLOWED_CID_HASHES.contains(&(hash_code, digest_len)) {
+        return Err(CidError::HashNotAllowed);
+    }
+
+    Ok((hash_code, digest_len))
+}
+
+fn ensure_cid(condition: bool) -> Result<(), CidError> {
+    if condition { Ok(()) } else { Err(CidError::Malformed) }
+}
+
+/// Read an unsigned varint (LEB128, at most 9 bytes) from the start of the input.
+fn read_varint(input: &mut &[u8]) -> Option<u64> {
+    let mut value: u64 = 0;
+    for (i, byte) in input.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *input = &input[i + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn decode_base58_btc(input: &[u8]) -> Option<Vec<u8>> {
+    // Big-endian base-256 digits of a decoded number.
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for c in input {
+        let mut carry = BASE58_BTC_ALPHABET.iter().position(|x| x == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    // Every leading '1' encodes a leading zero byte.
+    let leading_zeros = input.iter().take_while(|&&c| c == b'1').count();
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(bytes);
+    Some(decoded)
+}
+
+fn decode_base32(input: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    for c in input {
+        buffer = (buffer << 5) | BASE32_ALPHABET.iter().position(|x| x == c)? as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(decoded)
+}
+
+fn decode_base16(input: &[u8]) -> Option<Vec<u8>> {
+    if input.len() % 2 != 0 {
+        return None;
+    }
+    input.chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
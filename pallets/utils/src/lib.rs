@@ -1,24 +1,31 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeAll, Encode, FullCodec, FullEncode};
 use scale_info::TypeInfo;
 use frame_support::{
-    decl_error, decl_module, decl_storage, decl_event,
     dispatch::{DispatchError, DispatchResult}, ensure,
+    storage::{self, generator::StorageDoubleMap, migration::move_pallet},
+    weights::Weight,
     traits::{
-        Currency, Get,
-        Imbalance, OnUnbalanced,
+        Currency, Get, GetStorageVersion, PalletInfoAccess, StorageVersion,
+        Imbalance, OnUnbalanced, ReservableCurrency, BalanceStatus,
     },
+    ReversibleStorageHasher,
 };
+#[cfg(feature = "try-runtime")]
+use frame_support::storage::StoragePrefixedMap;
 use frame_system as system;
 
 #[cfg(feature = "std")]
-use serde::Deserialize;
-use sp_runtime::RuntimeDebug;
+use serde::{Deserialize, Serialize};
+use sp_runtime::{RuntimeDebug, traits::{Saturating, Zero}};
 use sp_std::{
     collections::btree_set::BTreeSet,
     prelude::*,
+    string::String,
 };
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::{RestrictionLevel, RestrictionLevelDetection};
 
 #[cfg(test)]
 mod mock;
@@ -27,6 +34,7 @@ pub mod mock_functions;
 #[cfg(test)]
 mod tests;
 
+pub mod cid;
 pub mod rpc;
 
 pub type SpaceId = u64;
@@ -54,6 +62,7 @@ impl<T: Config> WhoAndWhen<T> {
 }
 
 #[derive(Encode, Decode, Ord, PartialOrd, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum User<AccountId> {
     Account(AccountId),
     Space(SpaceId),
@@ -83,12 +92,12 @@ impl<AccountId> User<AccountId> {
 pub enum Content {
     /// No content.
     None,
-    /// A raw vector of bytes.
+    /// A raw vector of bytes, stored on-chain for a deposit.
     Raw(Vec<u8>),
-    /// IPFS CID v0 of content.
+    /// IPFS CID v0 or v1 of content.
     #[allow(clippy::upper_case_acronyms)]
     IPFS(Vec<u8>),
-    /// Hypercore protocol (former DAT) id of content.
+    /// Hypercore protocol (former DAT) key of content: 64 hex chars or 52 z-base-32 chars.
     Hyper(Vec<u8>),
 }
 
@@ -123,58 +132,194 @@ impl Content {
     }
 }
 
+/// The deposit and the registration period of a space or profile handle.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct HandleRegistration<BlockNumber, Balance> {
+    /// The amount reserved from a handle owner for this handle.
+    pub deposit: Balance,
+    /// The block at which this handle expires, or `None` if it never expires.
+    /// An expired handle stays with its owner for `HandleGracePeriod` more blocks,
+    /// so that it can be renewed before it is released.
+    pub expires_at: Option<BlockNumber>,
+}
+
+/// An item stored on-chain for a storage deposit.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum StorageItem<AccountId> {
+    Space(SpaceId),
+    Post(PostId),
+    Profile(AccountId),
+    Role(u64),
+    Reaction(u64),
+    Report(u64),
+}
+
+/// A deposit reserved from an owner of a stored item for the bytes this item takes on-chain.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct StorageDeposit<AccountId, Balance> {
+    /// The account from which a deposit is reserved.
+    pub owner: AccountId,
+    /// The amount reserved when an item was created or updated last time.
+    /// It's returned as is even if the deposit constants have changed since then.
+    pub amount: Balance,
+}
+
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance;
 
 type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
-pub trait Config: system::Config + pallet_timestamp::Config
-{
-    /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Config>::Event>;
+pub use pallet::*;
 
-    /// The currency mechanism.
-    type Currency: Currency<Self::AccountId>;
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
 
-    /// Minimal length of space/profile handle
-    type MinHandleLen: Get<u32>;
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
-    /// Max length of a space handle.
-    type MaxHandleLen: Get<u32>;
-}
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_timestamp::Config {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// The currency mechanism.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Minimal length of space/profile handle, in characters.
+        #[pallet::constant]
+        type MinHandleLen: Get<u32>;
+
+        /// Max length of a space handle, in characters.
+        #[pallet::constant]
+        type MaxHandleLen: Get<u32>;
+
+        /// Handles shorter than this length cost more: a handle deposit is doubled
+        /// for every character that a handle lacks to reach this length.
+        #[pallet::constant]
+        type PremiumHandleLen: Get<u32>;
+
+        /// The period for which a new handle is registered, or `None` if new handles never expire.
+        #[pallet::constant]
+        type HandleRegistrationPeriod: Get<Option<Self::BlockNumber>>;
+
+        /// The number of blocks after the expiration of a handle during which its owner
+        /// can still renew it. The handle is released once this period is over.
+        #[pallet::constant]
+        type HandleGracePeriod: Get<Self::BlockNumber>;
 
-decl_storage! {
-    trait Store for Module<T: Config> as UtilsModule {
-        pub TreasuryAccount get(fn treasury_account) build(|config| config.treasury_account.clone()): T::AccountId;
+        /// The maximum number of space and profile handles that each of these pallets
+        /// releases in one block. The rest of the handles are released in the next blocks.
+        #[pallet::constant]
+        type MaxHandlesToReleasePerBlock: Get<u32>;
+
+        /// Max length of `Raw` content in bytes. `Raw` content is not allowed if it's zero.
+        #[pallet::constant]
+        type MaxRawContentLen: Get<u32>;
+
+        /// The amount reserved from an owner of every space, post, profile, role, reaction and report.
+        #[pallet::constant]
+        type StorageDepositPerItem: Get<BalanceOf<Self>>;
+
+        /// The amount reserved from an owner of a stored item per every byte that this item takes on-chain.
+        #[pallet::constant]
+        type StorageDepositPerByte: Get<BalanceOf<Self>>;
+
+        /// The maximum number of storage items that `MultiBlockMigrations` migrate in one block.
+        #[pallet::constant]
+        type MaxItemsToMigratePerBlock: Get<u32>;
+
+        /// Migrations that are too big for one block, e.g. `MoveVecMapToDoubleMap`.
+        /// They run one after another from `on_initialize` within `MaxItemsToMigratePerBlock`.
+        type MultiBlockMigrations: MultiBlockMigration<Self>;
     }
-    add_extra_genesis {
-        config(treasury_account): T::AccountId;
-        build(|config| {
-			// Create Treasury account
-			let _ = T::Currency::make_free_balance_be(
-				&config.treasury_account,
-				T::Currency::minimum_balance(),
-			);
-		});
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let mut budget = T::MaxItemsToMigratePerBlock::get();
+            T::MultiBlockMigrations::migrate_step(&mut budget)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            ensure_storage_version::<Self>()?;
+            ensure!(TreasuryAccount::<T>::exists(), "Treasury account is not set");
+            Ok(())
+        }
     }
-}
 
-decl_module! {
-    pub struct Module<T: Config> for enum Call where origin: T::Origin {
+    #[pallet::storage]
+    #[pallet::getter(fn treasury_account)]
+    pub type TreasuryAccount<T: Config> = StorageValue<_, T::AccountId, ValueQuery>;
+
+    /// Lower-cased handles that cannot be registered by any space or profile.
+    #[pallet::storage]
+    #[pallet::getter(fn is_handle_blocked)]
+    pub type BlockedHandles<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, bool, ValueQuery>;
+
+    /// Whether handles can contain letters and digits of any script, not only `0-9`, `a-z` and `_`.
+    #[pallet::storage]
+    #[pallet::getter(fn unicode_handles_enabled)]
+    pub type UnicodeHandlesEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Storage deposits by stored item. Items created before storage deposits were introduced
+    /// have no deposit, and their deposit is not updated when they change.
+    #[pallet::storage]
+    #[pallet::getter(fn storage_deposit_by_item)]
+    pub type StorageDepositByItem<T: Config> =
+        StorageMap<_, Blake2_128Concat, StorageItem<T::AccountId>, StorageDeposit<T::AccountId, BalanceOf<T>>>;
+
+    /// The cursors of the `Vec` maps that are being moved to double maps by `MoveVecMapToDoubleMap`,
+    /// by the storage prefix of a map. A cursor is the last visited key of a map.
+    #[pallet::storage]
+    pub type VecMapMigrationCursors<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, Vec<u8>>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub treasury_account: T::AccountId,
+    }
 
-        const MinHandleLen: u32 = T::MinHandleLen::get();
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { treasury_account: Default::default() }
+        }
+    }
 
-        const MaxHandleLen: u32 = T::MaxHandleLen::get();
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            TreasuryAccount::<T>::put(&self.treasury_account);
 
-        // Initializing errors
-        type Error = Error<T>;
+            // Create Treasury account
+            let _ = T::Currency::make_free_balance_be(
+                &self.treasury_account,
+                T::Currency::minimum_balance(),
+            );
+        }
+    }
 
-        // Initializing events
-        fn deposit_event() = default;
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Deposit(BalanceOf<T>),
+        /// Handles were added to the list of blocked handles.
+        HandlesBlocked(Vec<Vec<u8>>),
+        /// Handles were removed from the list of blocked handles.
+        HandlesUnblocked(Vec<Vec<u8>>),
+        /// Unicode handles were enabled or disabled.
+        UnicodeHandlesSettingUpdated(bool),
     }
-}
 
-decl_error! {
-    pub enum Error for Module<T: Config> {
+    #[pallet::error]
+    pub enum Error<T> {
         /// Account is blocked in a given space.
         AccountIsBlocked,
         /// Content is blocked in a given space.
@@ -183,10 +328,10 @@ decl_error! {
         PostIsBlocked,
         /// IPFS CID is invalid.
         InvalidIpfsCid,
-        /// `Raw` content type is not yet supported.
-        RawContentTypeNotSupported,
-        /// `Hyper` content type is not yet supported.
-        HypercoreContentTypeNotSupported,
+        /// `Raw` content is longer than `MaxRawContentLen`.
+        RawContentIsTooLong,
+        /// Hypercore key is neither 64 hex chars nor 52 z-base-32 chars.
+        InvalidHypercoreKey,
         /// Space handle is too short.
         HandleIsTooShort,
         /// Space handle is too long.
@@ -195,15 +340,64 @@ decl_error! {
         HandleContainsInvalidChars,
         /// Content type is `None`.
         ContentIsEmpty,
+        /// Handle is blocked and cannot be registered.
+        HandleIsBlocked,
+        /// Handle was registered without an expiration, so it doesn't need a renewal.
+        HandleDoesNotExpire,
+        /// Handle can be renewed only when it expires in less than `HandleRegistrationPeriod`.
+        HandleRenewedTooEarly,
+        /// Handle mixes scripts whose letters can be confused with each other, e.g. Latin and Cyrillic.
+        HandleMixesScripts,
+        /// IPFS CID points to content of a codec that is not allowed (see `cid::ALLOWED_CID_CODECS`).
+        IpfsCidCodecNotAllowed,
+        /// IPFS CID is hashed with a function that is not allowed (see `cid::ALLOWED_CID_HASHES`).
+        IpfsCidHashNotAllowed,
     }
-}
 
-decl_event!(
-    pub enum Event<T> where Balance = BalanceOf<T>
-    {
-		Deposit(Balance),
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add handles to the list of handles that cannot be registered.
+        /// Handles that are already registered are not affected.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(handles.len() as u64))]
+        pub fn block_handles(origin: OriginFor<T>, handles: Vec<Vec<u8>>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let handles: Vec<Vec<u8>> = handles.into_iter().map(Self::lowercase_handle).collect();
+            for handle in handles.iter() {
+                BlockedHandles::<T>::insert(handle, true);
+            }
+
+            Self::deposit_event(Event::HandlesBlocked(handles));
+            Ok(Pays::No.into())
+        }
+
+        /// Remove handles from the list of handles that cannot be registered.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(handles.len() as u64))]
+        pub fn unblock_handles(origin: OriginFor<T>, handles: Vec<Vec<u8>>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let handles: Vec<Vec<u8>> = handles.into_iter().map(Self::lowercase_handle).collect();
+            for handle in handles.iter() {
+                BlockedHandles::<T>::remove(handle);
+            }
+
+            Self::deposit_event(Event::HandlesUnblocked(handles));
+            Ok(Pays::No.into())
+        }
+
+        /// Allow or disallow new handles with letters and digits of any script.
+        /// Unicode handles that are already registered are not affected when disabled.
+        #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+        pub fn set_unicode_handles_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            UnicodeHandlesEnabled::<T>::put(enabled);
+
+            Self::deposit_event(Event::UnicodeHandlesSettingUpdated(enabled));
+            Ok(Pays::No.into())
+        }
     }
-);
+}
 
 fn num_bits<P>() -> usize {
     sp_std::mem::size_of::<P>() * 8
@@ -226,28 +420,349 @@ pub fn remove_from_vec<F: PartialEq>(vector: &mut Vec<F>, element: F) {
     }
 }
 
+/// Get up to `limit` second keys of a double map `M` under the first key `k1`.
+///
+/// Keys are returned in the storage order starting right after the `cursor` key
+/// (or from the first key if `cursor` is `None`). Pass the last returned key as the `cursor`
+/// to get the next page.
+pub fn paginate_double_map_keys<M, K1, K2, V>(k1: &K1, cursor: Option<K2>, limit: u32) -> Vec<K2>
+where
+    K1: FullEncode,
+    K2: FullCodec,
+    V: FullCodec,
+    M: StorageDoubleMap<K1, K2, V>,
+    M::Hasher2: ReversibleStorageHasher,
+{
+    let prefix = M::storage_double_map_final_key1(k1);
+    let mut previous_key = cursor
+        .map(|k2| M::storage_double_map_final_key(k1, k2))
+        .unwrap_or_else(|| prefix.clone());
+
+    let mut keys = Vec::new();
+    while keys.len() < limit as usize {
+        let next_key = match sp_io::storage::next_key(&previous_key) {
+            Some(key) if key.starts_with(&prefix) => key,
+            _ => break,
+        };
+
+        if let Ok(k2) = K2::decode(&mut M::Hasher2::reverse(&next_key[prefix.len()..])) {
+            keys.push(k2);
+        }
+        previous_key = next_key;
+    }
+
+    keys
+}
+
+/// A migration that is run in steps over several blocks from `on_initialize`
+/// of this pallet, see `Config::MultiBlockMigrations`.
+pub trait MultiBlockMigration<T: Config> {
+    /// Migrate at most `budget` storage items and subtract their number from the `budget`.
+    fn migrate_step(budget: &mut u32) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<T: Config> MultiBlockMigration<T> for Tuple {
+    fn migrate_step(budget: &mut u32) -> Weight {
+        let mut weight: Weight = 0;
+        for_tuples!( #( weight = weight.saturating_add(Tuple::migrate_step(budget)); )* );
+        weight
+    }
+}
+
+/// Called when keys of a first key `K1` were moved from a `Vec` to a double map.
+pub trait OnKeysMovedToDoubleMap<K1> {
+    /// `new_keys` is the number of the keys that were not in the double map yet.
+    fn on_keys_moved(k1: &K1, new_keys: u32) -> Weight;
+}
+
+impl<K1> OnKeysMovedToDoubleMap<K1> for () {
+    fn on_keys_moved(_k1: &K1, _new_keys: u32) -> Weight {
+        0
+    }
+}
+
+/// Move the entries of a deprecated `map K1 => Vec<K2>` storage to the double map `M`
+/// that replaced it under the same storage prefix, as `(K1, K2) => true`.
+///
+/// Does nothing until the move is started with `start_moving_vec_map_to_double_map`.
+/// Every step visits the keys under the prefix of `M` from the cursor in `VecMapMigrationCursors`.
+/// Each visited key and each moved `K2` count as one item of the budget. A `Vec` that doesn't fit
+/// into the budget is moved in part, and the rest of it is left under the old key for the next step.
+/// Once all the keys are visited, the cursor is removed.
+pub struct MoveVecMapToDoubleMap<M, K1, K2, OnMoved = ()>(sp_std::marker::PhantomData<(M, K1, K2, OnMoved)>);
+
+impl<T, M, K1, K2, OnMoved> MultiBlockMigration<T> for MoveVecMapToDoubleMap<M, K1, K2, OnMoved>
+where
+    T: Config,
+    K1: FullCodec,
+    K2: FullCodec,
+    M: StorageDoubleMap<K1, K2, bool>,
+    M::Hasher1: ReversibleStorageHasher,
+    OnMoved: OnKeysMovedToDoubleMap<K1>,
+{
+    fn migrate_step(budget: &mut u32) -> Weight {
+        let prefix = M::prefix_hash();
+        let mut cursor = match VecMapMigrationCursors::<T>::get(&prefix) {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 1;
+        let mut hooks_weight: Weight = 0;
+        let mut is_finished = false;
+
+        while *budget > 0 {
+            let key = match sp_io::storage::next_key(&cursor) {
+                Some(key) if key.starts_with(&prefix) => key,
+                _ => {
+                    is_finished = true;
+                    break;
+                }
+            };
+            reads += 1;
+            *budget -= 1;
+
+            // Only the old keys end right after the first key, the new ones have a second key.
+            let old_k1 = K1::decode_all(M::Hasher1::reverse(&key[prefix.len()..])).ok();
+            if let Some(k1) = old_k1 {
+                let mut k2s_to_move: Vec<K2> = storage::unhashed::get(&key).unwrap_or_default();
+                let rest_of_k2s = k2s_to_move.split_off(k2s_to_move.len().min(*budget as usize));
+                *budget -= k2s_to_move.len() as u32;
+
+                let mut new_keys: u32 = 0;
+                for k2 in k2s_to_move.iter() {
+                    if !<M as storage::StorageDoubleMap<K1, K2, bool>>::contains_key(&k1, k2) {
+                        <M as storage::StorageDoubleMap<K1, K2, bool>>::insert(&k1, k2, true);
+                        new_keys += 1;
+                    }
+                }
+                reads += k2s_to_move.len() as u64;
+                writes += new_keys as u64 + 1;
+
+                if new_keys > 0 {
+                    hooks_weight = hooks_weight.saturating_add(OnMoved::on_keys_moved(&k1, new_keys));
+                }
+
+                if !rest_of_k2s.is_empty() {
+                    // Keep the cursor before this key to continue with the rest of its `Vec`.
+                    storage::unhashed::put(&key, &rest_of_k2s);
+                    break;
+                }
+                storage::unhashed::kill(&key);
+            }
+
+            cursor = key;
+        }
+
+        if is_finished {
+            VecMapMigrationCursors::<T>::remove(&prefix);
+        } else {
+            VecMapMigrationCursors::<T>::insert(&prefix, cursor);
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes).saturating_add(hooks_weight)
+    }
+}
+
+/// Start moving the `Vec`s of a deprecated `map K1 => Vec<K2>` storage to the double map `M`
+/// that replaced it. See `MoveVecMapToDoubleMap`, which has to be in `Config::MultiBlockMigrations`.
+pub fn start_moving_vec_map_to_double_map<T, M, K1, K2>() -> Weight
+where
+    T: Config,
+    M: StorageDoubleMap<K1, K2, bool>,
+    K1: FullEncode,
+    K2: FullEncode,
+{
+    let prefix = M::prefix_hash();
+    VecMapMigrationCursors::<T>::insert(&prefix, &prefix);
+    T::DbWeight::get().writes(1)
+}
+
+/// Whether there is any storage under a given pallet prefix.
+fn pallet_prefix_has_storage(pallet_prefix: &str) -> bool {
+    let prefix = sp_io::hashing::twox_128(pallet_prefix.as_bytes());
+    sp_io::storage::next_key(&prefix).map_or(false, |key| key.starts_with(&prefix))
+}
+
+/// Move all storage items of a pallet `P` from the prefix that its `decl_storage` used
+/// to the pallet name. The storage version of the pallet is not changed.
+///
+/// Does nothing and returns `false` if there is no storage under the old prefix.
+pub fn move_storage_from_old_pallet_prefix<P: PalletInfoAccess>(old_pallet_prefix: &str) -> bool {
+    let new_pallet_prefix = P::name();
+    if new_pallet_prefix == old_pallet_prefix || !pallet_prefix_has_storage(old_pallet_prefix) {
+        return false;
+    }
+
+    move_pallet(old_pallet_prefix.as_bytes(), new_pallet_prefix.as_bytes());
+    true
+}
+
+/// Ensure that nothing is left under the old `decl_storage` prefix of a pallet `P`.
+#[cfg(feature = "try-runtime")]
+pub fn ensure_old_pallet_prefix_is_empty<P: PalletInfoAccess>(old_pallet_prefix: &str) -> Result<(), &'static str> {
+    ensure!(
+        P::name() == old_pallet_prefix || !pallet_prefix_has_storage(old_pallet_prefix),
+        "Storage is left under an old pallet prefix"
+    );
+    Ok(())
+}
+
+/// Put the first storage version of a pallet `P`, if it has no storage version on the chain yet.
+/// Returns `true` if the version was put.
+pub fn put_initial_storage_version<P: GetStorageVersion + PalletInfoAccess>() -> bool {
+    if P::on_chain_storage_version() != StorageVersion::new(0) {
+        return false;
+    }
+
+    StorageVersion::new(1).put::<P>();
+    true
+}
+
+/// Ensure that a pallet `P` has no storage version on the chain yet,
+/// so that all its migrations guarded by storage versions will run.
+#[cfg(feature = "try-runtime")]
+pub fn ensure_no_storage_version<P: GetStorageVersion>() -> Result<(), &'static str> {
+    ensure!(
+        P::on_chain_storage_version() == StorageVersion::new(0),
+        "Pallet already has an on-chain storage version"
+    );
+    Ok(())
+}
+
+/// Ensure that the on-chain storage version of a pallet `P` is the current one.
+#[cfg(feature = "try-runtime")]
+pub fn ensure_storage_version<P: GetStorageVersion>() -> Result<(), &'static str> {
+    ensure!(
+        P::on_chain_storage_version() == P::current_storage_version(),
+        "On-chain storage version is not the current one"
+    );
+    Ok(())
+}
+
+/// Ensure that every value of a map `M` decodes, by comparing the number of its raw keys
+/// with the number of decoded values.
+#[cfg(feature = "try-runtime")]
+pub fn ensure_map_decodes<M, V>() -> Result<(), &'static str>
+where
+    M: StoragePrefixedMap<V>,
+    V: FullCodec,
+{
+    let prefix = M::final_prefix();
+    let mut previous_key = prefix.to_vec();
+    let mut raw_keys: usize = 0;
+    while let Some(next_key) = sp_io::storage::next_key(&previous_key).filter(|key| key.starts_with(&prefix)) {
+        raw_keys += 1;
+        previous_key = next_key;
+    }
+
+    ensure!(M::iter_values().count() == raw_keys, "Some values of a storage map don't decode");
+    Ok(())
+}
+
 pub fn bool_to_option(value: bool) -> Option<bool> {
     if value { Some(value) } else { None }
 }
 
-impl<T: Config> Module<T> {
+impl<T: Config> Pallet<T> {
 
     // TODO Rename to `ensure_content_is_valid`
     pub fn is_valid_content(content: Content) -> DispatchResult {
         match content {
             Content::None => Ok(()),
-            Content::Raw(_) => Err(Error::<T>::RawContentTypeNotSupported.into()),
+            Content::Raw(bytes) => {
+                ensure!(bytes.len() <= T::MaxRawContentLen::get() as usize, Error::<T>::RawContentIsTooLong);
+                Ok(())
+            },
             Content::IPFS(ipfs_cid) => {
-                let len = ipfs_cid.len();
-                // IPFS CID v0 is 46 bytes.
-                // IPFS CID v1 is 59 bytes.df-integration-tests/src/lib.rs:272:5
-                ensure!(len == 46 || len == 59, Error::<T>::InvalidIpfsCid);
+                cid::validate_cid(&ipfs_cid).map_err(|err| match err {
+                    cid::CidError::Malformed => Error::<T>::InvalidIpfsCid,
+                    cid::CidError::CodecNotAllowed => Error::<T>::IpfsCidCodecNotAllowed,
+                    cid::CidError::HashNotAllowed => Error::<T>::IpfsCidHashNotAllowed,
+                })?;
                 Ok(())
             },
-            Content::Hyper(_) => Err(Error::<T>::HypercoreContentTypeNotSupported.into())
+            Content::Hyper(key) => {
+                ensure!(Self::is_valid_hypercore_key(&key), Error::<T>::InvalidHypercoreKey);
+                Ok(())
+            }
+        }
+    }
+
+    /// Check if a Hypercore key is a 32-byte public key encoded either
+    /// as 64 hex chars or as 52 z-base-32 chars.
+    fn is_valid_hypercore_key(key: &[u8]) -> bool {
+        const Z_BASE_32_ALPHABET: &[u8] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+
+        match key.len() {
+            64 => key.iter().all(u8::is_ascii_hexdigit),
+            52 => key.iter().all(|c| Z_BASE_32_ALPHABET.contains(c)),
+            _ => false,
+        }
+    }
+
+    /// Get a storage deposit for an item that takes `encoded_len` bytes on-chain.
+    pub fn storage_deposit(encoded_len: usize) -> BalanceOf<T> {
+        let bytes_deposit = T::StorageDepositPerByte::get().saturating_mul((encoded_len as u32).into());
+        T::StorageDepositPerItem::get().saturating_add(bytes_deposit)
+    }
+
+    /// Reserve a storage deposit for a new item from its owner.
+    pub fn reserve_storage_deposit<V: Encode>(
+        owner: &T::AccountId,
+        item: StorageItem<T::AccountId>,
+        value: &V,
+    ) -> DispatchResult {
+        let amount = Self::storage_deposit(value.encoded_size());
+        T::Currency::reserve(owner, amount)?;
+
+        StorageDepositByItem::<T>::insert(item, StorageDeposit { owner: owner.clone(), amount });
+        Ok(())
+    }
+
+    /// Reserve or unreserve the difference between a storage deposit of an item
+    /// and a deposit for a new value of this item. The deposit stays with the item owner,
+    /// even if the item is updated by another account.
+    ///
+    /// Does nothing if the item has no storage deposit.
+    pub fn update_storage_deposit<V: Encode>(item: StorageItem<T::AccountId>, value: &V) -> DispatchResult {
+        StorageDepositByItem::<T>::try_mutate_exists(item, |deposit_opt| -> DispatchResult {
+            if let Some(deposit) = deposit_opt {
+                let new_amount = Self::storage_deposit(value.encoded_size());
+
+                if new_amount > deposit.amount {
+                    T::Currency::reserve(&deposit.owner, new_amount.saturating_sub(deposit.amount))?;
+                } else if deposit.amount > new_amount {
+                    T::Currency::unreserve(&deposit.owner, deposit.amount.saturating_sub(new_amount));
+                }
+                deposit.amount = new_amount;
+            }
+            Ok(())
+        })
+    }
+
+    /// Return a storage deposit of a removed item to its owner.
+    pub fn release_storage_deposit(item: StorageItem<T::AccountId>) {
+        if let Some(deposit) = StorageDepositByItem::<T>::take(item) {
+            T::Currency::unreserve(&deposit.owner, deposit.amount);
         }
     }
 
+    /// Move a storage deposit of an item to a new owner of this item.
+    pub fn move_storage_deposit(item: StorageItem<T::AccountId>, new_owner: &T::AccountId) -> DispatchResult {
+        StorageDepositByItem::<T>::try_mutate_exists(item, |deposit_opt| -> DispatchResult {
+            if let Some(deposit) = deposit_opt {
+                if !deposit.amount.is_zero() {
+                    T::Currency::repatriate_reserved(&deposit.owner, new_owner, deposit.amount, BalanceStatus::Reserved)?;
+                }
+                deposit.owner = new_owner.clone();
+            }
+            Ok(())
+        })
+    }
+
     pub fn convert_users_vec_to_btree_set(
         users_vec: Vec<User<T::AccountId>>
     ) -> Result<BTreeSet<User<T::AccountId>>, DispatchError> {
@@ -266,31 +781,114 @@ impl<T: Config> Module<T> {
         matches!(c, b'0'..=b'9' | b'a'..=b'z' | b'_')
     }
 
-    /// Lowercase a handle.
+    /// Lowercase a handle to get a key under which this handle is stored.
+    ///
+    /// A handle is also normalized (NFKC) and its confusable chars are replaced with their
+    /// prototypes (UTS #39 skeleton), so handles that look the same get one key.
+    /// E.g. `рара` written in Cyrillic gets the same key as `papa` written in Latin,
+    /// and `pa1` gets the same key as `pal`.
     pub fn lowercase_handle(handle: Vec<u8>) -> Vec<u8> {
-        handle.to_ascii_lowercase()
+        match sp_std::str::from_utf8(&handle) {
+            Ok(handle_str) => {
+                let normalized: String = handle_str.chars().flat_map(char::to_lowercase).nfkc().collect();
+                let skeleton: String = unicode_security::skeleton(&normalized).flat_map(char::to_lowercase).collect();
+                skeleton.into_bytes()
+            }
+            Err(_) => handle.to_ascii_lowercase(),
+        }
+    }
+
+    /// Check if a non-ASCII handle is a valid UTF-8 string of letters, digits and `_`
+    /// that doesn't mix scripts which can be confused with each other.
+    fn ensure_valid_unicode_handle(handle: &[u8]) -> DispatchResult {
+        ensure!(Self::unicode_handles_enabled(), Error::<T>::HandleContainsInvalidChars);
+
+        let handle_str = sp_std::str::from_utf8(handle).map_err(|_| Error::<T>::HandleContainsInvalidChars)?;
+
+        let is_only_valid_chars = handle_str.chars().all(|c| c == '_' || c.is_alphanumeric());
+        ensure!(is_only_valid_chars, Error::<T>::HandleContainsInvalidChars);
+
+        // Chars that are not allowed in identifiers by UTS #39 make the restriction level `Unrestricted`.
+        ensure!(
+            handle_str.check_restriction_level(RestrictionLevel::HighlyRestrictive),
+            Error::<T>::HandleMixesScripts
+        );
+
+        Ok(())
     }
 
     /// This function does the next:
     /// - Check if a handle length fits into min/max length constraints.
     /// - Lowercase a handle.
     /// - Check if a handle contains only valid chars: 0-9, a-z, _.
+    ///   If Unicode handles are enabled, a non-ASCII handle can contain letters and digits
+    ///   of any script, but should not mix scripts.
+    /// - Check if a handle is not blocked.
     pub fn lowercase_and_validate_a_handle(handle: Vec<u8>) -> Result<Vec<u8>, DispatchError> {
 
         // Check if a handle length fits into min/max length constraints:
-        ensure!(handle.len() >= T::MinHandleLen::get() as usize, Error::<T>::HandleIsTooShort);
-        ensure!(handle.len() <= T::MaxHandleLen::get() as usize, Error::<T>::HandleIsTooLong);
+        let handle_len = Self::handle_len(&handle);
+        ensure!(handle_len >= T::MinHandleLen::get() as usize, Error::<T>::HandleIsTooShort);
+        ensure!(handle_len <= T::MaxHandleLen::get() as usize, Error::<T>::HandleIsTooLong);
+
+        if handle.is_ascii() {
+            // Check if a handle contains only valid chars: 0-9, a-z, _.
+            let is_only_valid_chars = handle.to_ascii_lowercase().iter().all(|&x| Self::is_valid_handle_char(x));
+            ensure!(is_only_valid_chars, Error::<T>::HandleContainsInvalidChars);
+        } else {
+            Self::ensure_valid_unicode_handle(&handle)?;
+        }
 
         let handle_in_lowercase = Self::lowercase_handle(handle);
 
-        // Check if a handle contains only valid chars: 0-9, a-z, _.
-        let is_only_valid_chars = handle_in_lowercase.iter().all(|&x| Self::is_valid_handle_char(x));
-        ensure!(is_only_valid_chars, Error::<T>::HandleContainsInvalidChars);
+        ensure!(!Self::is_handle_blocked(&handle_in_lowercase), Error::<T>::HandleIsBlocked);
 
         // Return a lower-cased version of a handle.
         Ok(handle_in_lowercase)
     }
 
+    /// The number of characters in a handle, so that a multi-byte Unicode char counts as one,
+    /// or the number of bytes if a handle is not a valid UTF-8 string.
+    pub fn handle_len(handle: &[u8]) -> usize {
+        sp_std::str::from_utf8(handle).map_or(handle.len(), |handle_str| handle_str.chars().count())
+    }
+
+    /// Get a deposit for a handle, depending on its length in characters, where `base_deposit`
+    /// is a deposit for handles that are not shorter than `PremiumHandleLen`.
+    pub fn handle_deposit<Balance: Saturating + From<u32>>(base_deposit: Balance, handle: &[u8]) -> Balance {
+        let missing_chars = T::PremiumHandleLen::get().saturating_sub(Self::handle_len(handle) as u32);
+        base_deposit.saturating_mul(Balance::from(2u32.saturating_pow(missing_chars)))
+    }
+
+    /// Create a registration of a new handle for `HandleRegistrationPeriod` blocks.
+    pub fn new_handle_registration<Balance>(deposit: Balance) -> HandleRegistration<T::BlockNumber, Balance> {
+        let now = <system::Pallet<T>>::block_number();
+        HandleRegistration {
+            deposit,
+            expires_at: T::HandleRegistrationPeriod::get().map(|period| now.saturating_add(period)),
+        }
+    }
+
+    /// Extend the registration of a handle for one more `HandleRegistrationPeriod`.
+    /// A handle cannot be renewed more than one period ahead.
+    pub fn renew_handle_registration<Balance>(
+        registration: &mut HandleRegistration<T::BlockNumber, Balance>
+    ) -> DispatchResult {
+        let expires_at = registration.expires_at.ok_or(Error::<T>::HandleDoesNotExpire)?;
+        let period = T::HandleRegistrationPeriod::get().ok_or(Error::<T>::HandleDoesNotExpire)?;
+
+        let now = <system::Pallet<T>>::block_number();
+        ensure!(expires_at <= now.saturating_add(period), Error::<T>::HandleRenewedTooEarly);
+
+        registration.expires_at = Some(expires_at.saturating_add(period));
+        Ok(())
+    }
+
+    /// Get the block at which a handle is released if it's not renewed, i.e. the end of its grace period.
+    pub fn handle_release_block<Balance>(registration: &HandleRegistration<T::BlockNumber, Balance>) -> Option<T::BlockNumber> {
+        registration.expires_at.map(|expires_at| expires_at.saturating_add(T::HandleGracePeriod::get()))
+    }
+
     /// Ensure that a given content is not `None`.
     pub fn ensure_content_is_some(content: &Content) -> DispatchResult {
         ensure!(content.is_some(), Error::<T>::ContentIsEmpty);
@@ -298,7 +896,7 @@ impl<T: Config> Module<T> {
     }
 }
 
-impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Module<T> {
+impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
     fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
         let numeric_amount = amount.peek();
         let treasury_account = TreasuryAccount::<T>::get();
@@ -306,6 +904,6 @@ impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Module<T> {
         // Must resolve into existing but better to be safe.
         let _ = T::Currency::resolve_creating(&treasury_account, amount);
 
-        Self::deposit_event(RawEvent::Deposit(numeric_amount));
+        Self::deposit_event(Event::Deposit(numeric_amount));
     }
 }
@@ -7,7 +7,13 @@ use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup}, testing::Header,
 };
-use frame_support::{parameter_types, dispatch::DispatchError, traits::Everything};
+use frame_support::{
+    parameter_types, dispatch::DispatchError,
+    storage::{generator::StorageDoubleMap as _, types::{StorageDoubleMap, StorageMap, ValueQuery}, unhashed},
+    traits::{Everything, OnInitialize, StorageInstance},
+    weights::Weight,
+    Twox64Concat,
+};
 use frame_system as system;
 
 use crate as utils;
@@ -88,15 +94,51 @@ impl pallet_balances::Config for Test {
 parameter_types! {
     pub const MinHandleLen: u32 = DEFAULT_MIN_HANDLE_LEN;
     pub const MaxHandleLen: u32 = DEFAULT_MAX_HANDLE_LEN;
+    pub const MaxItemsToMigratePerBlock: u32 = 4;
   }
 impl Config for Test {
     type Event = Event;
     type Currency = Balances;
     type MinHandleLen = MinHandleLen;
     type MaxHandleLen = MaxHandleLen;
+    type PremiumHandleLen = ();
+    type HandleRegistrationPeriod = ();
+    type HandleGracePeriod = ();
+    type MaxHandlesToReleasePerBlock = ();
+    type MaxRawContentLen = ();
+    type StorageDepositPerItem = ();
+    type StorageDepositPerByte = ();
+    type MaxItemsToMigratePerBlock = MaxItemsToMigratePerBlock;
+    type MultiBlockMigrations = MoveVecMapToDoubleMap<IdsByOwner, AccountId, u64, CountMovedIds>;
 }
 
 pub type AccountId = u64;
+
+pub struct IdsByOwnerPrefix;
+impl StorageInstance for IdsByOwnerPrefix {
+    fn pallet_prefix() -> &'static str { "Test" }
+    const STORAGE_PREFIX: &'static str = "IdsByOwner";
+}
+
+/// A double map that replaced a `map AccountId => Vec<u64>` under the same prefix.
+pub(crate) type IdsByOwner =
+    StorageDoubleMap<IdsByOwnerPrefix, Twox64Concat, AccountId, Twox64Concat, u64, bool, ValueQuery>;
+
+pub struct IdsCountByOwnerPrefix;
+impl StorageInstance for IdsCountByOwnerPrefix {
+    fn pallet_prefix() -> &'static str { "Test" }
+    const STORAGE_PREFIX: &'static str = "IdsCountByOwner";
+}
+
+pub(crate) type IdsCountByOwner = StorageMap<IdsCountByOwnerPrefix, Twox64Concat, AccountId, u32, ValueQuery>;
+
+pub struct CountMovedIds;
+impl OnKeysMovedToDoubleMap<AccountId> for CountMovedIds {
+    fn on_keys_moved(owner: &AccountId, new_keys: u32) -> Weight {
+        IdsCountByOwner::mutate(owner, |count| *count += new_keys);
+        0
+    }
+}
 pub(crate) type UsersSet = BTreeSet<User<AccountId>>;
 
 pub struct ExtBuilder;
@@ -119,6 +161,32 @@ pub(crate) const USER1: User<AccountId> = User::Account(1);
 pub(crate) const USER2: User<AccountId> = User::Account(2);
 pub(crate) const USER3: User<AccountId> = User::Account(3);
 
+/// Put the ids of an owner as they were stored before `IdsByOwner` became a double map.
+pub(crate) fn put_old_ids_of_owner(owner: AccountId, ids: Vec<u64>) {
+    unhashed::put(&IdsByOwner::storage_double_map_final_key1(owner), &ids);
+}
+
+pub(crate) fn old_ids_of_owner(owner: AccountId) -> Option<Vec<u64>> {
+    unhashed::get(&IdsByOwner::storage_double_map_final_key1(owner))
+}
+
+pub(crate) fn ids_of_owner(owner: AccountId) -> Vec<u64> {
+    let mut ids: Vec<u64> = IdsByOwner::iter_key_prefix(owner).collect();
+    ids.sort_unstable();
+    ids
+}
+
+pub(crate) fn is_moving_ids_by_owner() -> bool {
+    VecMapMigrationCursors::<Test>::contains_key(IdsByOwner::prefix_hash())
+}
+
+pub(crate) fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Utils::on_initialize(System::block_number());
+    }
+}
+
 pub(crate) fn _convert_users_vec_to_btree_set(
     users_vec: Vec<User<AccountId>>
 ) -> Result<UsersSet, DispatchError> {
@@ -7,3 +7,7 @@ pub fn valid_content_ipfs() -> Content {
 pub fn invalid_content_ipfs() -> Content {
     Content::IPFS(b"QmRAQB6DaazhR8".to_vec())
 }
+
+pub fn updated_content_ipfs() -> Content {
+    Content::IPFS(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec())
+}
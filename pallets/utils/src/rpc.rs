@@ -4,10 +4,11 @@ use serde::{Deserialize, Serialize};
 use jsonrpc_core::{Error as RpcError, ErrorCode};
 
 use codec::{Decode, Encode};
-use sp_runtime::SaturatedConversion;
+use frame_support::{storage::{with_transaction, TransactionOutcome}, traits::ReservableCurrency};
+use sp_runtime::{SaturatedConversion, traits::{Dispatchable, Saturating}};
 use sp_std::prelude::*;
 
-use crate::{Content, bool_to_option, Config, WhoAndWhen};
+use crate::{BalanceOf, Content, bool_to_option, Config, WhoAndWhen};
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
@@ -61,6 +62,25 @@ impl<T: Config> From<WhoAndWhen<T>> for FlatWhoAndWhen<T::AccountId, T::BlockNum
     }
 }
 
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatEditedWhoAndWhen<AccountId, BlockNumber> {
+    pub edited_by: AccountId,
+    pub edited_at_block: BlockNumber,
+    pub edited_at_time: u64,
+}
+
+impl<T: Config> From<WhoAndWhen<T>> for FlatEditedWhoAndWhen<T::AccountId, T::BlockNumber> {
+    fn from(edited: WhoAndWhen<T>) -> Self {
+        Self {
+            edited_by: edited.account,
+            edited_at_block: edited.block,
+            edited_at_time: edited.time.saturated_into::<u64>(),
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
@@ -94,6 +114,37 @@ impl From<Content> for FlatContent {
     }
 }
 
+/// The change of the reserved balance of an account that a call would make,
+/// if it was dispatched by this account. Only one of the amounts can be non-zero.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatStorageDepositQuote<Balance> {
+    pub to_reserve: Balance,
+    pub to_unreserve: Balance,
+}
+
+/// Dispatch a call by a given account, measure how the reserved balance of this account changes,
+/// and roll back all the changes. Returns `None` if the call fails.
+pub fn quote_storage_deposit<T, C>(account: T::AccountId, call: C) -> Option<FlatStorageDepositQuote<BalanceOf<T>>>
+where
+    T: Config,
+    C: Dispatchable,
+    C::Origin: From<frame_system::RawOrigin<T::AccountId>>,
+{
+    with_transaction(|| {
+        let reserved_before = T::Currency::reserved_balance(&account);
+        let result = call.dispatch(frame_system::RawOrigin::Signed(account.clone()).into());
+        let reserved_after = T::Currency::reserved_balance(&account);
+
+        let quote = result.ok().map(|_| FlatStorageDepositQuote {
+            to_reserve: reserved_after.saturating_sub(reserved_before),
+            to_unreserve: reserved_before.saturating_sub(reserved_after),
+        });
+        TransactionOutcome::Rollback(quote)
+    })
+}
+
 pub trait ShouldSkip {
     fn should_skip(&self) -> bool;
 }
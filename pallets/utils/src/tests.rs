@@ -1,4 +1,8 @@
-use crate::{mock::*, remove_from_vec, log_2};
+use crate::{
+    mock::*, remove_from_vec, log_2, start_moving_vec_map_to_double_map,
+    Error, UnicodeHandlesEnabled, Content, cid::{validate_cid, CidError},
+};
+use frame_support::{assert_noop, assert_ok};
 
 #[test]
 fn log_2_should_work() {
@@ -89,3 +93,192 @@ fn convert_users_vec_to_btree_set_should_work() {
         );
     });
 }
+
+#[test]
+fn lowercase_handle_should_map_confusable_handles_to_one_key() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(Utils::lowercase_handle(b"Papa_Space".to_vec()), b"papa_space".to_vec());
+
+        // ASCII digits that look like letters
+        assert_eq!(Utils::lowercase_handle(b"pa1_space".to_vec()), Utils::lowercase_handle(b"pal_space".to_vec()));
+        assert_eq!(Utils::lowercase_handle("ра1_ѕрасе".as_bytes().to_vec()), Utils::lowercase_handle(b"pal_space".to_vec()));
+
+        // The same handle written in Cyrillic letters
+        assert_eq!(Utils::lowercase_handle("Рара_Ѕрасе".as_bytes().to_vec()), b"papa_space".to_vec());
+
+        // Fullwidth letters are normalized
+        assert_eq!(Utils::lowercase_handle("ＰＡＰＡ_space".as_bytes().to_vec()), b"papa_space".to_vec());
+    });
+}
+
+#[test]
+fn lowercase_and_validate_a_handle_should_work_with_unicode_handles() {
+    ExtBuilder::build().execute_with(|| {
+        let handle = "блог_кошек".as_bytes().to_vec();
+
+        // Unicode handles are disabled by default
+        assert_noop!(
+            Utils::lowercase_and_validate_a_handle(handle.clone()),
+            Error::<Test>::HandleContainsInvalidChars
+        );
+
+        UnicodeHandlesEnabled::<Test>::put(true);
+        assert_ok!(Utils::lowercase_and_validate_a_handle(handle.clone()));
+        assert_ok!(Utils::lowercase_and_validate_a_handle("日本語_handle".as_bytes().to_vec()));
+
+        // ASCII handles follow the same rules as before
+        assert_noop!(
+            Utils::lowercase_and_validate_a_handle(b"space-handle".to_vec()),
+            Error::<Test>::HandleContainsInvalidChars
+        );
+        assert_noop!(
+            Utils::lowercase_and_validate_a_handle("блог-кошек".as_bytes().to_vec()),
+            Error::<Test>::HandleContainsInvalidChars
+        );
+    });
+}
+
+#[test]
+fn lowercase_and_validate_a_handle_should_fail_when_handle_mixes_scripts() {
+    ExtBuilder::build().execute_with(|| {
+        UnicodeHandlesEnabled::<Test>::put(true);
+
+        // Latin `pa` followed by Cyrillic `ра`
+        assert_noop!(
+            Utils::lowercase_and_validate_a_handle("paра_space".as_bytes().to_vec()),
+            Error::<Test>::HandleMixesScripts
+        );
+
+        // Fullwidth letters are not allowed in identifiers
+        assert_noop!(
+            Utils::lowercase_and_validate_a_handle("ＰＡＰＡ_space".as_bytes().to_vec()),
+            Error::<Test>::HandleMixesScripts
+        );
+    });
+}
+
+#[test]
+fn validate_cid_should_work_with_cid_v0_and_v1() {
+    // CID v0
+    assert_eq!(validate_cid(b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW2CuDgwxkD4"), Ok(()));
+
+    // CID v1 in base32, base58btc and base16
+    assert_eq!(validate_cid(b"bafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525l54e"), Ok(()));
+    assert_eq!(validate_cid(b"zb2rhe5P4gXftAwvA4eXQ5HJwsER2owDyS9sKaQRRVQPn93bA"), Ok(()));
+    assert_eq!(
+        validate_cid(b"f01701220c3c4733ec8affd06cf9e9ff50ffc6bcd2ec85a6170004bb709669c31de94391a"),
+        Ok(())
+    );
+}
+
+#[test]
+fn validate_cid_should_fail_with_malformed_cid() {
+    // Too short
+    assert_eq!(validate_cid(b"QmRAQB6DaazhR8"), Err(CidError::Malformed));
+
+    // '0' is not a base58 char
+    assert_eq!(validate_cid(b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW2CuDgwxkD0"), Err(CidError::Malformed));
+
+    // Unknown multibase
+    assert_eq!(validate_cid(b"xafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525l54e"), Err(CidError::Malformed));
+
+    // A digest is shorter than its multihash says
+    assert_eq!(validate_cid(b"bafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525"), Err(CidError::Malformed));
+}
+
+#[test]
+fn validate_cid_should_fail_with_not_allowed_codec_or_hash() {
+    // `json` codec (0x0200)
+    assert_eq!(
+        validate_cid(b"bagaaierasords4njcts6vs7qvdjfcvgnume4hqohf65zsfguprqphs3icwea"),
+        Err(CidError::CodecNotAllowed)
+    );
+
+    // `identity` hash (0x00) of `dag-pb` content
+    assert_eq!(validate_cid(b"f01700003616263"), Err(CidError::HashNotAllowed));
+}
+
+#[test]
+fn is_valid_content_should_work_with_raw_and_hyper_content() {
+    ExtBuilder::build().execute_with(|| {
+        // `Raw` content is not allowed when `MaxRawContentLen` is zero
+        assert_noop!(
+            Utils::is_valid_content(Content::Raw(b"raw".to_vec())),
+            Error::<Test>::RawContentIsTooLong
+        );
+
+        assert_ok!(Utils::is_valid_content(Content::Hyper(
+            b"0a3e5a2fbcbf4e6d1cc9f4a3cd6a0b5d0fd6e43a5dd0fa3a3cbbd2a7a4b5c6d7".to_vec()
+        )));
+        assert_ok!(Utils::is_valid_content(Content::Hyper(
+            b"ybndrfg8ejkmcpqxot1uwisza345h769ybndrfg8ejkmcpqxot1u".to_vec()
+        )));
+        assert_noop!(
+            Utils::is_valid_content(Content::Hyper(b"not_a_hypercore_key".to_vec())),
+            Error::<Test>::InvalidHypercoreKey
+        );
+    });
+}
+
+#[test]
+fn move_vec_map_to_double_map_should_move_old_vecs_including_empty_ones() {
+    ExtBuilder::build().execute_with(|| {
+        IdsByOwner::insert(1, 10, true);
+        put_old_ids_of_owner(1, vec![10, 11]);
+        put_old_ids_of_owner(2, vec![]);
+        put_old_ids_of_owner(3, vec![30]);
+
+        start_moving_vec_map_to_double_map::<Test, IdsByOwner, AccountId, u64>();
+        run_to_block(10);
+
+        assert!(!is_moving_ids_by_owner());
+        for owner in 1..=3 {
+            assert_eq!(old_ids_of_owner(owner), None);
+        }
+
+        assert_eq!(ids_of_owner(1), vec![10, 11]);
+        assert!(ids_of_owner(2).is_empty());
+        assert_eq!(ids_of_owner(3), vec![30]);
+
+        // Only the ids that were not in the double map yet are counted.
+        assert_eq!(IdsCountByOwner::get(1), 1);
+        assert_eq!(IdsCountByOwner::get(2), 0);
+        assert_eq!(IdsCountByOwner::get(3), 1);
+    });
+}
+
+#[test]
+fn move_vec_map_to_double_map_should_move_long_vec_over_several_blocks() {
+    ExtBuilder::build().execute_with(|| {
+        put_old_ids_of_owner(1, (1..=10).collect());
+        start_moving_vec_map_to_double_map::<Test, IdsByOwner, AccountId, u64>();
+
+        // One item of the budget goes to the visit of the old key.
+        run_to_block(2);
+        assert_eq!(ids_of_owner(1), vec![1, 2, 3]);
+        assert_eq!(old_ids_of_owner(1), Some((4..=10).collect()));
+        assert!(is_moving_ids_by_owner());
+
+        run_to_block(3);
+        assert_eq!(ids_of_owner(1), (1..=6).collect::<Vec<_>>());
+        assert_eq!(old_ids_of_owner(1), Some((7..=10).collect()));
+
+        run_to_block(20);
+        assert!(!is_moving_ids_by_owner());
+        assert_eq!(old_ids_of_owner(1), None);
+        assert_eq!(ids_of_owner(1), (1..=10).collect::<Vec<_>>());
+        assert_eq!(IdsCountByOwner::get(1), 10);
+    });
+}
+
+#[test]
+fn move_vec_map_to_double_map_should_do_nothing_until_started() {
+    ExtBuilder::build().execute_with(|| {
+        put_old_ids_of_owner(1, vec![10, 11]);
+
+        run_to_block(5);
+
+        assert_eq!(old_ids_of_owner(1), Some(vec![10, 11]));
+        assert!(ids_of_owner(1).is_empty());
+    });
+}
@@ -12,6 +12,7 @@ use sp_std::{
     prelude::*,
     collections::btree_map::BTreeMap,
 };
+use codec::Decode;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 pub use subsocial_primitives::{AccountId, Signature, Balance, Index};
 use subsocial_primitives::{BlockNumber, Hash, Moment};
@@ -56,15 +57,21 @@ use pallet_transaction_payment::CurrencyAdapter;
 use static_assertions::const_assert;
 
 use pallet_permissions::SpacePermission;
+use pallet_post_history::rpc::FlatPostHistoryRecord;
 use pallet_posts::rpc::{FlatPost, FlatPostKind, RepliesByPostId};
+use pallet_profile_history::rpc::FlatProfileHistoryRecord;
 use pallet_profiles::rpc::FlatSocialAccount;
 use pallet_reactions::{
     ReactionId,
     ReactionKind,
+    ReactionTarget,
     rpc::FlatReaction,
 };
+use pallet_roles::RoleId;
+use pallet_space_history::rpc::FlatSpaceHistoryRecord;
+use pallet_space_ownership::rpc::FlatPendingOwnershipTransfer;
 use pallet_spaces::rpc::FlatSpace;
-use pallet_utils::{SpaceId, PostId, DEFAULT_MIN_HANDLE_LEN, DEFAULT_MAX_HANDLE_LEN};
+use pallet_utils::{SpaceId, PostId, User, DEFAULT_MIN_HANDLE_LEN, DEFAULT_MAX_HANDLE_LEN, rpc::FlatStorageDepositQuote};
 
 pub mod constants;
 use constants::{currency::*, time::*};
@@ -99,10 +106,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("subsocial"),
 	impl_name: create_runtime_str!("dappforce-subsocial"),
 	authoring_version: 0,
-	spec_version: 19,
+	spec_version: 21,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 3,
+	transaction_version: 4,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -166,7 +173,7 @@ const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO
 
 impl frame_system::Config for Runtime {
     /// The basic call filter to use in dispatchable.
-    type BaseCallFilter = BaseFilter;
+    type BaseCallFilter = pallet_call_filter::ExcludePausedCalls<Runtime>;
     /// Block & extrinsics weights: base values and limits.
     type BlockWeights = RuntimeBlockWeights;
     /// The maximum length of a block (in bytes).
@@ -286,7 +293,7 @@ parameter_types! {
 }
 
 impl pallet_transaction_payment::Config for Runtime {
-    type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
+    type OnChargeTransaction = pallet_energy::EnergyFeeAdapter<Runtime, CurrencyAdapter<Balances, DealWithFees>>;
     type TransactionByteFee = TransactionByteFee;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type WeightToFee = IdentityFee<Balance>;
@@ -328,13 +335,47 @@ impl pallet_randomness_collective_flip::Config for Runtime {}
 parameter_types! {
   pub const MinHandleLen: u32 = DEFAULT_MIN_HANDLE_LEN;
   pub const MaxHandleLen: u32 = DEFAULT_MAX_HANDLE_LEN;
+  pub const PremiumHandleLen: u32 = 8;
+  pub const HandleRegistrationPeriod: Option<BlockNumber> = Some(365 * DAYS);
+  pub const HandleGracePeriod: BlockNumber = 30 * DAYS;
+  pub const MaxHandlesToReleasePerBlock: u32 = 100;
+  pub const MaxRawContentLen: u32 = 1024;
+  pub const StorageDepositPerItem: Balance = 10 * CENTS;
+  pub const StorageDepositPerByte: Balance = deposit(0, 1);
+  pub const MaxItemsToMigratePerBlock: u32 = 500;
 }
 
+/// The `Vec` indexes that are moved to double maps by `MoveIdIndexesToDoubleMapsMigration`.
+/// The calls of their pallets must stay paused until `pallet_utils::VecMapMigrationCursors` is empty.
+// TODO delete these migrations after they're finished on the chain.
+type MoveIdIndexesToDoubleMaps = (
+	pallet_utils::MoveVecMapToDoubleMap<pallet_posts::ReplyIdsByPostId<Runtime>, PostId, PostId>,
+	pallet_utils::MoveVecMapToDoubleMap<pallet_posts::PostIdsBySpaceId<Runtime>, SpaceId, PostId>,
+	pallet_utils::MoveVecMapToDoubleMap<pallet_spaces::SpaceIdsByOwner<Runtime>, AccountId, SpaceId>,
+	pallet_utils::MoveVecMapToDoubleMap<pallet_space_follows::SpaceFollowers<Runtime>, SpaceId, AccountId>,
+	pallet_utils::MoveVecMapToDoubleMap<pallet_profile_follows::AccountFollowers<Runtime>, AccountId, AccountId>,
+	pallet_utils::MoveVecMapToDoubleMap<
+		pallet_roles::UsersByRoleId<Runtime>,
+		RoleId,
+		User<AccountId>,
+		pallet_roles::migrations::CountUsersMovedToDoubleMap<Runtime>,
+	>,
+);
+
 impl pallet_utils::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type MinHandleLen = MinHandleLen;
 	type MaxHandleLen = MaxHandleLen;
+	type PremiumHandleLen = PremiumHandleLen;
+	type HandleRegistrationPeriod = HandleRegistrationPeriod;
+	type HandleGracePeriod = HandleGracePeriod;
+	type MaxHandlesToReleasePerBlock = MaxHandlesToReleasePerBlock;
+	type MaxRawContentLen = MaxRawContentLen;
+	type StorageDepositPerItem = StorageDepositPerItem;
+	type StorageDepositPerByte = StorageDepositPerByte;
+	type MaxItemsToMigratePerBlock = MaxItemsToMigratePerBlock;
+	type MultiBlockMigrations = MoveIdIndexesToDoubleMaps;
 }
 
 use pallet_permissions::default_permissions::DefaultSpacePermissions;
@@ -349,68 +390,150 @@ parameter_types! {
 
 impl pallet_posts::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_posts::weights::SubstrateWeight<Runtime>;
 	type MaxCommentDepth = MaxCommentDepth;
 	type AfterPostUpdated = PostHistory;
-	type IsPostBlocked = ()/*Moderation*/;
+	type AfterPostDeleted = (Reactions, PostHistory);
+	type PostScores = Scores;
+	type IsPostBlocked = Moderation;
 }
 
-impl pallet_post_history::Config for Runtime {}
+parameter_types! {
+	pub const MaxEditHistoryRecords: u32 = 100;
+}
+
+impl pallet_post_history::Config for Runtime {
+	type MaxHistoryRecords = MaxEditHistoryRecords;
+}
 
 impl pallet_profile_follows::Config for Runtime {
 	type Event = Event;
-	type BeforeAccountFollowed = ();
-	type BeforeAccountUnfollowed = ();
+	type WeightInfo = pallet_profile_follows::weights::SubstrateWeight<Runtime>;
+	type BeforeAccountFollowed = Scores;
+	type BeforeAccountUnfollowed = Scores;
 }
 
 impl pallet_profiles::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_profiles::weights::SubstrateWeight<Runtime>;
 	type AfterProfileUpdated = ProfileHistory;
+	type Currency = Balances;
+	type SpaceHandles = Spaces;
+	type HandleDeposit = HandleDeposit;
+}
+
+impl pallet_profile_history::Config for Runtime {
+	type MaxHistoryRecords = MaxEditHistoryRecords;
 }
 
-impl pallet_profile_history::Config for Runtime {}
+parameter_types! {
+	pub const MaxCustomReactionKinds: u32 = 20;
+	pub const MaxCustomReactionKindLen: u32 = 32;
+}
 
 impl pallet_reactions::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_reactions::weights::SubstrateWeight<Runtime>;
+	type MaxCustomReactionKinds = MaxCustomReactionKinds;
+	type MaxCustomReactionKindLen = MaxCustomReactionKindLen;
+	type ReactionScores = Scores;
 }
 
 parameter_types! {
   pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+  pub const MaxSpaceRoleHoldersPerSpace: u32 = 20;
+  pub const MaxExpiredGrantsToProcessPerBlock: u32 = 100;
 }
 
 impl pallet_roles::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_roles::weights::SubstrateWeight<Runtime>;
 	type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+	type MaxSpaceRoleHoldersPerSpace = MaxSpaceRoleHoldersPerSpace;
+	type MaxExpiredGrantsToProcessPerBlock = MaxExpiredGrantsToProcessPerBlock;
 	type Spaces = Spaces;
 	type SpaceFollows = SpaceFollows;
-	type IsAccountBlocked = ()/*Moderation*/;
-	type IsContentBlocked = ()/*Moderation*/;
+	type IsAccountBlocked = Moderation;
+	type IsContentBlocked = Moderation;
+}
+
+parameter_types! {
+	pub const FollowSpaceActionWeight: i16 = 7;
+	pub const FollowAccountActionWeight: i16 = 3;
+
+	pub const SharePostActionWeight: i16 = 7;
+	pub const UpvotePostActionWeight: i16 = 5;
+	pub const DownvotePostActionWeight: i16 = -3;
+
+	pub const CreateCommentActionWeight: i16 = 5;
+	pub const ShareCommentActionWeight: i16 = 5;
+	pub const UpvoteCommentActionWeight: i16 = 4;
+	pub const DownvoteCommentActionWeight: i16 = -2;
+}
+
+impl pallet_scores::Config for Runtime {
+	type Event = Event;
+
+	type FollowSpaceActionWeight = FollowSpaceActionWeight;
+	type FollowAccountActionWeight = FollowAccountActionWeight;
+
+	type SharePostActionWeight = SharePostActionWeight;
+	type UpvotePostActionWeight = UpvotePostActionWeight;
+	type DownvotePostActionWeight = DownvotePostActionWeight;
+
+	type CreateCommentActionWeight = CreateCommentActionWeight;
+	type ShareCommentActionWeight = ShareCommentActionWeight;
+	type UpvoteCommentActionWeight = UpvoteCommentActionWeight;
+	type DownvoteCommentActionWeight = DownvoteCommentActionWeight;
 }
 
 impl pallet_space_follows::Config for Runtime {
 	type Event = Event;
-	type BeforeSpaceFollowed = ();
-	type BeforeSpaceUnfollowed = ();
+	type WeightInfo = pallet_space_follows::weights::SubstrateWeight<Runtime>;
+	type BeforeSpaceFollowed = Scores;
+	type BeforeSpaceUnfollowed = Scores;
+}
+
+parameter_types! {
+	pub const TransferExpirationPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxExpiredTransfersToProcessPerBlock: u32 = 100;
 }
 
 impl pallet_space_ownership::Config for Runtime {
 	type Event = Event;
+	type TransferExpirationPeriod = TransferExpirationPeriod;
+	type MaxExpiredTransfersToProcessPerBlock = MaxExpiredTransfersToProcessPerBlock;
+	type WeightInfo = pallet_space_ownership::weights::SubstrateWeight<Runtime>;
 }
 
-// TODO: do not change until we save a handle deposit into a storage per every handle.
 parameter_types! {
 	pub HandleDeposit: Balance = 5 * DOLLARS;
 }
 
+parameter_types! {
+	pub const MaxSubspaceDepth: u32 = 10;
+	pub const MaxSubspacesToMove: u32 = 100;
+	pub const MaxSpaceOwners: u16 = 20;
+	pub const MaxPendingSpaceOwnersActions: u32 = 10;
+}
+
 impl pallet_spaces::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_spaces::weights::SubstrateWeight<Runtime>;
 	type Currency = Balances;
 	type Roles = Roles;
 	type SpaceFollows = SpaceFollows;
 	type BeforeSpaceCreated = SpaceFollows;
 	type AfterSpaceUpdated = SpaceHistory;
-	type IsAccountBlocked = ()/*Moderation*/;
-	type IsContentBlocked = ()/*Moderation*/;
+	type BeforeSpaceDeleted = (Posts, Reactions, Roles, SpaceFollows, SpaceOwnership);
+	type IsAccountBlocked = Moderation;
+	type IsContentBlocked = Moderation;
+	type ProfileHandles = Profiles;
 	type HandleDeposit = HandleDeposit;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type MaxSubspacesToMove = MaxSubspacesToMove;
+	type MaxSpaceOwners = MaxSpaceOwners;
+	type MaxPendingSpaceOwnersActions = MaxPendingSpaceOwnersActions;
 }
 
 parameter_types! {
@@ -425,46 +548,121 @@ impl pallet_dotsama_claims::Config for Runtime {
     type WeightInfo = pallet_dotsama_claims::weights::SubstrateWeight<Runtime>;
 }
 
-impl pallet_space_history::Config for Runtime {}
-
-pub struct BaseFilter;
-impl Contains<Call> for BaseFilter {
-    fn contains(c: &Call) -> bool {
-        let is_set_balance = matches!(c, Call::Balances(pallet_balances::Call::set_balance { .. }));
-        let is_force_transfer = matches!(c, Call::Balances(pallet_balances::Call::force_transfer { .. }));
-
-        let is_social_call =
-            matches!(c,
-                Call::Permissions(..) |
-				Call::Posts(..) |
-				Call::ProfileFollows(..) |
-				Call::Profiles(..) |
-				Call::Reactions(..) |
-				Call::Roles(..) |
-				Call::SpaceFollows(..) |
-				Call::SpaceOwnership(..) |
-				Call::Spaces(..)
-			);
+/// Calls whose fees can be paid with energy.
+pub struct SocialCalls;
+impl Contains<Call> for SocialCalls {
+    fn contains(call: &Call) -> bool {
+        match call {
+            Call::Posts(..) | Call::Reactions(..) | Call::SpaceFollows(..) | Call::ProfileFollows(..) => true,
+            // Social calls made with a session key on behalf of its owner,
+            // that are paid with the energy of the owner, see `SessionKeyOwnerPaysEnergy`.
+            Call::SessionKeys(pallet_session_keys::Call::proxy { call }) => Self::contains(call),
+            _ => false,
+        }
+    }
+}
 
-        match *c {
-            Call::Balances(..) => is_set_balance || is_force_transfer,
-            _ if is_social_call => false,
-            _ => true,
+/// A call that a session key makes on behalf of its owner is paid with the energy of the owner,
+/// as a fresh session key has no reputation and so no energy.
+pub struct SessionKeyOwnerPaysEnergy;
+impl pallet_energy::EnergyPayer<AccountId, Call> for SessionKeyOwnerPaysEnergy {
+    fn energy_payer(signer: &AccountId, call: &Call) -> AccountId {
+        if let Call::SessionKeys(pallet_session_keys::Call::proxy { call }) = call {
+            if let Some(owner) = SessionKeys::owner_for_call(signer, call) {
+                return owner;
+            }
         }
+        signer.clone()
     }
 }
 
-/*parameter_types! {
+parameter_types! {
+    pub const BaseEnergyCapacity: Balance = DOLLARS;
+    pub const EnergyPerReputationPoint: Balance = 10 * CENTS;
+    pub const EnergyRegenerationPeriod: BlockNumber = DAYS;
+}
+
+impl pallet_energy::Config for Runtime {
+    type SocialCalls = SocialCalls;
+    type EnergyPayer = SessionKeyOwnerPaysEnergy;
+    type Reputation = Profiles;
+    type BaseEnergyCapacity = BaseEnergyCapacity;
+    type EnergyPerReputationPoint = EnergyPerReputationPoint;
+    type EnergyRegenerationPeriod = EnergyRegenerationPeriod;
+}
+
+parameter_types! {
+    pub const MaxSessionKeysPerAccount: u16 = 10;
+    pub const MaxScopesPerSessionKey: u16 = 20;
+    pub const MaxSessionKeyTimeToLive: BlockNumber = 90 * DAYS;
+}
+
+impl pallet_session_keys::Config for Runtime {
+    type Event = Event;
+    type Call = Call;
+    type MaxSessionKeysPerAccount = MaxSessionKeysPerAccount;
+    type MaxScopesPerSessionKey = MaxScopesPerSessionKey;
+    type MaxSessionKeyTimeToLive = MaxSessionKeyTimeToLive;
+    type WeightInfo = pallet_session_keys::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_space_history::Config for Runtime {
+	type MaxHistoryRecords = MaxEditHistoryRecords;
+}
+
+parameter_types! {
+    pub UnpausablePallets: Vec<Vec<u8>> = vec![
+        b"System".to_vec(),
+        b"Timestamp".to_vec(),
+        b"Sudo".to_vec(),
+    ];
+}
+
+impl pallet_call_filter::Config for Runtime {
+    type Event = Event;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type UnpausablePallets = UnpausablePallets;
+    type WeightInfo = pallet_call_filter::weights::SubstrateWeight<Runtime>;
+}
+
+/// Pallets that are paused until social features are launched on the chain.
+pub fn initially_paused_pallets() -> Vec<Vec<u8>> {
+    [
+        "Moderation",
+        "Permissions",
+        "Posts",
+        "ProfileFollows",
+        "Profiles",
+        "Reactions",
+        "Roles",
+        "SpaceFollows",
+        "SpaceOwnership",
+        "Spaces",
+    ].iter().map(|name| name.as_bytes().to_vec()).collect()
+}
+
+/// Balances calls that are paused, so that only `set_balance` and `force_transfer` are allowed.
+pub fn initially_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)> {
+    [
+        "transfer",
+        "transfer_keep_alive",
+        "transfer_all",
+        "force_unreserve",
+    ].iter().map(|call| (b"Balances".to_vec(), call.as_bytes().to_vec())).collect()
+}
+
+parameter_types! {
     pub const DefaultAutoblockThreshold: u16 = 20;
 }
 
 impl pallet_moderation::Config for Runtime {
     type Event = Event;
     type DefaultAutoblockThreshold = DefaultAutoblockThreshold;
-}*/
+}
 
 impl pallet_faucets::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_faucets::weights::SubstrateWeight<Runtime>;
 	type Currency = Balances;
 }
 
@@ -495,17 +693,22 @@ construct_runtime!(
 		ProfileHistory: pallet_profile_history::{Pallet, Storage},
 		Reactions: pallet_reactions::{Pallet, Call, Storage, Event<T>},
 		Roles: pallet_roles::{Pallet, Call, Storage, Event<T>},
+		Scores: pallet_scores::{Pallet, Storage, Event<T>},
 		SpaceFollows: pallet_space_follows::{Pallet, Call, Storage, Event<T>},
 		SpaceHistory: pallet_space_history::{Pallet, Storage},
 		SpaceOwnership: pallet_space_ownership::{Pallet, Call, Storage, Event<T>},
 		Spaces: pallet_spaces::{Pallet, Call, Storage, Event<T>, Config<T>},
-		Utils: pallet_utils::{Pallet, Storage, Event<T>, Config<T>},
+		Utils: pallet_utils::{Pallet, Call, Storage, Event<T>, Config<T>},
 
 		// New experimental pallets. Not recommended to use in production yet.
 
 		Faucets: pallet_faucets::{Pallet, Call, Storage, Event<T>},
 		DotsamaClaims: pallet_dotsama_claims::{Pallet, Call, Storage, Event<T>},
-		// Moderation: pallet_moderation::{Pallet, Call, Storage, Event<T>},
+		Moderation: pallet_moderation::{Pallet, Call, Storage, Event<T>},
+		Energy: pallet_energy::{Pallet, Storage},
+		SessionKeys: pallet_session_keys::{Pallet, Call, Storage, Event<T>},
+
+		CallFilter: pallet_call_filter::{Pallet, Call, Storage, Event<T>, Config},
     }
 );
 
@@ -527,6 +730,7 @@ pub type SignedExtra = (
     frame_system::CheckWeight<Runtime>,
     pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
     pallet_dotsama_claims::EnsureAllowedToClaimTokens<Runtime>,
+    pallet_energy::ChargeEnergy<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -539,7 +743,21 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPallets,
-    (MigratePalletVersionToStorageVersion, GrandpaStoragePrefixMigration),
+    (
+        MoveStorageToPalletPrefixesMigration,
+        PauseCallsOfBaseFilterMigration,
+        PutInitialStorageVersionsMigration,
+        GrandpaStoragePrefixMigration,
+        AddPermissionsToPostsMigration,
+        MovePostReactionsMigration,
+        MoveIdIndexesToDoubleMapsMigration,
+        BuildFeedIndexesMigration,
+        AddExpirationToPendingTransfersMigration,
+        AddHandlesToProfilesMigration,
+        AddRegistrationsToSpaceHandlesMigration,
+        RekeySpaceHandlesMigration,
+        ScheduleExpiringRolesMigration,
+    ),
 >;
 
 pub struct GrandpaStoragePrefixMigration;
@@ -567,14 +785,215 @@ impl frame_support::traits::OnRuntimeUpgrade for GrandpaStoragePrefixMigration {
     }
 }
 
-/// Migrate from `PalletVersion` to the new `StorageVersion`
-pub struct MigratePalletVersionToStorageVersion;
+/// Pause the calls that were disabled by the hard-coded `BaseFilter`, which is replaced
+/// with the `CallFilter` pallet. Runs only once, as it puts the first storage version of `CallFilter`.
+// TODO delete this migration after it's executed on the chain.
+pub struct PauseCallsOfBaseFilterMigration;
+
+impl OnRuntimeUpgrade for PauseCallsOfBaseFilterMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+        if CallFilter::on_chain_storage_version() != StorageVersion::new(0) {
+            return RocksDbWeight::get().reads(1);
+        }
+
+        let weight = CallFilter::pause_initial_calls(&initially_paused_pallets(), &initially_paused_calls());
+        StorageVersion::new(1).put::<CallFilter>();
+
+        weight.saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+    }
+}
+
+/// Move the storage of Subsocial pallets from their old `decl_storage` prefixes
+/// to the pallet names used in `construct_runtime!`.
+// TODO delete this migration after it's executed on the chain.
+pub struct MoveStorageToPalletPrefixesMigration;
+
+impl OnRuntimeUpgrade for MoveStorageToPalletPrefixesMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_utils::move_storage_from_old_pallet_prefix::<Utils>("UtilsModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<Spaces>("SpacesModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<Posts>("PostsModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<Roles>("PermissionsModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<Reactions>("ReactionsModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<SpaceFollows>("SpaceFollowsModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<ProfileFollows>("ProfileFollowsModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<Profiles>("ProfilesModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<Faucets>("FaucetsModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<PostHistory>("PostHistoryModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<ProfileHistory>("ProfileHistoryModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<SpaceHistory>("SpaceHistoryModule");
+        pallet_utils::move_storage_from_old_pallet_prefix::<SpaceOwnership>("SpaceOwnershipModule");
+
+        RuntimeBlockWeights::get().max_block
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<Utils>("UtilsModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<Spaces>("SpacesModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<Posts>("PostsModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<Roles>("PermissionsModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<Reactions>("ReactionsModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<SpaceFollows>("SpaceFollowsModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<ProfileFollows>("ProfileFollowsModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<Profiles>("ProfilesModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<Faucets>("FaucetsModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<PostHistory>("PostHistoryModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<ProfileHistory>("ProfileHistoryModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<SpaceHistory>("SpaceHistoryModule")?;
+        pallet_utils::ensure_old_pallet_prefix_is_empty::<SpaceOwnership>("SpaceOwnershipModule")?;
+
+        Ok(())
+    }
+}
+
+/// Put the first storage version of Subsocial pallets, that didn't have one with `decl_storage`.
+// TODO delete this migration after it's executed on the chain.
+pub struct PutInitialStorageVersionsMigration;
+
+impl OnRuntimeUpgrade for PutInitialStorageVersionsMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        let versions_put = [
+            pallet_utils::put_initial_storage_version::<Utils>(),
+            pallet_utils::put_initial_storage_version::<Spaces>(),
+            pallet_utils::put_initial_storage_version::<Posts>(),
+            pallet_utils::put_initial_storage_version::<Roles>(),
+            pallet_utils::put_initial_storage_version::<Permissions>(),
+            pallet_utils::put_initial_storage_version::<Reactions>(),
+            pallet_utils::put_initial_storage_version::<SpaceFollows>(),
+            pallet_utils::put_initial_storage_version::<ProfileFollows>(),
+            pallet_utils::put_initial_storage_version::<Profiles>(),
+            pallet_utils::put_initial_storage_version::<Faucets>(),
+            pallet_utils::put_initial_storage_version::<Moderation>(),
+            pallet_utils::put_initial_storage_version::<PostHistory>(),
+            pallet_utils::put_initial_storage_version::<ProfileHistory>(),
+            pallet_utils::put_initial_storage_version::<SpaceHistory>(),
+            pallet_utils::put_initial_storage_version::<SpaceOwnership>(),
+            pallet_utils::put_initial_storage_version::<Scores>(),
+        ];
+
+        let writes = versions_put.iter().filter(|put| **put).count() as Weight;
+        RocksDbWeight::get().reads_writes(versions_put.len() as Weight, writes)
+    }
+
+    /// The migrations that follow are guarded by storage versions, so they run only if
+    /// the pallets start from no version, that is put as version 1 here.
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<(), &'static str> {
+        pallet_utils::ensure_no_storage_version::<Utils>()?;
+        pallet_utils::ensure_no_storage_version::<Spaces>()?;
+        pallet_utils::ensure_no_storage_version::<Posts>()?;
+        pallet_utils::ensure_no_storage_version::<Roles>()?;
+        pallet_utils::ensure_no_storage_version::<Permissions>()?;
+        pallet_utils::ensure_no_storage_version::<Reactions>()?;
+        pallet_utils::ensure_no_storage_version::<SpaceFollows>()?;
+        pallet_utils::ensure_no_storage_version::<ProfileFollows>()?;
+        pallet_utils::ensure_no_storage_version::<Profiles>()?;
+        pallet_utils::ensure_no_storage_version::<Faucets>()?;
+        pallet_utils::ensure_no_storage_version::<Moderation>()?;
+        pallet_utils::ensure_no_storage_version::<PostHistory>()?;
+        pallet_utils::ensure_no_storage_version::<ProfileHistory>()?;
+        pallet_utils::ensure_no_storage_version::<SpaceHistory>()?;
+        pallet_utils::ensure_no_storage_version::<SpaceOwnership>()?;
+        pallet_utils::ensure_no_storage_version::<Scores>()?;
+        pallet_utils::ensure_no_storage_version::<CallFilter>()
+    }
+}
+
+/// Add the `permissions` field to all existing posts.
+// TODO delete this migration after it's executed on the chain.
+pub struct AddPermissionsToPostsMigration;
+
+impl OnRuntimeUpgrade for AddPermissionsToPostsMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_posts::migrations::add_permissions_to_posts::<Runtime>()
+    }
+}
+
+/// Move post reactions to the storages that are shared by all kinds of reaction targets.
+// TODO delete this migration after it's executed on the chain.
+pub struct MovePostReactionsMigration;
+
+impl OnRuntimeUpgrade for MovePostReactionsMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_reactions::migrations::move_post_reactions_to_generic_storages::<Runtime>()
+    }
+}
+
+/// Start moving the unbounded `Vec` indexes of posts, spaces, follows and roles to double maps.
+/// They are moved over several blocks by `MoveIdIndexesToDoubleMaps` of `pallet_utils`.
+// TODO delete this migration after it's executed on the chain.
+pub struct MoveIdIndexesToDoubleMapsMigration;
+
+impl OnRuntimeUpgrade for MoveIdIndexesToDoubleMapsMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_posts::migrations::move_post_indexes_to_double_maps::<Runtime>()
+            .saturating_add(pallet_spaces::migrations::move_space_ids_by_owner_to_double_map::<Runtime>())
+            .saturating_add(pallet_space_follows::migrations::move_space_followers_to_double_map::<Runtime>())
+            .saturating_add(pallet_profile_follows::migrations::move_account_followers_to_double_map::<Runtime>())
+            .saturating_add(pallet_roles::migrations::move_users_by_role_id_to_double_map::<Runtime>())
+    }
+}
+
+/// Fill the feed indexes of spaces and accounts with the existing root posts.
+// TODO delete this migration after it's executed on the chain.
+pub struct BuildFeedIndexesMigration;
+
+impl OnRuntimeUpgrade for BuildFeedIndexesMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_posts::migrations::build_feed_indexes::<Runtime>()
+    }
+}
+
+// TODO delete this migration after it's executed on the chain.
+pub struct AddExpirationToPendingTransfersMigration;
+
+impl OnRuntimeUpgrade for AddExpirationToPendingTransfersMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_space_ownership::migrations::add_expiration_to_pending_transfers::<Runtime>()
+    }
+}
+
+/// Add the `handle` field to stored profiles and to their edit history records.
+// TODO delete this migration after it's executed on the chain.
+pub struct AddHandlesToProfilesMigration;
 
-impl OnRuntimeUpgrade for MigratePalletVersionToStorageVersion {
+impl OnRuntimeUpgrade for AddHandlesToProfilesMigration {
     fn on_runtime_upgrade() -> frame_support::weights::Weight {
-        frame_support::migrations::migrate_from_pallet_version_to_storage_version::<AllPalletsWithSystem>(
-            &RocksDbWeight::get()
-        )
+        pallet_profiles::migrations::add_handles_to_profiles::<Runtime>()
+            .saturating_add(pallet_profile_history::migrations::add_handles_to_history_records::<Runtime>())
+    }
+}
+
+/// Save the deposits of the existing space handles, as deposits now depend on the handle length.
+// TODO delete this migration after it's executed on the chain.
+pub struct AddRegistrationsToSpaceHandlesMigration;
+
+impl OnRuntimeUpgrade for AddRegistrationsToSpaceHandlesMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_spaces::migrations::add_registrations_to_space_handles::<Runtime>()
+    }
+}
+
+/// Store ASCII space handles under their skeletons, the same as Unicode ones.
+// TODO delete this migration after it's executed on the chain.
+pub struct RekeySpaceHandlesMigration;
+
+impl OnRuntimeUpgrade for RekeySpaceHandlesMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_spaces::migrations::rekey_space_handles_by_skeletons::<Runtime>()
+    }
+}
+
+/// Schedule the deletion of the roles that were created with an expiration before it was enforced.
+// TODO delete this migration after it's executed on the chain.
+pub struct ScheduleExpiringRolesMigration;
+
+impl OnRuntimeUpgrade for ScheduleExpiringRolesMigration {
+    fn on_runtime_upgrade() -> frame_support::weights::Weight {
+        pallet_roles::migrations::schedule_expiring_roles::<Runtime>()
     }
 }
 
@@ -709,6 +1128,14 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade() -> Result<(Weight, Weight), sp_runtime::RuntimeString> {
+			let weight = Executive::try_runtime_upgrade()?;
+			Ok((weight, RuntimeBlockWeights::get().max_block))
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -725,19 +1152,22 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
 
+			list_benchmark!(list, extra, pallet_call_filter, CallFilter);
 			list_benchmark!(list, extra, pallet_dotsama_claims, DotsamaClaims);
-			// list_benchmark!(list, extra, pallet_faucets, Faucets);
-			// list_benchmark!(list, extra, pallet_posts, Posts);
-			// list_benchmark!(list, extra, pallet_profile_follows, DotsamaClaims);
-			// list_benchmark!(list, extra, pallet_profiles, Profiles);
-			// list_benchmark!(list, extra, pallet_reactions, Reactions);
-			// list_benchmark!(list, extra, pallet_roles, Roles);
-			// list_benchmark!(list, extra, pallet_space_follows, SpaceFollows);
-			// list_benchmark!(list, extra, pallet_space_ownership, SpaceOwnership);
-			// list_benchmark!(list, extra, pallet_spaces, Spaces);
+			list_benchmark!(list, extra, pallet_faucets, Faucets);
+			list_benchmark!(list, extra, pallet_posts, Posts);
+			list_benchmark!(list, extra, pallet_profile_follows, ProfileFollows);
+			list_benchmark!(list, extra, pallet_profiles, Profiles);
+			list_benchmark!(list, extra, pallet_reactions, Reactions);
+			list_benchmark!(list, extra, pallet_roles, Roles);
+			list_benchmark!(list, extra, pallet_session_keys, SessionKeys);
+			list_benchmark!(list, extra, pallet_space_follows, SpaceFollows);
+			list_benchmark!(list, extra, pallet_space_ownership, SpaceOwnership);
+			list_benchmark!(list, extra, pallet_spaces, Spaces);
 
 			// let storage_info = AllPalletsWithSystem::storage_info();
             let mut storage_info = DotsamaClaims::storage_info();
+            storage_info.append(&mut CallFilter::storage_info());
             storage_info.append(&mut Faucets::storage_info());
             storage_info.append(&mut Utils::storage_info());
             storage_info.append(&mut Spaces::storage_info());
@@ -745,6 +1175,7 @@ impl_runtime_apis! {
             storage_info.append(&mut SpaceHistory::storage_info());
             storage_info.append(&mut SpaceFollows::storage_info());
             storage_info.append(&mut Roles::storage_info());
+            storage_info.append(&mut Scores::storage_info());
             storage_info.append(&mut Reactions::storage_info());
             storage_info.append(&mut ProfileHistory::storage_info());
             storage_info.append(&mut Profiles::storage_info());
@@ -792,7 +1223,18 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, pallet_call_filter, CallFilter);
 			add_benchmark!(params, batches, pallet_dotsama_claims, DotsamaClaims);
+			add_benchmark!(params, batches, pallet_faucets, Faucets);
+			add_benchmark!(params, batches, pallet_posts, Posts);
+			add_benchmark!(params, batches, pallet_profile_follows, ProfileFollows);
+			add_benchmark!(params, batches, pallet_profiles, Profiles);
+			add_benchmark!(params, batches, pallet_reactions, Reactions);
+			add_benchmark!(params, batches, pallet_roles, Roles);
+			add_benchmark!(params, batches, pallet_session_keys, SessionKeys);
+			add_benchmark!(params, batches, pallet_space_follows, SpaceFollows);
+			add_benchmark!(params, batches, pallet_space_ownership, SpaceOwnership);
+			add_benchmark!(params, batches, pallet_spaces, Spaces);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -808,6 +1250,10 @@ impl_runtime_apis! {
     	fn filter_followed_space_ids(account: AccountId, space_ids: Vec<SpaceId>) -> Vec<SpaceId> {
     		SpaceFollows::filter_followed_space_ids(account, space_ids)
     	}
+
+    	fn get_space_followers_page(space_id: SpaceId, cursor: Option<AccountId>, limit: u16) -> Vec<AccountId> {
+    		SpaceFollows::get_space_followers_page(space_id, cursor, limit)
+    	}
     }
 
 	impl spaces_runtime_api::SpacesApi<Block, AccountId, BlockNumber> for Runtime
@@ -844,6 +1290,18 @@ impl_runtime_apis! {
         	Spaces::get_unlisted_space_ids_by_owner(owner)
         }
 
+        fn get_space_ids_by_owner_page(owner: AccountId, cursor: Option<SpaceId>, limit: u16) -> Vec<SpaceId> {
+        	Spaces::get_space_ids_by_owner_page(owner, cursor, limit)
+        }
+
+        fn get_subspace_ids(space_id: SpaceId) -> Vec<SpaceId> {
+        	Spaces::get_subspace_ids(space_id)
+        }
+
+        fn get_space_ancestor_ids(space_id: SpaceId) -> Vec<SpaceId> {
+        	Spaces::get_space_ancestor_ids(space_id)
+        }
+
         fn get_next_space_id() -> SpaceId {
         	Spaces::get_next_space_id()
         }
@@ -879,6 +1337,10 @@ impl_runtime_apis! {
 			Posts::get_replies_by_parent_id(parent_id, offset, limit)
 		}
 
+		fn get_reply_ids_by_parent_id_page(parent_id: PostId, cursor: Option<PostId>, limit: u16) -> Vec<PostId> {
+			Posts::get_reply_ids_by_parent_id_page(parent_id, cursor, limit)
+		}
+
 		fn get_replies_by_parent_ids(parent_ids: Vec<PostId>, offset: u64, limit: u16) -> RepliesByPostId<AccountId, BlockNumber> {
 			Posts::get_replies_by_parent_ids(parent_ids, offset, limit)
 		}
@@ -891,6 +1353,10 @@ impl_runtime_apis! {
 			Posts::get_unlisted_post_ids_by_space_id(space_id)
 		}
 
+		fn get_post_ids_by_space_id_page(space_id: SpaceId, cursor: Option<PostId>, limit: u16) -> Vec<PostId> {
+			Posts::get_post_ids_by_space_id_page(space_id, cursor, limit)
+		}
+
 		fn get_next_post_id() -> PostId {
 			Posts::get_next_post_id()
 		}
@@ -898,13 +1364,51 @@ impl_runtime_apis! {
 		fn get_feed(account: AccountId, offset: u64, limit: u16) -> Vec<FlatPost<AccountId, BlockNumber>> {
 			Posts::get_feed(account, offset, limit)
 		}
+
+		fn get_feed_page(
+			account: AccountId,
+			kind_filter: Vec<FlatPostKind>,
+			cursor: Option<PostId>,
+			limit: u16
+		) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_feed_page(account, kind_filter, cursor, limit)
+		}
     }
 
+	impl post_history_runtime_api::PostHistoryApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_post_history(post_id: PostId, offset: u64, limit: u16) -> Vec<FlatPostHistoryRecord<AccountId, BlockNumber>> {
+			PostHistory::get_post_history(post_id, offset, limit)
+		}
+	}
+
+	impl space_history_runtime_api::SpaceHistoryApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_space_history(space_id: SpaceId, offset: u64, limit: u16) -> Vec<FlatSpaceHistoryRecord<AccountId, BlockNumber>> {
+			SpaceHistory::get_space_history(space_id, offset, limit)
+		}
+	}
+
+	impl space_ownership_runtime_api::SpaceOwnershipApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_incoming_ownership_transfers(
+			account: AccountId,
+			cursor: Option<SpaceId>,
+			limit: u16,
+		) -> Vec<FlatPendingOwnershipTransfer<AccountId, BlockNumber>> {
+			SpaceOwnership::get_incoming_ownership_transfers(account, cursor, limit)
+		}
+	}
+
 	impl profile_follows_runtime_api::ProfileFollowsApi<Block, AccountId> for Runtime
     {
     	fn filter_followed_accounts(account: AccountId, maybe_following: Vec<AccountId>) -> Vec<AccountId> {
     		ProfileFollows::filter_followed_accounts(account, maybe_following)
     	}
+
+    	fn get_account_followers_page(account: AccountId, cursor: Option<AccountId>, limit: u16) -> Vec<AccountId> {
+    		ProfileFollows::get_account_followers_page(account, cursor, limit)
+    	}
     }
 
 	impl profiles_runtime_api::ProfilesApi<Block, AccountId, BlockNumber> for Runtime
@@ -914,6 +1418,21 @@ impl_runtime_apis! {
         ) -> Vec<FlatSocialAccount<AccountId, BlockNumber>> {
         	Profiles::get_social_accounts_by_ids(account_ids)
         }
+
+		fn get_handle_owner(handle: Vec<u8>) -> Option<User<AccountId>> {
+			Profiles::get_handle_owner(handle)
+		}
+	}
+
+	impl profile_history_runtime_api::ProfileHistoryApi<Block, AccountId, BlockNumber> for Runtime
+	{
+		fn get_profile_history(
+			account: AccountId,
+			offset: u64,
+			limit: u16,
+		) -> Vec<FlatProfileHistoryRecord<AccountId, BlockNumber>> {
+			ProfileHistory::get_profile_history(account, offset, limit)
+		}
 	}
 
     impl reactions_runtime_api::ReactionsApi<Block, AccountId, BlockNumber> for Runtime
@@ -936,8 +1455,55 @@ impl_runtime_apis! {
 		) -> BTreeMap<PostId, ReactionKind> {
 			Reactions::get_reaction_kinds_by_post_ids_and_reactor(post_ids, reactor)
 		}
+
+		fn get_reactions_by_target(
+			target: ReactionTarget<AccountId>,
+			limit: u64,
+			offset: u64
+		) -> Vec<FlatReaction<AccountId, BlockNumber>> {
+			Reactions::get_reactions_by_target(target, limit, offset)
+		}
+
+		fn get_reaction_ids_by_target_page(
+			target: ReactionTarget<AccountId>,
+			cursor: Option<ReactionId>,
+			limit: u16
+		) -> Vec<ReactionId> {
+			Reactions::get_reaction_ids_by_target_page(target, cursor, limit)
+		}
+
+		fn get_reaction_counts_by_target(target: ReactionTarget<AccountId>) -> BTreeMap<ReactionKind, u32> {
+			Reactions::get_reaction_counts_by_target(target)
+		}
+
+		fn get_reaction_counts_by_post_ids(post_ids: Vec<PostId>) -> BTreeMap<PostId, BTreeMap<ReactionKind, u32>> {
+			Reactions::get_reaction_counts_by_post_ids(post_ids)
+		}
+
+		fn get_space_reaction_kinds(space_id: SpaceId) -> Vec<Vec<u8>> {
+			Reactions::get_space_reaction_kinds(space_id)
+		}
     }
 
+	impl call_filter_runtime_api::CallFilterApi<Block> for Runtime
+	{
+		fn get_paused_pallets() -> Vec<Vec<u8>> {
+			CallFilter::get_paused_pallets()
+		}
+
+		fn get_paused_calls() -> Vec<(Vec<u8>, Vec<u8>)> {
+			CallFilter::get_paused_calls()
+		}
+
+		fn get_paused_calls_of_pallet(pallet_name: Vec<u8>) -> Vec<Vec<u8>> {
+			CallFilter::get_paused_calls_of_pallet(pallet_name)
+		}
+
+		fn is_call_paused(pallet_name: Vec<u8>, call_name: Vec<u8>) -> bool {
+			CallFilter::is_call_paused(&pallet_name, &call_name)
+		}
+	}
+
 	impl roles_runtime_api::RolesApi<Block, AccountId> for Runtime
 	{
 		fn get_space_permissions_by_account(
@@ -954,5 +1520,24 @@ impl_runtime_apis! {
         fn get_space_ids_for_account_with_any_role(account_id: AccountId) -> Vec<SpaceId> {
 			Roles::get_space_ids_for_account_with_any_role(account_id)
         }
+
+		fn get_users_by_role_id_page(role_id: RoleId, cursor: Option<User<AccountId>>, limit: u16) -> Vec<User<AccountId>> {
+			Roles::get_users_by_role_id_page(role_id, cursor, limit)
+		}
+	}
+
+	impl utils_runtime_api::UtilsApi<Block, AccountId, Balance> for Runtime
+	{
+		fn quote_storage_deposit(account: AccountId, call: Vec<u8>) -> Option<FlatStorageDepositQuote<Balance>> {
+			let call = Call::decode(&mut &call[..]).ok()?;
+			pallet_utils::rpc::quote_storage_deposit::<Runtime, Call>(account, call)
+		}
+	}
+
+	impl energy_runtime_api::EnergyApi<Block, AccountId, Balance> for Runtime
+	{
+		fn get_remaining_energy(account: AccountId) -> Balance {
+			Energy::remaining_energy(&account)
+		}
 	}
 }
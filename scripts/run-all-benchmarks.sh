@@ -0,0 +1,13 @@
+#!/usr/bin/env bash
+
+set -e
+
+SCRIPT_DIR=$(dirname "$0")
+ROOT_DIR=$SCRIPT_DIR/..
+
+cargo build --release -p subsocial-node --features runtime-benchmarks
+
+for PALLET_DIR in call-filter dotsama-claims faucets posts profile-follows profiles reactions \
+  roles session-keys space-follows space-ownership spaces; do
+  "$SCRIPT_DIR"/run-benchmark-on.sh "pallet_${PALLET_DIR//-/_}" "$ROOT_DIR"/pallets/"$PALLET_DIR"/src
+done